@@ -0,0 +1,902 @@
+//! ARC-4 ABI types and value encoding, as used for application call arguments and return values.
+//!
+//! Types are parsed from their canonical string form (for example `(uint64,string[])`) and values
+//! are encoded with the ARC-4 head/tail layout, where dynamic values are referenced by a 2-byte
+//! offset in the head and consecutive booleans are packed into single bytes.
+
+use crate::Address;
+pub use num_bigint::BigUint;
+
+mod method;
+pub use method::{Method, MethodArg, MethodArgType, MethodArgValue, ReferenceType, RETURN_PREFIX};
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+const ADDRESS_BYTE_LENGTH: usize = 32;
+const LENGTH_PREFIX_BYTE_LENGTH: usize = 2;
+const MAX_UINT_BITS: u16 = 512;
+const MAX_UFIXED_PRECISION: u8 = 160;
+/// Like go-algorand, static array lengths are parsed as 16-bit integers
+const MAX_STATIC_ARRAY_LENGTH: usize = u16::MAX as usize;
+/// ARC-4 lengths and offsets are 16-bit, and no AVM value is longer, so types that cannot be
+/// encoded in fewer bytes are rejected
+const MAX_ENCODED_LENGTH: usize = u16::MAX as usize;
+/// How deeply arrays and tuples can nest in a type
+const MAX_NESTING_DEPTH: usize = 32;
+const BOOL_TRUE: u8 = 0x80;
+const BOOL_FALSE: u8 = 0x00;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum AbiError {
+    #[error("invalid ABI type: {0}")]
+    InvalidType(String),
+
+    #[error("ABI type {0} is too large to encode")]
+    TypeTooLarge(String),
+
+    #[error("invalid value for ABI type {abi_type}: {message}")]
+    InvalidValue { abi_type: String, message: String },
+
+    #[error("could not decode ABI type {abi_type}: {message}")]
+    DecodingError { abi_type: String, message: String },
+
+    #[error("invalid ARC-4 method signature: {0}")]
+    InvalidMethodSignature(String),
+
+    #[error("invalid arguments for method {method}: {message}")]
+    InvalidMethodArgs { method: String, message: String },
+
+    #[error("no return value found in the logs of method {0}")]
+    MissingReturnValue(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AbiType {
+    /// `uintN`, where N is the bit size
+    Uint(u16),
+
+    /// `ufixedNxM`, where N is the bit size and M is the decimal precision
+    Ufixed(u16, u8),
+
+    Byte,
+
+    Bool,
+
+    Address,
+
+    String,
+
+    /// `T[N]`
+    StaticArray(Box<AbiType>, usize),
+
+    /// `T[]`
+    DynamicArray(Box<AbiType>),
+
+    /// `(T1,T2,...,TN)`
+    Tuple(Vec<AbiType>),
+}
+
+/// A value that can be encoded as an ARC-4 ABI type
+#[derive(Debug, Clone, PartialEq)]
+pub enum AbiValue {
+    /// The value of a `uintN`, or the unscaled integer of a `ufixedNxM`
+    Uint(BigUint),
+
+    Bool(bool),
+
+    Byte(u8),
+
+    Address(Address),
+
+    String(String),
+
+    /// The elements of a static or dynamic array, or the fields of a tuple
+    Array(Vec<AbiValue>),
+}
+
+impl From<u64> for AbiValue {
+    fn from(value: u64) -> Self {
+        AbiValue::Uint(BigUint::from(value))
+    }
+}
+
+/// Parse a number in its canonical form, which is ASCII digits with no sign and no leading zero.
+/// Type strings go into method selectors, so other forms are rejected rather than normalized
+fn parse_canonical<T: FromStr>(digits: &str) -> Option<T> {
+    let canonical = !digits.is_empty()
+        && !digits.starts_with('0')
+        && digits.bytes().all(|b| b.is_ascii_digit());
+    canonical.then(|| digits.parse().ok()).flatten()
+}
+
+fn parse_bit_size(bits: &str, type_str: &str) -> Result<u16, AbiError> {
+    let invalid = || AbiError::InvalidType(type_str.to_string());
+    let bits: u16 = parse_canonical(bits).ok_or_else(invalid)?;
+
+    if bits == 0 || bits > MAX_UINT_BITS || bits % 8 != 0 {
+        return Err(invalid());
+    }
+
+    Ok(bits)
+}
+
+/// Split the contents of a tuple on its top-level commas
+fn split_tuple(contents: &str) -> Result<Vec<&str>, AbiError> {
+    if contents.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut parts = vec![];
+    let mut depth = 0i32;
+    let mut start = 0;
+
+    for (i, c) in contents.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&contents[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+
+        if depth < 0 {
+            return Err(AbiError::InvalidType(format!("({})", contents)));
+        }
+    }
+
+    if depth != 0 {
+        return Err(AbiError::InvalidType(format!("({})", contents)));
+    }
+
+    parts.push(&contents[start..]);
+    Ok(parts)
+}
+
+impl FromStr for AbiType {
+    type Err = AbiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_type(s, 0)
+    }
+}
+
+/// Parse a type nested `depth` arrays and tuples deep
+fn parse_type(s: &str, depth: usize) -> Result<AbiType, AbiError> {
+    let invalid = || AbiError::InvalidType(s.to_string());
+    if depth > MAX_NESTING_DEPTH {
+        return Err(invalid());
+    }
+
+    if let Some(element) = s.strip_suffix("[]") {
+        return Ok(AbiType::DynamicArray(Box::new(parse_type(
+            element,
+            depth + 1,
+        )?)));
+    }
+
+    if s.ends_with(']') {
+        let open = s.rfind('[').ok_or_else(invalid)?;
+        let length: usize = parse_canonical(&s[open + 1..s.len() - 1]).ok_or_else(invalid)?;
+        if length > MAX_STATIC_ARRAY_LENGTH {
+            return Err(invalid());
+        }
+
+        // Nested arrays can still be too large to encode, which is rejected up front
+        let array = AbiType::StaticArray(Box::new(parse_type(&s[..open], depth + 1)?), length);
+        array.min_length()?;
+        return Ok(array);
+    }
+
+    if let Some(contents) = s.strip_prefix('(').and_then(|s| s.strip_suffix(')')) {
+        let tuple = AbiType::Tuple(
+            split_tuple(contents)?
+                .into_iter()
+                .map(|field| parse_type(field, depth + 1))
+                .collect::<Result<_, _>>()?,
+        );
+        tuple.min_length()?;
+        return Ok(tuple);
+    }
+
+    if let Some(bits) = s.strip_prefix("uint") {
+        return Ok(AbiType::Uint(parse_bit_size(bits, s)?));
+    }
+
+    if let Some(rest) = s.strip_prefix("ufixed") {
+        let (bits, precision) = rest.split_once('x').ok_or_else(invalid)?;
+        let precision: u8 = parse_canonical(precision).ok_or_else(invalid)?;
+
+        if precision > MAX_UFIXED_PRECISION {
+            return Err(invalid());
+        }
+
+        return Ok(AbiType::Ufixed(parse_bit_size(bits, s)?, precision));
+    }
+
+    match s {
+        "byte" => Ok(AbiType::Byte),
+        "bool" => Ok(AbiType::Bool),
+        "address" => Ok(AbiType::Address),
+        "string" => Ok(AbiType::String),
+        _ => Err(invalid()),
+    }
+}
+
+impl fmt::Display for AbiType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AbiType::Uint(bits) => write!(f, "uint{}", bits),
+            AbiType::Ufixed(bits, precision) => write!(f, "ufixed{}x{}", bits, precision),
+            AbiType::Byte => write!(f, "byte"),
+            AbiType::Bool => write!(f, "bool"),
+            AbiType::Address => write!(f, "address"),
+            AbiType::String => write!(f, "string"),
+            AbiType::StaticArray(element, length) => write!(f, "{}[{}]", element, length),
+            AbiType::DynamicArray(element) => write!(f, "{}[]", element),
+            AbiType::Tuple(fields) => {
+                let fields: Vec<String> = fields.iter().map(|t| t.to_string()).collect();
+                write!(f, "({})", fields.join(","))
+            }
+        }
+    }
+}
+
+impl AbiType {
+    pub fn is_dynamic(&self) -> bool {
+        match self {
+            AbiType::String | AbiType::DynamicArray(_) => true,
+            AbiType::StaticArray(element, _) => element.is_dynamic(),
+            AbiType::Tuple(fields) => fields.iter().any(AbiType::is_dynamic),
+            _ => false,
+        }
+    }
+
+    /// The number of bytes the encoding of a static type takes, or `None` for dynamic types.
+    /// Fails if the length does not fit in a `usize`
+    pub fn static_length(&self) -> Result<Option<usize>, AbiError> {
+        let too_large = || AbiError::TypeTooLarge(self.to_string());
+
+        match self {
+            AbiType::Uint(bits) | AbiType::Ufixed(bits, _) => Ok(Some(*bits as usize / 8)),
+            AbiType::Byte | AbiType::Bool => Ok(Some(1)),
+            AbiType::Address => Ok(Some(ADDRESS_BYTE_LENGTH)),
+            AbiType::String | AbiType::DynamicArray(_) => Ok(None),
+            AbiType::StaticArray(element, length) => match element.as_ref() {
+                AbiType::Bool => Ok(Some(length.div_ceil(8))),
+                _ => match element.static_length()? {
+                    Some(element_length) => element_length
+                        .checked_mul(*length)
+                        .map(Some)
+                        .ok_or_else(too_large),
+                    None => Ok(None),
+                },
+            },
+            AbiType::Tuple(fields) => {
+                let mut length: usize = 0;
+                let mut i = 0;
+                while i < fields.len() {
+                    let field_length = if fields[i] == AbiType::Bool {
+                        let bools = consecutive_bools(fields, i);
+                        i += bools;
+                        bools.div_ceil(8)
+                    } else {
+                        let Some(field_length) = fields[i].static_length()? else {
+                            return Ok(None);
+                        };
+                        i += 1;
+                        field_length
+                    };
+                    length = length.checked_add(field_length).ok_or_else(too_large)?;
+                }
+                Ok(Some(length))
+            }
+        }
+    }
+
+    /// The length of the encoding if every value took at least one byte, and dynamic values only
+    /// their offset. This bounds the number of values the type decodes to, even for types such
+    /// as `()[N]` that encode to nothing. Fails if it is longer than any encoding can be
+    fn min_length(&self) -> Result<usize, AbiError> {
+        let too_large = || AbiError::TypeTooLarge(self.to_string());
+
+        let length = match self {
+            AbiType::Uint(bits) | AbiType::Ufixed(bits, _) => *bits as usize / 8,
+            AbiType::Byte | AbiType::Bool => 1,
+            AbiType::Address => ADDRESS_BYTE_LENGTH,
+            AbiType::String | AbiType::DynamicArray(_) => LENGTH_PREFIX_BYTE_LENGTH,
+            AbiType::StaticArray(element, length) => element
+                .min_length()?
+                .checked_mul(*length)
+                .ok_or_else(too_large)?,
+            AbiType::Tuple(fields) => fields.iter().try_fold(0usize, |total, field| {
+                total.checked_add(field.min_length()?).ok_or_else(too_large)
+            })?,
+        };
+
+        if length > MAX_ENCODED_LENGTH {
+            return Err(too_large());
+        }
+        Ok(length.max(1))
+    }
+
+    fn invalid_value(&self, message: impl Into<String>) -> AbiError {
+        AbiError::InvalidValue {
+            abi_type: self.to_string(),
+            message: message.into(),
+        }
+    }
+
+    fn decoding_error(&self, message: impl Into<String>) -> AbiError {
+        AbiError::DecodingError {
+            abi_type: self.to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn encode(&self, value: &AbiValue) -> Result<Vec<u8>, AbiError> {
+        match (self, value) {
+            (AbiType::Uint(bits) | AbiType::Ufixed(bits, _), AbiValue::Uint(n)) => {
+                if n.bits() > *bits as u64 {
+                    return Err(self.invalid_value(format!("{} does not fit in {} bits", n, bits)));
+                }
+
+                let bytes = n.to_bytes_be();
+                let mut encoded = vec![0u8; *bits as usize / 8];
+                // to_bytes_be returns [0] for zero, which still fits
+                let start = encoded.len() - bytes.len();
+                encoded[start..].copy_from_slice(&bytes);
+                Ok(encoded)
+            }
+            (AbiType::Byte, AbiValue::Byte(b)) => Ok(vec![*b]),
+            (AbiType::Bool, AbiValue::Bool(b)) => Ok(vec![if *b { BOOL_TRUE } else { BOOL_FALSE }]),
+            (AbiType::Address, AbiValue::Address(address)) => Ok(address.pub_key.to_vec()),
+            (AbiType::String, AbiValue::String(s)) => {
+                let length = u16::try_from(s.len())
+                    .map_err(|_| self.invalid_value("string is longer than 65535 bytes"))?;
+
+                let mut encoded = length.to_be_bytes().to_vec();
+                encoded.extend_from_slice(s.as_bytes());
+                Ok(encoded)
+            }
+            (AbiType::StaticArray(element, length), AbiValue::Array(values)) => {
+                if values.len() != *length {
+                    return Err(self.invalid_value(format!(
+                        "expected {} elements, but got {}",
+                        length,
+                        values.len()
+                    )));
+                }
+
+                encode_tuple(&vec![element.as_ref().clone(); *length], values)
+            }
+            (AbiType::DynamicArray(element), AbiValue::Array(values)) => {
+                let length = u16::try_from(values.len())
+                    .map_err(|_| self.invalid_value("array has more than 65535 elements"))?;
+
+                let mut encoded = length.to_be_bytes().to_vec();
+                encoded.extend(encode_tuple(
+                    &vec![element.as_ref().clone(); values.len()],
+                    values,
+                )?);
+                Ok(encoded)
+            }
+            (AbiType::Tuple(fields), AbiValue::Array(values)) => {
+                if values.len() != fields.len() {
+                    return Err(self.invalid_value(format!(
+                        "expected {} fields, but got {}",
+                        fields.len(),
+                        values.len()
+                    )));
+                }
+
+                encode_tuple(fields, values)
+            }
+            _ => Err(self.invalid_value(format!("{:?} is not a valid value", value))),
+        }
+    }
+
+    pub fn decode(&self, bytes: &[u8]) -> Result<AbiValue, AbiError> {
+        self.min_length()?;
+        if let Some(length) = self.static_length()? {
+            if bytes.len() != length {
+                return Err(self.decoding_error(format!(
+                    "expected {} bytes, but got {}",
+                    length,
+                    bytes.len()
+                )));
+            }
+        }
+
+        match self {
+            AbiType::Uint(_) | AbiType::Ufixed(_, _) => {
+                Ok(AbiValue::Uint(BigUint::from_bytes_be(bytes)))
+            }
+            AbiType::Byte => Ok(AbiValue::Byte(bytes[0])),
+            AbiType::Bool => match bytes[0] {
+                BOOL_TRUE => Ok(AbiValue::Bool(true)),
+                BOOL_FALSE => Ok(AbiValue::Bool(false)),
+                b => Err(self.decoding_error(format!("{:#04x} is not a valid bool", b))),
+            },
+            AbiType::Address => Ok(AbiValue::Address(Address::from_pubkey(
+                bytes.try_into().expect("length was checked above"),
+            ))),
+            AbiType::String => {
+                let contents = self.length_prefixed(bytes)?;
+                String::from_utf8(contents.to_vec())
+                    .map(AbiValue::String)
+                    .map_err(|e| self.decoding_error(e.to_string()))
+            }
+            AbiType::StaticArray(element, length) => {
+                // Each dynamic element has at least a 2-byte offset, so a length the bytes
+                // cannot hold is rejected before the element types are allocated
+                if element.is_dynamic()
+                    && length.saturating_mul(LENGTH_PREFIX_BYTE_LENGTH) > bytes.len()
+                {
+                    return Err(self.decoding_error(format!(
+                        "{} bytes cannot hold {} elements",
+                        bytes.len(),
+                        length
+                    )));
+                }
+
+                Ok(AbiValue::Array(decode_tuple(
+                    self,
+                    &vec![element.as_ref().clone(); *length],
+                    bytes,
+                )?))
+            }
+            AbiType::DynamicArray(element) => {
+                if bytes.len() < LENGTH_PREFIX_BYTE_LENGTH {
+                    return Err(self.decoding_error("missing length prefix"));
+                }
+
+                let length = u16::from_be_bytes([bytes[0], bytes[1]]) as usize;
+                if length.saturating_mul(element.min_length()?) > MAX_ENCODED_LENGTH {
+                    return Err(self.decoding_error(format!("{} elements are too many", length)));
+                }
+
+                Ok(AbiValue::Array(decode_tuple(
+                    self,
+                    &vec![element.as_ref().clone(); length],
+                    &bytes[LENGTH_PREFIX_BYTE_LENGTH..],
+                )?))
+            }
+            AbiType::Tuple(fields) => Ok(AbiValue::Array(decode_tuple(self, fields, bytes)?)),
+        }
+    }
+
+    /// The contents of a length-prefixed string
+    fn length_prefixed<'a>(&self, bytes: &'a [u8]) -> Result<&'a [u8], AbiError> {
+        if bytes.len() < LENGTH_PREFIX_BYTE_LENGTH {
+            return Err(self.decoding_error("missing length prefix"));
+        }
+
+        let length = u16::from_be_bytes([bytes[0], bytes[1]]) as usize;
+        let contents = &bytes[LENGTH_PREFIX_BYTE_LENGTH..];
+
+        if contents.len() != length {
+            return Err(self.decoding_error(format!(
+                "expected {} bytes, but got {}",
+                length,
+                contents.len()
+            )));
+        }
+
+        Ok(contents)
+    }
+}
+
+/// The number of consecutive bools in `types` starting at `start`
+fn consecutive_bools(types: &[AbiType], start: usize) -> usize {
+    types[start..]
+        .iter()
+        .take_while(|t| **t == AbiType::Bool)
+        .count()
+}
+
+fn encode_tuple(types: &[AbiType], values: &[AbiValue]) -> Result<Vec<u8>, AbiError> {
+    // Dynamic values are written to the tail and referenced by their offset from the start of
+    // the tuple. Their head is a placeholder until the total head length is known
+    let mut heads: Vec<Vec<u8>> = vec![];
+    let mut tails: Vec<Option<Vec<u8>>> = vec![];
+
+    let mut i = 0;
+    while i < types.len() {
+        if types[i] == AbiType::Bool {
+            let bools = consecutive_bools(types, i).min(8);
+            let mut packed = 0u8;
+
+            for (bit, value) in values[i..i + bools].iter().enumerate() {
+                match value {
+                    AbiValue::Bool(true) => packed |= BOOL_TRUE >> bit,
+                    AbiValue::Bool(false) => {}
+                    _ => return Err(AbiType::Bool.invalid_value(format!("{:?}", value))),
+                }
+            }
+
+            heads.push(vec![packed]);
+            tails.push(None);
+            i += bools;
+        } else if types[i].is_dynamic() {
+            heads.push(vec![0; LENGTH_PREFIX_BYTE_LENGTH]);
+            tails.push(Some(types[i].encode(&values[i])?));
+            i += 1;
+        } else {
+            heads.push(types[i].encode(&values[i])?);
+            tails.push(None);
+            i += 1;
+        }
+    }
+
+    let head_length: usize = heads.iter().map(Vec::len).sum();
+    let mut offset = head_length;
+
+    for (head, tail) in heads.iter_mut().zip(&tails) {
+        if let Some(tail) = tail {
+            let offset_bytes = u16::try_from(offset).map_err(|_| {
+                AbiType::Tuple(types.to_vec())
+                    .invalid_value(format!("offset {} does not fit in 2 bytes", offset))
+            })?;
+            head.copy_from_slice(&offset_bytes.to_be_bytes());
+            offset += tail.len();
+        }
+    }
+
+    let mut encoded = heads.concat();
+    for tail in tails.into_iter().flatten() {
+        encoded.extend(tail);
+    }
+    Ok(encoded)
+}
+
+fn decode_tuple(
+    tuple_type: &AbiType,
+    types: &[AbiType],
+    bytes: &[u8],
+) -> Result<Vec<AbiValue>, AbiError> {
+    let mut values: Vec<Option<AbiValue>> = vec![None; types.len()];
+    // (index, offset) of each dynamic value
+    let mut dynamic: Vec<(usize, usize)> = vec![];
+    let mut position = 0;
+
+    let read = |position: usize, length: usize| {
+        bytes
+            .get(position..position + length)
+            .ok_or_else(|| tuple_type.decoding_error("not enough bytes"))
+    };
+
+    let mut i = 0;
+    while i < types.len() {
+        if types[i] == AbiType::Bool {
+            let bools = consecutive_bools(types, i).min(8);
+            let packed = read(position, 1)?[0];
+
+            for bit in 0..bools {
+                values[i + bit] = Some(AbiValue::Bool(packed & (BOOL_TRUE >> bit) != 0));
+            }
+
+            position += 1;
+            i += bools;
+        } else if types[i].is_dynamic() {
+            let offset = read(position, LENGTH_PREFIX_BYTE_LENGTH)?;
+            dynamic.push((i, u16::from_be_bytes([offset[0], offset[1]]) as usize));
+            position += LENGTH_PREFIX_BYTE_LENGTH;
+            i += 1;
+        } else {
+            let length = types[i]
+                .static_length()?
+                .expect("static types have a length");
+            values[i] = Some(types[i].decode(read(position, length)?)?);
+            position += length;
+            i += 1;
+        }
+    }
+
+    if dynamic.is_empty() && position != bytes.len() {
+        return Err(tuple_type.decoding_error(format!(
+            "expected {} bytes, but got {}",
+            position,
+            bytes.len()
+        )));
+    }
+
+    // Each dynamic value runs until the next one starts, and the last one runs to the end
+    for (n, (index, start)) in dynamic.iter().enumerate() {
+        let end = dynamic.get(n + 1).map_or(bytes.len(), |(_, next)| *next);
+
+        if (n == 0 && *start != position) || start > &end || end > bytes.len() {
+            return Err(tuple_type.decoding_error(format!("invalid offset {}", start)));
+        }
+
+        values[*index] = Some(types[*index].decode(&bytes[*start..end])?);
+    }
+
+    Ok(values
+        .into_iter()
+        .map(|v| v.expect("all values were decoded"))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn round_trip(type_str: &str, value: AbiValue, expected: &[u8]) {
+        let abi_type: AbiType = type_str.parse().unwrap();
+        assert_eq!(abi_type.to_string(), type_str);
+
+        let encoded = abi_type.encode(&value).unwrap();
+        assert_eq!(encoded, expected);
+        assert_eq!(abi_type.decode(&encoded).unwrap(), value);
+    }
+
+    #[test]
+    fn test_parse_types() {
+        assert_eq!(
+            "(uint64,string[])".parse::<AbiType>().unwrap(),
+            AbiType::Tuple(vec![
+                AbiType::Uint(64),
+                AbiType::DynamicArray(Box::new(AbiType::String)),
+            ])
+        );
+        assert_eq!(
+            "ufixed128x10".parse::<AbiType>().unwrap(),
+            AbiType::Ufixed(128, 10)
+        );
+        assert_eq!(
+            "(byte[32],(bool,address)[2])[]".parse::<AbiType>().unwrap(),
+            AbiType::DynamicArray(Box::new(AbiType::Tuple(vec![
+                AbiType::StaticArray(Box::new(AbiType::Byte), 32),
+                AbiType::StaticArray(
+                    Box::new(AbiType::Tuple(vec![AbiType::Bool, AbiType::Address])),
+                    2
+                ),
+            ])))
+        );
+        assert_eq!("()".parse::<AbiType>().unwrap(), AbiType::Tuple(vec![]));
+
+        for invalid in [
+            "uint",
+            "uint7",
+            "uint520",
+            "ufixed64",
+            "ufixed64x0",
+            "ufixed64x161",
+            "byte[01]",
+            "(uint64",
+            "uint64)",
+            "(uint64,)",
+            "int64",
+            "bool[-1]",
+            "bool[+1]",
+            "bool[]]",
+            "uint+64",
+            "uint 64",
+            "uint064",
+            "uint６４",
+            "ufixed64x010",
+            "ufixed64x+10",
+            "ufixed064x10",
+            "ufixedx10",
+            "ufixed64x",
+        ] {
+            assert!(invalid.parse::<AbiType>().is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_static_length() {
+        let length = |s: &str| s.parse::<AbiType>().unwrap().static_length().unwrap();
+        assert_eq!(length("uint64"), Some(8));
+        assert_eq!(length("bool[9]"), Some(2));
+        assert_eq!(length("(bool,bool,uint8,bool)"), Some(3));
+        assert_eq!(length("(address,byte[4])"), Some(36));
+        assert_eq!(length("(uint64,string)"), None);
+        assert_eq!(length("uint8[65535]"), Some(65535));
+    }
+
+    #[test]
+    fn test_too_large() {
+        // Static array lengths are limited to 16 bits, like go-algorand
+        for too_long in [
+            "uint64[3000000000000000000]",
+            "bool[65536]",
+            "string[65536]",
+        ] {
+            assert_eq!(
+                too_long.parse::<AbiType>(),
+                Err(AbiError::InvalidType(too_long.to_string()))
+            );
+        }
+
+        // Nested arrays within that limit can still overflow the length of their encoding
+        let nested = "uint512[65535][65535][65535][65535]";
+        assert!(matches!(
+            nested.parse::<AbiType>(),
+            Err(AbiError::TypeTooLarge(_))
+        ));
+
+        // Types built directly are checked when they are used rather than panicking
+        let huge = AbiType::StaticArray(Box::new(AbiType::Uint(64)), usize::MAX);
+        assert_eq!(
+            huge.static_length(),
+            Err(AbiError::TypeTooLarge(huge.to_string()))
+        );
+        assert!(matches!(
+            huge.decode(&[0; 8]),
+            Err(AbiError::TypeTooLarge(_))
+        ));
+        assert!(matches!(
+            huge.encode(&AbiValue::Array(vec![])),
+            Err(AbiError::InvalidValue { .. })
+        ));
+
+        // A huge array of dynamic elements is rejected before it allocates its element types
+        let strings = AbiType::StaticArray(Box::new(AbiType::String), usize::MAX);
+        assert!(matches!(
+            strings.decode(&[0; 8]),
+            Err(AbiError::TypeTooLarge(_))
+        ));
+    }
+
+    #[test]
+    fn test_empty_elements() {
+        // Zero-length arrays are rejected, which would otherwise decode billions of values
+        // from no bytes at all
+        for empty in ["uint8[0]", "uint8[0][65535][65535]", "(uint8[0],bool)"] {
+            assert!(empty.parse::<AbiType>().is_err(), "{}", empty);
+        }
+
+        // Empty tuples encode to nothing, so their number is bounded by counting each as a byte
+        assert_eq!(
+            "()[65535][65535]".parse::<AbiType>(),
+            Err(AbiError::TypeTooLarge("()[65535][65535]".to_string()))
+        );
+        let empty_tuples = AbiType::StaticArray(Box::new(AbiType::Tuple(vec![])), usize::MAX);
+        assert!(matches!(
+            empty_tuples.decode(&[]),
+            Err(AbiError::TypeTooLarge(_))
+        ));
+        let dynamic: AbiType = "()[65535][]".parse().unwrap();
+        assert!(matches!(
+            dynamic.decode(&[0xff, 0xff]),
+            Err(AbiError::DecodingError { .. })
+        ));
+
+        round_trip(
+            "()[3]",
+            AbiValue::Array(vec![AbiValue::Array(vec![]); 3]),
+            &[],
+        );
+        round_trip(
+            "()[]",
+            AbiValue::Array(vec![AbiValue::Array(vec![]); 2]),
+            &[0, 2],
+        );
+    }
+
+    #[test]
+    fn test_nesting_depth() {
+        let nested = |depth: usize| format!("{}uint64{}", "(".repeat(depth), ")".repeat(depth));
+        assert!(nested(MAX_NESTING_DEPTH).parse::<AbiType>().is_ok());
+        assert!(nested(MAX_NESTING_DEPTH + 1).parse::<AbiType>().is_err());
+
+        // Without a bound, this would overflow the stack
+        assert!(nested(100_000).parse::<AbiType>().is_err());
+        let arrays = format!("uint64{}", "[1]".repeat(100_000));
+        assert!(arrays.parse::<AbiType>().is_err());
+    }
+
+    #[test]
+    fn test_uint() {
+        round_trip("uint64", 1u64.into(), &[0, 0, 0, 0, 0, 0, 0, 1]);
+        round_trip("uint8", 255u64.into(), &[255]);
+        round_trip("ufixed16x2", 0u64.into(), &[0, 0]);
+
+        let too_big = "uint8".parse::<AbiType>().unwrap().encode(&256u64.into());
+        assert!(matches!(too_big, Err(AbiError::InvalidValue { .. })));
+
+        let max = BigUint::from(2u8).pow(512) - 1u8;
+        round_trip("uint512", AbiValue::Uint(max), &[0xff; 64]);
+    }
+
+    #[test]
+    fn test_bool() {
+        round_trip("bool", AbiValue::Bool(true), &[0x80]);
+        round_trip(
+            "bool[]",
+            AbiValue::Array(vec![
+                AbiValue::Bool(true),
+                AbiValue::Bool(false),
+                AbiValue::Bool(true),
+            ]),
+            &[0x00, 0x03, 0xa0],
+        );
+
+        let nine: Vec<AbiValue> = (0..9).map(|i| AbiValue::Bool(i % 2 == 0)).collect();
+        round_trip("bool[9]", AbiValue::Array(nine), &[0xaa, 0x80]);
+
+        let bad = "bool".parse::<AbiType>().unwrap().decode(&[0x01]);
+        assert!(matches!(bad, Err(AbiError::DecodingError { .. })));
+    }
+
+    #[test]
+    fn test_string_and_address() {
+        round_trip(
+            "string",
+            AbiValue::String("asdf".to_string()),
+            &[0x00, 0x04, b'a', b's', b'd', b'f'],
+        );
+        round_trip(
+            "address",
+            AbiValue::Address(Address::from_pubkey(&[7; 32])),
+            &[7; 32],
+        );
+    }
+
+    #[test]
+    fn test_tuple() {
+        // (string,bool,bool,bool,bool,string) from the reference SDK test suites
+        round_trip(
+            "(string,bool,bool,bool,bool,string)",
+            AbiValue::Array(vec![
+                AbiValue::String("AB".to_string()),
+                AbiValue::Bool(true),
+                AbiValue::Bool(false),
+                AbiValue::Bool(true),
+                AbiValue::Bool(false),
+                AbiValue::String("DE".to_string()),
+            ]),
+            &[
+                0x00, 0x05, 0xa0, 0x00, 0x09, 0x00, 0x02, b'A', b'B', 0x00, 0x02, b'D', b'E',
+            ],
+        );
+
+        round_trip(
+            "(uint16,byte[2])",
+            AbiValue::Array(vec![
+                3u64.into(),
+                AbiValue::Array(vec![AbiValue::Byte(1), AbiValue::Byte(2)]),
+            ]),
+            &[0x00, 0x03, 0x01, 0x02],
+        );
+
+        round_trip(
+            "(uint64,string[])",
+            AbiValue::Array(vec![
+                1u64.into(),
+                AbiValue::Array(vec![
+                    AbiValue::String("a".to_string()),
+                    AbiValue::String("bc".to_string()),
+                ]),
+            ]),
+            &[
+                0, 0, 0, 0, 0, 0, 0, 1, // uint64
+                0x00, 0x0a, // offset of string[]
+                0x00, 0x02, // 2 strings
+                0x00, 0x04, 0x00, 0x07, // offsets within string[]
+                0x00, 0x01, b'a', 0x00, 0x02, b'b', b'c',
+            ],
+        );
+
+        round_trip("()", AbiValue::Array(vec![]), &[]);
+    }
+
+    #[test]
+    fn test_invalid_values() {
+        let tuple: AbiType = "(uint64,bool)".parse().unwrap();
+        assert!(tuple.encode(&AbiValue::Array(vec![1u64.into()])).is_err());
+        assert!(tuple.encode(&1u64.into()).is_err());
+
+        let array: AbiType = "uint8[2]".parse().unwrap();
+        assert!(array.encode(&AbiValue::Array(vec![1u64.into()])).is_err());
+        assert!(array.decode(&[1]).is_err());
+
+        let string: AbiType = "string".parse().unwrap();
+        assert!(string.decode(&[0x00, 0x05, b'a']).is_err());
+    }
+}
@@ -0,0 +1,531 @@
+//! ARC-4 methods, which describe how an application call encodes its arguments and return value.
+
+use super::{split_tuple, AbiError, AbiType, AbiValue};
+use crate::{Address, ApplicationCallTransactionFields, TransactionType};
+use sha2::{Digest, Sha512_256};
+use std::fmt;
+use std::str::FromStr;
+
+const SELECTOR_BYTE_LENGTH: usize = 4;
+/// Application calls may have at most 16 arguments, the first of which is the selector
+const MAX_APP_ARGS: usize = 16;
+/// The prefix of the log that holds a method's return value, which is the first 4 bytes of
+/// SHA-512/256("return")
+pub const RETURN_PREFIX: [u8; 4] = [0x15, 0x1f, 0x7c, 0x75];
+
+/// Arguments that refer to an entry in one of the application call's foreign arrays. They are
+/// encoded as a `uint8` index into that array
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReferenceType {
+    Account,
+    Asset,
+    Application,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MethodArgType {
+    Abi(AbiType),
+
+    /// A transaction that must immediately precede the application call in its group. `None`
+    /// (`txn`) accepts any transaction type
+    Transaction(Option<TransactionType>),
+
+    Reference(ReferenceType),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MethodArg {
+    pub arg_type: MethodArgType,
+    pub name: Option<String>,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Method {
+    pub name: String,
+    pub args: Vec<MethodArg>,
+    /// The return type, or `None` for `void`
+    pub returns: Option<AbiType>,
+    pub description: Option<String>,
+}
+
+/// The value of a method argument when building an application call
+#[derive(Debug, Clone, PartialEq)]
+pub enum MethodArgValue {
+    Abi(AbiValue),
+
+    /// Placeholder for a transaction argument. Transaction arguments are not part of the
+    /// application arguments; the transaction is placed before the call in its group instead
+    Transaction,
+
+    Account(Address),
+
+    Asset(u64),
+
+    Application(u64),
+}
+
+fn transaction_type_from_str(s: &str) -> Option<Option<TransactionType>> {
+    match s {
+        "txn" => Some(None),
+        "pay" => Some(Some(TransactionType::Payment)),
+        "keyreg" => Some(Some(TransactionType::KeyRegistration)),
+        "acfg" => Some(Some(TransactionType::AssetConfig)),
+        "axfer" => Some(Some(TransactionType::AssetTransfer)),
+        "afrz" => Some(Some(TransactionType::AssetFreeze)),
+        "appl" => Some(Some(TransactionType::ApplicationCall)),
+        _ => None,
+    }
+}
+
+fn transaction_type_to_str(transaction_type: &Option<TransactionType>) -> &'static str {
+    match transaction_type {
+        None => "txn",
+        Some(TransactionType::Payment) => "pay",
+        Some(TransactionType::KeyRegistration) => "keyreg",
+        Some(TransactionType::AssetConfig) => "acfg",
+        Some(TransactionType::AssetTransfer) => "axfer",
+        Some(TransactionType::AssetFreeze) => "afrz",
+        Some(TransactionType::ApplicationCall) => "appl",
+    }
+}
+
+impl FromStr for MethodArgType {
+    type Err = AbiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(transaction_type) = transaction_type_from_str(s) {
+            return Ok(MethodArgType::Transaction(transaction_type));
+        }
+
+        match s {
+            "account" => Ok(MethodArgType::Reference(ReferenceType::Account)),
+            "asset" => Ok(MethodArgType::Reference(ReferenceType::Asset)),
+            "application" => Ok(MethodArgType::Reference(ReferenceType::Application)),
+            _ => Ok(MethodArgType::Abi(s.parse()?)),
+        }
+    }
+}
+
+impl fmt::Display for MethodArgType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MethodArgType::Abi(abi_type) => write!(f, "{}", abi_type),
+            MethodArgType::Transaction(transaction_type) => {
+                write!(f, "{}", transaction_type_to_str(transaction_type))
+            }
+            MethodArgType::Reference(ReferenceType::Account) => write!(f, "account"),
+            MethodArgType::Reference(ReferenceType::Asset) => write!(f, "asset"),
+            MethodArgType::Reference(ReferenceType::Application) => write!(f, "application"),
+        }
+    }
+}
+
+impl FromStr for Method {
+    type Err = AbiError;
+
+    /// Parse a method from its signature, i.e. `add(uint64,uint64)uint128`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || AbiError::InvalidMethodSignature(s.to_string());
+
+        let open = s.find('(').ok_or_else(invalid)?;
+        let name = &s[..open];
+        if name.is_empty() {
+            return Err(invalid());
+        }
+
+        // Find the parenthesis that closes the argument list, which may contain tuples
+        let mut depth = 0;
+        let close = s[open..]
+            .char_indices()
+            .find_map(|(i, c)| {
+                match c {
+                    '(' => depth += 1,
+                    ')' => depth -= 1,
+                    _ => {}
+                }
+                (depth == 0).then_some(open + i)
+            })
+            .ok_or_else(invalid)?;
+
+        let args = split_tuple(&s[open + 1..close])
+            .map_err(|_| invalid())?
+            .into_iter()
+            .map(|arg| {
+                Ok(MethodArg {
+                    arg_type: arg.parse()?,
+                    name: None,
+                    description: None,
+                })
+            })
+            .collect::<Result<_, AbiError>>()?;
+
+        let returns = match &s[close + 1..] {
+            "" => return Err(invalid()),
+            "void" => None,
+            return_type => Some(return_type.parse()?),
+        };
+
+        Ok(Method {
+            name: name.to_string(),
+            args,
+            returns,
+            description: None,
+        })
+    }
+}
+
+impl Method {
+    /// The canonical signature, i.e. `add(uint64,uint64)uint128`
+    pub fn signature(&self) -> String {
+        let args: Vec<String> = self.args.iter().map(|a| a.arg_type.to_string()).collect();
+        let returns = self
+            .returns
+            .as_ref()
+            .map_or("void".to_string(), AbiType::to_string);
+
+        format!("{}({}){}", self.name, args.join(","), returns)
+    }
+
+    /// The first 4 bytes of the SHA-512/256 hash of the signature, which is the first
+    /// application argument of a call to this method
+    pub fn selector(&self) -> [u8; SELECTOR_BYTE_LENGTH] {
+        let hash = Sha512_256::digest(self.signature().as_bytes());
+
+        let mut selector = [0u8; SELECTOR_BYTE_LENGTH];
+        selector.copy_from_slice(&hash[..SELECTOR_BYTE_LENGTH]);
+        selector
+    }
+
+    fn invalid_args(&self, message: impl Into<String>) -> AbiError {
+        AbiError::InvalidMethodArgs {
+            method: self.signature(),
+            message: message.into(),
+        }
+    }
+
+    /// Set the application arguments of `call` to a call of this method with `args`.
+    ///
+    /// Reference arguments are added to the call's foreign arrays (unless already present) and
+    /// encoded as their index. If there are more than 15 arguments, the 15th and later ones are
+    /// encoded together as a tuple in the last application argument
+    pub fn apply_args(
+        &self,
+        call: &mut ApplicationCallTransactionFields,
+        args: &[MethodArgValue],
+    ) -> Result<(), AbiError> {
+        if args.len() != self.args.len() {
+            return Err(self.invalid_args(format!(
+                "expected {} arguments, but got {}",
+                self.args.len(),
+                args.len()
+            )));
+        }
+
+        // Work on a copy so `call` is left untouched if an argument is invalid
+        let mut updated = call.clone();
+        let mut types = vec![];
+        let mut values = vec![];
+
+        for (i, (arg, value)) in self.args.iter().zip(args).enumerate() {
+            let index = match (&arg.arg_type, value) {
+                (MethodArgType::Abi(abi_type), MethodArgValue::Abi(value)) => {
+                    types.push(abi_type.clone());
+                    values.push(value.clone());
+                    continue;
+                }
+                (MethodArgType::Transaction(_), MethodArgValue::Transaction) => continue,
+                (
+                    MethodArgType::Reference(ReferenceType::Account),
+                    MethodArgValue::Account(address),
+                ) => {
+                    // Index 0 is the sender
+                    if *address == updated.header.sender {
+                        0
+                    } else {
+                        find_or_push(updated.accounts.get_or_insert_with(Vec::new), address) + 1
+                    }
+                }
+                (MethodArgType::Reference(ReferenceType::Asset), MethodArgValue::Asset(id)) => {
+                    find_or_push(updated.foreign_assets.get_or_insert_with(Vec::new), id)
+                }
+                (
+                    MethodArgType::Reference(ReferenceType::Application),
+                    MethodArgValue::Application(id),
+                ) => {
+                    // Index 0 is the called application
+                    if *id == updated.app_id {
+                        0
+                    } else {
+                        find_or_push(updated.foreign_apps.get_or_insert_with(Vec::new), id) + 1
+                    }
+                }
+                _ => {
+                    return Err(self.invalid_args(format!(
+                        "argument {} is a {}, but got {:?}",
+                        i, arg.arg_type, value
+                    )))
+                }
+            };
+
+            let index = u8::try_from(index).map_err(|_| {
+                self.invalid_args(format!("reference index {} does not fit in a uint8", index))
+            })?;
+            types.push(AbiType::Uint(8));
+            values.push(AbiValue::from(index as u64));
+        }
+
+        if types.len() > MAX_APP_ARGS - 1 {
+            let packed = MAX_APP_ARGS - 2;
+            let tuple_type = AbiType::Tuple(types.split_off(packed));
+            let tuple_value = AbiValue::Array(values.split_off(packed));
+            types.push(tuple_type);
+            values.push(tuple_value);
+        }
+
+        let mut app_args = vec![self.selector().to_vec()];
+        for (abi_type, value) in types.iter().zip(&values) {
+            app_args.push(abi_type.encode(value)?);
+        }
+
+        updated.args = Some(app_args);
+        *call = updated;
+        Ok(())
+    }
+
+    /// Decode the return value from the logs of a call to this method. The value is in the last
+    /// log, prefixed with `RETURN_PREFIX`. Returns `None` for `void` methods
+    pub fn decode_return(&self, logs: &[Vec<u8>]) -> Result<Option<AbiValue>, AbiError> {
+        let Some(returns) = &self.returns else {
+            return Ok(None);
+        };
+
+        let value = logs
+            .last()
+            .and_then(|log| log.strip_prefix(RETURN_PREFIX.as_slice()))
+            .ok_or_else(|| AbiError::MissingReturnValue(self.signature()))?;
+
+        Ok(Some(returns.decode(value)?))
+    }
+}
+
+/// The index of `value` in `values`, adding it to the end if it is not present
+fn find_or_push<T: PartialEq + Clone>(values: &mut Vec<T>, value: &T) -> usize {
+    values.iter().position(|v| v == value).unwrap_or_else(|| {
+        values.push(value.clone());
+        values.len() - 1
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MicroAlgos, OnApplicationComplete, TransactionHeader};
+    use pretty_assertions::assert_eq;
+
+    fn app_call(sender: Address, app_id: u64) -> ApplicationCallTransactionFields {
+        ApplicationCallTransactionFields {
+            header: TransactionHeader {
+                transaction_type: TransactionType::ApplicationCall,
+                sender,
+                fee: MicroAlgos(1000),
+                first_valid: 1,
+                last_valid: 1000,
+                genesis_hash: None,
+                genesis_id: None,
+                note: None,
+                rekey_to: None,
+                lease: None,
+                group: None,
+            },
+            app_id,
+            on_complete: OnApplicationComplete::NoOp,
+            approval_program: None,
+            clear_state_program: None,
+            global_state_schema: None,
+            local_state_schema: None,
+            extra_program_pages: 0,
+            args: None,
+            accounts: None,
+            foreign_apps: None,
+            foreign_assets: None,
+            boxes: None,
+        }
+    }
+
+    #[test]
+    fn test_signature_and_selector() {
+        let method: Method = "add(uint64,uint64)uint128".parse().unwrap();
+        assert_eq!(method.name, "add");
+        assert_eq!(method.returns, Some(AbiType::Uint(128)));
+        assert_eq!(method.signature(), "add(uint64,uint64)uint128");
+        assert_eq!(method.selector(), [0x8a, 0xa3, 0xb6, 0x1f]);
+
+        let signature = "foo(string,(uint64,bool[]),pay,txn,account,asset,application)void";
+        let method: Method = signature.parse().unwrap();
+        assert_eq!(method.returns, None);
+        assert_eq!(
+            method.args[2].arg_type,
+            MethodArgType::Transaction(Some(TransactionType::Payment))
+        );
+        assert_eq!(method.args[3].arg_type, MethodArgType::Transaction(None));
+        assert_eq!(
+            method.args[6].arg_type,
+            MethodArgType::Reference(ReferenceType::Application)
+        );
+        assert_eq!(method.signature(), signature);
+
+        assert_eq!("noargs()void".parse::<Method>().unwrap().args, vec![]);
+    }
+
+    #[test]
+    fn test_invalid_signatures() {
+        for signature in [
+            "foo",
+            "(uint64)void",
+            "foo(uint64",
+            "foo(uint64)",
+            "foo)(void",
+        ] {
+            assert_eq!(
+                signature.parse::<Method>(),
+                Err(AbiError::InvalidMethodSignature(signature.to_string())),
+            );
+        }
+
+        assert_eq!(
+            "foo(uint7)void".parse::<Method>(),
+            Err(AbiError::InvalidType("uint7".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_reference_args() {
+        let sender = Address::from_pubkey(&[1; 32]);
+        let other = Address::from_pubkey(&[2; 32]);
+        let method: Method =
+            "refs(account,account,account,asset,asset,application,application,pay)void"
+                .parse()
+                .unwrap();
+
+        let mut call = app_call(sender.clone(), 1234);
+        call.foreign_assets = Some(vec![5]);
+        method
+            .apply_args(
+                &mut call,
+                &[
+                    MethodArgValue::Account(sender),
+                    MethodArgValue::Account(other.clone()),
+                    MethodArgValue::Account(other.clone()),
+                    MethodArgValue::Asset(10),
+                    MethodArgValue::Asset(5),
+                    MethodArgValue::Application(1234),
+                    MethodArgValue::Application(99),
+                    MethodArgValue::Transaction,
+                ],
+            )
+            .unwrap();
+
+        assert_eq!(call.accounts, Some(vec![other]));
+        assert_eq!(call.foreign_assets, Some(vec![5, 10]));
+        assert_eq!(call.foreign_apps, Some(vec![99]));
+        assert_eq!(
+            call.args.unwrap(),
+            vec![
+                method.selector().to_vec(),
+                vec![0],
+                vec![1],
+                vec![1],
+                vec![1],
+                vec![0],
+                vec![0],
+                vec![1],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_more_than_15_args() {
+        let signature = format!("many({})void", vec!["uint64"; 17].join(","));
+        let method: Method = signature.parse().unwrap();
+        let args: Vec<MethodArgValue> = (0..17u64)
+            .map(|i| MethodArgValue::Abi(AbiValue::from(i)))
+            .collect();
+
+        let mut call = app_call(Address::default(), 1);
+        method.apply_args(&mut call, &args).unwrap();
+        let app_args = call.args.unwrap();
+
+        assert_eq!(app_args.len(), 16);
+        assert_eq!(app_args[0], method.selector());
+        assert_eq!(app_args[14], 13u64.to_be_bytes());
+        // Arguments 15 to 17 are packed into a (uint64,uint64,uint64) tuple
+        assert_eq!(
+            app_args[15],
+            [
+                14u64.to_be_bytes(),
+                15u64.to_be_bytes(),
+                16u64.to_be_bytes()
+            ]
+            .concat()
+        );
+    }
+
+    #[test]
+    fn test_invalid_args() {
+        let method: Method = "foo(uint64,account)void".parse().unwrap();
+        let mut call = app_call(Address::default(), 1);
+
+        assert!(matches!(
+            method.apply_args(&mut call, &[MethodArgValue::Abi(AbiValue::from(1))]),
+            Err(AbiError::InvalidMethodArgs { .. })
+        ));
+        assert!(matches!(
+            method.apply_args(
+                &mut call,
+                &[
+                    MethodArgValue::Abi(AbiValue::from(1)),
+                    MethodArgValue::Asset(1)
+                ]
+            ),
+            Err(AbiError::InvalidMethodArgs { .. })
+        ));
+
+        let method: Method = "foo(account,uint8)void".parse().unwrap();
+        assert!(matches!(
+            method.apply_args(
+                &mut call,
+                &[
+                    MethodArgValue::Account(Address::from_pubkey(&[2; 32])),
+                    MethodArgValue::Abi(AbiValue::from(256)),
+                ]
+            ),
+            Err(AbiError::InvalidValue { .. })
+        ));
+        assert_eq!(call, app_call(Address::default(), 1));
+    }
+
+    #[test]
+    fn test_decode_return() {
+        let method: Method = "add(uint64,uint64)uint128".parse().unwrap();
+        let mut log = RETURN_PREFIX.to_vec();
+        log.extend_from_slice(&[0; 15]);
+        log.push(3);
+
+        assert_eq!(
+            method.decode_return(&[b"debug".to_vec(), log]).unwrap(),
+            Some(AbiValue::from(3))
+        );
+        assert_eq!(
+            method.decode_return(&[b"debug".to_vec()]),
+            Err(AbiError::MissingReturnValue(method.signature()))
+        );
+        assert_eq!(
+            method.decode_return(&[]),
+            Err(AbiError::MissingReturnValue(method.signature()))
+        );
+
+        let void: Method = "noop()void".parse().unwrap();
+        assert_eq!(void.decode_return(&[]).unwrap(), None);
+    }
+}
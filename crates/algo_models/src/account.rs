@@ -0,0 +1,523 @@
+//! Accounts as returned by algod's `/v2/accounts/{address}`, in JSON or msgpack.
+//!
+//! Unlike transactions, these models use the kebab-case names of algod's REST API in both
+//! encodings, and encode addresses as base32 strings. Bytes are base64 encoded in JSON and
+//! binary in msgpack, except for the keys and values of application state, which algod
+//! base64 encodes in both.
+
+use crate::{
+    Address, AddressString, AlgoModelsError, AlgorandMsgpack, Base64Bytes, Base64String, MicroAlgos,
+};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum AccountError {
+    #[error("invalid account JSON: {0}")]
+    InvalidJson(#[from] serde_json::Error),
+}
+
+/// Whether an account participates in consensus
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy, Default)]
+pub enum AccountStatus {
+    #[default]
+    Offline,
+    Online,
+    /// The account can never participate again, and earns no rewards
+    NotParticipating,
+}
+
+/// How the transactions of an account are signed. Accounts that have never sent a transaction
+/// have no signature type
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+pub enum SignatureType {
+    #[serde(rename = "sig")]
+    Single,
+
+    #[serde(rename = "msig")]
+    Multisig,
+
+    #[serde(rename = "lsig")]
+    LogicSig,
+}
+
+/// The participation keys that an online account votes with
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct AccountParticipation {
+    #[serde(rename = "selection-participation-key")]
+    #[serde_as(as = "Base64Bytes")]
+    pub selection_participation_key: Vec<u8>,
+
+    #[serde(rename = "state-proof-key")]
+    #[serde_as(as = "Option<Base64Bytes>")]
+    #[serde(default)]
+    pub state_proof_key: Option<Vec<u8>>,
+
+    #[serde(rename = "vote-first-valid")]
+    pub vote_first_valid: u64,
+
+    #[serde(rename = "vote-key-dilution")]
+    pub vote_key_dilution: u64,
+
+    #[serde(rename = "vote-last-valid")]
+    pub vote_last_valid: u64,
+
+    #[serde(rename = "vote-participation-key")]
+    #[serde_as(as = "Base64Bytes")]
+    pub vote_participation_key: Vec<u8>,
+}
+
+/// An account's balance of an asset it has opted in to
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct AssetHolding {
+    pub amount: u64,
+
+    #[serde(rename = "asset-id")]
+    pub asset_id: u64,
+
+    #[serde(rename = "is-frozen")]
+    pub is_frozen: bool,
+}
+
+/// The parameters of an asset. The name, unit name and URL are only present as strings when
+/// they are valid UTF-8, and are always present as bytes
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct AssetParams {
+    #[serde_as(as = "Option<AddressString>")]
+    #[serde(default)]
+    pub clawback: Option<Address>,
+
+    #[serde_as(as = "AddressString")]
+    pub creator: Address,
+
+    pub decimals: u64,
+
+    #[serde(rename = "default-frozen")]
+    #[serde(default)]
+    pub default_frozen: Option<bool>,
+
+    #[serde_as(as = "Option<AddressString>")]
+    #[serde(default)]
+    pub freeze: Option<Address>,
+
+    #[serde_as(as = "Option<AddressString>")]
+    #[serde(default)]
+    pub manager: Option<Address>,
+
+    #[serde(rename = "metadata-hash")]
+    #[serde_as(as = "Option<Base64Bytes>")]
+    #[serde(default)]
+    pub metadata_hash: Option<Vec<u8>>,
+
+    #[serde(default)]
+    pub name: Option<String>,
+
+    #[serde(rename = "name-b64")]
+    #[serde_as(as = "Option<Base64Bytes>")]
+    #[serde(default)]
+    pub name_bytes: Option<Vec<u8>>,
+
+    #[serde_as(as = "Option<AddressString>")]
+    #[serde(default)]
+    pub reserve: Option<Address>,
+
+    /// The total number of base units of the asset
+    pub total: u64,
+
+    #[serde(rename = "unit-name")]
+    #[serde(default)]
+    pub unit_name: Option<String>,
+
+    #[serde(rename = "unit-name-b64")]
+    #[serde_as(as = "Option<Base64Bytes>")]
+    #[serde(default)]
+    pub unit_name_bytes: Option<Vec<u8>>,
+
+    #[serde(default)]
+    pub url: Option<String>,
+
+    #[serde(rename = "url-b64")]
+    #[serde_as(as = "Option<Base64Bytes>")]
+    #[serde(default)]
+    pub url_bytes: Option<Vec<u8>>,
+}
+
+/// An asset created by an account
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct Asset {
+    #[serde(rename = "index")]
+    pub id: u64,
+
+    pub params: AssetParams,
+}
+
+/// The type of a TEAL value. Encoded as an integer
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum TealValueType {
+    Bytes = 1,
+    Uint = 2,
+}
+
+impl TryFrom<u64> for TealValueType {
+    type Error = AlgoModelsError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::Bytes),
+            2 => Ok(Self::Uint),
+            _ => Err(AlgoModelsError::InputError(format!(
+                "unknown TEAL value type: {}",
+                value
+            ))),
+        }
+    }
+}
+
+impl Serialize for TealValueType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(*self as u64)
+    }
+}
+
+impl<'de> Deserialize<'de> for TealValueType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = u64::deserialize(deserializer)?;
+        Self::try_from(value).map_err(serde::de::Error::custom)
+    }
+}
+
+/// A value in application state. Both fields are always present, and the one that does not
+/// match the type is empty
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct TealValue {
+    #[serde(rename = "type")]
+    pub value_type: TealValueType,
+
+    #[serde_as(as = "Base64String")]
+    pub bytes: Vec<u8>,
+
+    pub uint: u64,
+}
+
+/// A key and its value in application state
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct TealKeyValue {
+    #[serde_as(as = "Base64String")]
+    pub key: Vec<u8>,
+
+    pub value: TealValue,
+}
+
+/// The number of values of each type in application state. Unlike `StateSchema` in
+/// transactions, this uses algod's names for the fields
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct ApplicationStateSchema {
+    #[serde(rename = "num-uint")]
+    pub num_uints: u64,
+
+    #[serde(rename = "num-byte-slice")]
+    pub num_byte_slices: u64,
+}
+
+/// An account's local state in an application it has opted in to
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct ApplicationLocalState {
+    pub id: u64,
+
+    pub schema: ApplicationStateSchema,
+
+    #[serde(rename = "key-value")]
+    #[serde(default)]
+    pub key_values: Option<Vec<TealKeyValue>>,
+}
+
+/// The parameters and global state of an application
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct ApplicationParams {
+    #[serde(rename = "approval-program")]
+    #[serde_as(as = "Base64Bytes")]
+    pub approval_program: Vec<u8>,
+
+    #[serde(rename = "clear-state-program")]
+    #[serde_as(as = "Base64Bytes")]
+    pub clear_state_program: Vec<u8>,
+
+    #[serde_as(as = "AddressString")]
+    pub creator: Address,
+
+    #[serde(rename = "extra-program-pages")]
+    #[serde(default)]
+    pub extra_program_pages: Option<u64>,
+
+    #[serde(rename = "global-state")]
+    #[serde(default)]
+    pub global_state: Option<Vec<TealKeyValue>>,
+
+    #[serde(rename = "global-state-schema")]
+    #[serde(default)]
+    pub global_state_schema: Option<ApplicationStateSchema>,
+
+    #[serde(rename = "local-state-schema")]
+    #[serde(default)]
+    pub local_state_schema: Option<ApplicationStateSchema>,
+
+    /// The number of times the programs have been updated
+    #[serde(default)]
+    pub version: Option<u64>,
+}
+
+/// An application created by an account
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct Application {
+    pub id: u64,
+
+    pub params: ApplicationParams,
+}
+
+/// An account and the assets and applications it holds or created, as of `round`
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct Account {
+    #[serde_as(as = "AddressString")]
+    pub address: Address,
+
+    /// The balance, including pending rewards
+    pub amount: MicroAlgos,
+
+    #[serde(rename = "amount-without-pending-rewards")]
+    pub amount_without_pending_rewards: MicroAlgos,
+
+    /// The balance the account must keep for the assets and applications it holds or created
+    #[serde(rename = "min-balance")]
+    pub min_balance: MicroAlgos,
+
+    #[serde(rename = "pending-rewards")]
+    pub pending_rewards: MicroAlgos,
+
+    /// The rewards earned over the lifetime of the account
+    pub rewards: MicroAlgos,
+
+    #[serde(rename = "reward-base")]
+    #[serde(default)]
+    pub reward_base: Option<u64>,
+
+    pub round: u64,
+
+    pub status: AccountStatus,
+
+    #[serde(rename = "sig-type")]
+    #[serde(default)]
+    pub signature_type: Option<SignatureType>,
+
+    /// The address that signs for the account, when it has been rekeyed
+    #[serde(rename = "auth-addr")]
+    #[serde_as(as = "Option<AddressString>")]
+    #[serde(default)]
+    pub auth_address: Option<Address>,
+
+    #[serde(default)]
+    pub participation: Option<AccountParticipation>,
+
+    /// Whether the account earns block incentives when it proposes a block
+    #[serde(rename = "incentive-eligible")]
+    #[serde(default)]
+    pub incentive_eligible: Option<bool>,
+
+    #[serde(rename = "last-proposed")]
+    #[serde(default)]
+    pub last_proposed: Option<u64>,
+
+    #[serde(rename = "last-heartbeat")]
+    #[serde(default)]
+    pub last_heartbeat: Option<u64>,
+
+    #[serde(default)]
+    pub assets: Option<Vec<AssetHolding>>,
+
+    #[serde(rename = "created-assets")]
+    #[serde(default)]
+    pub created_assets: Option<Vec<Asset>>,
+
+    #[serde(rename = "apps-local-state")]
+    #[serde(default)]
+    pub apps_local_state: Option<Vec<ApplicationLocalState>>,
+
+    #[serde(rename = "created-apps")]
+    #[serde(default)]
+    pub created_apps: Option<Vec<Application>>,
+
+    /// The local state schemas of opted in applications and the global state schemas of
+    /// created applications, summed
+    #[serde(rename = "apps-total-schema")]
+    #[serde(default)]
+    pub apps_total_schema: Option<ApplicationStateSchema>,
+
+    #[serde(rename = "apps-total-extra-pages")]
+    #[serde(default)]
+    pub apps_total_extra_pages: Option<u64>,
+
+    #[serde(rename = "total-apps-opted-in")]
+    pub total_apps_opted_in: u64,
+
+    #[serde(rename = "total-assets-opted-in")]
+    pub total_assets_opted_in: u64,
+
+    #[serde(rename = "total-created-apps")]
+    pub total_created_apps: u64,
+
+    #[serde(rename = "total-created-assets")]
+    pub total_created_assets: u64,
+
+    #[serde(rename = "total-boxes")]
+    #[serde(default)]
+    pub total_boxes: Option<u64>,
+
+    #[serde(rename = "total-box-bytes")]
+    #[serde(default)]
+    pub total_box_bytes: Option<u64>,
+}
+
+impl AlgorandMsgpack for Account {
+    const PREFIX: &'static [u8] = b"";
+}
+
+impl Account {
+    pub fn from_json(json: &str) -> Result<Self, AccountError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn to_json(&self) -> Result<String, AccountError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// The account's holding of an asset, if it has opted in to the asset
+    pub fn asset_holding(&self, asset_id: u64) -> Option<&AssetHolding> {
+        self.assets
+            .iter()
+            .flatten()
+            .find(|holding| holding.asset_id == asset_id)
+    }
+
+    /// The account's local state in an application, if it has opted in to the application
+    pub fn app_local_state(&self, app_id: u64) -> Option<&ApplicationLocalState> {
+        self.apps_local_state
+            .iter()
+            .flatten()
+            .find(|state| state.id == app_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    // The same `/v2/accounts/{address}` response in both formats, with made-up accounts, keys
+    // and programs
+    const ACCOUNT_JSON: &str = include_str!("../test_data/account.json");
+    const ACCOUNT_MSGPACK: &[u8] = include_bytes!("../test_data/account.msgpack");
+
+    #[test]
+    fn test_account() {
+        let account = Account::decode(ACCOUNT_MSGPACK).unwrap();
+
+        assert_eq!(
+            account.address.address(),
+            "S42GIXSHUKLVVFE537KRRLGFRWZSIRFD6LPCMG4QNNW5ZMHOAFMREKN6LQ"
+        );
+        assert_eq!(account.amount, MicroAlgos(12_345_678_901));
+        assert_eq!(account.min_balance, MicroAlgos(1_528_500));
+        assert_eq!(account.status, AccountStatus::Online);
+        assert_eq!(account.signature_type, Some(SignatureType::Single));
+        assert_eq!(
+            account.auth_address.as_ref().map(Address::address),
+            Some("XLHDWUMLF27E3SDXO7ZOXF3HORHONT72NWCXCCGPYSXFZM2QF4JOBRWH7U".to_string())
+        );
+
+        let participation = account.participation.as_ref().unwrap();
+        assert_eq!(participation.vote_last_valid, 48_000_000);
+        assert_eq!(participation.selection_participation_key.len(), 32);
+        assert_eq!(
+            participation.state_proof_key.as_ref().map(Vec::len),
+            Some(64)
+        );
+
+        assert_eq!(
+            account.asset_holding(3_000_000_001),
+            Some(&AssetHolding {
+                amount: 0,
+                asset_id: 3_000_000_001,
+                is_frozen: true
+            })
+        );
+        assert_eq!(account.asset_holding(1), None);
+
+        let params = &account.created_assets.as_ref().unwrap()[0].params;
+        assert_eq!(params.name.as_deref(), Some("Example Token"));
+        assert_eq!(
+            params.name_bytes.as_deref(),
+            Some(b"Example Token".as_slice())
+        );
+        assert_eq!(params.total, 10_000_000_000_000);
+        assert_eq!(params.creator, account.address);
+    }
+
+    #[test]
+    fn test_application_state() {
+        let account = Account::decode(ACCOUNT_MSGPACK).unwrap();
+
+        let local_state = account.app_local_state(1002).unwrap();
+        assert_eq!(local_state.schema.num_uints, 1);
+        assert_eq!(
+            local_state.key_values.as_ref().unwrap()[1],
+            TealKeyValue {
+                key: vec![0, 0, 0, 0, 0, 0, 0, 7],
+                value: TealValue {
+                    value_type: TealValueType::Bytes,
+                    bytes: vec![0xff, 0xfe, 1],
+                    uint: 0
+                }
+            }
+        );
+
+        let params = &account.created_apps.as_ref().unwrap()[0].params;
+        assert_eq!(params.approval_program, [0x0a, 0x81, 0x01, 0x43]);
+        assert_eq!(params.extra_program_pages, Some(1));
+        let global_state = params.global_state.as_ref().unwrap();
+        assert_eq!(global_state[0].key, b"counter");
+        assert_eq!(global_state[0].value.value_type, TealValueType::Uint);
+        assert_eq!(global_state[0].value.uint, 42);
+    }
+
+    #[test]
+    fn test_json() {
+        let account = Account::from_json(ACCOUNT_JSON).unwrap();
+        assert_eq!(account, Account::decode(ACCOUNT_MSGPACK).unwrap());
+        assert_eq!(
+            Account::from_json(&account.to_json().unwrap()).unwrap(),
+            account
+        );
+
+        assert!(matches!(
+            Account::from_json(r#"{"address": "not an address"}"#),
+            Err(AccountError::InvalidJson(_))
+        ));
+    }
+
+    #[test]
+    fn test_msgpack_encoding() {
+        let account = Account::decode(ACCOUNT_MSGPACK).unwrap();
+        assert_eq!(account.encode().unwrap(), ACCOUNT_MSGPACK);
+    }
+}
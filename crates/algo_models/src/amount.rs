@@ -0,0 +1,218 @@
+//! Amounts of Algos and of assets.
+//!
+//! Algo amounts are `MicroAlgos`, which encode as a plain integer like the `u64` they wrap, so
+//! they cannot be confused with asset amounts or with Algos. Asset amounts stay `u64` in base
+//! units, and are formatted and parsed using the decimals of the asset.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use thiserror::Error;
+
+/// The number of decimal places of an Algo
+pub const ALGO_DECIMALS: u8 = 6;
+
+/// The number of microAlgos in one Algo
+pub const MICROALGOS_PER_ALGO: u64 = 1_000_000;
+
+/// The maximum number of decimal places of an asset, as every u64 has at most 20 digits
+pub const MAX_ASSET_DECIMALS: u8 = 19;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum AmountError {
+    #[error("\"{0}\" is not a decimal amount")]
+    Invalid(String),
+
+    #[error("\"{amount}\" has more than {decimals} decimal places")]
+    TooPrecise { amount: String, decimals: u8 },
+
+    #[error("\"{0}\" is too large")]
+    Overflow(String),
+
+    #[error("{0} decimal places exceeds the maximum of {MAX_ASSET_DECIMALS}")]
+    InvalidDecimals(u8),
+}
+
+/// An amount of Algos, in microAlgos
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default,
+)]
+#[serde(transparent)]
+pub struct MicroAlgos(pub u64);
+
+impl MicroAlgos {
+    pub const ZERO: MicroAlgos = MicroAlgos(0);
+
+    /// Parse a decimal amount of Algos, such as `"1.5"`
+    pub fn from_algos(algos: &str) -> Result<Self, AmountError> {
+        parse_units(algos, ALGO_DECIMALS).map(MicroAlgos)
+    }
+
+    /// The amount as a decimal number of Algos without trailing zeros, such as `"1.5"`
+    pub fn to_algos(self) -> String {
+        format_units(self.0, ALGO_DECIMALS)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn checked_add(self, other: MicroAlgos) -> Option<MicroAlgos> {
+        self.0.checked_add(other.0).map(MicroAlgos)
+    }
+
+    pub fn checked_sub(self, other: MicroAlgos) -> Option<MicroAlgos> {
+        self.0.checked_sub(other.0).map(MicroAlgos)
+    }
+
+    pub fn checked_mul(self, factor: u64) -> Option<MicroAlgos> {
+        self.0.checked_mul(factor).map(MicroAlgos)
+    }
+}
+
+impl From<u64> for MicroAlgos {
+    fn from(micro_algos: u64) -> Self {
+        MicroAlgos(micro_algos)
+    }
+}
+
+impl From<MicroAlgos> for u64 {
+    fn from(amount: MicroAlgos) -> Self {
+        amount.0
+    }
+}
+
+impl fmt::Display for MicroAlgos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ALGO", self.to_algos())
+    }
+}
+
+/// Format an amount in base units as a decimal number with `decimals` decimal places, without
+/// trailing zeros. For an asset with 2 decimals, 1050 is formatted as `"10.5"`
+pub fn format_units(amount: u64, decimals: u8) -> String {
+    let digits = format!("{:0>width$}", amount, width = decimals as usize + 1);
+    let (whole, fraction) = digits.split_at(digits.len() - decimals as usize);
+    let fraction = fraction.trim_end_matches('0');
+
+    if fraction.is_empty() {
+        whole.to_string()
+    } else {
+        format!("{whole}.{fraction}")
+    }
+}
+
+/// Parse a decimal number into base units with `decimals` decimal places. For an asset with
+/// 2 decimals, `"10.5"` is parsed as 1050
+pub fn parse_units(amount: &str, decimals: u8) -> Result<u64, AmountError> {
+    if decimals > MAX_ASSET_DECIMALS {
+        return Err(AmountError::InvalidDecimals(decimals));
+    }
+
+    let invalid = || AmountError::Invalid(amount.to_string());
+    let is_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+
+    let (whole, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+    if !is_digits(whole) || (amount.contains('.') && !is_digits(fraction)) {
+        return Err(invalid());
+    }
+
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.len() > decimals as usize {
+        return Err(AmountError::TooPrecise {
+            amount: amount.to_string(),
+            decimals,
+        });
+    }
+
+    let digits = format!("{whole}{fraction:0<width$}", width = decimals as usize);
+    digits
+        .parse()
+        .map_err(|_| AmountError::Overflow(amount.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_algos() {
+        assert_eq!(MicroAlgos::from_algos("1.5"), Ok(MicroAlgos(1_500_000)));
+        assert_eq!(MicroAlgos::from_algos("0.000001"), Ok(MicroAlgos(1)));
+        assert_eq!(MicroAlgos::from_algos("2"), Ok(MicroAlgos(2_000_000)));
+        assert_eq!(
+            MicroAlgos::from_algos("1.500000000"),
+            Ok(MicroAlgos(1_500_000))
+        );
+
+        assert_eq!(MicroAlgos(1_500_000).to_algos(), "1.5");
+        assert_eq!(MicroAlgos(1).to_algos(), "0.000001");
+        assert_eq!(MicroAlgos(2_000_000).to_algos(), "2");
+        assert_eq!(MicroAlgos::ZERO.to_algos(), "0");
+        assert_eq!(MicroAlgos(u64::MAX).to_algos(), "18446744073709.551615");
+        assert_eq!(MicroAlgos(1_500_000).to_string(), "1.5 ALGO");
+
+        for invalid in ["", ".5", "1.", "-1", "1,5", "1.5 ALGO", " 1"] {
+            assert_eq!(
+                MicroAlgos::from_algos(invalid),
+                Err(AmountError::Invalid(invalid.to_string()))
+            );
+        }
+        assert_eq!(
+            MicroAlgos::from_algos("0.0000001"),
+            Err(AmountError::TooPrecise {
+                amount: "0.0000001".to_string(),
+                decimals: 6
+            })
+        );
+        assert_eq!(
+            MicroAlgos::from_algos("18446744073709.551616"),
+            Err(AmountError::Overflow("18446744073709.551616".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let amount = MicroAlgos(1000);
+
+        assert_eq!(amount.checked_add(MicroAlgos(500)), Some(MicroAlgos(1500)));
+        assert_eq!(amount.checked_add(MicroAlgos(u64::MAX)), None);
+        assert_eq!(amount.checked_sub(MicroAlgos(1000)), Some(MicroAlgos::ZERO));
+        assert_eq!(amount.checked_sub(MicroAlgos(1001)), None);
+        assert_eq!(amount.checked_mul(3), Some(MicroAlgos(3000)));
+        assert_eq!(amount.checked_mul(u64::MAX), None);
+    }
+
+    #[test]
+    fn test_asset_units() {
+        assert_eq!(format_units(1050, 2), "10.5");
+        assert_eq!(format_units(5, 0), "5");
+        assert_eq!(format_units(1, 19), "0.0000000000000000001");
+        assert_eq!(format_units(u64::MAX, 19), "1.8446744073709551615");
+
+        assert_eq!(parse_units("10.5", 2), Ok(1050));
+        assert_eq!(parse_units("5", 0), Ok(5));
+        assert_eq!(parse_units("5.0", 0), Ok(5));
+        assert_eq!(
+            parse_units("5.5", 0),
+            Err(AmountError::TooPrecise {
+                amount: "5.5".to_string(),
+                decimals: 0
+            })
+        );
+        assert_eq!(parse_units("1", 20), Err(AmountError::InvalidDecimals(20)));
+    }
+
+    #[test]
+    fn test_encoding() {
+        // MicroAlgos encode exactly like the u64 they wrap
+        assert_eq!(
+            rmp_serde::to_vec(&MicroAlgos(1000)).unwrap(),
+            rmp_serde::to_vec(&1000u64).unwrap()
+        );
+        assert_eq!(
+            rmp_serde::from_slice::<MicroAlgos>(&rmp_serde::to_vec(&1000u64).unwrap()).unwrap(),
+            MicroAlgos(1000)
+        );
+    }
+}
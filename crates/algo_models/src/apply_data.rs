@@ -0,0 +1,358 @@
+//! The effects of a transaction, as recorded with it in blocks and pending transaction responses.
+//!
+//! Besides closing amounts, rewards and the IDs of created assets and applications, an
+//! application call records the changes to application state, its logs and the inner
+//! transactions it sent. Inner transactions have apply data of their own, so they nest to any
+//! depth.
+
+use crate::{
+    is_empty_vec_opt, is_zero, is_zero_addr_opt, Address, AlgoModelsError, AlgorandMsgpack,
+    Base64Bytes, LogicSignature, MicroAlgos, MultisigSignature, StringBytes, Transaction,
+};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none};
+use std::collections::BTreeMap;
+
+/// Changes to the state of an application, by key
+pub type StateDelta = BTreeMap<Vec<u8>, ValueDelta>;
+
+fn is_empty_map_opt<K, V>(map: &Option<BTreeMap<K, V>>) -> bool {
+    map.as_ref().is_none_or(BTreeMap::is_empty)
+}
+
+fn is_empty_bytes_opt(bytes: &Option<Vec<u8>>) -> bool {
+    bytes.as_ref().is_none_or(Vec::is_empty)
+}
+
+/// How a state value changed. Encoded as an integer
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ValueDeltaAction {
+    SetBytes = 1,
+    SetUint = 2,
+    Delete = 3,
+}
+
+impl TryFrom<u64> for ValueDeltaAction {
+    type Error = AlgoModelsError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::SetBytes),
+            2 => Ok(Self::SetUint),
+            3 => Ok(Self::Delete),
+            _ => Err(AlgoModelsError::InputError(format!(
+                "unknown value delta action: {}",
+                value
+            ))),
+        }
+    }
+}
+
+impl Serialize for ValueDeltaAction {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(*self as u64)
+    }
+}
+
+impl<'de> Deserialize<'de> for ValueDeltaAction {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = u64::deserialize(deserializer)?;
+        Self::try_from(value).map_err(serde::de::Error::custom)
+    }
+}
+
+/// The new value of a key in application state
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct ValueDelta {
+    #[serde(rename = "at")]
+    pub action: ValueDeltaAction,
+
+    #[serde(rename = "bs")]
+    #[serde_as(as = "Option<StringBytes>")]
+    #[serde(skip_serializing_if = "is_empty_bytes_opt")]
+    #[serde(default)]
+    pub bytes: Option<Vec<u8>>,
+
+    #[serde(rename = "ui")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    pub uint: u64,
+}
+
+/// The changes made by an application call
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct EvalDelta {
+    #[serde(rename = "gd")]
+    #[serde_as(as = "Option<BTreeMap<StringBytes, _>>")]
+    #[serde(skip_serializing_if = "is_empty_map_opt")]
+    #[serde(default)]
+    pub global_delta: Option<StateDelta>,
+
+    /// Changes to local state, keyed by the index of the account in the sender followed by the
+    /// transaction's accounts, then `shared_accounts`
+    #[serde(rename = "ld")]
+    #[serde_as(as = "Option<BTreeMap<_, BTreeMap<StringBytes, _>>>")]
+    #[serde(skip_serializing_if = "is_empty_map_opt")]
+    #[serde(default)]
+    pub local_deltas: Option<BTreeMap<u64, StateDelta>>,
+
+    /// Accounts whose local state changed that are not the sender or in the transaction's
+    /// accounts
+    #[serde(rename = "sa")]
+    #[serde(skip_serializing_if = "is_empty_vec_opt")]
+    #[serde(default)]
+    pub shared_accounts: Option<Vec<Address>>,
+
+    #[serde(rename = "lg")]
+    #[serde_as(as = "Option<Vec<StringBytes>>")]
+    #[serde(skip_serializing_if = "is_empty_vec_opt")]
+    #[serde(default)]
+    pub logs: Option<Vec<Vec<u8>>>,
+
+    #[serde(rename = "itx")]
+    #[serde(skip_serializing_if = "is_empty_vec_opt")]
+    #[serde(default)]
+    pub inner_transactions: Option<Vec<SignedTransactionWithApplyData>>,
+}
+
+/// The effects of a transaction
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct ApplyData {
+    /// The amount sent to the close-to account of a payment
+    #[serde(rename = "ca")]
+    #[serde(skip_serializing_if = "MicroAlgos::is_zero")]
+    #[serde(default)]
+    pub closing_amount: MicroAlgos,
+
+    /// The amount of the asset sent to the close-to account of an asset transfer
+    #[serde(rename = "aca")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    pub asset_closing_amount: u64,
+
+    #[serde(rename = "rs")]
+    #[serde(skip_serializing_if = "MicroAlgos::is_zero")]
+    #[serde(default)]
+    pub sender_rewards: MicroAlgos,
+
+    #[serde(rename = "rr")]
+    #[serde(skip_serializing_if = "MicroAlgos::is_zero")]
+    #[serde(default)]
+    pub receiver_rewards: MicroAlgos,
+
+    #[serde(rename = "rc")]
+    #[serde(skip_serializing_if = "MicroAlgos::is_zero")]
+    #[serde(default)]
+    pub close_rewards: MicroAlgos,
+
+    #[serde(rename = "dt")]
+    #[serde(default)]
+    pub eval_delta: Option<EvalDelta>,
+
+    /// The ID of the asset created by the transaction
+    #[serde(rename = "caid")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    pub config_asset: u64,
+
+    /// The ID of the application created by the transaction
+    #[serde(rename = "apid")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    pub application_id: u64,
+}
+
+impl ApplyData {
+    /// The logs of the application call, which do not include the logs of inner transactions
+    pub fn logs(&self) -> &[Vec<u8>] {
+        self.eval_delta
+            .as_ref()
+            .and_then(|delta| delta.logs.as_deref())
+            .unwrap_or_default()
+    }
+
+    /// The inner transactions sent by the application call, in order
+    pub fn inner_transactions(&self) -> &[SignedTransactionWithApplyData] {
+        self.eval_delta
+            .as_ref()
+            .and_then(|delta| delta.inner_transactions.as_deref())
+            .unwrap_or_default()
+    }
+}
+
+/// A signed transaction together with its effects. Inner transactions are not signed, so they
+/// have no signature
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct SignedTransactionWithApplyData {
+    #[serde(rename = "txn")]
+    pub transaction: Transaction,
+
+    #[serde(rename = "sig")]
+    #[serde_as(as = "Option<Base64Bytes>")]
+    #[serde(default)]
+    pub signature: Option<[u8; 64]>,
+
+    #[serde(rename = "msig")]
+    #[serde(default)]
+    pub multisig_signature: Option<MultisigSignature>,
+
+    #[serde(rename = "lsig")]
+    #[serde(default)]
+    pub logic_signature: Option<LogicSignature>,
+
+    /// The address that signed the transaction, when it is not the sender because the sender
+    /// was rekeyed
+    #[serde(rename = "sgnr")]
+    #[serde(skip_serializing_if = "is_zero_addr_opt")]
+    #[serde(default)]
+    pub auth_address: Option<Address>,
+
+    #[serde(flatten)]
+    pub apply_data: ApplyData,
+}
+
+impl AlgorandMsgpack for SignedTransactionWithApplyData {
+    const PREFIX: &'static [u8] = b"";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block::Block;
+    use pretty_assertions::assert_eq;
+
+    const BLOCK_RESPONSE: &[u8] = include_bytes!("../test_data/block.msgpack");
+
+    /// The application call in the block fixture, which changed state, logged and sent inner
+    /// transactions
+    fn app_call() -> SignedTransactionWithApplyData {
+        let block = Block::from_response(BLOCK_RESPONSE).unwrap();
+        block.transactions.unwrap()[4]
+            .decoded()
+            .unwrap()
+            .signed_transaction
+            .clone()
+    }
+
+    #[test]
+    fn test_eval_delta() {
+        let apply_data = app_call().apply_data;
+        let delta = apply_data.eval_delta.as_ref().unwrap();
+
+        // State keys and byte values are usually not valid UTF-8
+        let global_delta = delta.global_delta.as_ref().unwrap();
+        assert_eq!(
+            global_delta.get(b"counter".as_slice()),
+            Some(&ValueDelta {
+                action: ValueDeltaAction::SetUint,
+                bytes: None,
+                uint: 42
+            })
+        );
+        assert_eq!(
+            global_delta.get([0, 0, 0, 0, 0, 0, 0, 7].as_slice()),
+            Some(&ValueDelta {
+                action: ValueDeltaAction::SetBytes,
+                bytes: Some(vec![0xff, 0xfe, 1]),
+                uint: 0
+            })
+        );
+        assert_eq!(
+            global_delta.get(b"owner".as_slice()).map(|d| d.action),
+            Some(ValueDeltaAction::Delete)
+        );
+
+        let local_deltas = delta.local_deltas.as_ref().unwrap();
+        assert_eq!(local_deltas.keys().collect::<Vec<_>>(), [&0, &1]);
+        assert_eq!(local_deltas[&0][b"balance".as_slice()].uint, 250_000);
+        assert_eq!(delta.shared_accounts.as_ref().map(Vec::len), Some(1));
+
+        assert_eq!(
+            apply_data.logs(),
+            [
+                b"withdraw".to_vec(),
+                vec![0x15, 0x1f, 0x7c, 0x75, 0, 0, 0, 0, 0, 0x03, 0xd0, 0x90]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_inner_transactions() {
+        let app_call = app_call();
+        let inner = app_call.apply_data.inner_transactions();
+        assert_eq!(inner.len(), 2);
+
+        let Transaction::Payment(payment) = &inner[0].transaction else {
+            panic!("expected an inner payment");
+        };
+        assert_eq!(payment.amount, MicroAlgos(250_000));
+        assert_eq!(payment.header.sender, Address::from_app_id(1002));
+        assert_eq!(inner[0].signature, None);
+
+        // The inner application call sent an inner transaction of its own
+        let nested = &inner[1];
+        assert_eq!(nested.apply_data.application_id, 3_000_000_002);
+        assert_eq!(nested.apply_data.logs(), [b"created".to_vec()]);
+        let Transaction::AssetTransfer(transfer) =
+            &nested.apply_data.inner_transactions()[0].transaction
+        else {
+            panic!("expected a nested asset transfer");
+        };
+        assert_eq!(transfer.amount, 10);
+        assert_eq!(transfer.header.sender, Address::from_app_id(3_000_000_002));
+
+        // Re-encoding keeps state keys and logs as msgpack strings, as go-algorand encodes them
+        let encoded = app_call.encode().unwrap();
+        assert_eq!(
+            SignedTransactionWithApplyData::decode(&encoded).unwrap(),
+            app_call
+        );
+    }
+
+    #[test]
+    fn test_string_bytes_encoding() {
+        // A 40 byte state key, a byte value and logs that are not valid UTF-8, one of them
+        // longer than 255 bytes
+        let key: Vec<u8> = (0..40).map(|i| 0x80 | i).collect();
+        let long_log = vec![0xff; 300];
+        let mut with_delta = app_call();
+        with_delta.apply_data.eval_delta = Some(EvalDelta {
+            global_delta: Some(StateDelta::from([(
+                key.clone(),
+                ValueDelta {
+                    action: ValueDeltaAction::SetBytes,
+                    bytes: Some(vec![0xff, 0xfe, 1]),
+                    uint: 0,
+                },
+            )])),
+            logs: Some(vec![vec![0xc3], long_log.clone()]),
+            ..Default::default()
+        });
+
+        // go-algorand encodes all of them as msgpack strings, never as binary
+        let expected_delta = [
+            b"\xa2dt\x82\xa2gd\x81\xd9\x28".as_slice(),
+            &key,
+            b"\x82\xa2at\x01\xa2bs\xa3\xff\xfe\x01",
+            b"\xa2lg\x92\xa1\xc3\xda\x01\x2c",
+            &long_log,
+        ]
+        .concat();
+        let encoded = with_delta.encode().unwrap();
+        assert!(encoded
+            .windows(expected_delta.len())
+            .any(|window| window == expected_delta));
+
+        let decoded = SignedTransactionWithApplyData::decode(&encoded).unwrap();
+        assert_eq!(decoded, with_delta);
+        assert_eq!(decoded.encode().unwrap(), encoded);
+    }
+}
@@ -0,0 +1,1395 @@
+//! ARC-56 application specifications, which describe the methods, state and programs of an
+//! application so that clients can be generated for it.
+//!
+//! ARC-32 `application.json` files can be loaded as well and are converted to ARC-56.
+
+use crate::abi::{self, AbiError, AbiType, MethodArgType};
+use crate::OnApplicationComplete;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::collections::{BTreeMap, BTreeSet};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// The AVM types that may be used for storage keys and values in addition to ABI types and
+/// struct names
+pub const AVM_TYPES: [&str; 3] = ["AVMBytes", "AVMString", "AVMUint64"];
+
+#[derive(Debug, Error)]
+pub enum AppSpecError {
+    #[error("invalid app spec JSON: {0}")]
+    InvalidJson(#[from] serde_json::Error),
+
+    #[error("invalid ARC-32 app spec: {0}")]
+    InvalidArc32(String),
+}
+
+/// Serialize on completion actions by name (i.e. `"NoOp"`) rather than by their integer value
+mod action_names {
+    use crate::OnApplicationComplete;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub(super) fn name(action: &OnApplicationComplete) -> &'static str {
+        match action {
+            OnApplicationComplete::NoOp => "NoOp",
+            OnApplicationComplete::OptIn => "OptIn",
+            OnApplicationComplete::CloseOut => "CloseOut",
+            OnApplicationComplete::ClearState => "ClearState",
+            OnApplicationComplete::UpdateApplication => "UpdateApplication",
+            OnApplicationComplete::DeleteApplication => "DeleteApplication",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<OnApplicationComplete> {
+        match name {
+            "NoOp" => Some(OnApplicationComplete::NoOp),
+            "OptIn" => Some(OnApplicationComplete::OptIn),
+            "CloseOut" => Some(OnApplicationComplete::CloseOut),
+            "ClearState" => Some(OnApplicationComplete::ClearState),
+            "UpdateApplication" => Some(OnApplicationComplete::UpdateApplication),
+            "DeleteApplication" => Some(OnApplicationComplete::DeleteApplication),
+            _ => None,
+        }
+    }
+
+    pub fn serialize<S: Serializer>(
+        actions: &[OnApplicationComplete],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(actions.iter().map(name))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<OnApplicationComplete>, D::Error> {
+        Vec::<String>::deserialize(deserializer)?
+            .iter()
+            .map(|n| from_name(n).ok_or_else(|| D::Error::custom(format!("unknown action: {}", n))))
+            .collect()
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Arc56Contract {
+    /// The ARCs the application implements
+    #[serde(default)]
+    pub arcs: Vec<u32>,
+
+    pub name: String,
+
+    pub desc: Option<String>,
+
+    /// The ID of the application on each network, keyed by network name or genesis hash
+    pub networks: Option<BTreeMap<String, Network>>,
+
+    /// Named structs, which give names to the fields of ABI tuples
+    #[serde(default)]
+    pub structs: BTreeMap<String, Vec<StructField>>,
+
+    pub methods: Vec<Method>,
+
+    pub state: State,
+
+    /// The on completion actions that may be performed without calling a method
+    pub bare_actions: Actions,
+
+    pub source_info: Option<ProgramSourceInfos>,
+
+    /// The base64 encoded TEAL source of the programs
+    pub source: Option<Programs>,
+
+    /// The base64 encoded compiled programs
+    pub byte_code: Option<Programs>,
+
+    pub compiler_info: Option<CompilerInfo>,
+
+    /// ARC-28 events the application may emit
+    pub events: Option<Vec<Event>>,
+
+    /// Template variables that must be substituted into the TEAL before compilation
+    pub template_variables: Option<BTreeMap<String, TemplateVariable>>,
+
+    pub scratch_variables: Option<BTreeMap<String, ScratchVariable>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct Network {
+    #[serde(rename = "appID")]
+    pub app_id: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct StructField {
+    pub name: String,
+
+    #[serde(rename = "type")]
+    pub field_type: StructFieldType,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(untagged)]
+pub enum StructFieldType {
+    /// An ABI type or the name of another struct
+    Type(String),
+
+    /// An anonymous nested struct
+    Fields(Vec<StructField>),
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Method {
+    pub name: String,
+
+    pub desc: Option<String>,
+
+    pub args: Vec<MethodArg>,
+
+    pub returns: MethodReturns,
+
+    pub actions: Actions,
+
+    /// Whether the method can be called without changing state, i.e. through simulate
+    pub readonly: Option<bool>,
+
+    pub events: Option<Vec<Event>>,
+
+    pub recommendations: Option<Recommendations>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MethodArg {
+    /// An ABI type, transaction type or reference type
+    #[serde(rename = "type")]
+    pub arg_type: String,
+
+    /// The struct that gives names to the fields of a tuple argument
+    #[serde(rename = "struct")]
+    pub struct_name: Option<String>,
+
+    pub name: Option<String>,
+
+    pub desc: Option<String>,
+
+    pub default_value: Option<DefaultValue>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct MethodReturns {
+    /// An ABI type or `void`
+    #[serde(rename = "type")]
+    pub return_type: String,
+
+    #[serde(rename = "struct")]
+    pub struct_name: Option<String>,
+
+    pub desc: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct DefaultValue {
+    /// The base64 encoded literal or storage key, or the signature of the method to call
+    pub data: String,
+
+    /// How `data` is encoded, when it differs from the argument type
+    #[serde(rename = "type")]
+    pub value_type: Option<String>,
+
+    pub source: DefaultValueSource,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum DefaultValueSource {
+    Box,
+    Global,
+    Local,
+    Literal,
+    Method,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct Actions {
+    /// Actions allowed when creating the application
+    #[serde(with = "action_names")]
+    pub create: Vec<OnApplicationComplete>,
+
+    /// Actions allowed when calling an existing application
+    #[serde(with = "action_names")]
+    pub call: Vec<OnApplicationComplete>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Recommendations {
+    pub inner_transaction_count: Option<u64>,
+
+    pub boxes: Option<BoxRecommendation>,
+
+    pub accounts: Option<Vec<String>>,
+
+    pub apps: Option<Vec<u64>>,
+
+    pub assets: Option<Vec<u64>>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BoxRecommendation {
+    pub app: Option<u64>,
+
+    /// The base64 encoded box name
+    pub key: String,
+
+    pub read_bytes: u64,
+
+    pub write_bytes: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct State {
+    pub schema: StateSchemas,
+
+    #[serde(default)]
+    pub keys: StorageKeys,
+
+    #[serde(default)]
+    pub maps: StorageMaps,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct StateSchemas {
+    pub global: Schema,
+
+    pub local: Schema,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct Schema {
+    pub ints: u64,
+
+    pub bytes: u64,
+}
+
+impl From<&Schema> for crate::StateSchema {
+    fn from(schema: &Schema) -> Self {
+        crate::StateSchema {
+            num_uints: schema.ints,
+            num_byte_slices: schema.bytes,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct StorageKeys {
+    #[serde(default)]
+    pub global: BTreeMap<String, StorageKey>,
+
+    #[serde(default)]
+    pub local: BTreeMap<String, StorageKey>,
+
+    #[serde(rename = "box")]
+    #[serde(default)]
+    pub boxes: BTreeMap<String, StorageKey>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct StorageMaps {
+    #[serde(default)]
+    pub global: BTreeMap<String, StorageMap>,
+
+    #[serde(default)]
+    pub local: BTreeMap<String, StorageMap>,
+
+    #[serde(rename = "box")]
+    #[serde(default)]
+    pub boxes: BTreeMap<String, StorageMap>,
+}
+
+/// A single value in global, local or box storage
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageKey {
+    pub desc: Option<String>,
+
+    pub key_type: String,
+
+    pub value_type: String,
+
+    /// The base64 encoded key
+    pub key: String,
+}
+
+/// A mapping of keys to values in global, local or box storage, where every key starts with
+/// the same prefix
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageMap {
+    pub desc: Option<String>,
+
+    pub key_type: String,
+
+    pub value_type: String,
+
+    /// The base64 encoded prefix of the keys
+    pub prefix: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct ProgramSourceInfos {
+    pub approval: ProgramSourceInfo,
+
+    pub clear: ProgramSourceInfo,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ProgramSourceInfo {
+    pub source_info: Vec<SourceInfo>,
+
+    pub pc_offset_method: PcOffsetMethod,
+}
+
+/// How program counters in source info relate to the compiled program
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum PcOffsetMethod {
+    /// The program counters are exact
+    None,
+
+    /// The program counters do not include the constant blocks (`intcblock` and `bytecblock`)
+    /// at the start of the program, whose length depends on template variables
+    Cblocks,
+}
+
+/// Information about the source of one or more program counters, such as the error message
+/// to show when the program fails there
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceInfo {
+    pub pc: Vec<u64>,
+
+    pub error_message: Option<String>,
+
+    /// The line of the TEAL source
+    pub teal: Option<u64>,
+
+    /// The location in the original (pre-TEAL) source
+    pub source: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct Programs {
+    pub approval: String,
+
+    pub clear: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CompilerInfo {
+    pub compiler: Compiler,
+
+    pub compiler_version: CompilerVersion,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum Compiler {
+    Algod,
+    Puya,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CompilerVersion {
+    pub major: u64,
+
+    pub minor: u64,
+
+    pub patch: u64,
+
+    pub commit_hash: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct Event {
+    pub name: String,
+
+    pub desc: Option<String>,
+
+    pub args: Vec<EventArg>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct EventArg {
+    #[serde(rename = "type")]
+    pub arg_type: String,
+
+    pub name: Option<String>,
+
+    pub desc: Option<String>,
+
+    #[serde(rename = "struct")]
+    pub struct_name: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct TemplateVariable {
+    /// An ABI type, AVM type or struct name
+    #[serde(rename = "type")]
+    pub variable_type: String,
+
+    /// The base64 encoded value, if it is known at compile time
+    pub value: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct ScratchVariable {
+    pub slot: u64,
+
+    #[serde(rename = "type")]
+    pub variable_type: String,
+}
+
+impl Method {
+    /// The ARC-4 signature, i.e. `add(uint64,uint64)uint128`
+    pub fn signature(&self) -> String {
+        let args: Vec<&str> = self.args.iter().map(|a| a.arg_type.as_str()).collect();
+        format!(
+            "{}({}){}",
+            self.name,
+            args.join(","),
+            self.returns.return_type
+        )
+    }
+
+    /// Parse the method into an ARC-4 method that can be used to build application calls
+    pub fn abi_method(&self) -> Result<abi::Method, AbiError> {
+        let mut method: abi::Method = self.signature().parse()?;
+        method.description = self.desc.clone();
+
+        for (arg, spec_arg) in method.args.iter_mut().zip(&self.args) {
+            arg.name = spec_arg.name.clone();
+            arg.description = spec_arg.desc.clone();
+        }
+
+        Ok(method)
+    }
+}
+
+impl Arc56Contract {
+    /// Parse an ARC-56 app spec, or an ARC-32 app spec which is converted to ARC-56
+    pub fn from_json(json: &str) -> Result<Self, AppSpecError> {
+        let value: serde_json::Value = serde_json::from_str(json)?;
+
+        if value.get("hints").is_some() && value.get("contract").is_some() {
+            let arc32: arc32::Arc32Contract = serde_json::from_value(value)?;
+            return arc32.try_into();
+        }
+
+        Ok(serde_json::from_value(value)?)
+    }
+
+    /// Parse an ARC-32 `application.json` and convert it to ARC-56
+    pub fn from_arc32_json(json: &str) -> Result<Self, AppSpecError> {
+        serde_json::from_str::<arc32::Arc32Contract>(json)?.try_into()
+    }
+
+    pub fn to_json(&self) -> Result<String, AppSpecError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Find a method by its signature, or by its name if no other method has the same name
+    pub fn get_method(&self, name_or_signature: &str) -> Option<&Method> {
+        if name_or_signature.contains('(') {
+            return self
+                .methods
+                .iter()
+                .find(|m| m.signature() == name_or_signature);
+        }
+
+        let mut matches = self.methods.iter().filter(|m| m.name == name_or_signature);
+        match (matches.next(), matches.next()) {
+            (Some(method), None) => Some(method),
+            _ => None,
+        }
+    }
+
+    /// The ABI tuple type that a struct is encoded as
+    pub fn struct_abi_type(&self, name: &str) -> Result<AbiType, AbiError> {
+        self.struct_abi_type_inner(name, &mut vec![])
+    }
+
+    fn struct_abi_type_inner<'a>(
+        &'a self,
+        name: &'a str,
+        visiting: &mut Vec<&'a str>,
+    ) -> Result<AbiType, AbiError> {
+        let fields = self
+            .structs
+            .get(name)
+            .ok_or_else(|| AbiError::InvalidType(name.to_string()))?;
+
+        // Structs that (indirectly) contain themselves cannot be encoded
+        if visiting.contains(&name) {
+            return Err(AbiError::InvalidType(name.to_string()));
+        }
+
+        visiting.push(name);
+        let abi_type = self.fields_abi_type(fields, visiting);
+        visiting.pop();
+        abi_type
+    }
+
+    fn fields_abi_type<'a>(
+        &'a self,
+        fields: &'a [StructField],
+        visiting: &mut Vec<&'a str>,
+    ) -> Result<AbiType, AbiError> {
+        let types = fields
+            .iter()
+            .map(|field| match &field.field_type {
+                StructFieldType::Type(t) if self.structs.contains_key(t) => {
+                    self.struct_abi_type_inner(t, visiting)
+                }
+                StructFieldType::Type(t) => t.parse(),
+                StructFieldType::Fields(fields) => self.fields_abi_type(fields, visiting),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(AbiType::Tuple(types))
+    }
+
+    /// Check the spec for inconsistencies, such as types that cannot be parsed, references to
+    /// structs that do not exist, or more state keys than the schema allows.
+    /// Returns a description of each problem that was found
+    pub fn validate(&self) -> Vec<String> {
+        let mut issues = vec![];
+
+        for (name, fields) in &self.structs {
+            if fields.is_empty() {
+                issues.push(format!("struct {} has no fields", name));
+            }
+            if let Err(e) = self.struct_abi_type(name) {
+                issues.push(format!("struct {} cannot be encoded: {}", name, e));
+            }
+        }
+
+        let mut signatures = BTreeSet::new();
+        for method in &self.methods {
+            let signature = method.signature();
+            if !signatures.insert(signature.clone()) {
+                issues.push(format!("method {} is defined more than once", signature));
+            }
+
+            for (i, arg) in method.args.iter().enumerate() {
+                let context = format!("argument {} of method {}", i, signature);
+
+                if let Err(e) = MethodArgType::from_str(&arg.arg_type) {
+                    issues.push(format!("{}: {}", context, e));
+                }
+                self.check_struct(&context, &arg.arg_type, &arg.struct_name, &mut issues);
+
+                if let Some(default) = &arg.default_value {
+                    if default.source == DefaultValueSource::Method {
+                        if self.get_method(&default.data).is_none() {
+                            issues.push(format!(
+                                "{}: default value method {} does not exist",
+                                context, default.data
+                            ));
+                        }
+                    } else {
+                        check_base64(&context, &default.data, &mut issues);
+                    }
+                }
+            }
+
+            let context = format!("return value of method {}", signature);
+            if method.returns.return_type != "void" {
+                if let Err(e) = AbiType::from_str(&method.returns.return_type) {
+                    issues.push(format!("{}: {}", context, e));
+                }
+            }
+            self.check_struct(
+                &context,
+                &method.returns.return_type,
+                &method.returns.struct_name,
+                &mut issues,
+            );
+
+            check_actions(
+                &format!("method {}", signature),
+                &method.actions,
+                &mut issues,
+            );
+            if method.actions.create.is_empty() && method.actions.call.is_empty() {
+                issues.push(format!("method {} has no actions", signature));
+            }
+
+            for event in method.events.iter().flatten() {
+                self.check_event(event, &mut issues);
+            }
+        }
+
+        check_actions("bare actions", &self.bare_actions, &mut issues);
+
+        let keys = &self.state.keys;
+        let maps = &self.state.maps;
+        for (storage, keys, maps) in [
+            ("global", &keys.global, &maps.global),
+            ("local", &keys.local, &maps.local),
+            ("box", &keys.boxes, &maps.boxes),
+        ] {
+            let mut seen = BTreeSet::new();
+            for (name, key) in keys {
+                let context = format!("{} key {}", storage, name);
+                check_base64(&context, &key.key, &mut issues);
+                self.check_storage_type(&context, &key.key_type, &mut issues);
+                self.check_storage_type(&context, &key.value_type, &mut issues);
+
+                if !seen.insert(&key.key) {
+                    issues.push(format!(
+                        "{}: key {} is used more than once",
+                        context, key.key
+                    ));
+                }
+            }
+
+            for (name, map) in maps {
+                let context = format!("{} map {}", storage, name);
+                if let Some(prefix) = &map.prefix {
+                    check_base64(&context, prefix, &mut issues);
+                }
+                self.check_storage_type(&context, &map.key_type, &mut issues);
+                self.check_storage_type(&context, &map.value_type, &mut issues);
+            }
+        }
+
+        let schema = &self.state.schema;
+        for (storage, keys, schema) in [
+            ("global", &keys.global, &schema.global),
+            ("local", &keys.local, &schema.local),
+        ] {
+            if keys.len() as u64 > schema.ints + schema.bytes {
+                issues.push(format!(
+                    "{} state has {} keys, but the schema only allows {}",
+                    storage,
+                    keys.len(),
+                    schema.ints + schema.bytes
+                ));
+            }
+        }
+
+        for (name, programs) in [("source", &self.source), ("byte code", &self.byte_code)] {
+            if let Some(programs) = programs {
+                check_base64(
+                    &format!("approval {}", name),
+                    &programs.approval,
+                    &mut issues,
+                );
+                check_base64(&format!("clear {}", name), &programs.clear, &mut issues);
+            }
+        }
+
+        for event in self.events.iter().flatten() {
+            self.check_event(event, &mut issues);
+        }
+
+        for (name, variable) in self.template_variables.iter().flatten() {
+            let context = format!("template variable {}", name);
+            self.check_storage_type(&context, &variable.variable_type, &mut issues);
+            if let Some(value) = &variable.value {
+                check_base64(&context, value, &mut issues);
+            }
+        }
+
+        issues
+    }
+
+    /// Check that a struct exists and is encoded as the given ABI type
+    fn check_struct(
+        &self,
+        context: &str,
+        abi_type: &str,
+        struct_name: &Option<String>,
+        issues: &mut Vec<String>,
+    ) {
+        let Some(struct_name) = struct_name else {
+            return;
+        };
+
+        if !self.structs.contains_key(struct_name) {
+            issues.push(format!(
+                "{}: struct {} does not exist",
+                context, struct_name
+            ));
+            return;
+        }
+
+        if let Ok(struct_type) = self.struct_abi_type(struct_name) {
+            if abi_type.parse::<AbiType>().ok().as_ref() != Some(&struct_type) {
+                issues.push(format!(
+                    "{}: struct {} is encoded as {}, not {}",
+                    context, struct_name, struct_type, abi_type
+                ));
+            }
+        }
+    }
+
+    fn check_storage_type(&self, context: &str, storage_type: &str, issues: &mut Vec<String>) {
+        if !AVM_TYPES.contains(&storage_type)
+            && !self.structs.contains_key(storage_type)
+            && AbiType::from_str(storage_type).is_err()
+        {
+            issues.push(format!("{}: unknown type {}", context, storage_type));
+        }
+    }
+
+    fn check_event(&self, event: &Event, issues: &mut Vec<String>) {
+        for (i, arg) in event.args.iter().enumerate() {
+            let context = format!("argument {} of event {}", i, event.name);
+            if let Err(e) = AbiType::from_str(&arg.arg_type) {
+                issues.push(format!("{}: {}", context, e));
+            }
+            self.check_struct(&context, &arg.arg_type, &arg.struct_name, issues);
+        }
+    }
+}
+
+fn check_base64(context: &str, data: &str, issues: &mut Vec<String>) {
+    if BASE64.decode(data).is_err() {
+        issues.push(format!("{}: {} is not valid base64", context, data));
+    }
+}
+
+/// Applications cannot be created with `CloseOut` or `UpdateApplication`, and `ClearState`
+/// calls only run the clear state program
+fn check_actions(context: &str, actions: &Actions, issues: &mut Vec<String>) {
+    for action in &actions.create {
+        if matches!(
+            action,
+            OnApplicationComplete::CloseOut
+                | OnApplicationComplete::UpdateApplication
+                | OnApplicationComplete::ClearState
+        ) {
+            issues.push(format!(
+                "{}: {} is not a valid create action",
+                context,
+                action_names::name(action)
+            ));
+        }
+    }
+
+    if actions.call.contains(&OnApplicationComplete::ClearState) {
+        issues.push(format!(
+            "{}: ClearState is not a valid call action",
+            context
+        ));
+    }
+}
+
+/// ARC-32 `application.json` models, which are only used to convert to ARC-56
+mod arc32 {
+    use super::*;
+
+    #[derive(Deserialize)]
+    pub(super) struct Arc32Contract {
+        #[serde(default)]
+        pub hints: BTreeMap<String, Hint>,
+        pub source: Option<Programs>,
+        #[serde(default)]
+        pub state: StateSchemas,
+        #[serde(default)]
+        pub schema: Schemas,
+        pub contract: Contract,
+        #[serde(default)]
+        pub bare_call_config: BTreeMap<String, String>,
+    }
+
+    #[derive(Deserialize, Default)]
+    pub(super) struct Hint {
+        #[serde(default)]
+        pub structs: BTreeMap<String, StructHint>,
+        pub read_only: Option<bool>,
+        #[serde(default)]
+        pub default_arguments: BTreeMap<String, DefaultArgument>,
+        #[serde(default)]
+        pub call_config: BTreeMap<String, String>,
+    }
+
+    #[derive(Deserialize)]
+    pub(super) struct StructHint {
+        pub name: String,
+        /// (name, type) pairs
+        pub elements: Vec<(String, String)>,
+    }
+
+    #[derive(Deserialize)]
+    pub(super) struct DefaultArgument {
+        pub source: String,
+        pub data: serde_json::Value,
+    }
+
+    #[derive(Deserialize, Default)]
+    pub(super) struct StateSchemas {
+        pub global: Schema,
+        pub local: Schema,
+    }
+
+    #[derive(Deserialize, Default)]
+    pub(super) struct Schema {
+        #[serde(default)]
+        pub num_uints: u64,
+        #[serde(default)]
+        pub num_byte_slices: u64,
+    }
+
+    #[derive(Deserialize, Default)]
+    pub(super) struct Schemas {
+        #[serde(default)]
+        pub global: DeclaredSchema,
+        #[serde(default)]
+        pub local: DeclaredSchema,
+    }
+
+    #[derive(Deserialize, Default)]
+    pub(super) struct DeclaredSchema {
+        #[serde(default)]
+        pub declared: BTreeMap<String, DeclaredValue>,
+    }
+
+    #[derive(Deserialize)]
+    pub(super) struct DeclaredValue {
+        #[serde(rename = "type")]
+        pub value_type: String,
+        pub key: String,
+        pub descr: Option<String>,
+    }
+
+    /// The ARC-4 contract description
+    #[derive(Deserialize)]
+    pub(super) struct Contract {
+        pub name: String,
+        pub desc: Option<String>,
+        pub methods: Vec<ContractMethod>,
+        pub networks: Option<BTreeMap<String, Network>>,
+    }
+
+    #[derive(Deserialize)]
+    pub(super) struct ContractMethod {
+        pub name: String,
+        pub desc: Option<String>,
+        pub args: Vec<ContractArg>,
+        pub returns: ContractReturns,
+    }
+
+    #[derive(Deserialize)]
+    pub(super) struct ContractArg {
+        #[serde(rename = "type")]
+        pub arg_type: String,
+        pub name: Option<String>,
+        pub desc: Option<String>,
+    }
+
+    #[derive(Deserialize)]
+    pub(super) struct ContractReturns {
+        #[serde(rename = "type")]
+        pub return_type: String,
+        pub desc: Option<String>,
+    }
+
+    impl ContractMethod {
+        fn signature(&self) -> String {
+            let args: Vec<&str> = self.args.iter().map(|a| a.arg_type.as_str()).collect();
+            format!(
+                "{}({}){}",
+                self.name,
+                args.join(","),
+                self.returns.return_type
+            )
+        }
+    }
+
+    fn invalid(message: impl Into<String>) -> AppSpecError {
+        AppSpecError::InvalidArc32(message.into())
+    }
+
+    fn schema(schema: &Schema) -> super::Schema {
+        super::Schema {
+            ints: schema.num_uints,
+            bytes: schema.num_byte_slices,
+        }
+    }
+
+    /// Convert an ARC-32 call config (`{"no_op": "CREATE", ...}`) to ARC-56 actions
+    fn actions(call_config: &BTreeMap<String, String>) -> Result<Actions, AppSpecError> {
+        let mut actions = Actions::default();
+
+        for (on_complete, config) in call_config {
+            let action = match on_complete.as_str() {
+                "no_op" => OnApplicationComplete::NoOp,
+                "opt_in" => OnApplicationComplete::OptIn,
+                "close_out" => OnApplicationComplete::CloseOut,
+                "update_application" => OnApplicationComplete::UpdateApplication,
+                "delete_application" => OnApplicationComplete::DeleteApplication,
+                _ => return Err(invalid(format!("unknown call config {}", on_complete))),
+            };
+
+            let (create, call) = match config.as_str() {
+                "NEVER" => (false, false),
+                "CALL" => (false, true),
+                "CREATE" => (true, false),
+                "ALL" => (true, true),
+                _ => return Err(invalid(format!("unknown call config value {}", config))),
+            };
+
+            if create {
+                actions.create.push(action);
+            }
+            if call {
+                actions.call.push(action);
+            }
+        }
+
+        Ok(actions)
+    }
+
+    fn default_value(
+        arg_type: &str,
+        default: &DefaultArgument,
+    ) -> Result<super::DefaultValue, AppSpecError> {
+        let key = |data: &serde_json::Value| {
+            data.as_str()
+                .map(|key| BASE64.encode(key))
+                .ok_or_else(|| invalid(format!("state key must be a string, but got {}", data)))
+        };
+
+        Ok(match default.source.as_str() {
+            "constant" => {
+                let (value_type, value) = match &default.data {
+                    serde_json::Value::Number(n) => {
+                        let n = n
+                            .as_u64()
+                            .ok_or_else(|| invalid(format!("invalid constant {}", n)))?;
+                        // Use the argument's own uintN type when possible so the literal can be
+                        // used as is
+                        let abi_type = match arg_type.parse::<AbiType>() {
+                            Ok(t @ AbiType::Uint(_)) => t,
+                            _ => AbiType::Uint(64),
+                        };
+                        let encoded = abi_type
+                            .encode(&n.into())
+                            .map_err(|e| invalid(e.to_string()))?;
+                        (abi_type.to_string(), encoded)
+                    }
+                    serde_json::Value::String(s) => {
+                        ("AVMString".to_string(), s.as_bytes().to_vec())
+                    }
+                    data => return Err(invalid(format!("invalid constant {}", data))),
+                };
+
+                super::DefaultValue {
+                    data: BASE64.encode(value),
+                    value_type: (value_type != arg_type).then_some(value_type),
+                    source: DefaultValueSource::Literal,
+                }
+            }
+            "global-state" | "local-state" => super::DefaultValue {
+                data: key(&default.data)?,
+                value_type: Some("AVMString".to_string()),
+                source: if default.source == "global-state" {
+                    DefaultValueSource::Global
+                } else {
+                    DefaultValueSource::Local
+                },
+            },
+            "abi-method" => {
+                let method: ContractMethod = serde_json::from_value(default.data.clone())?;
+                super::DefaultValue {
+                    data: method.signature(),
+                    value_type: None,
+                    source: DefaultValueSource::Method,
+                }
+            }
+            source => {
+                return Err(invalid(format!(
+                    "unknown default argument source {}",
+                    source
+                )))
+            }
+        })
+    }
+
+    fn storage_keys(schema: &DeclaredSchema) -> BTreeMap<String, StorageKey> {
+        schema
+            .declared
+            .iter()
+            .map(|(name, value)| {
+                let value_type = match value.value_type.as_str() {
+                    "uint64" => "AVMUint64",
+                    _ => "AVMBytes",
+                };
+
+                (
+                    name.clone(),
+                    StorageKey {
+                        desc: value.descr.clone(),
+                        key_type: "AVMString".to_string(),
+                        value_type: value_type.to_string(),
+                        key: BASE64.encode(&value.key),
+                    },
+                )
+            })
+            .collect()
+    }
+
+    impl TryFrom<Arc32Contract> for Arc56Contract {
+        type Error = AppSpecError;
+
+        fn try_from(arc32: Arc32Contract) -> Result<Self, Self::Error> {
+            let mut structs = BTreeMap::new();
+            let mut methods = vec![];
+
+            for method in &arc32.contract.methods {
+                let signature = method.signature();
+                let hint = arc32.hints.get(&signature);
+                let default_hint = Hint::default();
+                let hint = hint.unwrap_or(&default_hint);
+
+                for struct_hint in hint.structs.values() {
+                    let fields = struct_hint
+                        .elements
+                        .iter()
+                        .map(|(name, field_type)| StructField {
+                            name: name.clone(),
+                            field_type: StructFieldType::Type(field_type.clone()),
+                        })
+                        .collect();
+                    structs.insert(struct_hint.name.clone(), fields);
+                }
+
+                let struct_name = |name: &Option<String>| {
+                    name.as_ref()
+                        .and_then(|name| hint.structs.get(name))
+                        .map(|s| s.name.clone())
+                };
+
+                let args = method
+                    .args
+                    .iter()
+                    .map(|arg| {
+                        Ok(MethodArg {
+                            arg_type: arg.arg_type.clone(),
+                            struct_name: struct_name(&arg.name),
+                            name: arg.name.clone(),
+                            desc: arg.desc.clone(),
+                            default_value: arg
+                                .name
+                                .as_ref()
+                                .and_then(|name| hint.default_arguments.get(name))
+                                .map(|default| default_value(&arg.arg_type, default))
+                                .transpose()?,
+                        })
+                    })
+                    .collect::<Result<_, AppSpecError>>()?;
+
+                methods.push(Method {
+                    name: method.name.clone(),
+                    desc: method.desc.clone(),
+                    args,
+                    returns: MethodReturns {
+                        return_type: method.returns.return_type.clone(),
+                        // ARC-32 uses "output" as the name of the return value in struct hints
+                        struct_name: struct_name(&Some("output".to_string())),
+                        desc: method.returns.desc.clone(),
+                    },
+                    actions: actions(&hint.call_config)?,
+                    readonly: hint.read_only,
+                    events: None,
+                    recommendations: None,
+                });
+            }
+
+            for signature in arc32.hints.keys() {
+                if !arc32
+                    .contract
+                    .methods
+                    .iter()
+                    .any(|m| m.signature() == *signature)
+                {
+                    return Err(invalid(format!("hint for unknown method {}", signature)));
+                }
+            }
+
+            Ok(Arc56Contract {
+                arcs: vec![],
+                name: arc32.contract.name,
+                desc: arc32.contract.desc,
+                networks: arc32.contract.networks,
+                structs,
+                methods,
+                state: State {
+                    schema: super::StateSchemas {
+                        global: schema(&arc32.state.global),
+                        local: schema(&arc32.state.local),
+                    },
+                    keys: StorageKeys {
+                        global: storage_keys(&arc32.schema.global),
+                        local: storage_keys(&arc32.schema.local),
+                        boxes: BTreeMap::new(),
+                    },
+                    maps: StorageMaps::default(),
+                },
+                bare_actions: actions(&arc32.bare_call_config)?,
+                source_info: None,
+                source: arc32.source,
+                byte_code: None,
+                compiler_info: None,
+                events: None,
+                template_variables: None,
+                scratch_variables: None,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const ARC56_JSON: &str = include_str!("../test_data/app_spec.arc56.json");
+    const ARC32_JSON: &str = include_str!("../test_data/app_spec.arc32.json");
+
+    fn b64(data: &[u8]) -> String {
+        BASE64.encode(data)
+    }
+
+    #[test]
+    fn test_arc56() {
+        let spec = Arc56Contract::from_json(ARC56_JSON).unwrap();
+
+        assert_eq!(spec.name, "Voting");
+        assert_eq!(spec.arcs, vec![4, 56]);
+        assert_eq!(spec.methods.len(), 6);
+        assert_eq!(
+            spec.bare_actions.call,
+            vec![OnApplicationComplete::DeleteApplication]
+        );
+        assert_eq!(spec.state.maps.boxes["proposals"].prefix, Some(b64(b"p")));
+        assert_eq!(
+            spec.source_info.as_ref().unwrap().approval.pc_offset_method,
+            PcOffsetMethod::Cblocks
+        );
+        assert_eq!(
+            spec.compiler_info.as_ref().unwrap().compiler,
+            Compiler::Puya
+        );
+
+        let propose = spec.get_method("propose").unwrap();
+        assert_eq!(
+            propose.signature(),
+            "propose((string,(uint64,uint64),(uint64,uint64)),pay)uint64"
+        );
+        assert_eq!(propose.args[0].struct_name.as_deref(), Some("Proposal"));
+
+        let abi_method = propose.abi_method().unwrap();
+        assert_eq!(
+            abi_method.selector(),
+            spec.methods[1].abi_method().unwrap().selector()
+        );
+        assert_eq!(abi_method.args[1].name.as_deref(), Some("deposit"));
+        assert_eq!(
+            abi_method.args[1].description.as_deref(),
+            Some("Covers the box MBR")
+        );
+
+        assert_eq!(spec.validate(), Vec::<String>::new());
+
+        // Round trip through JSON
+        assert_eq!(
+            Arc56Contract::from_json(&spec.to_json().unwrap()).unwrap(),
+            spec
+        );
+    }
+
+    #[test]
+    fn test_arc32() {
+        let spec = Arc56Contract::from_arc32_json(ARC32_JSON).unwrap();
+        assert_eq!(Arc56Contract::from_json(ARC32_JSON).unwrap(), spec);
+
+        assert_eq!(spec.name, "Calculator");
+        assert_eq!(spec.desc.as_deref(), Some("A simple calculator"));
+        assert_eq!(
+            spec.networks.as_ref().unwrap()["wGHE2Pwdvd7S12BL5FaOP20EGYesN73ktiC1qzkkit8="].app_id,
+            1234
+        );
+        assert_eq!(
+            spec.bare_actions,
+            Actions {
+                create: vec![OnApplicationComplete::NoOp],
+                call: vec![OnApplicationComplete::DeleteApplication],
+            }
+        );
+        assert_eq!(spec.state.schema.global, Schema { ints: 1, bytes: 1 });
+        assert_eq!(
+            spec.state.keys.global["last"],
+            StorageKey {
+                desc: Some("The result of the last addition".to_string()),
+                key_type: "AVMString".to_string(),
+                value_type: "AVMUint64".to_string(),
+                key: b64(b"last"),
+            }
+        );
+        assert_eq!(spec.state.keys.global["greeting"].value_type, "AVMBytes");
+
+        let create = spec.get_method("create").unwrap();
+        assert_eq!(create.actions.create, vec![OnApplicationComplete::NoOp]);
+        assert_eq!(create.actions.call, vec![]);
+
+        let opt_in = spec.get_method("opt_in").unwrap();
+        assert_eq!(opt_in.actions.create, vec![OnApplicationComplete::OptIn]);
+        assert_eq!(opt_in.actions.call, vec![OnApplicationComplete::OptIn]);
+
+        let add = spec.get_method("add(uint64,uint64)uint64").unwrap();
+        assert_eq!(add.readonly, Some(true));
+        assert_eq!(add.returns.desc.as_deref(), Some("The sum"));
+        assert_eq!(
+            add.args[1].default_value,
+            Some(DefaultValue {
+                data: b64(b"last"),
+                value_type: Some("AVMString".to_string()),
+                source: DefaultValueSource::Global,
+            })
+        );
+
+        let hello = spec.get_method("hello").unwrap();
+        assert_eq!(
+            hello.args[0].default_value,
+            Some(DefaultValue {
+                data: b64(b"world"),
+                value_type: Some("AVMString".to_string()),
+                source: DefaultValueSource::Literal,
+            })
+        );
+
+        assert_eq!(
+            spec.structs["Point"],
+            vec![
+                StructField {
+                    name: "x".to_string(),
+                    field_type: StructFieldType::Type("uint64".to_string()),
+                },
+                StructField {
+                    name: "y".to_string(),
+                    field_type: StructFieldType::Type("uint64".to_string()),
+                },
+            ]
+        );
+        let scale = spec.get_method("scale").unwrap();
+        assert_eq!(scale.args[0].struct_name.as_deref(), Some("Point"));
+        assert_eq!(scale.args[1].struct_name, None);
+        assert_eq!(scale.returns.struct_name.as_deref(), Some("Point"));
+        assert_eq!(
+            scale.args[1].default_value,
+            Some(DefaultValue {
+                data: "default_factor()uint64".to_string(),
+                value_type: None,
+                source: DefaultValueSource::Method,
+            })
+        );
+
+        assert_eq!(spec.validate(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_invalid_arc32() {
+        let mut arc32: serde_json::Value = serde_json::from_str(ARC32_JSON).unwrap();
+        arc32["hints"]["missing()void"] = serde_json::json!({});
+        assert!(matches!(
+            Arc56Contract::from_json(&arc32.to_string()),
+            Err(AppSpecError::InvalidArc32(_))
+        ));
+
+        let mut arc32: serde_json::Value = serde_json::from_str(ARC32_JSON).unwrap();
+        arc32["bare_call_config"]["no_op"] = "SOMETIMES".into();
+        assert!(matches!(
+            Arc56Contract::from_json(&arc32.to_string()),
+            Err(AppSpecError::InvalidArc32(_))
+        ));
+
+        assert!(matches!(
+            Arc56Contract::from_json("{}"),
+            Err(AppSpecError::InvalidJson(_))
+        ));
+    }
+
+    #[test]
+    fn test_struct_abi_type() {
+        let mut spec = Arc56Contract::from_json(ARC56_JSON).unwrap();
+        assert_eq!(
+            spec.struct_abi_type("Proposal").unwrap().to_string(),
+            "(string,(uint64,uint64),(uint64,uint64))"
+        );
+        assert!(spec.struct_abi_type("Missing").is_err());
+
+        // A struct that contains itself cannot be encoded
+        spec.structs.get_mut("Tally").unwrap()[0].field_type =
+            StructFieldType::Type("Proposal".to_string());
+        assert!(spec.struct_abi_type("Proposal").is_err());
+    }
+
+    #[test]
+    fn test_validate() {
+        let mut spec = Arc56Contract::from_json(ARC56_JSON).unwrap();
+
+        spec.methods[1].args[0].struct_name = Some("Tally".to_string());
+        spec.methods[2].args[0].arg_type = "uint65".to_string();
+        spec.methods[3].actions.call = vec![OnApplicationComplete::ClearState];
+        spec.methods[4].args[0].default_value.as_mut().unwrap().data = "missing()void".into();
+        spec.methods.push(spec.methods[5].clone());
+        spec.state.schema.local.ints = 0;
+        spec.state.keys.global.get_mut("admin").unwrap().key = "not base64!".to_string();
+        spec.state
+            .maps
+            .boxes
+            .get_mut("proposals")
+            .unwrap()
+            .value_type = "Missing".into();
+        spec.bare_actions.create = vec![OnApplicationComplete::UpdateApplication];
+
+        assert_eq!(
+            spec.validate(),
+            vec![
+                "argument 0 of method propose((string,(uint64,uint64),(uint64,uint64)),pay)uint64: \
+                 struct Tally is encoded as (uint64,uint64), not (string,(uint64,uint64),(uint64,uint64))",
+                "argument 0 of method vote(uint65,bool,account)(uint64,uint64): invalid ABI type: uint65",
+                "method optIn()void: ClearState is not a valid call action",
+                "argument 0 of method tally(uint64)(uint64,uint64): default value method missing()void does not exist",
+                "method latest()uint64 is defined more than once",
+                "bare actions: UpdateApplication is not a valid create action",
+                "global key admin: not base64! is not valid base64",
+                "box map proposals: unknown type Missing",
+                "local state has 1 keys, but the schema only allows 0",
+            ]
+        );
+    }
+}
@@ -0,0 +1,1014 @@
+//! Blocks as returned by algod's `/v2/blocks/{round}?format=msgpack`.
+//!
+//! A block is its header followed by the transactions it confirmed. Transactions are stored with
+//! the `ApplyData` recording their effects, and without their genesis ID and hash, which are the
+//! block's. `hgi` records that the genesis ID was elided, and so does `hgh` for the genesis hash
+//! in protocols that do not require it. The bytes of a stored transaction therefore differ from
+//! the bytes that were signed, and the elided fields must be restored to compute its ID.
+//! Transactions of the types that `Transaction` does not support, such as key registrations,
+//! asset configurations and heartbeats, or with fields it does not know, are kept as raw msgpack
+//! values. A header with fields `BlockHeader` does not know fails to decode instead.
+
+use crate::apply_data::SignedTransactionWithApplyData;
+use crate::consensus::ConsensusParams;
+use crate::{
+    decode_as, is_empty_bytes32, is_empty_string_opt, is_empty_vec_opt, is_false, is_zero,
+    is_zero_addr, is_zero_addr_opt, sort_msgpack_value, write_msgpack_value, Address,
+    AlgoModelsError, AlgorandMsgpack, Byte32, MicroAlgos, STRING_BYTES_EXT_TYPE,
+};
+use rmpv::Value;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none, Bytes};
+use sha2::{Digest, Sha512_256};
+use std::collections::BTreeMap;
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum BlockError {
+    #[error("transaction in block has genesis ID \"{0}\", which blocks elide")]
+    GenesisIdNotElided(String),
+
+    #[error("transaction in block has a genesis hash, which blocks elide")]
+    GenesisHashNotElided,
+
+    #[error("transaction in block sets hgh, but its protocol always elides the genesis hash")]
+    UnexpectedHasGenesisHash,
+
+    #[error("block header field \"{0}\" is not supported, so the header would hash differently")]
+    UnsupportedHeaderField(String),
+}
+
+fn is_zero_i64(n: &i64) -> bool {
+    *n == 0
+}
+
+/// The state of the rewards distribution, which has paid no rewards since they were replaced
+/// by staking rewards
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct RewardsState {
+    /// The fee sink, which receives transaction fees
+    #[serde(rename = "fees")]
+    #[serde(skip_serializing_if = "is_zero_addr")]
+    #[serde(default)]
+    pub fee_sink: Address,
+
+    /// The rewards pool, which pays out rewards
+    #[serde(rename = "rwd")]
+    #[serde(skip_serializing_if = "is_zero_addr")]
+    #[serde(default)]
+    pub rewards_pool: Address,
+
+    /// The number of rewards units earned so far by each full Algo
+    #[serde(rename = "earn")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    pub rewards_level: u64,
+
+    /// The number of microAlgos added to the rewards each round
+    #[serde(rename = "rate")]
+    #[serde(skip_serializing_if = "MicroAlgos::is_zero")]
+    #[serde(default)]
+    pub rewards_rate: MicroAlgos,
+
+    /// The microAlgos left over after the rewards of a round were split into whole units
+    #[serde(rename = "frac")]
+    #[serde(skip_serializing_if = "MicroAlgos::is_zero")]
+    #[serde(default)]
+    pub rewards_residue: MicroAlgos,
+
+    /// The round at which the rewards rate is next recalculated
+    #[serde(rename = "rwcalr")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    pub rewards_recalculation_round: u64,
+}
+
+/// The progress of a protocol upgrade
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct UpgradeState {
+    /// The consensus version of the block
+    #[serde(rename = "proto")]
+    #[serde(default)]
+    pub current_protocol: String,
+
+    /// The consensus version being voted on
+    #[serde(rename = "nextproto")]
+    #[serde(skip_serializing_if = "is_empty_string_opt")]
+    #[serde(default)]
+    pub next_protocol: Option<String>,
+
+    /// The number of blocks so far that approved the next protocol
+    #[serde(rename = "nextyes")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    pub next_protocol_approvals: u64,
+
+    /// The round by which the next protocol must be approved
+    #[serde(rename = "nextbefore")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    pub next_protocol_vote_before: u64,
+
+    /// The round at which the next protocol takes effect, once approved
+    #[serde(rename = "nextswitch")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    pub next_protocol_switch_on: u64,
+}
+
+/// The block proposer's vote on a protocol upgrade
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct UpgradeVote {
+    /// The consensus version proposed by this block
+    #[serde(rename = "upgradeprop")]
+    #[serde(skip_serializing_if = "is_empty_string_opt")]
+    #[serde(default)]
+    pub upgrade_propose: Option<String>,
+
+    /// The number of rounds between approval of the proposal and the switch to it
+    #[serde(rename = "upgradedelay")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    pub upgrade_delay: u64,
+
+    /// Whether this block approves the protocol being voted on
+    #[serde(rename = "upgradeyes")]
+    #[serde(skip_serializing_if = "is_false")]
+    #[serde(default)]
+    pub upgrade_approve: bool,
+}
+
+/// The progress of the state proofs of one type
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct StateProofTracking {
+    /// The commitment to the online accounts that sign the next state proof
+    #[serde(rename = "v")]
+    #[serde_as(as = "Option<Bytes>")]
+    #[serde(default)]
+    pub voters_commitment: Option<Vec<u8>>,
+
+    /// The total online stake of the accounts that sign the next state proof
+    #[serde(rename = "t")]
+    #[serde(skip_serializing_if = "MicroAlgos::is_zero")]
+    #[serde(default)]
+    pub online_total_weight: MicroAlgos,
+
+    /// The round of the next state proof
+    #[serde(rename = "n")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    pub next_round: u64,
+}
+
+/// The accounts whose participation in consensus ended in this block
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct ParticipationUpdates {
+    /// Online accounts whose participation keys expired
+    #[serde(rename = "partupdrmv")]
+    #[serde(skip_serializing_if = "is_empty_vec_opt")]
+    #[serde(default)]
+    pub expired_participation_accounts: Option<Vec<Address>>,
+
+    /// Online accounts that were suspended for not proposing or not sending heartbeats
+    #[serde(rename = "partupdabs")]
+    #[serde(skip_serializing_if = "is_empty_vec_opt")]
+    #[serde(default)]
+    pub absent_participation_accounts: Option<Vec<Address>>,
+}
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct BlockHeader {
+    #[serde(rename = "rnd")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    pub round: u64,
+
+    /// The hash of the previous block's header
+    #[serde(rename = "prev")]
+    #[serde_as(as = "Bytes")]
+    #[serde(skip_serializing_if = "is_empty_bytes32")]
+    #[serde(default)]
+    pub previous_block_hash: Byte32,
+
+    /// The SHA-512 hash of the previous block's header
+    #[serde(rename = "prev512")]
+    #[serde_as(as = "Option<Bytes>")]
+    #[serde(default)]
+    pub previous_block_hash_sha512: Option<[u8; 64]>,
+
+    /// The sortition seed of the block
+    #[serde_as(as = "Bytes")]
+    #[serde(skip_serializing_if = "is_empty_bytes32")]
+    #[serde(default)]
+    pub seed: Byte32,
+
+    /// The SHA-512/256 Merkle root of the block's transactions
+    #[serde(rename = "txn")]
+    #[serde_as(as = "Bytes")]
+    #[serde(skip_serializing_if = "is_empty_bytes32")]
+    #[serde(default)]
+    pub txn_root: Byte32,
+
+    /// The SHA-256 Merkle root of the block's transactions
+    #[serde(rename = "txn256")]
+    #[serde_as(as = "Bytes")]
+    #[serde(skip_serializing_if = "is_empty_bytes32")]
+    #[serde(default)]
+    pub txn_root_sha256: Byte32,
+
+    /// The SHA-512 Merkle root of the block's transactions
+    #[serde(rename = "txn512")]
+    #[serde_as(as = "Option<Bytes>")]
+    #[serde(default)]
+    pub txn_root_sha512: Option<[u8; 64]>,
+
+    /// The time the block was proposed, in seconds since the Unix epoch
+    #[serde(rename = "ts")]
+    #[serde(skip_serializing_if = "is_zero_i64")]
+    #[serde(default)]
+    pub timestamp: i64,
+
+    #[serde(rename = "gen")]
+    #[serde(default)]
+    pub genesis_id: String,
+
+    #[serde(rename = "gh")]
+    #[serde_as(as = "Bytes")]
+    #[serde(skip_serializing_if = "is_empty_bytes32")]
+    #[serde(default)]
+    pub genesis_hash: Byte32,
+
+    /// The account that proposed the block
+    #[serde(rename = "prp")]
+    #[serde(skip_serializing_if = "is_zero_addr_opt")]
+    #[serde(default)]
+    pub proposer: Option<Address>,
+
+    /// The fees collected from the block's transactions
+    #[serde(rename = "fc")]
+    #[serde(skip_serializing_if = "MicroAlgos::is_zero")]
+    #[serde(default)]
+    pub fees_collected: MicroAlgos,
+
+    /// The bonus paid to the proposer on top of the fees
+    #[serde(rename = "bi")]
+    #[serde(skip_serializing_if = "MicroAlgos::is_zero")]
+    #[serde(default)]
+    pub bonus: MicroAlgos,
+
+    /// The amount paid to the proposer
+    #[serde(rename = "pp")]
+    #[serde(skip_serializing_if = "MicroAlgos::is_zero")]
+    #[serde(default)]
+    pub proposer_payout: MicroAlgos,
+
+    #[serde(flatten)]
+    pub rewards_state: RewardsState,
+
+    #[serde(flatten)]
+    pub upgrade_state: UpgradeState,
+
+    #[serde(flatten)]
+    pub upgrade_vote: UpgradeVote,
+
+    /// The number of transactions committed before this block, including inner transactions
+    #[serde(rename = "tc")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    pub txn_counter: u64,
+
+    /// The progress of the state proofs, by state proof type
+    #[serde(rename = "spt")]
+    #[serde(default)]
+    pub state_proof_tracking: Option<BTreeMap<u64, StateProofTracking>>,
+
+    #[serde(flatten)]
+    pub participation_updates: ParticipationUpdates,
+}
+
+impl AlgorandMsgpack for BlockHeader {
+    const PREFIX: &'static [u8] = b"BH";
+
+    fn decode(bytes: &[u8]) -> Result<Self, AlgoModelsError> {
+        let bytes = bytes.strip_prefix(Self::PREFIX).unwrap_or(bytes);
+        let (header, value) = decode_marked::<Self>(bytes, |_| {})?;
+        header.check_fields(&value)?;
+        Ok(header)
+    }
+}
+
+impl BlockHeader {
+    /// Fail if the encoded block, or header, has fields that the header does not keep the same,
+    /// since the header would then encode and hash differently than it did on the network
+    fn check_fields(&self, value: &Value) -> Result<(), AlgoModelsError> {
+        let encoded = rmpv::decode::read_value(&mut &self.encode_raw()?[..])?;
+        let kept = encoded.as_map().map(Vec::as_slice).unwrap_or_default();
+
+        let fields = value.as_map().into_iter().flatten();
+        for (key, value) in fields.filter(|(key, _)| key.as_str() != Some("txns")) {
+            if !kept.iter().any(|(k, v)| k == key && v == value) {
+                let key = key.as_str().map_or_else(|| key.to_string(), str::to_string);
+                return Err(BlockError::UnsupportedHeaderField(key).into());
+            }
+        }
+        Ok(())
+    }
+
+    /// A transaction of the block as it was signed, with the genesis ID and hash that the block
+    /// elided restored. Follows the rules of the block's protocol, or of the current protocol if
+    /// the block's is unknown
+    pub fn signed_transaction(
+        &self,
+        stxn: &SignedTransactionInBlock,
+    ) -> Result<SignedTransactionWithApplyData, BlockError> {
+        let mut signed = stxn.signed_transaction.clone();
+        let header = signed.transaction.header_mut();
+
+        let (genesis_id, genesis_hash) = self.elided_genesis(
+            header.genesis_id.as_deref(),
+            header
+                .genesis_hash
+                .is_some_and(|hash| !is_empty_bytes32(&hash)),
+            stxn.has_genesis_id,
+            stxn.has_genesis_hash,
+        )?;
+        if genesis_id.is_some() {
+            header.genesis_id = genesis_id;
+        }
+        if genesis_hash.is_some() {
+            header.genesis_hash = genesis_hash;
+        }
+
+        Ok(signed)
+    }
+
+    /// The genesis ID and hash to restore to a transaction, which must not have its own
+    fn elided_genesis(
+        &self,
+        genesis_id: Option<&str>,
+        has_own_genesis_hash: bool,
+        has_genesis_id: bool,
+        has_genesis_hash: bool,
+    ) -> Result<(Option<String>, Option<Byte32>), BlockError> {
+        let require_genesis_hash =
+            ConsensusParams::for_version(&self.upgrade_state.current_protocol)
+                .unwrap_or_default()
+                .require_genesis_hash;
+
+        if let Some(genesis_id) = genesis_id.filter(|id| !id.is_empty()) {
+            return Err(BlockError::GenesisIdNotElided(genesis_id.to_string()));
+        }
+        if has_own_genesis_hash {
+            return Err(BlockError::GenesisHashNotElided);
+        }
+        if require_genesis_hash && has_genesis_hash {
+            return Err(BlockError::UnexpectedHasGenesisHash);
+        }
+
+        Ok((
+            has_genesis_id.then(|| self.genesis_id.clone()),
+            (require_genesis_hash || has_genesis_hash).then_some(self.genesis_hash),
+        ))
+    }
+
+    /// The encoding of a transaction of the block as it was signed, with its "TX" prefix
+    pub fn signed_transaction_bytes(
+        &self,
+        txn: &BlockTransaction,
+    ) -> Result<Vec<u8>, AlgoModelsError> {
+        let value = match txn {
+            BlockTransaction::Decoded(stxn) => {
+                return self.signed_transaction(stxn)?.transaction.encode()
+            }
+            BlockTransaction::Raw(value) => value,
+        };
+
+        let flag = |key: &str| map_get(value, key).and_then(Value::as_bool) == Some(true);
+        let Some(Value::Map(transaction)) = map_get(value, "txn") else {
+            return Err(AlgoModelsError::InputError(
+                "transaction in block has no txn map".to_string(),
+            ));
+        };
+        let stored_genesis_id = transaction
+            .iter()
+            .find(|(key, _)| key.as_str() == Some("gen"))
+            .map(|(_, value)| value.as_str().unwrap_or("?"));
+        let has_own_genesis_hash = transaction
+            .iter()
+            .any(|(key, value)| key.as_str() == Some("gh") && value.as_slice() != Some(&[0; 32]));
+
+        let (genesis_id, genesis_hash) = self.elided_genesis(
+            stored_genesis_id,
+            has_own_genesis_hash,
+            flag("hgi"),
+            flag("hgh"),
+        )?;
+
+        let mut transaction = transaction.clone();
+        transaction.retain(|(key, _)| !matches!(key.as_str(), Some("gen" | "gh")));
+        if let Some(genesis_id) = genesis_id {
+            transaction.push(("gen".into(), genesis_id.into()));
+        }
+        if let Some(genesis_hash) = genesis_hash {
+            transaction.push(("gh".into(), Value::Binary(genesis_hash.to_vec())));
+        }
+
+        let mut encoded = b"TX".to_vec();
+        write_msgpack_value(&mut encoded, &sort_msgpack_value(Value::Map(transaction)))?;
+        Ok(encoded)
+    }
+
+    /// The ID of a transaction of the block, which is computed over the transaction as it was
+    /// signed
+    pub fn transaction_id(&self, txn: &BlockTransaction) -> Result<String, AlgoModelsError> {
+        let raw_id: Byte32 = Sha512_256::digest(self.signed_transaction_bytes(txn)?).into();
+        Ok(base32::encode(
+            base32::Alphabet::Rfc4648 { padding: false },
+            &raw_id,
+        ))
+    }
+}
+
+/// A signed transaction as stored in a block
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct SignedTransactionInBlock {
+    #[serde(flatten)]
+    pub signed_transaction: SignedTransactionWithApplyData,
+
+    /// Whether the genesis ID was elided because it matches the block's
+    #[serde(rename = "hgi")]
+    #[serde(skip_serializing_if = "is_false")]
+    #[serde(default)]
+    pub has_genesis_id: bool,
+
+    /// Whether the genesis hash was elided because it matches the block's
+    #[serde(rename = "hgh")]
+    #[serde(skip_serializing_if = "is_false")]
+    #[serde(default)]
+    pub has_genesis_hash: bool,
+}
+
+impl AlgorandMsgpack for SignedTransactionInBlock {
+    const PREFIX: &'static [u8] = b"";
+}
+
+/// The transaction types that `Transaction` decodes
+const DECODED_TYPES: [&str; 3] = ["pay", "axfer", "appl"];
+
+/// A transaction as stored in a block
+#[derive(Debug, PartialEq, Clone)]
+pub enum BlockTransaction {
+    Decoded(SignedTransactionInBlock),
+
+    /// A transaction that is, or sent an inner transaction that is, of a type `Transaction`
+    /// does not support, or that has fields it does not keep. It is kept as its msgpack map so
+    /// the block still encodes to the same bytes
+    Raw(Value),
+}
+
+fn map_get<'a>(value: &'a Value, key: &str) -> Option<&'a Value> {
+    value
+        .as_map()?
+        .iter()
+        .find(|(k, _)| k.as_str() == Some(key))
+        .map(|(_, v)| v)
+}
+
+/// Whether a stored transaction and all of its inner transactions are of types that can be
+/// decoded
+fn has_decoded_types(value: &Value) -> bool {
+    let transaction_type = map_get(value, "txn").and_then(|txn| map_get(txn, "type"));
+    let inner_transactions = map_get(value, "dt")
+        .and_then(|dt| map_get(dt, "itx"))
+        .and_then(Value::as_array);
+
+    transaction_type
+        .and_then(Value::as_str)
+        .is_some_and(|t| DECODED_TYPES.contains(&t))
+        && inner_transactions
+            .into_iter()
+            .flatten()
+            .all(has_decoded_types)
+}
+
+/// The stored transaction decoded, if its types can be decoded and it encodes back to the same
+/// value. A transaction with fields that `Transaction` does not keep is kept raw instead, since
+/// it would otherwise hash differently
+fn decode_stored(value: &Value) -> Option<SignedTransactionInBlock> {
+    if !has_decoded_types(value) {
+        return None;
+    }
+
+    let mut buf = Vec::new();
+    write_msgpack_value(&mut buf, value).ok()?;
+    let stxn = SignedTransactionInBlock::decode(&buf).ok()?;
+    let encoded = rmpv::decode::read_value(&mut &stxn.encode_raw().ok()?[..]).ok()?;
+    let same = invalid_strings_as_binary(encoded)
+        == invalid_strings_as_binary(sort_msgpack_value(value.clone()));
+    same.then_some(stxn)
+}
+
+/// Serde reads msgpack strings that are not valid UTF-8 as binary, so values are compared with
+/// those strings read the same way
+fn invalid_strings_as_binary(value: Value) -> Value {
+    match value {
+        Value::String(string) if string.as_str().is_none() => Value::Binary(string.into_bytes()),
+        Value::Map(entries) => Value::Map(
+            entries
+                .into_iter()
+                .map(|(k, v)| (invalid_strings_as_binary(k), invalid_strings_as_binary(v)))
+                .collect(),
+        ),
+        Value::Array(items) => {
+            Value::Array(items.into_iter().map(invalid_strings_as_binary).collect())
+        }
+        value => value,
+    }
+}
+
+fn is_decodable(value: &Value) -> bool {
+    decode_stored(value).is_some()
+}
+
+impl BlockTransaction {
+    /// The transaction, if its type is supported
+    pub fn decoded(&self) -> Option<&SignedTransactionInBlock> {
+        match self {
+            BlockTransaction::Decoded(stxn) => Some(stxn),
+            BlockTransaction::Raw(_) => None,
+        }
+    }
+}
+
+impl Serialize for BlockTransaction {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            BlockTransaction::Decoded(stxn) => stxn.serialize(serializer),
+            BlockTransaction::Raw(value) => value.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for BlockTransaction {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        Ok(match decode_stored(&value) {
+            Some(stxn) => BlockTransaction::Decoded(stxn),
+            None => BlockTransaction::Raw(value),
+        })
+    }
+}
+
+impl AlgorandMsgpack for BlockTransaction {
+    const PREFIX: &'static [u8] = b"";
+
+    fn decode(bytes: &[u8]) -> Result<Self, AlgoModelsError> {
+        let (txn, _) = decode_marked(bytes, |value| {
+            if !is_decodable(value) {
+                mark_strings(value);
+            }
+        })?;
+        Ok(txn)
+    }
+}
+
+/// Serde reads msgpack strings that are not valid UTF-8 as binary, so before a transaction that
+/// is kept raw is decoded they are marked, which keeps them strings
+fn mark_strings(value: &mut Value) {
+    match value {
+        Value::String(string) if string.as_str().is_none() => {
+            *value = Value::Ext(STRING_BYTES_EXT_TYPE, string.as_bytes().to_vec());
+        }
+        Value::Map(entries) => entries.iter_mut().for_each(|(k, v)| {
+            mark_strings(k);
+            mark_strings(v);
+        }),
+        Value::Array(items) => items.iter_mut().for_each(mark_strings),
+        _ => {}
+    }
+}
+
+/// Marks the strings of the transactions of a block, or of a block response, that are kept raw
+fn mark_raw_strings(value: &mut Value) {
+    let Value::Map(entries) = value else {
+        return;
+    };
+    for (key, value) in entries {
+        match (key.as_str(), value) {
+            (Some("block"), block) => mark_raw_strings(block),
+            (Some("txns"), Value::Array(txns)) => txns
+                .iter_mut()
+                .filter(|txn| !is_decodable(txn))
+                .for_each(mark_strings),
+            _ => {}
+        }
+    }
+}
+
+/// Decodes after marking strings, also returning the marked value
+fn decode_marked<T: DeserializeOwned>(
+    bytes: &[u8],
+    mark: impl FnOnce(&mut Value),
+) -> Result<(T, Value), AlgoModelsError> {
+    let mut value = rmpv::decode::read_value(&mut &bytes[..])?;
+    mark(&mut value);
+
+    let mut buf = Vec::new();
+    rmpv::encode::write_value(&mut buf, &value)?;
+    let decoded = decode_as(false, || rmp_serde::from_slice(&buf))?;
+    Ok((decoded, value))
+}
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct Block {
+    #[serde(flatten)]
+    pub header: BlockHeader,
+
+    #[serde(rename = "txns")]
+    #[serde(skip_serializing_if = "is_empty_vec_opt")]
+    #[serde(default)]
+    pub transactions: Option<Vec<BlockTransaction>>,
+}
+
+impl AlgorandMsgpack for Block {
+    const PREFIX: &'static [u8] = b"";
+
+    fn decode(bytes: &[u8]) -> Result<Self, AlgoModelsError> {
+        let (block, value) = decode_marked::<Self>(bytes, mark_raw_strings)?;
+        block.header.check_fields(&value)?;
+        Ok(block)
+    }
+}
+
+/// The response of algod's `/v2/blocks/{round}?format=msgpack`. The agreement certificate that
+/// accompanies the block is not decoded
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct BlockResponse {
+    pub block: Block,
+}
+
+impl AlgorandMsgpack for BlockResponse {
+    const PREFIX: &'static [u8] = b"";
+
+    fn decode(bytes: &[u8]) -> Result<Self, AlgoModelsError> {
+        let (response, value) = decode_marked::<Self>(bytes, mark_raw_strings)?;
+        let block = map_get(&value, "block").unwrap_or(&Value::Nil);
+        response.block.header.check_fields(block)?;
+        Ok(response)
+    }
+}
+
+impl Block {
+    /// Decode a block from the body of algod's `/v2/blocks/{round}?format=msgpack`
+    pub fn from_response(bytes: &[u8]) -> Result<Self, AlgoModelsError> {
+        Ok(BlockResponse::decode(bytes)?.block)
+    }
+
+    /// The IDs of the block's transactions, in order
+    pub fn transaction_ids(&self) -> Result<Vec<String>, AlgoModelsError> {
+        self.transactions
+            .iter()
+            .flatten()
+            .map(|txn| self.header.transaction_id(txn))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consensus::CURRENT_VERSION;
+    use crate::TransactionType;
+    use pretty_assertions::assert_eq;
+
+    // A `/v2/blocks/{round}?format=msgpack` response shaped like a mainnet block, including its
+    // certificate, with made-up accounts, hashes and signatures. It was built by hand rather than
+    // fetched from algod, but is encoded canonically like algod encodes blocks. Besides
+    // payments, asset transfers and application calls, it has a key registration, an asset
+    // configuration and an application call that configured an asset in an inner transaction
+    const BLOCK_RESPONSE: &[u8] = include_bytes!("../test_data/block.msgpack");
+
+    /// The bytes of the block in the response
+    fn block_value() -> Vec<u8> {
+        let (key, block) = BLOCK_RESPONSE[1..].split_at(6);
+        assert_eq!(key, b"\xa5block");
+
+        let mut rest = block;
+        rmpv::decode::read_value(&mut rest).unwrap();
+        block[..block.len() - rest.len()].to_vec()
+    }
+
+    #[test]
+    fn test_block_header() {
+        let block = Block::from_response(BLOCK_RESPONSE).unwrap();
+        let header = &block.header;
+
+        assert_eq!(header.round, 46_000_000);
+        assert_eq!(header.timestamp, 1_735_000_000);
+        assert_eq!(header.genesis_id, "mainnet-v1.0");
+        assert_eq!(
+            base64::Engine::encode(&base64::prelude::BASE64_STANDARD, header.genesis_hash),
+            "wGHE2Pwdvd7S12BL5FaOP20EGYesN73ktiC1qzkkit8="
+        );
+        assert_eq!(header.txn_counter, 2_900_000_000);
+        assert!(header.previous_block_hash_sha512.is_some());
+        assert!(header.txn_root_sha512.is_some());
+        let tracking = &header.state_proof_tracking.as_ref().unwrap()[&0];
+        assert_eq!(tracking.next_round, 46_000_128);
+        assert_eq!(
+            tracking.online_total_weight,
+            MicroAlgos(1_900_000_000_000_000)
+        );
+        assert_eq!(header.fees_collected, MicroAlgos(4000));
+        assert_eq!(header.proposer_payout, MicroAlgos(10_002_000));
+        assert_eq!(
+            header.rewards_state.fee_sink.address(),
+            "Y76M3MSY6DKBRHBL7C3NNDXGS5IIMQVQVUAB6MP4XEMMGVF2QWNPL226CA"
+        );
+        assert_eq!(
+            header.rewards_state.rewards_pool.address(),
+            "737777777777777777777777777777777777777777777777777UFEJ2CI"
+        );
+        assert_eq!(header.rewards_state.rewards_level, 218_288);
+        assert_eq!(header.upgrade_state.current_protocol, CURRENT_VERSION);
+        assert_eq!(header.upgrade_state.next_protocol, None);
+        assert_eq!(header.upgrade_vote, UpgradeVote::default());
+        assert_eq!(
+            header
+                .participation_updates
+                .absent_participation_accounts
+                .as_ref()
+                .map(Vec::len),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_block_transactions() {
+        let block = Block::decode(&block_value()).unwrap();
+        let txns = block.transactions.as_ref().unwrap();
+
+        let decoded: Vec<_> = txns.iter().map_while(BlockTransaction::decoded).collect();
+        let types: Vec<TransactionType> = decoded
+            .iter()
+            .map(|stxn| {
+                stxn.signed_transaction
+                    .transaction
+                    .header()
+                    .transaction_type
+                    .clone()
+            })
+            .collect();
+        assert_eq!(
+            types,
+            [
+                TransactionType::Payment,
+                TransactionType::Payment,
+                TransactionType::AssetTransfer,
+                TransactionType::ApplicationCall,
+                TransactionType::ApplicationCall
+            ]
+        );
+
+        // The key registration, the asset configuration and the application call whose inner
+        // transaction configured an asset are kept raw
+        let raw_types: Vec<_> = txns[decoded.len()..]
+            .iter()
+            .map(|txn| match txn {
+                BlockTransaction::Raw(value) => map_get(map_get(value, "txn").unwrap(), "type")
+                    .and_then(Value::as_str)
+                    .unwrap(),
+                BlockTransaction::Decoded(_) => panic!("expected a raw transaction"),
+            })
+            .collect();
+        assert_eq!(raw_types, ["keyreg", "acfg", "appl"]);
+
+        // The genesis ID and hash are elided from every transaction, and `hgh` is not set
+        // because the protocol requires a genesis hash
+        let txns = decoded;
+        assert!(txns.iter().all(|stxn| {
+            let header = stxn.signed_transaction.transaction.header();
+            header.genesis_id.is_none() && header.genesis_hash.is_none() && !stxn.has_genesis_hash
+        }));
+        assert!(!txns[0].has_genesis_id);
+        assert!(txns[1].has_genesis_id);
+
+        assert_eq!(
+            txns[1].signed_transaction.apply_data.closing_amount,
+            MicroAlgos(2_997_000)
+        );
+        assert!(txns[2].signed_transaction.auth_address.is_some());
+        assert_eq!(
+            txns[3].signed_transaction.apply_data.application_id,
+            3_000_000_001
+        );
+        assert!(txns
+            .iter()
+            .all(|stxn| stxn.signed_transaction.signature.is_some()));
+    }
+
+    #[test]
+    fn test_block_encoding() {
+        // Re-encoding the decoded block gives back the same bytes, including the raw
+        // transactions
+        let bytes = block_value();
+        assert_eq!(Block::decode(&bytes).unwrap().encode().unwrap(), bytes);
+        let response = BlockResponse::decode(BLOCK_RESPONSE).unwrap();
+        assert_eq!(response.block, Block::decode(&bytes).unwrap());
+
+        let header = Block::decode(&bytes).unwrap().header;
+        let encoded_header = header.encode().unwrap();
+        assert_eq!(&encoded_header[..2], b"BH");
+        assert_eq!(BlockHeader::decode(&encoded_header).unwrap(), header);
+    }
+
+    /// The bytes of the block with an extra field, added to the header, or to the transaction
+    /// at `index`
+    fn with_unknown_field(index: Option<usize>) -> Vec<u8> {
+        let mut block = rmpv::decode::read_value(&mut &block_value()[..]).unwrap();
+        let Value::Map(fields) = &mut block else {
+            panic!("expected a map");
+        };
+        let target = match index {
+            None => fields,
+            Some(index) => {
+                let (_, txns) = fields
+                    .iter_mut()
+                    .find(|(key, _)| key.as_str() == Some("txns"))
+                    .unwrap();
+                let Value::Array(txns) = txns else {
+                    panic!("expected an array");
+                };
+                let Value::Map(fields) = &mut txns[index] else {
+                    panic!("expected a map");
+                };
+                fields
+            }
+        };
+        target.push(("zzz".into(), Value::from(1)));
+
+        let mut bytes = Vec::new();
+        mark_strings(&mut block);
+        write_msgpack_value(&mut bytes, &block).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_unknown_fields() {
+        // The header would hash differently without the field, so decoding fails
+        let bytes = with_unknown_field(None);
+        assert_eq!(
+            Block::decode(&bytes).unwrap_err().to_string(),
+            AlgoModelsError::from(BlockError::UnsupportedHeaderField("zzz".to_string()))
+                .to_string()
+        );
+        let mut encoded_header = b"BH".to_vec();
+        encoded_header.extend_from_slice(&bytes);
+        assert!(BlockHeader::decode(&encoded_header).is_err());
+
+        // A transaction with the field is kept raw, so it still encodes to the same bytes
+        let bytes = with_unknown_field(Some(0));
+        let block = Block::decode(&bytes).unwrap();
+        let txns = block.transactions.as_ref().unwrap();
+        assert!(txns[0].decoded().is_none());
+        assert!(txns[1].decoded().is_some());
+        assert_eq!(block.encode().unwrap(), bytes);
+        assert_eq!(
+            block.transaction_ids().unwrap(),
+            Block::decode(&block_value())
+                .unwrap()
+                .transaction_ids()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_transaction_ids() {
+        let block = Block::from_response(BLOCK_RESPONSE).unwrap();
+        let txns = block.transactions.as_ref().unwrap();
+
+        // The genesis hash is restored to every transaction, and the genesis ID only to those
+        // that had one when they were signed
+        let signed = block
+            .header
+            .signed_transaction(txns[1].decoded().unwrap())
+            .unwrap();
+        let header = signed.transaction.header();
+        assert_eq!(header.genesis_id.as_deref(), Some("mainnet-v1.0"));
+        assert_eq!(header.genesis_hash, Some(block.header.genesis_hash));
+        let signed = block
+            .header
+            .signed_transaction(txns[0].decoded().unwrap())
+            .unwrap();
+        assert_eq!(signed.transaction.header().genesis_id, None);
+
+        // Computed independently over the signed bytes of each transaction
+        assert_eq!(
+            block.transaction_ids().unwrap(),
+            [
+                "23K33OBYWLDNZBYGFZIXE55N7VJV5S6DLZIYFGI5UYVZU4WQHBIQ",
+                "OCQHHY6KQCRQ65SY6BUVJMAOVO3TIZ3QE7VYMXBNKAOR25JQEJXA",
+                "4BTGQ54XXOGMYASBEND2LNMHNTJBK6HKMOHNJIL2ZQGWMJ5ZMFJA",
+                "76HJLLUMWUI3W5T4NZKWL4Z5G75SNFP77QQLVOTYFFZ2HJOYXVYQ",
+                "EAPVVVMYJJ3WXNHTK3FR54JHXZX5A7JI4UIZVCVKD4PDMIW6R4GQ",
+                "KJVPIRHJ5POVMA7FWP5VS26HJ4U3XS6L2SYODQOCOGCJAWILXDWA",
+                "V64HMDSQYITACFJJVYDDUNITNX7NPVKNHADFOZ6QU74QYME4CUQA",
+                "J6OEBHEOPX6GJPIPCBYQRNE4NFPINPRZWM7SA3L34SGQENIMMKDA",
+            ]
+        );
+        // The ID of the stored transaction, without the genesis hash, is different
+        assert_ne!(
+            txns[0]
+                .decoded()
+                .unwrap()
+                .signed_transaction
+                .transaction
+                .id()
+                .unwrap(),
+            "23K33OBYWLDNZBYGFZIXE55N7VJV5S6DLZIYFGI5UYVZU4WQHBIQ"
+        );
+    }
+
+    #[test]
+    fn test_elided_fields() {
+        let block = Block::from_response(BLOCK_RESPONSE).unwrap();
+        let mut stxn = block.transactions.as_ref().unwrap()[0]
+            .decoded()
+            .unwrap()
+            .clone();
+
+        stxn.has_genesis_hash = true;
+        assert_eq!(
+            block.header.signed_transaction(&stxn),
+            Err(BlockError::UnexpectedHasGenesisHash)
+        );
+
+        // Before genesis hashes were required, they are only restored when `hgh` is set
+        let mut header = block.header.clone();
+        header.upgrade_state.current_protocol = "v15".to_string();
+        let signed = header.signed_transaction(&stxn).unwrap();
+        assert_eq!(
+            signed.transaction.header().genesis_hash,
+            Some(header.genesis_hash)
+        );
+        stxn.has_genesis_hash = false;
+        let signed = header.signed_transaction(&stxn).unwrap();
+        assert_eq!(signed.transaction.header().genesis_hash, None);
+
+        stxn.signed_transaction.transaction.header_mut().genesis_id = Some("testnet-v1.0".into());
+        assert_eq!(
+            block.header.signed_transaction(&stxn),
+            Err(BlockError::GenesisIdNotElided("testnet-v1.0".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_raw_transactions() {
+        let block = Block::from_response(BLOCK_RESPONSE).unwrap();
+        let txns = block.transactions.as_ref().unwrap();
+
+        // The asset name of the configuration is a string that is not valid UTF-8, and stays a
+        // string when the transaction is encoded on its own
+        let BlockTransaction::Raw(acfg) = &txns[6] else {
+            panic!("expected a raw transaction");
+        };
+        let encoded = txns[6].encode().unwrap();
+        let name = [&[0xa4][..], b"Caf\xc3"].concat();
+        assert!(encoded.windows(name.len()).any(|w| w == name));
+        assert_eq!(BlockTransaction::decode(&encoded).unwrap(), txns[6]);
+        assert!(map_get(acfg, "hgi").and_then(Value::as_bool).unwrap());
+
+        // The genesis ID and hash are restored to the raw transaction as they are to decoded ones
+        let signed = block.header.signed_transaction_bytes(&txns[6]).unwrap();
+        let signed = rmpv::decode::read_value(&mut &signed[2..]).unwrap();
+        assert_eq!(
+            map_get(&signed, "gen").and_then(Value::as_str),
+            Some("mainnet-v1.0")
+        );
+        assert_eq!(
+            map_get(&signed, "gh").and_then(Value::as_slice),
+            Some(block.header.genesis_hash.as_slice())
+        );
+        assert_eq!(
+            map_get(&signed, "type").and_then(Value::as_str),
+            Some("acfg")
+        );
+    }
+}
@@ -0,0 +1,763 @@
+//! Builders for transactions, which fill in the header from suggested params.
+//!
+//! Each builder sets the transaction type to match its fields, takes the genesis ID and hash and
+//! the validity window from the suggested params, and estimates the fee once the transaction is
+//! complete. Required fields that are never set are reported by `build`.
+
+use crate::fee::{FeeOptions, SuggestedParams};
+use crate::program::{Program, ProgramError};
+use crate::{
+    Address, AlgoModelsError, ApplicationCallTransactionFields, AssetTransferTransactionFields,
+    BoxReference, Byte32, MicroAlgos, OnApplicationComplete, PayTransactionFields, StateSchema,
+    Transaction, TransactionHeader, TransactionType,
+};
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum BuilderError {
+    #[error("{0} is required")]
+    MissingField(&'static str),
+
+    #[error("last valid round {last_valid} is before first valid round {first_valid}")]
+    InvalidValidityWindow { first_valid: u64, last_valid: u64 },
+
+    #[error("validity window of {rounds} rounds exceeds the maximum of {max}")]
+    ValidityWindowTooLong { rounds: u64, max: u64 },
+
+    #[error("{0} can only be set when creating an application")]
+    CreationOnly(&'static str),
+
+    #[error("programs can only be set when creating or updating an application")]
+    ProgramsNotAllowed,
+
+    #[error("{0}")]
+    InvalidProgram(#[from] ProgramError),
+}
+
+/// The header fields shared by all builders
+#[derive(Debug, Clone)]
+struct Header {
+    params: SuggestedParams,
+    sender: Option<Address>,
+    note: Option<Vec<u8>>,
+    lease: Option<Byte32>,
+    rekey_to: Option<Address>,
+    first_valid: u64,
+    last_valid: u64,
+    validity_window: Option<u64>,
+    fee_options: FeeOptions,
+}
+
+impl Header {
+    fn new(params: &SuggestedParams) -> Self {
+        Header {
+            params: params.clone(),
+            sender: None,
+            note: None,
+            lease: None,
+            rekey_to: None,
+            first_valid: params.first_valid,
+            last_valid: params.last_valid,
+            validity_window: None,
+            fee_options: FeeOptions::default(),
+        }
+    }
+
+    fn build(&self, transaction_type: TransactionType) -> Result<TransactionHeader, BuilderError> {
+        let sender = self
+            .sender
+            .clone()
+            .ok_or(BuilderError::MissingField("sender"))?;
+
+        let last_valid = match self.validity_window {
+            Some(rounds) => self.first_valid.saturating_add(rounds),
+            None => self.last_valid,
+        };
+
+        if last_valid < self.first_valid {
+            return Err(BuilderError::InvalidValidityWindow {
+                first_valid: self.first_valid,
+                last_valid,
+            });
+        }
+
+        // Unknown consensus versions, such as those of private networks, use the current limits
+        let max = self
+            .params
+            .consensus_params()
+            .unwrap_or_default()
+            .max_txn_life;
+        let rounds = last_valid - self.first_valid;
+        if rounds > max {
+            return Err(BuilderError::ValidityWindowTooLong { rounds, max });
+        }
+
+        Ok(TransactionHeader {
+            transaction_type,
+            sender,
+            fee: MicroAlgos::ZERO,
+            first_valid: self.first_valid,
+            last_valid,
+            genesis_hash: Some(self.params.genesis_hash),
+            genesis_id: Some(self.params.genesis_id.clone()).filter(|id| !id.is_empty()),
+            note: self.note.clone(),
+            rekey_to: self.rekey_to.clone(),
+            lease: self.lease,
+            group: None,
+        })
+    }
+
+    /// Assign the fee of the finished transaction
+    fn finish(&self, mut transaction: Transaction) -> Result<Transaction, AlgoModelsError> {
+        transaction.assign_fee(&self.params, &self.fee_options)?;
+        Ok(transaction)
+    }
+}
+
+/// Setters for the header fields, which are the same for every builder
+macro_rules! header_setters {
+    () => {
+        /// The account that sends the transaction and pays its fee
+        pub fn sender(mut self, sender: Address) -> Self {
+            self.header.sender = Some(sender);
+            self
+        }
+
+        pub fn note(mut self, note: Vec<u8>) -> Self {
+            self.header.note = Some(note);
+            self
+        }
+
+        /// A lease, which prevents another transaction from the sender with the same lease
+        /// being confirmed until this transaction's last valid round
+        pub fn lease(mut self, lease: Byte32) -> Self {
+            self.header.lease = Some(lease);
+            self
+        }
+
+        /// Rekey the sender to this account once the transaction is confirmed
+        pub fn rekey_to(mut self, rekey_to: Address) -> Self {
+            self.header.rekey_to = Some(rekey_to);
+            self
+        }
+
+        /// Override the first valid round from the suggested params
+        pub fn first_valid(mut self, round: u64) -> Self {
+            self.header.first_valid = round;
+            self
+        }
+
+        /// Override the last valid round from the suggested params
+        pub fn last_valid(mut self, round: u64) -> Self {
+            self.header.last_valid = round;
+            self.header.validity_window = None;
+            self
+        }
+
+        /// Set the last valid round to `rounds` after the first valid round
+        pub fn validity_window(mut self, rounds: u64) -> Self {
+            self.header.validity_window = Some(rounds);
+            self
+        }
+
+        /// How the fee is estimated. By default the transaction pays its own fee with a single
+        /// signature
+        pub fn fee_options(mut self, fee_options: FeeOptions) -> Self {
+            self.header.fee_options = fee_options;
+            self
+        }
+    };
+}
+
+/// A builder for payment transactions. `sender`, `receiver` and `amount` are required
+#[derive(Debug, Clone)]
+pub struct PaymentBuilder {
+    header: Header,
+    receiver: Option<Address>,
+    amount: Option<MicroAlgos>,
+    close_remainder_to: Option<Address>,
+}
+
+impl PaymentBuilder {
+    pub fn new(params: &SuggestedParams) -> Self {
+        PaymentBuilder {
+            header: Header::new(params),
+            receiver: None,
+            amount: None,
+            close_remainder_to: None,
+        }
+    }
+
+    header_setters!();
+
+    pub fn receiver(mut self, receiver: Address) -> Self {
+        self.receiver = Some(receiver);
+        self
+    }
+
+    pub fn amount(mut self, amount: MicroAlgos) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    /// Close the sender's account, sending its remaining balance to this account
+    pub fn close_remainder_to(mut self, close_remainder_to: Address) -> Self {
+        self.close_remainder_to = Some(close_remainder_to);
+        self
+    }
+
+    pub fn build(self) -> Result<Transaction, AlgoModelsError> {
+        let transaction = Transaction::Payment(PayTransactionFields {
+            header: self.header.build(TransactionType::Payment)?,
+            receiver: self
+                .receiver
+                .ok_or(BuilderError::MissingField("receiver"))?,
+            amount: self.amount.ok_or(BuilderError::MissingField("amount"))?,
+            close_remainder_to: self.close_remainder_to,
+        });
+
+        self.header.finish(transaction)
+    }
+}
+
+/// A builder for asset transfer transactions. `sender`, `asset_id`, `receiver` and `amount`
+/// are required
+#[derive(Debug, Clone)]
+pub struct AssetTransferBuilder {
+    header: Header,
+    asset_id: Option<u64>,
+    receiver: Option<Address>,
+    amount: Option<u64>,
+    asset_sender: Option<Address>,
+    close_remainder_to: Option<Address>,
+}
+
+impl AssetTransferBuilder {
+    pub fn new(params: &SuggestedParams) -> Self {
+        AssetTransferBuilder {
+            header: Header::new(params),
+            asset_id: None,
+            receiver: None,
+            amount: None,
+            asset_sender: None,
+            close_remainder_to: None,
+        }
+    }
+
+    /// A builder for an opt-in, which is a transfer of 0 units of the asset to the sender
+    pub fn opt_in(params: &SuggestedParams, sender: Address, asset_id: u64) -> Self {
+        Self::new(params)
+            .sender(sender.clone())
+            .asset_id(asset_id)
+            .receiver(sender)
+            .amount(0)
+    }
+
+    header_setters!();
+
+    pub fn asset_id(mut self, asset_id: u64) -> Self {
+        self.asset_id = Some(asset_id);
+        self
+    }
+
+    pub fn receiver(mut self, receiver: Address) -> Self {
+        self.receiver = Some(receiver);
+        self
+    }
+
+    /// The amount to send, in the asset's base units
+    pub fn amount(mut self, amount: u64) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    /// Send the asset from this account instead of the sender, which must be the asset's
+    /// clawback account
+    pub fn asset_sender(mut self, asset_sender: Address) -> Self {
+        self.asset_sender = Some(asset_sender);
+        self
+    }
+
+    /// Opt the sender out of the asset, sending its remaining holding to this account
+    pub fn close_remainder_to(mut self, close_remainder_to: Address) -> Self {
+        self.close_remainder_to = Some(close_remainder_to);
+        self
+    }
+
+    pub fn build(self) -> Result<Transaction, AlgoModelsError> {
+        let transaction = Transaction::AssetTransfer(AssetTransferTransactionFields {
+            header: self.header.build(TransactionType::AssetTransfer)?,
+            asset_id: self
+                .asset_id
+                .ok_or(BuilderError::MissingField("asset_id"))?,
+            amount: self.amount.ok_or(BuilderError::MissingField("amount"))?,
+            receiver: self
+                .receiver
+                .ok_or(BuilderError::MissingField("receiver"))?,
+            asset_sender: self.asset_sender,
+            close_remainder_to: self.close_remainder_to,
+        });
+
+        self.header.finish(transaction)
+    }
+}
+
+/// A builder for application calls. `sender` is required, and creating an application (an
+/// `app_id` of 0) also requires its programs
+#[derive(Debug, Clone)]
+pub struct ApplicationCallBuilder {
+    header: Header,
+    app_id: u64,
+    on_complete: OnApplicationComplete,
+    programs: Option<(Program, Program)>,
+    global_state_schema: Option<StateSchema>,
+    local_state_schema: Option<StateSchema>,
+    extra_program_pages: u32,
+    args: Option<Vec<Vec<u8>>>,
+    accounts: Option<Vec<Address>>,
+    foreign_apps: Option<Vec<u64>>,
+    foreign_assets: Option<Vec<u64>>,
+    boxes: Option<Vec<BoxReference>>,
+}
+
+impl ApplicationCallBuilder {
+    pub fn new(params: &SuggestedParams) -> Self {
+        ApplicationCallBuilder {
+            header: Header::new(params),
+            app_id: 0,
+            on_complete: OnApplicationComplete::NoOp,
+            programs: None,
+            global_state_schema: None,
+            local_state_schema: None,
+            extra_program_pages: 0,
+            args: None,
+            accounts: None,
+            foreign_apps: None,
+            foreign_assets: None,
+            boxes: None,
+        }
+    }
+
+    header_setters!();
+
+    /// The application to call, or 0 (the default) to create an application
+    pub fn app_id(mut self, app_id: u64) -> Self {
+        self.app_id = app_id;
+        self
+    }
+
+    pub fn on_complete(mut self, on_complete: OnApplicationComplete) -> Self {
+        self.on_complete = on_complete;
+        self
+    }
+
+    /// The approval and clear state programs, when creating or updating an application
+    pub fn programs(mut self, approval: Program, clear_state: Program) -> Self {
+        self.programs = Some((approval, clear_state));
+        self
+    }
+
+    pub fn global_state_schema(mut self, schema: StateSchema) -> Self {
+        self.global_state_schema = Some(schema);
+        self
+    }
+
+    pub fn local_state_schema(mut self, schema: StateSchema) -> Self {
+        self.local_state_schema = Some(schema);
+        self
+    }
+
+    /// Reserve extra program pages when creating an application, so later updates can use
+    /// larger programs. At least the pages the programs need are always reserved
+    pub fn extra_program_pages(mut self, pages: u32) -> Self {
+        self.extra_program_pages = pages;
+        self
+    }
+
+    pub fn args(mut self, args: Vec<Vec<u8>>) -> Self {
+        self.args = Some(args);
+        self
+    }
+
+    pub fn accounts(mut self, accounts: Vec<Address>) -> Self {
+        self.accounts = Some(accounts);
+        self
+    }
+
+    pub fn foreign_apps(mut self, foreign_apps: Vec<u64>) -> Self {
+        self.foreign_apps = Some(foreign_apps);
+        self
+    }
+
+    pub fn foreign_assets(mut self, foreign_assets: Vec<u64>) -> Self {
+        self.foreign_assets = Some(foreign_assets);
+        self
+    }
+
+    pub fn boxes(mut self, boxes: Vec<BoxReference>) -> Self {
+        self.boxes = Some(boxes);
+        self
+    }
+
+    pub fn build(self) -> Result<Transaction, AlgoModelsError> {
+        // An existing application keeps the schemas and extra pages it was created with
+        if self.app_id != 0 {
+            if self.global_state_schema.is_some() {
+                Err(BuilderError::CreationOnly("global_state_schema"))?;
+            }
+            if self.local_state_schema.is_some() {
+                Err(BuilderError::CreationOnly("local_state_schema"))?;
+            }
+            if self.extra_program_pages != 0 {
+                Err(BuilderError::CreationOnly("extra_program_pages"))?;
+            }
+            if self.programs.is_some()
+                && self.on_complete != OnApplicationComplete::UpdateApplication
+            {
+                Err(BuilderError::ProgramsNotAllowed)?;
+            }
+        }
+
+        let mut fields = ApplicationCallTransactionFields {
+            header: self.header.build(TransactionType::ApplicationCall)?,
+            app_id: self.app_id,
+            on_complete: self.on_complete,
+            approval_program: None,
+            clear_state_program: None,
+            global_state_schema: self.global_state_schema,
+            local_state_schema: self.local_state_schema,
+            extra_program_pages: 0,
+            args: self.args,
+            accounts: self.accounts,
+            foreign_apps: self.foreign_apps,
+            foreign_assets: self.foreign_assets,
+            boxes: self.boxes,
+        };
+
+        match self.programs {
+            Some((approval, clear_state)) => fields
+                .set_programs(approval, clear_state)
+                .map_err(BuilderError::from)?,
+            None if self.app_id == 0 => Err(BuilderError::MissingField("programs"))?,
+            None => {}
+        }
+
+        // Extra pages can only be reserved when the application is created, so an update must
+        // fit in the pages reserved then
+        if self.app_id == 0 {
+            fields.extra_program_pages = fields
+                .extra_program_pages
+                .max(self.extra_program_pages.into());
+        } else {
+            fields.extra_program_pages = 0;
+        }
+
+        self.header.finish(Transaction::ApplicationCall(fields))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consensus::ConsensusParams;
+    use crate::fee::SignatureKind;
+    use pretty_assertions::assert_eq;
+
+    fn params() -> SuggestedParams {
+        SuggestedParams {
+            fee_per_byte: 0,
+            min_fee: MicroAlgos(1000),
+            first_valid: 1000,
+            last_valid: 2000,
+            genesis_id: "testnet-v1.0".to_string(),
+            genesis_hash: [7; 32],
+            consensus_version: "future".to_string(),
+        }
+    }
+
+    fn alice() -> Address {
+        Address::from_pubkey(&[1; 32])
+    }
+
+    fn bob() -> Address {
+        Address::from_pubkey(&[2; 32])
+    }
+
+    fn build_error(result: Result<Transaction, AlgoModelsError>) -> String {
+        result.unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_payment() {
+        let transaction = PaymentBuilder::new(&params())
+            .sender(alice())
+            .receiver(bob())
+            .amount(MicroAlgos(5_000_000))
+            .note(b"hello".to_vec())
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            transaction,
+            Transaction::Payment(PayTransactionFields {
+                header: TransactionHeader {
+                    transaction_type: TransactionType::Payment,
+                    sender: alice(),
+                    fee: MicroAlgos(1000),
+                    first_valid: 1000,
+                    last_valid: 2000,
+                    genesis_hash: Some([7; 32]),
+                    genesis_id: Some("testnet-v1.0".to_string()),
+                    note: Some(b"hello".to_vec()),
+                    rekey_to: None,
+                    lease: None,
+                    group: None,
+                },
+                receiver: bob(),
+                amount: MicroAlgos(5_000_000),
+                close_remainder_to: None,
+            })
+        );
+
+        assert_eq!(
+            build_error(
+                PaymentBuilder::new(&params())
+                    .sender(alice())
+                    .amount(MicroAlgos(1))
+                    .build()
+            ),
+            "receiver is required"
+        );
+        assert_eq!(
+            build_error(
+                PaymentBuilder::new(&params())
+                    .receiver(bob())
+                    .amount(MicroAlgos(1))
+                    .build()
+            ),
+            "sender is required"
+        );
+    }
+
+    #[test]
+    fn test_validity_window() {
+        let payment = || {
+            PaymentBuilder::new(&params())
+                .sender(alice())
+                .receiver(bob())
+                .amount(MicroAlgos::ZERO)
+        };
+
+        let header = |transaction: Transaction| {
+            let header = transaction.header();
+            (header.first_valid, header.last_valid)
+        };
+
+        assert_eq!(
+            header(payment().validity_window(10).build().unwrap()),
+            (1000, 1010)
+        );
+        assert_eq!(
+            header(
+                payment()
+                    .validity_window(10)
+                    .first_valid(1500)
+                    .build()
+                    .unwrap()
+            ),
+            (1500, 1510)
+        );
+        assert_eq!(
+            header(payment().last_valid(1100).build().unwrap()),
+            (1000, 1100)
+        );
+
+        assert_eq!(
+            build_error(payment().validity_window(1001).build()),
+            "validity window of 1001 rounds exceeds the maximum of 1000"
+        );
+        assert_eq!(
+            build_error(payment().first_valid(2001).build()),
+            "last valid round 2000 is before first valid round 2001"
+        );
+    }
+
+    #[test]
+    fn test_fee() {
+        let payment = PaymentBuilder::new(&params())
+            .sender(alice())
+            .receiver(bob())
+            .amount(MicroAlgos(1));
+
+        let covering = payment
+            .clone()
+            .fee_options(FeeOptions {
+                covered_transactions: 2,
+                ..Default::default()
+            })
+            .build()
+            .unwrap();
+        assert_eq!(covering.header().fee, MicroAlgos(3000));
+
+        let congested = payment
+            .fee_options(FeeOptions {
+                signature: SignatureKind::Multisig { subsigs: 2 },
+                ..Default::default()
+            })
+            .build()
+            .unwrap();
+        let size = congested
+            .estimate_signed_size(&SignatureKind::Multisig { subsigs: 2 })
+            .unwrap();
+        assert_eq!(
+            congested
+                .estimate_fee(
+                    &SuggestedParams {
+                        fee_per_byte: 10,
+                        ..params()
+                    },
+                    &FeeOptions {
+                        signature: SignatureKind::Multisig { subsigs: 2 },
+                        ..Default::default()
+                    }
+                )
+                .unwrap(),
+            MicroAlgos(10 * size as u64)
+        );
+    }
+
+    #[test]
+    fn test_asset_transfer() {
+        let transaction = AssetTransferBuilder::opt_in(&params(), alice(), 1234)
+            .build()
+            .unwrap();
+
+        let Transaction::AssetTransfer(fields) = transaction else {
+            panic!("expected an asset transfer");
+        };
+        assert_eq!(
+            fields.header.transaction_type,
+            TransactionType::AssetTransfer
+        );
+        assert_eq!(fields.asset_id, 1234);
+        assert_eq!(fields.receiver, alice());
+        assert_eq!(fields.amount, 0);
+
+        assert_eq!(
+            build_error(
+                AssetTransferBuilder::new(&params())
+                    .sender(alice())
+                    .receiver(bob())
+                    .amount(1)
+                    .build()
+            ),
+            "asset_id is required"
+        );
+    }
+
+    #[test]
+    fn test_application_call() {
+        let approval = Program::assemble("#pragma version 10\npushint 1").unwrap();
+        let clear_state = Program::assemble("#pragma version 10\npushint 1").unwrap();
+
+        let transaction = ApplicationCallBuilder::new(&params())
+            .sender(alice())
+            .programs(approval.clone(), clear_state.clone())
+            .extra_program_pages(2)
+            .global_state_schema(StateSchema {
+                num_uints: 1,
+                num_byte_slices: 0,
+            })
+            .build()
+            .unwrap();
+
+        let Transaction::ApplicationCall(fields) = transaction else {
+            panic!("expected an application call");
+        };
+        assert_eq!(
+            fields.header.transaction_type,
+            TransactionType::ApplicationCall
+        );
+        assert_eq!(fields.approval_program, Some(vec![0x0a, 0x81, 0x01]));
+        assert_eq!(fields.extra_program_pages, 2);
+
+        let call = ApplicationCallBuilder::new(&params())
+            .sender(alice())
+            .app_id(1234)
+            .on_complete(OnApplicationComplete::OptIn)
+            .args(vec![b"opt_in".to_vec()])
+            .build()
+            .unwrap();
+        let Transaction::ApplicationCall(fields) = call else {
+            panic!("expected an application call");
+        };
+        assert_eq!(fields.extra_program_pages, 0);
+        assert_eq!(fields.on_complete, OnApplicationComplete::OptIn);
+
+        // Schemas and extra pages are fixed when the application is created
+        let opt_in = ApplicationCallBuilder::new(&params())
+            .sender(alice())
+            .app_id(1234)
+            .on_complete(OnApplicationComplete::OptIn);
+        assert_eq!(
+            build_error(opt_in.clone().extra_program_pages(2).build()),
+            "extra_program_pages can only be set when creating an application"
+        );
+        assert_eq!(
+            build_error(
+                opt_in
+                    .clone()
+                    .local_state_schema(StateSchema {
+                        num_uints: 1,
+                        num_byte_slices: 0,
+                    })
+                    .build()
+            ),
+            "local_state_schema can only be set when creating an application"
+        );
+        assert_eq!(
+            build_error(
+                opt_in
+                    .programs(approval.clone(), clear_state.clone())
+                    .build()
+            ),
+            "programs can only be set when creating or updating an application"
+        );
+
+        assert_eq!(
+            build_error(
+                ApplicationCallBuilder::new(&params())
+                    .sender(alice())
+                    .build()
+            ),
+            "programs is required"
+        );
+    }
+
+    #[test]
+    fn test_update_application() {
+        // An approval program of about 4.5 KB needs 2 extra pages when it is created
+        let mut source = String::from("#pragma version 10\n");
+        for _ in 0..1500 {
+            source.push_str("pushint 1\npop\n");
+        }
+        source.push_str("pushint 1");
+        let approval = Program::assemble(&source).unwrap();
+        let clear_state = Program::assemble("#pragma version 10\npushint 1").unwrap();
+        assert_eq!(Program::extra_pages(&approval, &clear_state).unwrap(), 2);
+
+        let update = ApplicationCallBuilder::new(&params())
+            .sender(alice())
+            .app_id(1234)
+            .on_complete(OnApplicationComplete::UpdateApplication)
+            .programs(approval, clear_state)
+            .build()
+            .unwrap();
+        let Transaction::ApplicationCall(fields) = &update else {
+            panic!("expected an application call");
+        };
+        assert_eq!(fields.extra_program_pages, 0);
+        assert!(fields.approval_program.as_ref().unwrap().len() > 4500);
+        assert!(update.validate(&ConsensusParams::default()).is_ok());
+    }
+}
@@ -0,0 +1,415 @@
+//! Protocol limits that transactions and balances must respect, for every published consensus
+//! version.
+//!
+//! The limits are set by the consensus protocol and can change when the network upgrades, so
+//! checks take them as a parameter instead of hard-coding them. A consensus version is named by
+//! the URL of its specification, as returned in the `consensus-version` of algod's suggested
+//! params. Like go-algorand, each version is described by how it changes the one before it.
+
+use crate::MicroAlgos;
+
+/// The consensus limits that apply to transactions and account balances
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConsensusParams {
+    /// The maximum number of rounds between the first and last valid rounds of a transaction
+    pub max_txn_life: u64,
+
+    /// The maximum size of a transaction note, in bytes
+    pub max_txn_note_bytes: usize,
+
+    /// The minimum fee of a transaction
+    pub min_txn_fee: MicroAlgos,
+
+    /// The maximum number of transactions in a group
+    pub max_tx_group_size: usize,
+
+    /// The highest program version a logic signature may use, or 0 without logic signatures
+    pub logic_sig_version: u64,
+
+    /// The maximum size of a logic signature, including its program and arguments
+    pub logic_sig_max_size: usize,
+
+    /// The maximum number of arguments of an application call
+    pub max_app_args: usize,
+
+    /// The maximum combined size of the arguments of an application call, in bytes
+    pub max_app_total_arg_len: usize,
+
+    /// The maximum number of accounts an application call may reference
+    pub max_app_txn_accounts: usize,
+
+    /// The maximum number of applications an application call may reference
+    pub max_app_txn_foreign_apps: usize,
+
+    /// The maximum number of assets an application call may reference
+    pub max_app_txn_foreign_assets: usize,
+
+    /// The maximum number of accounts, applications, assets and boxes an application call may
+    /// reference in total
+    pub max_app_total_txn_references: usize,
+
+    /// The maximum number of boxes an application call may reference
+    pub max_app_box_references: usize,
+
+    /// The maximum size of a state key or box name, in bytes
+    pub max_app_key_len: usize,
+
+    /// The maximum size of a box, in bytes
+    pub max_box_size: u64,
+
+    /// The maximum size of each program of an application, in bytes per program page
+    pub max_app_program_len: usize,
+
+    /// The maximum combined size of the programs of an application, in bytes per program page,
+    /// or 0 before v28 when only each program was limited
+    pub max_app_total_program_len: usize,
+
+    /// The maximum number of extra program pages an application may request
+    pub max_extra_app_program_pages: u64,
+
+    /// The maximum number of values in the global state schema of an application
+    pub max_global_schema_entries: u64,
+
+    /// The maximum number of values in the local state schema of an application
+    pub max_local_schema_entries: u64,
+
+    /// Whether transactions must have a genesis hash, which blocks then elide from every
+    /// transaction
+    pub require_genesis_hash: bool,
+
+    /// The minimum balance of an account, and the amount it increases by for each asset held
+    pub min_balance: MicroAlgos,
+
+    /// The minimum balance increase for each application created, and for each extra program
+    /// page of those applications
+    pub app_flat_params_min_balance: MicroAlgos,
+
+    /// The minimum balance increase for each application opted in to
+    pub app_flat_opt_in_min_balance: MicroAlgos,
+
+    /// The minimum balance increase for each entry of a state schema, whatever its type
+    pub schema_min_balance_per_entry: MicroAlgos,
+
+    /// The additional minimum balance increase for each integer of a state schema
+    pub schema_uint_min_balance: MicroAlgos,
+
+    /// The additional minimum balance increase for each byte slice of a state schema
+    pub schema_bytes_min_balance: MicroAlgos,
+
+    /// The minimum balance increase for each box
+    pub box_flat_min_balance: MicroAlgos,
+
+    /// The minimum balance increase for each byte of a box's name and value
+    pub box_byte_min_balance: MicroAlgos,
+}
+
+/// The version of the network's current protocol
+pub const CURRENT_VERSION: &str =
+    "https://github.com/algorandfoundation/specs/tree/953304de35264fc3ef91bcd05c123242015eeaed";
+
+type Upgrade = fn(&mut ConsensusParams);
+
+/// Every published consensus version, oldest first, with the changes it made to the limits.
+/// Versions before v7 never ran on a public network
+const VERSIONS: &[(&str, Upgrade)] = &[
+    ("v7", |_| {}),
+    ("v8", |_| {}),
+    // v9: the minimum balance rises to 0.1 Algo
+    ("v9", |p| p.min_balance = MicroAlgos(100_000)),
+    ("v10", |_| {}),
+    ("v11", |_| {}),
+    ("v12", |_| {}),
+    (
+        "https://github.com/algorand/spec/tree/0c8a9dc44d7368cc266d5407b79fb3311f4fc795",
+        |_| {},
+    ),
+    (
+        "https://github.com/algorand/spec/tree/2526b6ae062b4fe5e163e06e41e1d9b9219135a9",
+        |_| {},
+    ),
+    (
+        "https://github.com/algorand/spec/tree/a26ed78ed8f834e2b9ccb6eb7d3ee9f629a6e622",
+        |_| {},
+    ),
+    (
+        "https://github.com/algorand/spec/tree/22726c9dcd12d9cddce4a8bd7e8ccaa707f74101",
+        |p| p.require_genesis_hash = true,
+    ),
+    (
+        "https://github.com/algorandfoundation/specs/tree/5615adc36bad610c7f165fa2967f4ecfa75125f0",
+        |_| {},
+    ),
+    // v18: transaction groups and logic signatures
+    (
+        "https://github.com/algorandfoundation/specs/tree/6c6bd668be0ab14098e51b37e806c509f7b7e31f",
+        |p| {
+            p.max_tx_group_size = 16;
+            p.logic_sig_version = 1;
+            p.logic_sig_max_size = 1000;
+        },
+    ),
+    (
+        "https://github.com/algorandfoundation/specs/tree/0e196e82a8b1d1f04e73dea6ef27e6f51bc6c5e4",
+        |_| {},
+    ),
+    (
+        "https://github.com/algorandfoundation/specs/tree/4a9db6a25595c6fd097cf9cc137cc83027787eaa",
+        |_| {},
+    ),
+    (
+        "https://github.com/algorandfoundation/specs/tree/8096e2df2da75c3339986317f9abe69d4fa86b4b",
+        |_| {},
+    ),
+    (
+        "https://github.com/algorandfoundation/specs/tree/57016b942f6d97e6d4c0688b373bb0a2fc85a1a2",
+        |_| {},
+    ),
+    (
+        "https://github.com/algorandfoundation/specs/tree/e5f565421d720c6f75cdd186f7098495caf9101f",
+        |_| {},
+    ),
+    // v24: applications
+    (
+        "https://github.com/algorandfoundation/specs/tree/3a83c4c743f8b17adfd73944b4319c25722a6782",
+        |p| {
+            p.logic_sig_version = 2;
+            p.max_app_args = 16;
+            p.max_app_total_arg_len = 2048;
+            p.max_app_txn_accounts = 4;
+            p.max_app_txn_foreign_apps = 2;
+            p.max_app_txn_foreign_assets = 2;
+            p.max_app_total_txn_references = 8;
+            p.max_app_key_len = 64;
+            p.max_app_program_len = 1024;
+            p.max_global_schema_entries = 64;
+            p.max_local_schema_entries = 16;
+            p.app_flat_params_min_balance = MicroAlgos(100_000);
+            p.app_flat_opt_in_min_balance = MicroAlgos(100_000);
+            p.schema_min_balance_per_entry = MicroAlgos(25_000);
+            p.schema_uint_min_balance = MicroAlgos(3_500);
+            p.schema_bytes_min_balance = MicroAlgos(25_000);
+        },
+    ),
+    (
+        "https://github.com/algorandfoundation/specs/tree/bea19289bf41217d2c0af30522fa222ef1366466",
+        |_| {},
+    ),
+    (
+        "https://github.com/algorandfoundation/specs/tree/ac2255d586c4474d4ebcf3809acccb59b7ef34ff",
+        |p| {
+            p.logic_sig_version = 3;
+            p.max_app_txn_foreign_apps = 8;
+            p.max_app_txn_foreign_assets = 8;
+        },
+    ),
+    (
+        "https://github.com/algorandfoundation/specs/tree/d050b3cade6d5c664df8bd729bf219f179812595",
+        |_| {},
+    ),
+    // v28: extra program pages
+    (
+        "https://github.com/algorandfoundation/specs/tree/65b4ab3266c52c56a0fa7d591754887d68faad0a",
+        |p| {
+            p.logic_sig_version = 4;
+            p.max_app_program_len = 2048;
+            p.max_app_total_program_len = 2048;
+            p.max_extra_app_program_pages = 3;
+        },
+    ),
+    (
+        "https://github.com/algorandfoundation/specs/tree/abc54f79f9ad679d2d22f0fb9909fb005c16f8a1",
+        |_| {},
+    ),
+    (
+        "https://github.com/algorandfoundation/specs/tree/bc36005dbd776e6d1eaf0c560619bb183215645c",
+        |p| p.logic_sig_version = 5,
+    ),
+    (
+        "https://github.com/algorandfoundation/specs/tree/85e6db1fdbdef00aa232c75199e10dc5fe9498f6",
+        |p| p.logic_sig_version = 6,
+    ),
+    (
+        "https://github.com/algorandfoundation/specs/tree/d5ac876d7ede07367dbaa26e149aa42589aac1f7",
+        |_| {},
+    ),
+    (
+        "https://github.com/algorandfoundation/specs/tree/830a83e61e8b9b8e1d8e0ab6c5e6e3fd68d7b40c",
+        |_| {},
+    ),
+    (
+        "https://github.com/algorandfoundation/specs/tree/2dd5435993f6f6d65691140f592ebca5ef19ffbd",
+        |p| p.logic_sig_version = 7,
+    ),
+    // v35: boxes
+    (
+        "https://github.com/algorandfoundation/specs/tree/433d8e9a7274b6fca703d91213e05c7e6a589e69",
+        |p| {
+            p.logic_sig_version = 8;
+            p.max_app_box_references = 8;
+            p.max_box_size = 32768;
+            p.box_flat_min_balance = MicroAlgos(2_500);
+            p.box_byte_min_balance = MicroAlgos(400);
+        },
+    ),
+    (
+        "https://github.com/algorandfoundation/specs/tree/44fa607d6051730f5264526bf3c108d51f0eadb6",
+        |_| {},
+    ),
+    (
+        "https://github.com/algorandfoundation/specs/tree/1ac4dd1f85470e1fb36c8a65520e1313d7dab9d5",
+        |_| {},
+    ),
+    (
+        "https://github.com/algorandfoundation/specs/tree/abd3d4823c6f77349fc04c3af7b1e99fe4df699f",
+        |p| p.logic_sig_version = 9,
+    ),
+    (
+        "https://github.com/algorandfoundation/specs/tree/925a46433742afb0b51bb939354bd907fa88bf95",
+        |p| p.logic_sig_version = 10,
+    ),
+    (
+        "https://github.com/algorandfoundation/specs/tree/236dcc18c9c507d794813ab768e467ea42d1b4d9",
+        |p| p.logic_sig_version = 11,
+    ),
+    (CURRENT_VERSION, |_| {}),
+];
+
+/// The limits of v7, the first version of the public networks
+fn initial_params() -> ConsensusParams {
+    ConsensusParams {
+        max_txn_life: 1000,
+        max_txn_note_bytes: 1024,
+        min_txn_fee: MicroAlgos(1000),
+        max_tx_group_size: 1,
+        logic_sig_version: 0,
+        logic_sig_max_size: 0,
+        max_app_args: 0,
+        max_app_total_arg_len: 0,
+        max_app_txn_accounts: 0,
+        max_app_txn_foreign_apps: 0,
+        max_app_txn_foreign_assets: 0,
+        max_app_total_txn_references: 0,
+        max_app_box_references: 0,
+        max_app_key_len: 0,
+        max_box_size: 0,
+        max_app_program_len: 0,
+        max_app_total_program_len: 0,
+        max_extra_app_program_pages: 0,
+        max_global_schema_entries: 0,
+        max_local_schema_entries: 0,
+        require_genesis_hash: false,
+        min_balance: MicroAlgos(10_000),
+        app_flat_params_min_balance: MicroAlgos::ZERO,
+        app_flat_opt_in_min_balance: MicroAlgos::ZERO,
+        schema_min_balance_per_entry: MicroAlgos::ZERO,
+        schema_uint_min_balance: MicroAlgos::ZERO,
+        schema_bytes_min_balance: MicroAlgos::ZERO,
+        box_flat_min_balance: MicroAlgos::ZERO,
+        box_byte_min_balance: MicroAlgos::ZERO,
+    }
+}
+
+impl ConsensusParams {
+    /// The limits of a consensus version, given its URL or its short name such as `v40`.
+    /// Returns `None` for versions this crate does not know about
+    pub fn for_version(version: &str) -> Option<Self> {
+        let mut params = initial_params();
+
+        for (index, (url, upgrade)) in VERSIONS.iter().enumerate() {
+            upgrade(&mut params);
+            if *url == version || short_name(index) == version {
+                return Some(params);
+            }
+        }
+
+        None
+    }
+
+    /// The URLs of every consensus version this crate knows about, oldest first
+    pub fn versions() -> Vec<&'static str> {
+        VERSIONS.iter().map(|(url, _)| *url).collect()
+    }
+}
+
+/// The `vN` name of the version at `index`, counting from v7
+fn short_name(index: usize) -> String {
+    format!("v{}", index + 7)
+}
+
+impl Default for ConsensusParams {
+    /// The limits of the current consensus protocol
+    fn default() -> Self {
+        Self::for_version(CURRENT_VERSION).expect("the current version is in the table")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_versions() {
+        let versions = ConsensusParams::versions();
+        assert_eq!(versions.first(), Some(&"v7"));
+        assert_eq!(versions.last(), Some(&CURRENT_VERSION));
+
+        // v41 is the current version
+        assert_eq!(versions[41 - 7], CURRENT_VERSION);
+        assert_eq!(
+            ConsensusParams::for_version("v41"),
+            ConsensusParams::for_version(CURRENT_VERSION)
+        );
+        assert_eq!(ConsensusParams::for_version("v6"), None);
+        assert_eq!(ConsensusParams::for_version("unknown"), None);
+
+        // The development networks' future version changes with each release, so it is unknown
+        assert_eq!(ConsensusParams::for_version("future"), None);
+        assert_eq!(ConsensusParams::for_version("v42"), None);
+    }
+
+    #[test]
+    fn test_limits_follow_upgrades() {
+        let v17 = ConsensusParams::for_version("v17").unwrap();
+        assert_eq!(v17.max_tx_group_size, 1);
+        assert_eq!(v17.logic_sig_version, 0);
+        assert_eq!(v17.max_app_args, 0);
+        assert!(v17.require_genesis_hash);
+        assert_eq!(v17.min_balance, MicroAlgos(100_000));
+        assert_eq!(
+            ConsensusParams::for_version("v8").unwrap().min_balance,
+            MicroAlgos(10_000)
+        );
+        assert!(
+            !ConsensusParams::for_version("v15")
+                .unwrap()
+                .require_genesis_hash
+        );
+
+        let v24 = ConsensusParams::for_version(
+            "https://github.com/algorandfoundation/specs/tree/3a83c4c743f8b17adfd73944b4319c25722a6782",
+        )
+        .unwrap();
+        assert_eq!(v24.max_tx_group_size, 16);
+        assert_eq!(v24.logic_sig_version, 2);
+        assert_eq!(v24.max_app_program_len, 1024);
+        assert_eq!(v24.max_app_total_program_len, 0);
+        assert_eq!(v24.max_extra_app_program_pages, 0);
+        assert_eq!(v24.max_box_size, 0);
+        assert_eq!(v24.schema_uint_min_balance, MicroAlgos(3_500));
+        assert_eq!(v24.box_byte_min_balance, MicroAlgos::ZERO);
+
+        let current = ConsensusParams::default();
+        assert_eq!(current.max_txn_life, 1000);
+        assert_eq!(current.max_txn_note_bytes, 1024);
+        assert_eq!(current.min_txn_fee, MicroAlgos(1000));
+        assert_eq!(current.logic_sig_version, 11);
+        assert_eq!(current.logic_sig_max_size, 1000);
+        assert_eq!(current.max_app_program_len, 2048);
+        assert_eq!(current.max_app_total_program_len, 2048);
+        assert_eq!(current.max_extra_app_program_pages, 3);
+        assert_eq!(current.max_app_box_references, 8);
+        assert_eq!(current.max_box_size, 32768);
+        assert_eq!(current.box_flat_min_balance, MicroAlgos(2_500));
+    }
+}
@@ -0,0 +1,344 @@
+//! Transaction fee estimation from the suggested params returned by algod.
+//!
+//! The required fee of a transaction is `max(min_fee, fee_per_byte * size)`, where `size` is the
+//! length of the signed transaction. The fee per byte is 0 unless the network is congested.
+//! Transactions in a group pool their fees, so one transaction can pay for others in its group or
+//! for the inner transactions of an application call.
+
+use crate::consensus::ConsensusParams;
+use crate::{AlgoModelsError, AlgorandMsgpack, Byte32, MicroAlgos, Transaction};
+use rmpv::Value;
+
+/// The parameters for new transactions, as returned by algod's `/v2/transactions/params`
+#[derive(Debug, Clone, PartialEq)]
+pub struct SuggestedParams {
+    /// The fee per byte of the signed transaction in microAlgos, which is 0 unless the network
+    /// is congested
+    pub fee_per_byte: u64,
+
+    /// The minimum fee of a transaction
+    pub min_fee: MicroAlgos,
+
+    pub first_valid: u64,
+
+    pub last_valid: u64,
+
+    pub genesis_id: String,
+
+    pub genesis_hash: Byte32,
+
+    /// The consensus protocol version of the network
+    pub consensus_version: String,
+}
+
+impl SuggestedParams {
+    /// The limits of the network's consensus version, if it is a published version
+    pub fn consensus_params(&self) -> Option<ConsensusParams> {
+        ConsensusParams::for_version(&self.consensus_version)
+    }
+}
+
+/// How a transaction will be signed, which determines the size of the signed transaction
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum SignatureKind {
+    /// An ed25519 signature from a single account
+    #[default]
+    Single,
+
+    /// A multisig signature, assuming all `subsigs` accounts sign
+    Multisig { subsigs: u8 },
+
+    /// A logic signature, with the size of its program and of each of its arguments
+    LogicSig {
+        program_size: usize,
+        arg_sizes: Vec<usize>,
+    },
+}
+
+impl SignatureKind {
+    /// The signature fields of the signed transaction, with placeholder values of the right size
+    fn placeholder(&self) -> (&'static str, Value) {
+        let bytes = |size: usize| Value::Binary(vec![0; size]);
+        let map = |entries: Vec<(&str, Value)>| {
+            Value::Map(entries.into_iter().map(|(k, v)| (k.into(), v)).collect())
+        };
+
+        match self {
+            SignatureKind::Single => ("sig", bytes(64)),
+            SignatureKind::Multisig { subsigs } => (
+                "msig",
+                map(vec![
+                    (
+                        "subsig",
+                        Value::Array(
+                            (0..*subsigs)
+                                .map(|_| map(vec![("pk", bytes(32)), ("s", bytes(64))]))
+                                .collect(),
+                        ),
+                    ),
+                    ("thr", Value::from(*subsigs)),
+                    ("v", Value::from(1)),
+                ]),
+            ),
+            SignatureKind::LogicSig {
+                program_size,
+                arg_sizes,
+            } => {
+                let mut fields = vec![];
+                if !arg_sizes.is_empty() {
+                    fields.push((
+                        "arg",
+                        Value::Array(arg_sizes.iter().map(|size| bytes(*size)).collect()),
+                    ));
+                }
+                fields.push(("l", bytes(*program_size)));
+                ("lsig", map(fields))
+            }
+        }
+    }
+}
+
+/// How the fee of a transaction is set
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FeeOptions {
+    /// How the transaction will be signed
+    pub signature: SignatureKind,
+
+    /// Use this fee instead of estimating one from the size of the transaction
+    pub flat_fee: Option<MicroAlgos>,
+
+    /// The number of other transactions whose minimum fee this transaction pays, such as the
+    /// inner transactions of an application call or other transactions in its group that have
+    /// a fee of 0. Ignored when `flat_fee` is set
+    pub covered_transactions: u64,
+}
+
+fn fee_overflow() -> AlgoModelsError {
+    AlgoModelsError::InputError("fee overflows a u64".to_string())
+}
+
+impl Transaction {
+    /// The size in bytes of the transaction once it is signed
+    pub fn estimate_signed_size(
+        &self,
+        signature: &SignatureKind,
+    ) -> Result<usize, AlgoModelsError> {
+        let transaction = rmpv::decode::read_value(&mut self.encode_raw()?.as_slice())?;
+        let (key, value) = signature.placeholder();
+
+        let mut signed = vec![];
+        rmpv::encode::write_value(
+            &mut signed,
+            &Value::Map(vec![(key.into(), value), ("txn".into(), transaction)]),
+        )?;
+
+        Ok(signed.len())
+    }
+
+    /// The fee this transaction needs, given the suggested params and how it will be signed
+    pub fn estimate_fee(
+        &self,
+        params: &SuggestedParams,
+        options: &FeeOptions,
+    ) -> Result<MicroAlgos, AlgoModelsError> {
+        if let Some(flat_fee) = options.flat_fee {
+            return Ok(flat_fee);
+        }
+
+        let covered = params
+            .min_fee
+            .checked_mul(options.covered_transactions)
+            .ok_or_else(fee_overflow)?;
+
+        // The fee is part of the transaction, so a larger fee can make the transaction larger.
+        // Starting from 0, the fee only grows, so this stops once it covers its own encoding
+        let mut transaction = self.clone();
+        transaction.header_mut().fee = MicroAlgos::ZERO;
+
+        loop {
+            let size = transaction.estimate_signed_size(&options.signature)?;
+            let fee = params
+                .fee_per_byte
+                .checked_mul(size as u64)
+                .map(|fee| MicroAlgos(fee).max(params.min_fee))
+                .and_then(|fee| fee.checked_add(covered))
+                .ok_or_else(fee_overflow)?;
+
+            if fee == transaction.header().fee {
+                return Ok(fee);
+            }
+            transaction.header_mut().fee = fee;
+        }
+    }
+
+    /// Set the fee of this transaction to the estimated fee, returning the fee
+    pub fn assign_fee(
+        &mut self,
+        params: &SuggestedParams,
+        options: &FeeOptions,
+    ) -> Result<MicroAlgos, AlgoModelsError> {
+        let fee = self.estimate_fee(params, options)?;
+        self.header_mut().fee = fee;
+        Ok(fee)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TransactionType;
+    use crate::{Address, PayTransactionFields, SignedTransaction, TransactionHeader};
+    use pretty_assertions::assert_eq;
+
+    fn params(fee_per_byte: u64) -> SuggestedParams {
+        SuggestedParams {
+            fee_per_byte,
+            min_fee: MicroAlgos(1000),
+            first_valid: 1000,
+            last_valid: 2000,
+            genesis_id: "testnet-v1.0".to_string(),
+            genesis_hash: [7; 32],
+            consensus_version: "https://github.com/algorandfoundation/specs/tree/236dcc18c9c507d794813ab768e467ea42d1b4d9".to_string(),
+        }
+    }
+
+    fn payment() -> Transaction {
+        Transaction::Payment(PayTransactionFields {
+            header: TransactionHeader {
+                transaction_type: TransactionType::Payment,
+                sender: Address::from_pubkey(&[1; 32]),
+                fee: MicroAlgos::ZERO,
+                first_valid: 1000,
+                last_valid: 2000,
+                genesis_hash: Some([7; 32]),
+                genesis_id: Some("testnet-v1.0".to_string()),
+                note: None,
+                rekey_to: None,
+                lease: None,
+                group: None,
+            },
+            receiver: Address::from_pubkey(&[2; 32]),
+            amount: MicroAlgos(1000),
+            close_remainder_to: None,
+        })
+    }
+
+    #[test]
+    fn test_estimate_signed_size() {
+        let mut transaction = payment();
+        transaction.header_mut().fee = MicroAlgos(5000);
+        let signed = SignedTransaction {
+            signature: [1; 64],
+            ..SignedTransaction::with_empty_signature(transaction.clone())
+        };
+
+        assert_eq!(
+            transaction
+                .estimate_signed_size(&SignatureKind::Single)
+                .unwrap(),
+            signed.encode().unwrap().len()
+        );
+
+        let single = transaction
+            .estimate_signed_size(&SignatureKind::Single)
+            .unwrap();
+        // "msig", a map with "subsig", "thr" and "v", and 3 maps with "pk" and "s"
+        assert_eq!(
+            transaction
+                .estimate_signed_size(&SignatureKind::Multisig { subsigs: 3 })
+                .unwrap(),
+            single - 70 + 5 + 1 + 7 + 1 + 4 + 1 + 2 + 1 + 3 * (1 + 3 + 34 + 2 + 66)
+        );
+        // "lsig", a map with "arg" and "l", and the arguments and program
+        assert_eq!(
+            transaction
+                .estimate_signed_size(&SignatureKind::LogicSig {
+                    program_size: 100,
+                    arg_sizes: vec![10, 0],
+                })
+                .unwrap(),
+            single - 70 + 5 + 1 + 4 + 1 + 12 + 2 + 2 + 102
+        );
+    }
+
+    #[test]
+    fn test_estimate_fee() {
+        let transaction = payment();
+
+        // Without congestion the fee is the minimum fee
+        assert_eq!(
+            transaction
+                .estimate_fee(&params(0), &FeeOptions::default())
+                .unwrap(),
+            MicroAlgos(1000)
+        );
+
+        // The fee is included in the size of the transaction it pays for
+        let fee = transaction
+            .estimate_fee(&params(10), &FeeOptions::default())
+            .unwrap();
+        let mut with_fee = transaction.clone();
+        with_fee.header_mut().fee = fee;
+        assert_eq!(
+            fee,
+            MicroAlgos(
+                10 * with_fee
+                    .estimate_signed_size(&SignatureKind::Single)
+                    .unwrap() as u64
+            )
+        );
+
+        let logic_sig = FeeOptions {
+            signature: SignatureKind::LogicSig {
+                program_size: 500,
+                arg_sizes: vec![],
+            },
+            ..Default::default()
+        };
+        assert!(transaction.estimate_fee(&params(10), &logic_sig).unwrap().0 > fee.0 + 4000);
+
+        assert_eq!(
+            transaction
+                .estimate_fee(
+                    &params(10),
+                    &FeeOptions {
+                        flat_fee: Some(MicroAlgos(2000)),
+                        ..Default::default()
+                    }
+                )
+                .unwrap(),
+            MicroAlgos(2000)
+        );
+
+        assert_eq!(
+            transaction
+                .estimate_fee(&params(u64::MAX), &FeeOptions::default())
+                .unwrap_err()
+                .to_string(),
+            "fee overflows a u64"
+        );
+    }
+
+    #[test]
+    fn test_fee_pooling() {
+        let mut transaction = payment();
+        let options = FeeOptions {
+            covered_transactions: 2,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            transaction.assign_fee(&params(0), &options).unwrap(),
+            MicroAlgos(3000)
+        );
+        assert_eq!(transaction.header().fee, MicroAlgos(3000));
+
+        let uncovered = transaction
+            .estimate_fee(&params(10), &FeeOptions::default())
+            .unwrap();
+        assert_eq!(
+            transaction.estimate_fee(&params(10), &options).unwrap(),
+            uncovered.checked_add(MicroAlgos(2000)).unwrap()
+        );
+    }
+}
@@ -0,0 +1,476 @@
+//! Hierarchical deterministic keys as specified in ARC-52, which uses BIP32-Ed25519
+//! (Khovratovich and Law) derivation over BIP-44 paths such as `m/44'/283'/account'/change/index`.
+//!
+//! Extended private keys are 96 bytes: the 32-byte scalar `kL`, the 32-byte nonce key `kR` and the
+//! 32-byte chain code. Extended public keys are the 32-byte public key followed by the chain code,
+//! which is enough to derive non-hardened children for watch-only wallets.
+
+use crate::Address;
+use curve25519_dalek::{edwards::CompressedEdwardsY, EdwardsPoint, Scalar};
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256, Sha512};
+use thiserror::Error;
+
+type HmacSha512 = Hmac<Sha512>;
+
+/// Indexes at or above this offset are hardened and can only be derived from a private key
+pub const HARDENED_OFFSET: u32 = 0x8000_0000;
+
+const BIP44_PURPOSE: u32 = 44;
+const ALGORAND_COIN_TYPE: u32 = 283;
+const IDENTITY_COIN_TYPE: u32 = 0;
+
+pub const EXTENDED_PRIVATE_KEY_LENGTH: usize = 96;
+pub const EXTENDED_PUBLIC_KEY_LENGTH: usize = 64;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum HdError {
+    #[error("hardened index {0} cannot be derived from a public key")]
+    HardenedPublicDerivation(u32),
+
+    #[error("public key is not a valid ed25519 point")]
+    InvalidPublicKey,
+
+    #[error("derived key overflowed 256 bits")]
+    KeyOverflow,
+
+    #[error("{0}")]
+    InvalidLength(String),
+}
+
+/// How many of the most significant bits of `zL` are discarded during child derivation.
+/// ARC-52 recommends `Peikert`, which discards fewer bits; `Khovratovich` matches the original
+/// BIP32-Ed25519 paper
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DerivationType {
+    Khovratovich,
+    Peikert,
+}
+
+impl DerivationType {
+    fn truncated_bits(&self) -> usize {
+        match self {
+            DerivationType::Khovratovich => 32,
+            DerivationType::Peikert => 9,
+        }
+    }
+}
+
+/// The ARC-52 key context, which determines the BIP-44 coin type of the path
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum KeyContext {
+    Address,
+    Identity,
+}
+
+/// The BIP-44 path `m/44'/coin_type'/account'/change/key_index` for the given context
+pub fn bip44_path(context: KeyContext, account: u32, change: u32, key_index: u32) -> [u32; 5] {
+    let coin_type = match context {
+        KeyContext::Address => ALGORAND_COIN_TYPE,
+        KeyContext::Identity => IDENTITY_COIN_TYPE,
+    };
+
+    [
+        BIP44_PURPOSE + HARDENED_OFFSET,
+        coin_type + HARDENED_OFFSET,
+        account + HARDENED_OFFSET,
+        change,
+        key_index,
+    ]
+}
+
+/// Zero the `g` most significant bits of a little-endian 256-bit integer
+fn truncate(bytes: &[u8], g: usize) -> [u8; 32] {
+    let mut truncated: [u8; 32] = bytes[..32].try_into().expect("slice should be 32 bytes");
+    let mut remaining = g;
+
+    for byte in truncated.iter_mut().rev() {
+        if remaining >= 8 {
+            *byte = 0;
+            remaining -= 8;
+        } else {
+            *byte &= 0xff >> remaining;
+            break;
+        }
+    }
+
+    truncated
+}
+
+/// Add two little-endian 256-bit integers, returning the sum and whether it overflowed
+fn add_256(a: &[u8; 32], b: &[u8; 32]) -> ([u8; 32], bool) {
+    let mut sum = [0u8; 32];
+    let mut carry = 0u16;
+
+    for i in 0..32 {
+        let total = a[i] as u16 + b[i] as u16 + carry;
+        sum[i] = total as u8;
+        carry = total >> 8;
+    }
+
+    (sum, carry != 0)
+}
+
+/// Multiply a little-endian 256-bit integer by 8, returning the product and whether it overflowed
+fn mul_8(a: &[u8; 32]) -> ([u8; 32], bool) {
+    let mut product = [0u8; 32];
+    let mut carry = 0u8;
+
+    for i in 0..32 {
+        product[i] = (a[i] << 3) | carry;
+        carry = a[i] >> 5;
+    }
+
+    (product, carry != 0)
+}
+
+fn hmac_sha512(key: &[u8], parts: &[&[u8]]) -> [u8; 64] {
+    let mut mac = HmacSha512::new_from_slice(key).expect("HMAC accepts keys of any length");
+    for part in parts {
+        mac.update(part);
+    }
+    mac.finalize().into_bytes().into()
+}
+
+/// SHA-512 of the concatenated parts, reduced modulo the group order
+fn hash_to_scalar(parts: &[&[u8]]) -> Scalar {
+    let mut hasher = Sha512::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    Scalar::from_bytes_mod_order_wide(&hasher.finalize().into())
+}
+
+/// `8 * truncate(zL)` as a scalar, which is the tweak applied to the parent key
+fn z_left_tweak(z: &[u8; 64], derivation: DerivationType) -> Result<[u8; 32], HdError> {
+    let (tweak, overflow) = mul_8(&truncate(&z[..32], derivation.truncated_bits()));
+    if overflow {
+        return Err(HdError::KeyOverflow);
+    }
+    Ok(tweak)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtendedPrivateKey {
+    pub kl: [u8; 32],
+    pub kr: [u8; 32],
+    pub chain_code: [u8; 32],
+}
+
+impl ExtendedPrivateKey {
+    /// Create the root key from a BIP-39 seed
+    pub fn from_seed(seed: &[u8]) -> Self {
+        let mut k: [u8; 64] = Sha512::digest(seed).into();
+
+        // The third highest bit of kL must be clear, so keep hashing until it is
+        while k[31] & 0b0010_0000 != 0 {
+            k = hmac_sha512(&k[..32], &[&k[32..]]);
+        }
+
+        let mut kl: [u8; 32] = k[..32].try_into().expect("slice should be 32 bytes");
+        kl[0] &= 0b1111_1000;
+        kl[31] &= 0b0111_1111;
+        kl[31] |= 0b0100_0000;
+
+        let chain_code: [u8; 32] = Sha256::new()
+            .chain_update([0x01])
+            .chain_update(seed)
+            .finalize()
+            .into();
+
+        Self {
+            kl,
+            kr: k[32..].try_into().expect("slice should be 32 bytes"),
+            chain_code,
+        }
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, HdError> {
+        if bytes.len() != EXTENDED_PRIVATE_KEY_LENGTH {
+            return Err(HdError::InvalidLength(format!(
+                "extended private key must be {} bytes, but got {}",
+                EXTENDED_PRIVATE_KEY_LENGTH,
+                bytes.len()
+            )));
+        }
+
+        Ok(Self {
+            kl: bytes[..32].try_into().expect("slice should be 32 bytes"),
+            kr: bytes[32..64].try_into().expect("slice should be 32 bytes"),
+            chain_code: bytes[64..].try_into().expect("slice should be 32 bytes"),
+        })
+    }
+
+    pub fn to_bytes(&self) -> [u8; EXTENDED_PRIVATE_KEY_LENGTH] {
+        let mut bytes = [0u8; EXTENDED_PRIVATE_KEY_LENGTH];
+        bytes[..32].copy_from_slice(&self.kl);
+        bytes[32..64].copy_from_slice(&self.kr);
+        bytes[64..].copy_from_slice(&self.chain_code);
+        bytes
+    }
+
+    fn scalar(&self) -> Scalar {
+        Scalar::from_bytes_mod_order(self.kl)
+    }
+
+    /// The ed25519 public key, computed without clamping `kL`
+    pub fn public_key(&self) -> [u8; 32] {
+        EdwardsPoint::mul_base(&self.scalar()).compress().to_bytes()
+    }
+
+    pub fn extended_public_key(&self) -> ExtendedPublicKey {
+        ExtendedPublicKey {
+            public_key: self.public_key(),
+            chain_code: self.chain_code,
+        }
+    }
+
+    pub fn address(&self) -> Address {
+        Address::from_pubkey(&self.public_key())
+    }
+
+    pub fn derive_child(&self, index: u32, derivation: DerivationType) -> Result<Self, HdError> {
+        let index_bytes = index.to_le_bytes();
+
+        let (z, child_chain) = if index >= HARDENED_OFFSET {
+            (
+                hmac_sha512(
+                    &self.chain_code,
+                    &[&[0x00], &self.kl, &self.kr, &index_bytes],
+                ),
+                hmac_sha512(
+                    &self.chain_code,
+                    &[&[0x01], &self.kl, &self.kr, &index_bytes],
+                ),
+            )
+        } else {
+            let public_key = self.public_key();
+            (
+                hmac_sha512(&self.chain_code, &[&[0x02], &public_key, &index_bytes]),
+                hmac_sha512(&self.chain_code, &[&[0x03], &public_key, &index_bytes]),
+            )
+        };
+
+        let (kl, overflow) = add_256(&z_left_tweak(&z, derivation)?, &self.kl);
+        if overflow {
+            return Err(HdError::KeyOverflow);
+        }
+
+        // kR wraps around modulo 2^256
+        let (kr, _) = add_256(
+            z[32..].try_into().expect("slice should be 32 bytes"),
+            &self.kr,
+        );
+
+        Ok(Self {
+            kl,
+            kr,
+            chain_code: child_chain[32..]
+                .try_into()
+                .expect("slice should be 32 bytes"),
+        })
+    }
+
+    pub fn derive_path(&self, path: &[u32], derivation: DerivationType) -> Result<Self, HdError> {
+        path.iter().try_fold(self.clone(), |key, index| {
+            key.derive_child(*index, derivation)
+        })
+    }
+
+    /// Sign the message with the extended key, producing a standard ed25519 signature that
+    /// verifies against `public_key`
+    pub fn sign(&self, message: &[u8]) -> [u8; 64] {
+        let public_key = self.public_key();
+
+        let r = hash_to_scalar(&[&self.kr, message]);
+        let big_r = EdwardsPoint::mul_base(&r).compress().to_bytes();
+
+        let h = hash_to_scalar(&[&big_r, &public_key, message]);
+        let s = r + h * self.scalar();
+
+        let mut signature = [0u8; 64];
+        signature[..32].copy_from_slice(&big_r);
+        signature[32..].copy_from_slice(s.as_bytes());
+        signature
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtendedPublicKey {
+    pub public_key: [u8; 32],
+    pub chain_code: [u8; 32],
+}
+
+impl ExtendedPublicKey {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, HdError> {
+        if bytes.len() != EXTENDED_PUBLIC_KEY_LENGTH {
+            return Err(HdError::InvalidLength(format!(
+                "extended public key must be {} bytes, but got {}",
+                EXTENDED_PUBLIC_KEY_LENGTH,
+                bytes.len()
+            )));
+        }
+
+        Ok(Self {
+            public_key: bytes[..32].try_into().expect("slice should be 32 bytes"),
+            chain_code: bytes[32..].try_into().expect("slice should be 32 bytes"),
+        })
+    }
+
+    pub fn to_bytes(&self) -> [u8; EXTENDED_PUBLIC_KEY_LENGTH] {
+        let mut bytes = [0u8; EXTENDED_PUBLIC_KEY_LENGTH];
+        bytes[..32].copy_from_slice(&self.public_key);
+        bytes[32..].copy_from_slice(&self.chain_code);
+        bytes
+    }
+
+    pub fn address(&self) -> Address {
+        Address::from_pubkey(&self.public_key)
+    }
+
+    /// Derive a non-hardened child public key without access to the private key
+    pub fn derive_child(&self, index: u32, derivation: DerivationType) -> Result<Self, HdError> {
+        if index >= HARDENED_OFFSET {
+            return Err(HdError::HardenedPublicDerivation(index));
+        }
+
+        let index_bytes = index.to_le_bytes();
+        let z = hmac_sha512(&self.chain_code, &[&[0x02], &self.public_key, &index_bytes]);
+        let child_chain = hmac_sha512(&self.chain_code, &[&[0x03], &self.public_key, &index_bytes]);
+
+        let parent = CompressedEdwardsY(self.public_key)
+            .decompress()
+            .ok_or(HdError::InvalidPublicKey)?;
+        let tweak = Scalar::from_bytes_mod_order(z_left_tweak(&z, derivation)?);
+
+        Ok(Self {
+            public_key: (parent + EdwardsPoint::mul_base(&tweak))
+                .compress()
+                .to_bytes(),
+            chain_code: child_chain[32..]
+                .try_into()
+                .expect("slice should be 32 bytes"),
+        })
+    }
+
+    pub fn derive_path(&self, path: &[u32], derivation: DerivationType) -> Result<Self, HdError> {
+        path.iter().try_fold(self.clone(), |key, index| {
+            key.derive_child(*index, derivation)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+    use pretty_assertions::assert_eq;
+
+    // BIP-39 seed of the ARC-52 test mnemonic "salon zoo engage submit smile frost later decide
+    // wing sight chaos renew lizard rely canal coral scene hobby scare step bus leaf tobacco slice"
+    const SEED: &str = "3aff2db416b895ec3cf9a4f8d1e970bc9819920e7bf44a5e350477af0ef557b1511b0986debf78dd38c7c520cd44ff7c7231618f958e21ef0250733a8c1915ea";
+
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn root() -> ExtendedPrivateKey {
+        ExtendedPrivateKey::from_seed(&hex(SEED))
+    }
+
+    fn key_gen(context: KeyContext, account: u32, key_index: u32) -> [u8; 32] {
+        root()
+            .derive_path(
+                &bip44_path(context, account, 0, key_index),
+                DerivationType::Peikert,
+            )
+            .unwrap()
+            .public_key()
+    }
+
+    #[test]
+    fn test_root_key() {
+        assert_eq!(
+            root().to_bytes().to_vec(),
+            hex("a8ba80028922d9fcfa055c78aede55b5c575bcd8d5a53168edf45f36d9ec8f4694592b4bc892907583e22669ecdf1b0409a9f3bd5549f2dd751b51360909cd05796b9206ec30e142e94b790a98805bf999042b55046963174ee6cee2d0375946")
+        );
+    }
+
+    #[test]
+    fn test_arc52_address_vectors() {
+        assert_eq!(
+            key_gen(KeyContext::Address, 0, 0).to_vec(),
+            hex("7bda7ac12627b2c259f1df6875d30c10b35f55b33ad2cc8ea2736eaa3ebcfab9")
+        );
+        assert_eq!(
+            key_gen(KeyContext::Address, 0, 1).to_vec(),
+            hex("5bae8828f111064637ac5061bd63bc4fcfe4a833252305f25eeab9c64ecdf519")
+        );
+        assert_eq!(
+            key_gen(KeyContext::Address, 0, 2).to_vec(),
+            hex("00a72635e97cba966529e9bfb4baf4a32d7b8cd2fcd8e2476ce5be1177848cb3")
+        );
+        assert_eq!(
+            key_gen(KeyContext::Address, 1, 0).to_vec(),
+            hex("358d8c4382992849a764438e02b1c45c2ca4e86bbcfe10fd5b963f3610012bc9")
+        );
+    }
+
+    #[test]
+    fn test_arc52_identity_vectors() {
+        assert_eq!(
+            key_gen(KeyContext::Identity, 0, 0).to_vec(),
+            hex("ff8b1863ef5e40d0a48c245f26a6dbdf5da94dc75a1851f51d8a04e547bd5f5a")
+        );
+    }
+
+    #[test]
+    fn test_public_derivation_matches_private() {
+        for derivation in [DerivationType::Peikert, DerivationType::Khovratovich] {
+            let account = root()
+                .derive_path(&bip44_path(KeyContext::Address, 0, 0, 0)[..3], derivation)
+                .unwrap();
+
+            let private_child = account.derive_path(&[0, 7], derivation).unwrap();
+            let public_child = account
+                .extended_public_key()
+                .derive_path(&[0, 7], derivation)
+                .unwrap();
+
+            assert_eq!(private_child.extended_public_key(), public_child);
+        }
+    }
+
+    #[test]
+    fn test_hardened_public_derivation() {
+        let xpub = root().extended_public_key();
+        assert_eq!(
+            xpub.derive_child(HARDENED_OFFSET, DerivationType::Peikert),
+            Err(HdError::HardenedPublicDerivation(HARDENED_OFFSET))
+        );
+    }
+
+    #[test]
+    fn test_sign() {
+        let key = root()
+            .derive_path(
+                &bip44_path(KeyContext::Address, 0, 0, 0),
+                DerivationType::Peikert,
+            )
+            .unwrap();
+
+        let message = b"arc52 signing test";
+        let signature = key.sign(message);
+
+        let verifying_key = VerifyingKey::from_bytes(&key.public_key()).unwrap();
+        assert!(verifying_key
+            .verify(message, &Signature::from_bytes(&signature))
+            .is_ok());
+        assert!(verifying_key
+            .verify(b"another message", &Signature::from_bytes(&signature))
+            .is_err());
+    }
+}
@@ -1,18 +1,45 @@
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
 #[cfg(test)]
 use pretty_assertions::assert_eq;
 use serde::{Deserialize, Serialize};
 // Primary using serde_with for Bytes, which is better than serde_bytes
 // see https://docs.rs/serde_with/latest/serde_with/struct.Bytes.html
 // It also has some other nice QOL features, like skip_serializing_none
-use serde_with::{serde_as, skip_serializing_none, Bytes};
+use serde_with::{
+    ser::SerializeAsWrap, serde_as, skip_serializing_none, Bytes, DeserializeAs, SerializeAs,
+};
 use sha2::{Digest, Sha512_256};
-use std::{any::Any, collections::BTreeMap};
+use std::cell::Cell;
 use thiserror::Error;
 
+pub mod abi;
+pub mod account;
+pub mod amount;
+pub mod apply_data;
+pub mod arc56;
+pub mod block;
+pub mod builder;
+pub mod consensus;
+pub mod fee;
+#[cfg(feature = "hd")]
+pub mod hd;
+pub mod merkle;
+pub mod min_balance;
+pub mod mnemonic;
+pub mod pending;
+pub mod program;
+pub mod simulate;
+pub mod stream;
+pub mod transaction_file;
+pub mod validation;
+
+pub use amount::MicroAlgos;
+
 const HASH_BYTES_LENGTH: usize = 32;
 const ALGORAND_CHECKSUM_BYTE_LENGTH: usize = 4;
 const ALGORAND_ADDRESS_LENGTH: usize = 58;
 const ALGORAND_PUBLIC_KEY_BYTE_LENGTH: usize = 32;
+const APP_ID_PREFIX: &[u8] = b"appID";
 
 #[derive(Debug, Error)]
 pub enum AlgoModelsError {
@@ -36,6 +63,49 @@ pub enum AlgoModelsError {
 
     #[error("{0}")]
     InvalidAddress(String),
+
+    #[error("{0}")]
+    InvalidMnemonic(#[from] mnemonic::MnemonicError),
+
+    #[error("{0}")]
+    AbiError(#[from] abi::AbiError),
+
+    #[error("{0}")]
+    AppSpecError(#[from] arc56::AppSpecError),
+
+    #[error("{0}")]
+    InvalidProgram(#[from] program::ProgramError),
+
+    #[error("{0}")]
+    BuilderError(#[from] builder::BuilderError),
+
+    #[error("{0}")]
+    InvalidAmount(#[from] amount::AmountError),
+
+    #[error("{0}")]
+    InvalidTransaction(#[from] validation::ValidationErrors),
+
+    #[error("{0}")]
+    InvalidAccount(#[from] account::AccountError),
+
+    #[error("{0}")]
+    InvalidBlock(#[from] block::BlockError),
+
+    #[error("{0}")]
+    InvalidCommitment(#[from] merkle::MerkleError),
+
+    #[error("{0}")]
+    InvalidSimulation(#[from] simulate::SimulateError),
+
+    #[error("{0}")]
+    InvalidPendingTransaction(#[from] pending::PendingTransactionError),
+
+    #[error("{0}")]
+    InvalidTransactionFile(#[from] transaction_file::TransactionFileError),
+
+    #[cfg(feature = "hd")]
+    #[error("{0}")]
+    InvalidHdKey(#[from] hd::HdError),
 }
 
 pub trait AlgorandMsgpack: Serialize + for<'de> Deserialize<'de> {
@@ -58,7 +128,7 @@ pub trait AlgorandMsgpack: Serialize + for<'de> Deserialize<'de> {
 
         // Serialize the sorted value
         let mut final_buf = Vec::new();
-        rmpv::encode::write_value(&mut final_buf, &sorted_value)?;
+        write_msgpack_value(&mut final_buf, &sorted_value)?;
 
         Ok(final_buf)
     }
@@ -73,15 +143,15 @@ pub trait AlgorandMsgpack: Serialize + for<'de> Deserialize<'de> {
 
         // If there is a PREFIX defined, bytes is longer than the prefix, and the bytes start
         // with the prefix, decode the bytes without the prefix
-        if Self::PREFIX.len() > 0
+        let bytes = if !Self::PREFIX.is_empty()
             && bytes.len() > Self::PREFIX.len()
             && &bytes[..Self::PREFIX.len()] == Self::PREFIX
         {
-            let without_prefix = &bytes[Self::PREFIX.len()..];
-            Ok(rmp_serde::from_slice(&without_prefix)?)
+            &bytes[Self::PREFIX.len()..]
         } else {
-            Ok(rmp_serde::from_slice(bytes)?)
-        }
+            bytes
+        };
+        decode_as(false, || Ok(rmp_serde::from_slice(bytes)?))
     }
 
     /// msgpack encoding of the transaction with keys sorted and empty fields omitted
@@ -100,26 +170,203 @@ pub trait AlgorandMsgpack: Serialize + for<'de> Deserialize<'de> {
     }
 }
 
-fn sort_msgpack_value(value: rmpv::Value) -> rmpv::Value {
-    match value {
-        rmpv::Value::Map(m) => {
-            let mut sorted_map: BTreeMap<String, rmpv::Value> = BTreeMap::new();
+thread_local! {
+    /// Whether the value being decoded is in a human-readable format like JSON. Serde reports
+    /// every format as human readable to the fields of flattened structs, which it buffers, so
+    /// decoding records the format here
+    static HUMAN_READABLE: Cell<Option<bool>> = const { Cell::new(None) };
+}
+
+/// Restores the recorded format when a decode finishes, even if it panics
+struct FormatGuard(Option<bool>);
+
+impl Drop for FormatGuard {
+    fn drop(&mut self) {
+        HUMAN_READABLE.set(self.0);
+    }
+}
+
+/// Run `decode` with the format recorded as human readable or not, unless the decode it is
+/// nested in already recorded it
+fn decode_as<T>(human_readable: bool, decode: impl FnOnce() -> T) -> T {
+    let outer = HUMAN_READABLE.get();
+    let _guard = FormatGuard(outer);
+    HUMAN_READABLE.set(Some(outer.unwrap_or(human_readable)));
+    decode()
+}
+
+/// Whether the format being decoded is human readable, as recorded or as the deserializer
+/// reports it
+fn is_human_readable<'de, D: serde::Deserializer<'de>>(deserializer: &D) -> bool {
+    HUMAN_READABLE
+        .get()
+        .unwrap_or_else(|| deserializer.is_human_readable())
+}
+
+/// The ext type that `StringBytes` uses for bytes that are not valid UTF-8
+const STRING_BYTES_EXT_TYPE: i8 = 0x53;
+
+/// Bytes that go-algorand encodes as a msgpack string, such as logs and application state keys,
+/// which are often not valid UTF-8. Serde can only serialize valid UTF-8 strings, so other bytes
+/// are serialized as an ext value that `write_msgpack_value` writes back as a string
+pub(crate) struct StringBytes;
+
+impl SerializeAs<Vec<u8>> for StringBytes {
+    fn serialize_as<S: serde::Serializer>(
+        source: &Vec<u8>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match std::str::from_utf8(source) {
+            Ok(string) => serializer.serialize_str(string),
+            Err(_) => serializer.serialize_newtype_struct(
+                rmp_serde::MSGPACK_EXT_STRUCT_NAME,
+                &(
+                    STRING_BYTES_EXT_TYPE,
+                    SerializeAsWrap::<_, Bytes>::new(source),
+                ),
+            ),
+        }
+    }
+}
+
+impl<'de> DeserializeAs<'de, Vec<u8>> for StringBytes {
+    fn deserialize_as<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        // Strings that are not valid UTF-8 are decoded as bytes
+        <Bytes as DeserializeAs<'de, Vec<u8>>>::deserialize_as(deserializer)
+    }
+}
+
+/// Bytes that are base64 encoded in JSON, and msgpack binary otherwise. algod's JSON APIs, such
+/// as simulate, embed transactions in this form, so transactions use it for their bytes fields
+pub(crate) struct Base64Bytes;
+
+impl<T: AsRef<[u8]>> SerializeAs<T> for Base64Bytes {
+    fn serialize_as<S: serde::Serializer>(source: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&BASE64.encode(source))
+        } else {
+            serializer.serialize_bytes(source.as_ref())
+        }
+    }
+}
+
+impl<'de> DeserializeAs<'de, Vec<u8>> for Base64Bytes {
+    fn deserialize_as<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        struct Visitor {
+            human_readable: bool,
+        }
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = Vec<u8>;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                if self.human_readable {
+                    f.write_str("base64 encoded bytes")
+                } else {
+                    f.write_str("binary bytes")
+                }
+            }
 
-            // Convert and sort all key-value pairs
-            for (k, v) in m {
-                if let rmpv::Value::String(key) = k {
-                    let key_str = key.into_str().unwrap_or_default();
-                    sorted_map.insert(key_str, sort_msgpack_value(v));
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Vec<u8>, E> {
+                if !self.human_readable {
+                    return Err(E::invalid_type(serde::de::Unexpected::Str(v), &self));
                 }
+                BASE64.decode(v).map_err(E::custom)
             }
 
-            // Convert back to rmpv::Value::Map
-            rmpv::Value::Map(
-                sorted_map
-                    .into_iter()
-                    .map(|(k, v)| (rmpv::Value::String(k.into()), v))
-                    .collect(),
-            )
+            fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Vec<u8>, E> {
+                Ok(v.to_vec())
+            }
+        }
+
+        // JSON deserializers read strings as bytes when asked for bytes
+        if is_human_readable(&deserializer) {
+            deserializer.deserialize_any(Visitor {
+                human_readable: true,
+            })
+        } else {
+            deserializer.deserialize_bytes(Visitor {
+                human_readable: false,
+            })
+        }
+    }
+}
+
+impl<'de, const N: usize> DeserializeAs<'de, [u8; N]> for Base64Bytes {
+    fn deserialize_as<D: serde::Deserializer<'de>>(deserializer: D) -> Result<[u8; N], D::Error> {
+        let bytes: Vec<u8> = Base64Bytes::deserialize_as(deserializer)?;
+        bytes.try_into().map_err(|bytes: Vec<u8>| {
+            serde::de::Error::invalid_length(bytes.len(), &format!("{} bytes", N).as_str())
+        })
+    }
+}
+
+/// Bytes that are base64 encoded strings in JSON and msgpack alike, such as the keys and values
+/// of application state in algod responses
+pub(crate) struct Base64String;
+
+impl SerializeAs<Vec<u8>> for Base64String {
+    fn serialize_as<S: serde::Serializer>(
+        source: &Vec<u8>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&BASE64.encode(source))
+    }
+}
+
+impl<'de> DeserializeAs<'de, Vec<u8>> for Base64String {
+    fn deserialize_as<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        BASE64.decode(string).map_err(serde::de::Error::custom)
+    }
+}
+
+/// An address that is encoded as its base32 string, as algod responses encode addresses
+pub(crate) struct AddressString;
+
+impl SerializeAs<Address> for AddressString {
+    fn serialize_as<S: serde::Serializer>(
+        source: &Address,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&source.address())
+    }
+}
+
+impl<'de> DeserializeAs<'de, Address> for AddressString {
+    fn deserialize_as<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Address, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        Address::from_string(&string).map_err(serde::de::Error::custom)
+    }
+}
+
+/// The bytes of a string map key, including those serialized by `StringBytes`
+fn string_bytes(value: &rmpv::Value) -> Option<&[u8]> {
+    match value {
+        rmpv::Value::String(string) => Some(string.as_bytes()),
+        rmpv::Value::Ext(STRING_BYTES_EXT_TYPE, bytes) => Some(bytes),
+        _ => None,
+    }
+}
+
+fn sort_msgpack_value(value: rmpv::Value) -> rmpv::Value {
+    match value {
+        rmpv::Value::Map(m) => {
+            let mut entries: Vec<(rmpv::Value, rmpv::Value)> = m
+                .into_iter()
+                .map(|(k, v)| (k, sort_msgpack_value(v)))
+                .collect();
+
+            // Like go-algorand, sort integer keys by value and string keys by their bytes
+            entries.sort_by(|(a, _), (b, _)| match (a, b) {
+                (rmpv::Value::Integer(a), rmpv::Value::Integer(b)) => a
+                    .as_u64()
+                    .cmp(&b.as_u64())
+                    .then(a.as_i64().cmp(&b.as_i64())),
+                _ => string_bytes(a).cmp(&string_bytes(b)),
+            });
+
+            rmpv::Value::Map(entries)
         }
         rmpv::Value::Array(arr) => {
             rmpv::Value::Array(arr.into_iter().map(sort_msgpack_value).collect())
@@ -129,6 +376,56 @@ fn sort_msgpack_value(value: rmpv::Value) -> rmpv::Value {
     }
 }
 
+/// Write the header of a msgpack string, array or map of `len` items. Lengths below
+/// `fix_limit` fit in the fixed form, and `marker16` is followed by the 32-bit form
+fn write_msgpack_header(
+    buf: &mut Vec<u8>,
+    len: usize,
+    fix_marker: u8,
+    fix_limit: usize,
+    marker16: u8,
+) {
+    if len < fix_limit {
+        buf.push(fix_marker | len as u8);
+    } else if len <= 0xffff {
+        buf.push(marker16);
+        buf.extend_from_slice(&(len as u16).to_be_bytes());
+    } else {
+        buf.push(marker16 + 1);
+        buf.extend_from_slice(&(len as u32).to_be_bytes());
+    }
+}
+
+/// Write `value` like `rmpv::encode::write_value`, except that `StringBytes` are written as
+/// strings, which rmpv writes as binary when they are not valid UTF-8
+fn write_msgpack_value(buf: &mut Vec<u8>, value: &rmpv::Value) -> Result<(), AlgoModelsError> {
+    match value {
+        rmpv::Value::Map(entries) => {
+            write_msgpack_header(buf, entries.len(), 0x80, 16, 0xde);
+            for (k, v) in entries {
+                write_msgpack_value(buf, k)?;
+                write_msgpack_value(buf, v)?;
+            }
+        }
+        rmpv::Value::Array(items) => {
+            write_msgpack_header(buf, items.len(), 0x90, 16, 0xdc);
+            for item in items {
+                write_msgpack_value(buf, item)?;
+            }
+        }
+        rmpv::Value::Ext(STRING_BYTES_EXT_TYPE, bytes) => {
+            if (32..=0xff).contains(&bytes.len()) {
+                buf.extend_from_slice(&[0xd9, bytes.len() as u8]);
+            } else {
+                write_msgpack_header(buf, bytes.len(), 0xa0, 32, 0xda);
+            }
+            buf.extend_from_slice(bytes);
+        }
+        v => rmpv::encode::write_value(buf, v)?,
+    }
+    Ok(())
+}
+
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 pub enum TransactionType {
     #[serde(rename = "pay")]
@@ -154,7 +451,7 @@ type Byte32 = [u8; 32];
 
 fn pub_key_to_checksum(pub_key: &Byte32) -> [u8; ALGORAND_CHECKSUM_BYTE_LENGTH] {
     let mut hasher = Sha512_256::new();
-    hasher.update(&pub_key);
+    hasher.update(pub_key);
 
     let mut checksum = [0u8; ALGORAND_CHECKSUM_BYTE_LENGTH];
     checksum
@@ -162,19 +459,92 @@ fn pub_key_to_checksum(pub_key: &Byte32) -> [u8; ALGORAND_CHECKSUM_BYTE_LENGTH]
     checksum
 }
 
-#[serde_as]
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
-#[serde(transparent)]
+#[derive(Debug, PartialEq, Clone, Default)]
 pub struct Address {
-    #[serde_as(as = "Bytes")]
     pub pub_key: Byte32,
 }
 
+/// Like go-algorand, addresses are encoded as their public key in msgpack and as their base32
+/// string in JSON, so that transactions in algod's JSON APIs, such as simulate, decode and encode
+/// as algod expects
+impl Serialize for Address {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.address())
+        } else {
+            serializer.serialize_bytes(&self.pub_key)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Address {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor {
+            human_readable: bool,
+        }
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = Address;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                if self.human_readable {
+                    f.write_str("a base32 address")
+                } else {
+                    f.write_str("a 32-byte public key")
+                }
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Address, E> {
+                if !self.human_readable {
+                    return Err(E::invalid_type(serde::de::Unexpected::Str(v), &self));
+                }
+                Address::from_string(v).map_err(E::custom)
+            }
+
+            fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Address, E> {
+                let pub_key: Byte32 = v
+                    .try_into()
+                    .map_err(|_| E::invalid_length(v.len(), &"32 bytes"))?;
+                Ok(Address::from_pubkey(&pub_key))
+            }
+        }
+
+        if is_human_readable(&deserializer) {
+            deserializer.deserialize_any(Visitor {
+                human_readable: true,
+            })
+        } else {
+            deserializer.deserialize_bytes(Visitor {
+                human_readable: false,
+            })
+        }
+    }
+}
+
 impl Address {
     pub fn from_pubkey(pub_key: &Byte32) -> Self {
         Address { pub_key: *pub_key }
     }
 
+    /// Derive an address from the SHA-512/256 hash of `prefix` followed by `bytes`.
+    /// Algorand uses these domain-separated hashes for accounts that have no private key,
+    /// such as application accounts
+    pub fn from_domain_hash(prefix: &[u8], bytes: &[u8]) -> Self {
+        let mut hasher = Sha512_256::new();
+        hasher.update(prefix);
+        hasher.update(bytes);
+
+        Address {
+            pub_key: hasher.finalize().into(),
+        }
+    }
+
+    /// The account address of an application, which is the hash of "appID" followed by the
+    /// big-endian application ID
+    pub fn from_app_id(app_id: u64) -> Self {
+        Self::from_domain_hash(APP_ID_PREFIX, &app_id.to_be_bytes())
+    }
+
     pub fn from_string(address: &str) -> Result<Self, AlgoModelsError> {
         if address.len() != ALGORAND_ADDRESS_LENGTH {
             return Err(AlgoModelsError::InvalidAddress(
@@ -182,7 +552,15 @@ impl Address {
             ));
         }
         let decoded = base32::decode(base32::Alphabet::Rfc4648 { padding: false }, address)
-            .expect("decoded value should exist");
+            .ok_or_else(|| {
+                AlgoModelsError::InvalidAddress("address is not valid base32".to_string())
+            })?;
+
+        if decoded.len() != ALGORAND_PUBLIC_KEY_BYTE_LENGTH + ALGORAND_CHECKSUM_BYTE_LENGTH {
+            return Err(AlgoModelsError::InvalidAddress(
+                "decoded address is not 36 bytes".to_string(),
+            ));
+        }
 
         let pub_key: [u8; 32] = decoded[..ALGORAND_PUBLIC_KEY_BYTE_LENGTH]
             .try_into()
@@ -231,12 +609,16 @@ fn is_zero(n: &u64) -> bool {
     *n == 0u64
 }
 
+fn is_false(b: &bool) -> bool {
+    !b
+}
+
 fn is_zero_addr(addr: &Address) -> bool {
     addr.pub_key == [0u8; 32]
 }
 
 fn is_zero_addr_opt(addr: &Option<Address>) -> bool {
-    addr.as_ref().map_or(true, is_zero_addr)
+    addr.as_ref().is_none_or(is_zero_addr)
 }
 
 fn is_empty_bytes32(bytes: &Byte32) -> bool {
@@ -244,15 +626,25 @@ fn is_empty_bytes32(bytes: &Byte32) -> bool {
 }
 
 fn is_empty_bytes32_opt(bytes: &Option<Byte32>) -> bool {
-    bytes.as_ref().map_or(true, is_empty_bytes32)
+    bytes.as_ref().is_none_or(is_empty_bytes32)
 }
 
 fn is_empty_string_opt(string: &Option<String>) -> bool {
-    string.as_ref().map_or(true, String::is_empty)
+    string.as_ref().is_none_or(String::is_empty)
 }
 
 fn is_empty_vec_opt<T>(vec: &Option<Vec<T>>) -> bool {
-    vec.as_ref().map_or(true, Vec::is_empty)
+    vec.as_ref().is_none_or(Vec::is_empty)
+}
+
+fn is_empty_schema_opt(schema: &Option<StateSchema>) -> bool {
+    schema
+        .as_ref()
+        .is_none_or(|s| s.num_uints == 0 && s.num_byte_slices == 0)
+}
+
+fn is_no_op(on_complete: &OnApplicationComplete) -> bool {
+    *on_complete == OnApplicationComplete::NoOp
 }
 
 #[serde_as]
@@ -267,9 +659,9 @@ pub struct TransactionHeader {
     #[serde(default)]
     pub sender: Address,
 
-    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(skip_serializing_if = "MicroAlgos::is_zero")]
     #[serde(default)]
-    pub fee: u64,
+    pub fee: MicroAlgos,
 
     #[serde(rename = "fv")]
     #[serde(skip_serializing_if = "is_zero")]
@@ -282,7 +674,7 @@ pub struct TransactionHeader {
     pub last_valid: u64,
 
     #[serde(rename = "gh")]
-    #[serde_as(as = "Option<Bytes>")]
+    #[serde_as(as = "Option<Base64Bytes>")]
     #[serde(skip_serializing_if = "is_empty_bytes32_opt")]
     #[serde(default)]
     pub genesis_hash: Option<Byte32>,
@@ -292,7 +684,7 @@ pub struct TransactionHeader {
     #[serde(default)]
     pub genesis_id: Option<String>,
 
-    #[serde_as(as = "Option<Bytes>")]
+    #[serde_as(as = "Option<Base64Bytes>")]
     #[serde(skip_serializing_if = "is_empty_vec_opt")]
     #[serde(default)]
     pub note: Option<Vec<u8>>,
@@ -303,13 +695,13 @@ pub struct TransactionHeader {
     pub rekey_to: Option<Address>,
 
     #[serde(rename = "lx")]
-    #[serde_as(as = "Option<Bytes>")]
+    #[serde_as(as = "Option<Base64Bytes>")]
     #[serde(skip_serializing_if = "is_empty_bytes32_opt")]
     #[serde(default)]
     pub lease: Option<Byte32>,
 
     #[serde(rename = "grp")]
-    #[serde_as(as = "Option<Bytes>")]
+    #[serde_as(as = "Option<Base64Bytes>")]
     #[serde(skip_serializing_if = "is_empty_bytes32_opt")]
     #[serde(default)]
     pub group: Option<Byte32>,
@@ -330,9 +722,9 @@ pub struct PayTransactionFields {
     pub receiver: Address,
 
     #[serde(rename = "amt")]
-    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(skip_serializing_if = "MicroAlgos::is_zero")]
     #[serde(default)]
-    pub amount: u64,
+    pub amount: MicroAlgos,
 
     #[serde(rename = "close")]
     #[serde(skip_serializing_if = "is_zero_addr_opt")]
@@ -377,11 +769,260 @@ pub struct AssetTransferTransactionFields {
 
 impl AlgorandMsgpack for AssetTransferTransactionFields {}
 
+/// The action an application call performs once the approval program (or, for `ClearState`,
+/// the clear state program) has run. Encoded as an integer
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum OnApplicationComplete {
+    #[default]
+    NoOp = 0,
+    OptIn = 1,
+    CloseOut = 2,
+    ClearState = 3,
+    UpdateApplication = 4,
+    DeleteApplication = 5,
+}
+
+impl TryFrom<u64> for OnApplicationComplete {
+    type Error = AlgoModelsError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::NoOp),
+            1 => Ok(Self::OptIn),
+            2 => Ok(Self::CloseOut),
+            3 => Ok(Self::ClearState),
+            4 => Ok(Self::UpdateApplication),
+            5 => Ok(Self::DeleteApplication),
+            _ => Err(AlgoModelsError::InputError(format!(
+                "unknown on completion action: {}",
+                value
+            ))),
+        }
+    }
+}
+
+impl Serialize for OnApplicationComplete {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(*self as u64)
+    }
+}
+
+impl<'de> Deserialize<'de> for OnApplicationComplete {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = u64::deserialize(deserializer)?;
+        Self::try_from(value).map_err(serde::de::Error::custom)
+    }
+}
+
+/// The number of global or local state values an application may store
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct StateSchema {
+    #[serde(rename = "nui")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    pub num_uints: u64,
+
+    #[serde(rename = "nbs")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    pub num_byte_slices: u64,
+}
+
+/// A box the application call may access
+#[serde_as]
+#[skip_serializing_none]
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct BoxReference {
+    /// The index of the box's application in the foreign apps array, or 0 for the called
+    /// application
+    #[serde(rename = "i")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    pub app_index: u64,
+
+    #[serde(rename = "n")]
+    #[serde_as(as = "Base64Bytes")]
+    #[serde(default)]
+    pub name: Vec<u8>,
+}
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct ApplicationCallTransactionFields {
+    #[serde(flatten)]
+    pub header: TransactionHeader,
+
+    /// The application being called, or 0 when creating an application
+    #[serde(rename = "apid")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    pub app_id: u64,
+
+    #[serde(rename = "apan")]
+    #[serde(skip_serializing_if = "is_no_op")]
+    #[serde(default)]
+    pub on_complete: OnApplicationComplete,
+
+    #[serde(rename = "apap")]
+    #[serde_as(as = "Option<Base64Bytes>")]
+    #[serde(skip_serializing_if = "is_empty_vec_opt")]
+    #[serde(default)]
+    pub approval_program: Option<Vec<u8>>,
+
+    #[serde(rename = "apsu")]
+    #[serde_as(as = "Option<Base64Bytes>")]
+    #[serde(skip_serializing_if = "is_empty_vec_opt")]
+    #[serde(default)]
+    pub clear_state_program: Option<Vec<u8>>,
+
+    #[serde(rename = "apgs")]
+    #[serde(skip_serializing_if = "is_empty_schema_opt")]
+    #[serde(default)]
+    pub global_state_schema: Option<StateSchema>,
+
+    #[serde(rename = "apls")]
+    #[serde(skip_serializing_if = "is_empty_schema_opt")]
+    #[serde(default)]
+    pub local_state_schema: Option<StateSchema>,
+
+    #[serde(rename = "apep")]
+    #[serde(skip_serializing_if = "is_zero")]
+    #[serde(default)]
+    pub extra_program_pages: u64,
+
+    #[serde(rename = "apaa")]
+    #[serde_as(as = "Option<Vec<Base64Bytes>>")]
+    #[serde(skip_serializing_if = "is_empty_vec_opt")]
+    #[serde(default)]
+    pub args: Option<Vec<Vec<u8>>>,
+
+    #[serde(rename = "apat")]
+    #[serde(skip_serializing_if = "is_empty_vec_opt")]
+    #[serde(default)]
+    pub accounts: Option<Vec<Address>>,
+
+    #[serde(rename = "apfa")]
+    #[serde(skip_serializing_if = "is_empty_vec_opt")]
+    #[serde(default)]
+    pub foreign_apps: Option<Vec<u64>>,
+
+    #[serde(rename = "apas")]
+    #[serde(skip_serializing_if = "is_empty_vec_opt")]
+    #[serde(default)]
+    pub foreign_assets: Option<Vec<u64>>,
+
+    #[serde(rename = "apbx")]
+    #[serde(skip_serializing_if = "is_empty_vec_opt")]
+    #[serde(default)]
+    pub boxes: Option<Vec<BoxReference>>,
+}
+
+impl ApplicationCallTransactionFields {
+    /// Set the approval and clear state programs, along with the extra program pages they need
+    pub fn set_programs(
+        &mut self,
+        approval: program::Program,
+        clear_state: program::Program,
+    ) -> Result<(), program::ProgramError> {
+        self.extra_program_pages = program::Program::extra_pages(&approval, &clear_state)?.into();
+        self.approval_program = Some(approval.into_bytes());
+        self.clear_state_program = Some(clear_state.into_bytes());
+        Ok(())
+    }
+}
+
+impl AlgorandMsgpack for ApplicationCallTransactionFields {}
+
+#[derive(Serialize, Debug, PartialEq, Clone)]
 #[serde(untagged)]
 pub enum Transaction {
     Payment(PayTransactionFields),
     AssetTransfer(AssetTransferTransactionFields),
+    ApplicationCall(ApplicationCallTransactionFields),
+}
+
+/// Since we provide default values for all transaction fields, an untagged enum would decode
+/// every transaction as the first type. Instead, the transaction is buffered and decoded with
+/// `Transaction::decode`, which checks the type. Buffering also lets JSON transactions be decoded,
+/// as the strings that encode their bytes and addresses are accepted when the format was JSON
+impl<'de> Deserialize<'de> for Transaction {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let human_readable = is_human_readable(&deserializer);
+        let value = rmpv::Value::deserialize(deserializer)?;
+
+        let mut buf = Vec::new();
+        rmpv::encode::write_value(&mut buf, &value).map_err(serde::de::Error::custom)?;
+
+        decode_as(human_readable, || Transaction::decode(&buf)).map_err(serde::de::Error::custom)
+    }
+}
+
+impl Transaction {
+    pub fn header(&self) -> &TransactionHeader {
+        match self {
+            Transaction::Payment(tx) => &tx.header,
+            Transaction::AssetTransfer(tx) => &tx.header,
+            Transaction::ApplicationCall(tx) => &tx.header,
+        }
+    }
+
+    pub fn header_mut(&mut self) -> &mut TransactionHeader {
+        match self {
+            Transaction::Payment(tx) => &mut tx.header,
+            Transaction::AssetTransfer(tx) => &mut tx.header,
+            Transaction::ApplicationCall(tx) => &mut tx.header,
+        }
+    }
+
+    /// The SHA-512/256 hash of the encoded transaction, including its "TX" prefix
+    pub fn raw_id(&self) -> Result<Byte32, AlgoModelsError> {
+        Ok(Sha512_256::digest(self.encode()?).into())
+    }
+
+    /// The transaction ID, which is the base32 encoding of the raw ID without padding
+    pub fn id(&self) -> Result<String, AlgoModelsError> {
+        Ok(base32::encode(
+            base32::Alphabet::Rfc4648 { padding: false },
+            &self.raw_id()?,
+        ))
+    }
+}
+
+/// The IDs of the transactions in a group, which are hashed with the "TG" prefix for the group
+/// ID
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+struct TransactionGroup {
+    #[serde(rename = "txlist")]
+    #[serde_as(as = "Vec<Bytes>")]
+    transaction_ids: Vec<Byte32>,
+}
+
+impl AlgorandMsgpack for TransactionGroup {
+    const PREFIX: &'static [u8] = b"TG";
+}
+
+/// The ID of a group of transactions, to be set as the group of each of them. The transactions
+/// are hashed without their current group, so this also checks an existing group ID
+pub fn compute_group_id(transactions: &[Transaction]) -> Result<Byte32, AlgoModelsError> {
+    if transactions.is_empty() {
+        return Err(AlgoModelsError::InputError(
+            "a transaction group must not be empty".to_string(),
+        ));
+    }
+
+    let transaction_ids = transactions
+        .iter()
+        .map(|transaction| {
+            let mut transaction = transaction.clone();
+            transaction.header_mut().group = None;
+            transaction.raw_id()
+        })
+        .collect::<Result<_, _>>()?;
+    let group = TransactionGroup { transaction_ids };
+    Ok(Sha512_256::digest(group.encode()?).into())
 }
 
 impl AlgorandMsgpack for Transaction {
@@ -389,6 +1030,7 @@ impl AlgorandMsgpack for Transaction {
         match self {
             Transaction::Payment(tx) => tx.encode(),
             Transaction::AssetTransfer(tx) => tx.encode(),
+            Transaction::ApplicationCall(tx) => tx.encode(),
         }
     }
 
@@ -401,6 +1043,9 @@ impl AlgorandMsgpack for Transaction {
             TransactionType::AssetTransfer => Ok(Transaction::AssetTransfer(
                 AssetTransferTransactionFields::decode(bytes)?,
             )),
+            TransactionType::ApplicationCall => Ok(Transaction::ApplicationCall(
+                ApplicationCallTransactionFields::decode(bytes)?,
+            )),
             _ => Err(AlgoModelsError::UnknownTransactionType(format!(
                 "{:?}",
                 header.transaction_type
@@ -415,47 +1060,136 @@ pub struct SignedTransaction {
     #[serde(rename = "txn")]
     pub transaction: Transaction,
 
+    /// The signature, which is all zeros and omitted when encoding for an unsigned transaction
     #[serde(rename = "sig")]
-    #[serde_as(as = "Bytes")]
+    #[serde_as(as = "Base64Bytes")]
+    #[serde(skip_serializing_if = "is_empty_signature")]
+    #[serde(default = "empty_signature")]
     pub signature: [u8; 64],
+
+    /// The signatures of a multisig account, in place of `signature`
+    #[serde(rename = "msig")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub multisig_signature: Option<MultisigSignature>,
+
+    /// A program that approves the transaction, in place of `signature`
+    #[serde(rename = "lsig")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub logic_signature: Option<LogicSignature>,
+
+    /// The address that signed the transaction, when it is not the sender because the sender
+    /// was rekeyed
+    #[serde(rename = "sgnr")]
+    #[serde(skip_serializing_if = "is_zero_addr_opt")]
+    #[serde(default)]
+    pub auth_address: Option<Address>,
 }
 
-impl AlgorandMsgpack for SignedTransaction {
-    const PREFIX: &'static [u8] = b"";
+/// One of the accounts of a multisig account, and its signature if it has signed
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct MultisigSubsignature {
+    #[serde(rename = "pk")]
+    #[serde_as(as = "Base64Bytes")]
+    pub public_key: Byte32,
+
+    #[serde(rename = "s")]
+    #[serde_as(as = "Option<Base64Bytes>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub signature: Option<[u8; 64]>,
+}
 
-    // Since we provide default values for all transaction fields, serde will not know which
-    // transaction type the bytes actually correspond with. To fix this we need to manually
-    // decode the transaction using Transaction::decode (which does check the type) and
-    // then add it to the decoded struct
-    fn decode(bytes: &[u8]) -> Result<Self, AlgoModelsError> {
-        let value: rmpv::Value = rmp_serde::from_slice(bytes)?;
+/// The signatures of a multisig account. Every account of the multisig is listed in order,
+/// whether it has signed or not, so the multisig address can be derived from them
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct MultisigSignature {
+    #[serde(rename = "v")]
+    pub version: u8,
 
-        match value {
-            rmpv::Value::Map(map) => {
-                let txn_value = &map
-                    .iter()
-                    .find(|(k, _)| k.as_str() == Some("txn"))
-                    .unwrap()
-                    .1;
+    #[serde(rename = "thr")]
+    pub threshold: u8,
 
-                let mut txn_buf = Vec::new();
-                rmpv::encode::write_value(&mut txn_buf, &txn_value)?;
+    #[serde(rename = "subsig")]
+    pub subsignatures: Vec<MultisigSubsignature>,
+}
 
-                let txn = Transaction::decode(&txn_buf)?;
-                let mut stxn: SignedTransaction = rmp_serde::from_slice(bytes)?;
+impl MultisigSignature {
+    /// The address of the multisig account, which commits to its version, threshold and
+    /// accounts in order
+    pub fn address(&self) -> Address {
+        let mut bytes = vec![self.version, self.threshold];
+        for subsig in &self.subsignatures {
+            bytes.extend_from_slice(&subsig.public_key);
+        }
+        Address::from_domain_hash(b"MultisigAddr", &bytes)
+    }
+}
+
+/// A program and its arguments that approve a transaction. The program is signed by a single
+/// or multisig account when it is delegated, and its hash is the sender otherwise
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct LogicSignature {
+    #[serde(rename = "l")]
+    #[serde_as(as = "Base64Bytes")]
+    pub logic: Vec<u8>,
 
-                stxn.transaction = txn;
+    #[serde(rename = "arg")]
+    #[serde_as(as = "Option<Vec<Base64Bytes>>")]
+    #[serde(skip_serializing_if = "is_empty_vec_opt")]
+    #[serde(default)]
+    pub args: Option<Vec<Vec<u8>>>,
 
-                return Ok(stxn);
-            }
-            _ => {
-                return Err(AlgoModelsError::InputError(format!(
-                    "expected signed transaction to be a map, but got a: {:#?}",
-                    value.type_id()
-                )))
-            }
+    #[serde(rename = "sig")]
+    #[serde_as(as = "Option<Base64Bytes>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub signature: Option<[u8; 64]>,
+
+    #[serde(rename = "msig")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub multisig_signature: Option<MultisigSignature>,
+
+    /// A multisig signature of the program that also commits to the multisig address, which
+    /// replaces `multisig_signature` from AVM version 11 on
+    #[serde(rename = "lmsig")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub logic_multisig_signature: Option<MultisigSignature>,
+}
+
+fn empty_signature() -> [u8; 64] {
+    [0; 64]
+}
+
+fn is_empty_signature(signature: &[u8; 64]) -> bool {
+    signature == &empty_signature()
+}
+
+impl SignedTransaction {
+    /// Wrap a transaction with an empty signature, as algod's simulate endpoint accepts with
+    /// `allow-empty-signatures`
+    pub fn with_empty_signature(transaction: Transaction) -> Self {
+        Self {
+            transaction,
+            signature: empty_signature(),
+            multisig_signature: None,
+            logic_signature: None,
+            auth_address: None,
         }
     }
+
+    pub fn has_empty_signature(&self) -> bool {
+        is_empty_signature(&self.signature)
+    }
+}
+
+impl AlgorandMsgpack for SignedTransaction {
+    const PREFIX: &'static [u8] = b"";
 }
 
 #[test]
@@ -465,7 +1199,7 @@ fn test_pay_transaction() {
             genesis_id: None,
             transaction_type: TransactionType::Payment,
             sender: Address::from_pubkey(&[1; 32]),
-            fee: 0,
+            fee: MicroAlgos::ZERO,
             first_valid: 1000,
             last_valid: 1000,
             genesis_hash: None,
@@ -475,7 +1209,7 @@ fn test_pay_transaction() {
             group: None,
         },
         receiver: Address::from_pubkey(&[1; 32]),
-        amount: 1000,
+        amount: MicroAlgos(1000),
         close_remainder_to: None,
     };
 
@@ -492,6 +1226,9 @@ fn test_pay_transaction() {
     let signed_tx = SignedTransaction {
         transaction: tx_enum.clone(),
         signature: [0; 64],
+        multisig_signature: None,
+        logic_signature: None,
+        auth_address: None,
     };
     let encoded_stx = signed_tx.encode().unwrap();
     let decoded_stx = SignedTransaction::decode(&encoded_stx).unwrap();
@@ -513,7 +1250,7 @@ fn test_asset_transfer_transaction() {
             genesis_id: None,
             transaction_type: TransactionType::AssetTransfer,
             sender: Address::from_pubkey(&[1; 32]),
-            fee: 0,
+            fee: MicroAlgos::ZERO,
             first_valid: 1000,
             last_valid: 1000,
             genesis_hash: None,
@@ -541,6 +1278,9 @@ fn test_asset_transfer_transaction() {
     let signed_tx = SignedTransaction {
         transaction: tx_enum.clone(),
         signature: [0; 64],
+        multisig_signature: None,
+        logic_signature: None,
+        auth_address: None,
     };
     let encoded_stx = signed_tx.encode().unwrap();
     let decoded_stx = SignedTransaction::decode(&encoded_stx).unwrap();
@@ -555,6 +1295,124 @@ fn test_asset_transfer_transaction() {
     assert_eq!(encoded_struct.len(), 117);
 }
 
+#[test]
+fn test_application_call_transaction() {
+    let tx_struct = ApplicationCallTransactionFields {
+        header: TransactionHeader {
+            genesis_id: None,
+            transaction_type: TransactionType::ApplicationCall,
+            sender: Address::from_pubkey(&[1; 32]),
+            fee: MicroAlgos(1000),
+            first_valid: 1000,
+            last_valid: 2000,
+            genesis_hash: None,
+            note: None,
+            rekey_to: None,
+            lease: None,
+            group: None,
+        },
+        app_id: 1234,
+        on_complete: OnApplicationComplete::OptIn,
+        approval_program: None,
+        clear_state_program: None,
+        global_state_schema: None,
+        local_state_schema: Some(StateSchema::default()),
+        extra_program_pages: 0,
+        args: Some(vec![vec![0x8a, 0xa3, 0xb6, 0x1f], vec![]]),
+        accounts: Some(vec![Address::from_pubkey(&[2; 32])]),
+        foreign_apps: Some(vec![5678]),
+        foreign_assets: None,
+        boxes: Some(vec![BoxReference {
+            app_index: 0,
+            name: b"box".to_vec(),
+        }]),
+    };
+
+    let encoded_struct = tx_struct.encode().unwrap();
+    let decoded_struct = ApplicationCallTransactionFields::decode(&encoded_struct).unwrap();
+    // Empty state schemas are omitted
+    assert_eq!(
+        decoded_struct,
+        ApplicationCallTransactionFields {
+            local_state_schema: None,
+            ..tx_struct.clone()
+        }
+    );
+
+    let value = rmpv::decode::read_value(&mut &encoded_struct[2..]).unwrap();
+    let keys: Vec<&str> = value
+        .as_map()
+        .unwrap()
+        .iter()
+        .map(|(k, _)| k.as_str().unwrap())
+        .collect();
+    assert_eq!(
+        keys,
+        vec!["apaa", "apan", "apat", "apbx", "apfa", "apid", "fee", "fv", "lv", "snd", "type"]
+    );
+
+    let tx_enum = Transaction::ApplicationCall(decoded_struct.clone());
+    let decoded_enum = Transaction::decode(&tx_enum.encode().unwrap()).unwrap();
+    assert_eq!(decoded_enum, tx_enum);
+
+    let signed_tx = SignedTransaction {
+        transaction: tx_enum.clone(),
+        signature: [0; 64],
+        multisig_signature: None,
+        logic_signature: None,
+        auth_address: None,
+    };
+    let decoded_stx = SignedTransaction::decode(&signed_tx.encode().unwrap()).unwrap();
+    assert_eq!(decoded_stx, signed_tx);
+}
+
+#[test]
+fn test_application_call_set_programs() {
+    let mut tx_struct = ApplicationCallTransactionFields {
+        header: TransactionHeader {
+            genesis_id: None,
+            transaction_type: TransactionType::ApplicationCall,
+            sender: Address::from_pubkey(&[1; 32]),
+            fee: MicroAlgos(1000),
+            first_valid: 1000,
+            last_valid: 2000,
+            genesis_hash: None,
+            note: None,
+            rekey_to: None,
+            lease: None,
+            group: None,
+        },
+        app_id: 0,
+        on_complete: OnApplicationComplete::NoOp,
+        approval_program: None,
+        clear_state_program: None,
+        global_state_schema: None,
+        local_state_schema: None,
+        extra_program_pages: 0,
+        args: None,
+        accounts: None,
+        foreign_apps: None,
+        foreign_assets: None,
+        boxes: None,
+    };
+
+    let large = format!("#pragma version 8\n{}", "pushbytes 0x00\n".repeat(700));
+    let approval = program::Program::assemble(&large).unwrap();
+    let clear_state = program::Program::assemble("#pragma version 8\npushint 1").unwrap();
+    tx_struct
+        .set_programs(approval.clone(), clear_state.clone())
+        .unwrap();
+
+    assert_eq!(tx_struct.approval_program, Some(approval.into_bytes()));
+    assert_eq!(tx_struct.clear_state_program, Some(vec![0x08, 0x81, 0x01]));
+    assert_eq!(tx_struct.extra_program_pages, 1);
+
+    let too_large = format!("#pragma version 8\n{}", "pushbytes 0x00\n".repeat(3000));
+    assert!(tx_struct
+        .set_programs(program::Program::assemble(&too_large).unwrap(), clear_state)
+        .is_err());
+}
+
 #[test]
 fn test_address() {
     let addr = Address::from_pubkey(&[0; 32]);
@@ -566,3 +1424,212 @@ fn test_address() {
     let addr_from_str = Address::from_string(&addr.address()).unwrap();
     assert_eq!(addr, addr_from_str);
 }
+
+#[test]
+fn test_invalid_address() {
+    // Lowercase characters are not part of the base32 alphabet
+    let not_base32 = "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+    assert!(Address::from_string(not_base32).is_err());
+
+    let bad_checksum = "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAY5HFKA";
+    assert!(Address::from_string(bad_checksum).is_err());
+
+    assert!(Address::from_string("AAAA").is_err());
+}
+
+#[test]
+fn test_address_from_app_id() {
+    // Vector shared with the reference SDK implementations
+    assert_eq!(
+        Address::from_app_id(77).address(),
+        "PCYUFPA2ZTOYWTP43MX2MOX2OWAIAXUDNC2WFCXAGMRUZ3DYD6BWFDL5YM"
+    );
+
+    assert_eq!(
+        Address::from_app_id(1284326447).address(),
+        "JP3ENKDQC2BOYRMLFGKBS7RB2IVNF7VNHCFHVTRNHOENRQ6R4UN7MCNXPI"
+    );
+
+    assert_eq!(
+        Address::from_app_id(77),
+        Address::from_domain_hash(b"appID", &77u64.to_be_bytes())
+    );
+}
+
+#[test]
+fn test_empty_signature() {
+    let transaction = Transaction::Payment(PayTransactionFields {
+        header: TransactionHeader {
+            genesis_id: None,
+            transaction_type: TransactionType::Payment,
+            sender: Address::from_pubkey(&[1; 32]),
+            fee: MicroAlgos(1000),
+            first_valid: 1000,
+            last_valid: 2000,
+            genesis_hash: None,
+            note: None,
+            rekey_to: None,
+            lease: None,
+            group: None,
+        },
+        receiver: Address::from_pubkey(&[2; 32]),
+        amount: MicroAlgos(1000),
+        close_remainder_to: None,
+    });
+
+    // An empty signature is omitted, so the encoding is just the transaction under "txn"
+    let signed = SignedTransaction::with_empty_signature(transaction.clone());
+    assert!(signed.has_empty_signature());
+    let encoded = signed.encode().unwrap();
+    let mut expected = vec![0x81, 0xa3];
+    expected.extend_from_slice(b"txn");
+    expected.extend_from_slice(&transaction.encode_raw().unwrap());
+    assert_eq!(encoded, expected);
+    assert_eq!(SignedTransaction::decode(&encoded).unwrap(), signed);
+}
+
+#[test]
+fn test_transaction_json() {
+    let transaction = Transaction::Payment(PayTransactionFields {
+        header: TransactionHeader {
+            genesis_id: Some("testnet-v1.0".to_string()),
+            transaction_type: TransactionType::Payment,
+            sender: Address::from_pubkey(&[1; 32]),
+            fee: MicroAlgos(1000),
+            first_valid: 1000,
+            last_valid: 2000,
+            genesis_hash: Some([7; 32]),
+            note: None,
+            rekey_to: None,
+            lease: None,
+            group: None,
+        },
+        receiver: Address::from_pubkey(&[1; 32]),
+        amount: MicroAlgos(5),
+        close_remainder_to: None,
+    });
+
+    // Like algod, JSON has base32 addresses and base64 bytes under the msgpack field names
+    let json = serde_json::to_value(&transaction).unwrap();
+    assert_eq!(
+        json["snd"],
+        "AEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEA5RCDXMI"
+    );
+    assert_eq!(json["gh"], "BwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwc=");
+    assert_eq!(json["type"], "pay");
+    assert_eq!(
+        serde_json::from_value::<Transaction>(json).unwrap(),
+        transaction
+    );
+}
+
+#[test]
+fn test_address_serde() {
+    let address = Address::from_pubkey(&[1; 32]);
+    let base32 = "AEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEA5RCDXMI";
+
+    // A base32 string in JSON
+    let json = serde_json::to_string(&address).unwrap();
+    assert_eq!(json, format!("\"{}\"", base32));
+    assert_eq!(serde_json::from_str::<Address>(&json).unwrap(), address);
+    assert!(serde_json::from_str::<Address>("\"AEAQCAIB\"").is_err());
+
+    // The public key as binary in msgpack, where a base32 string is not accepted
+    let msgpack = rmp_serde::to_vec(&address).unwrap();
+    assert_eq!(msgpack[..2], [0xc4, 32]);
+    assert_eq!(rmp_serde::from_slice::<Address>(&msgpack).unwrap(), address);
+    let msgpack_string = rmp_serde::to_vec(base32).unwrap();
+    assert!(rmp_serde::from_slice::<Address>(&msgpack_string).is_err());
+    assert!(serde_json::from_str::<Address>("[1, 2, 3]").is_err());
+    let short = rmp_serde::to_vec(&rmpv::Value::Binary(vec![1; 31])).unwrap();
+    assert!(rmp_serde::from_slice::<Address>(&short).is_err());
+}
+
+#[test]
+fn test_base64_bytes_json() {
+    let transaction = Transaction::Payment(PayTransactionFields {
+        header: TransactionHeader {
+            genesis_id: None,
+            transaction_type: TransactionType::Payment,
+            sender: Address::from_pubkey(&[1; 32]),
+            fee: MicroAlgos(1000),
+            first_valid: 1000,
+            last_valid: 2000,
+            genesis_hash: None,
+            note: Some(vec![0xff, 0, 1]),
+            rekey_to: None,
+            lease: None,
+            group: None,
+        },
+        receiver: Address::from_pubkey(&[2; 32]),
+        amount: MicroAlgos(1000),
+        close_remainder_to: None,
+    });
+    let signed = SignedTransaction {
+        signature: [9; 64],
+        ..SignedTransaction::with_empty_signature(transaction)
+    };
+
+    // Bytes are base64 in JSON, including fixed-size arrays such as signatures
+    let mut json = serde_json::to_value(&signed).unwrap();
+    assert_eq!(json["txn"]["note"], "/wAB");
+    assert_eq!(json["sig"], BASE64.encode([9; 64]));
+    assert_eq!(
+        serde_json::from_value::<SignedTransaction>(json.clone()).unwrap(),
+        signed
+    );
+
+    // and binary in msgpack
+    let encoded = signed.encode().unwrap();
+    let sig = [&[0xa3][..], b"sig", &[0xc4, 64], &[9; 64]].concat();
+    assert!(encoded.windows(sig.len()).any(|w| w == sig));
+
+    // where the strings of JSON are rejected, including in the flattened transaction header
+    let json_as_msgpack = rmp_serde::to_vec(&json).unwrap();
+    assert!(SignedTransaction::decode(&json_as_msgpack).is_err());
+    let mut txn =
+        rmpv::decode::read_value(&mut &signed.transaction.encode_raw().unwrap()[..]).unwrap();
+    let rmpv::Value::Map(fields) = &mut txn else {
+        panic!("expected a map");
+    };
+    for (key, value) in fields.iter_mut() {
+        if key.as_str() == Some("note") {
+            *value = "/wAB".into();
+        }
+    }
+    let mut string_note = Vec::new();
+    rmpv::encode::write_value(&mut string_note, &txn).unwrap();
+    assert_eq!(
+        Transaction::decode(&string_note).unwrap_err().to_string(),
+        "Error ocurred during decoding: invalid type: string \"/wAB\", expected binary bytes"
+    );
+
+    json["sig"] = BASE64.encode([9; 63]).into();
+    assert!(serde_json::from_value::<SignedTransaction>(json).is_err());
+}
+
+#[test]
+fn test_compute_group_id() {
+    let request =
+        simulate::SimulateRequest::decode(include_bytes!("../test_data/simulate_request.msgpack"))
+            .unwrap();
+    let transactions: Vec<_> = request.txn_groups[0]
+        .txns
+        .iter()
+        .map(|txn| txn.transaction.clone())
+        .collect();
+
+    // The transactions of the fixture already have their group set
+    let group = compute_group_id(&transactions).unwrap();
+    assert_eq!(
+        BASE64.encode(group),
+        "5X6ntOKp8Yu4956KndbVwS3o3S2eqPaXqeKO/6Kv0CU="
+    );
+    assert_eq!(transactions[0].header().group, Some(group));
+
+    // The order of the transactions matters
+    let reversed: Vec<_> = transactions.iter().rev().cloned().collect();
+    assert_ne!(compute_group_id(&reversed).unwrap(), group);
+
+    assert!(compute_group_id(&[]).is_err());
+}
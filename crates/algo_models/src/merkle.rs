@@ -0,0 +1,397 @@
+//! Commitments to the transactions of a block, and proofs that a transaction is in a block.
+//!
+//! A block header commits to its transactions with two Merkle roots: `txn`, a SHA-512/256 tree,
+//! and `txn256`, a SHA-256 vector commitment. The leaves of both are the hash of "TL" followed
+//! by the ID of the transaction as it was signed and the hash of the transaction as stored in
+//! the block, prefixed with "STIB". Internal nodes are the hash of "MA" followed by both
+//! children, where a missing right child is all zeros. A vector commitment pads the leaves to a
+//! power of two with the hash of nothing, and places leaf `i` at the position given by reversing
+//! the bits of `i`, so that proofs of the same index have the same shape in every block.
+
+use crate::block::{Block, BlockHeader, BlockTransaction};
+use crate::{AlgoModelsError, AlgorandMsgpack, Base64Bytes, Byte32, Transaction};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use sha2::{Digest, Sha256, Sha512_256};
+use thiserror::Error;
+
+const TXN_MERKLE_LEAF_PREFIX: &[u8] = b"TL";
+const MERKLE_ARRAY_NODE_PREFIX: &[u8] = b"MA";
+const SIGNED_TXN_IN_BLOCK_PREFIX: &[u8] = b"STIB";
+
+#[derive(Debug, Error, PartialEq)]
+pub enum MerkleError {
+    #[error("transaction index {index} is out of range for a block of {len} transactions")]
+    IndexOutOfRange { index: usize, len: usize },
+
+    #[error("proof of {len} bytes does not match a tree depth of {depth}")]
+    InvalidProofLength { len: usize, depth: u64 },
+
+    #[error("hash of {0} bytes is not 32 bytes")]
+    InvalidHashLength(usize),
+
+    #[error("proof does not match the transaction and root")]
+    InvalidProof,
+
+    #[error("{0:?} commitment does not match the block header")]
+    CommitmentMismatch(HashType),
+}
+
+/// The hash function of a Merkle tree. SHA-256 trees are vector commitments
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+pub enum HashType {
+    #[serde(rename = "sha512_256")]
+    Sha512_256,
+
+    #[serde(rename = "sha256")]
+    Sha256,
+}
+
+impl HashType {
+    fn hash(self, parts: &[&[u8]]) -> Byte32 {
+        match self {
+            HashType::Sha512_256 => {
+                let mut hasher = Sha512_256::new();
+                parts.iter().for_each(|part| hasher.update(part));
+                hasher.finalize().into()
+            }
+            HashType::Sha256 => {
+                let mut hasher = Sha256::new();
+                parts.iter().for_each(|part| hasher.update(part));
+                hasher.finalize().into()
+            }
+        }
+    }
+
+    fn is_vector_commitment(self) -> bool {
+        self == HashType::Sha256
+    }
+
+    fn node(self, left: &Byte32, right: &Byte32) -> Byte32 {
+        self.hash(&[MERKLE_ARRAY_NODE_PREFIX, left, right])
+    }
+}
+
+/// A proof that a transaction is in a block, as returned by algod's
+/// `/v2/blocks/{round}/transactions/{txid}/proof`
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct TransactionProof {
+    #[serde(rename = "hashtype")]
+    pub hash_type: HashType,
+
+    /// The index of the transaction in the block
+    #[serde(rename = "idx")]
+    pub index: u64,
+
+    /// The sibling of each node on the path from the leaf to the root, concatenated
+    #[serde_as(as = "Base64Bytes")]
+    pub proof: Vec<u8>,
+
+    /// The hash of the transaction as stored in the block
+    #[serde(rename = "stibhash")]
+    #[serde_as(as = "Base64Bytes")]
+    pub stib_hash: Vec<u8>,
+
+    #[serde(rename = "treedepth")]
+    pub tree_depth: u64,
+}
+
+impl TransactionProof {
+    /// Check that `transaction`, as it was signed, is in the block whose header has the
+    /// commitment `root` for the proof's hash type
+    pub fn verify(&self, transaction: &Transaction, root: &Byte32) -> Result<(), AlgoModelsError> {
+        let hash_type = self.hash_type;
+
+        if self.tree_depth >= 64 || self.proof.len() as u64 != self.tree_depth * 32 {
+            return Err(MerkleError::InvalidProofLength {
+                len: self.proof.len(),
+                depth: self.tree_depth,
+            }
+            .into());
+        }
+        let stib_hash: Byte32 = self
+            .stib_hash
+            .as_slice()
+            .try_into()
+            .map_err(|_| MerkleError::InvalidHashLength(self.stib_hash.len()))?;
+
+        if self.index >> self.tree_depth != 0 {
+            return Err(MerkleError::InvalidProof.into());
+        }
+
+        let txid = hash_type.hash(&[&transaction.encode()?]);
+        let mut node = hash_type.hash(&[TXN_MERKLE_LEAF_PREFIX, &txid, &stib_hash]);
+
+        let mut position = tree_position(self.index, self.tree_depth as u32, hash_type);
+        for sibling in self.proof.chunks_exact(32) {
+            let sibling: Byte32 = sibling.try_into().expect("chunks are 32 bytes");
+            node = if position & 1 == 0 {
+                hash_type.node(&node, &sibling)
+            } else {
+                hash_type.node(&sibling, &node)
+            };
+            position >>= 1;
+        }
+
+        if &node != root {
+            return Err(MerkleError::InvalidProof.into());
+        }
+        Ok(())
+    }
+}
+
+/// The position of leaf `index` in a tree of `depth` levels above its leaves
+fn tree_position(index: u64, depth: u32, hash_type: HashType) -> u64 {
+    if hash_type.is_vector_commitment() && depth > 0 {
+        index.reverse_bits() >> (64 - depth)
+    } else {
+        index
+    }
+}
+
+/// The levels of a Merkle tree, from its leaves to its root
+struct MerkleTree {
+    hash_type: HashType,
+    levels: Vec<Vec<Byte32>>,
+}
+
+impl MerkleTree {
+    fn new(mut leaves: Vec<Byte32>, hash_type: HashType) -> Self {
+        if hash_type.is_vector_commitment() && leaves.len() > 1 {
+            let depth = usize::BITS - (leaves.len() - 1).leading_zeros();
+            let padding = hash_type.hash(&[]);
+
+            leaves = (0..1u64 << depth)
+                .map(|position| {
+                    // Reversing the bits of a position gives the index of its leaf
+                    let index = tree_position(position, depth, hash_type) as usize;
+                    leaves.get(index).copied().unwrap_or(padding)
+                })
+                .collect();
+        }
+
+        let mut levels = vec![leaves];
+        while let Some(level) = levels.last().filter(|level| level.len() > 1) {
+            let next = level
+                .chunks(2)
+                .map(|pair| hash_type.node(&pair[0], pair.get(1).unwrap_or(&[0u8; 32])))
+                .collect();
+            levels.push(next);
+        }
+
+        MerkleTree { hash_type, levels }
+    }
+
+    /// The root of the tree, which is all zeros for an empty tree
+    fn root(&self) -> Byte32 {
+        self.levels
+            .last()
+            .and_then(|level| level.first())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn depth(&self) -> u64 {
+        self.levels.len() as u64 - 1
+    }
+
+    /// The siblings on the path from leaf `index` to the root, concatenated
+    fn prove(&self, index: u64) -> Vec<u8> {
+        let mut position = tree_position(index, self.depth() as u32, self.hash_type) as usize;
+        let mut proof = Vec::with_capacity(self.depth() as usize * 32);
+
+        for level in &self.levels[..self.levels.len() - 1] {
+            proof.extend_from_slice(level.get(position ^ 1).unwrap_or(&[0u8; 32]));
+            position /= 2;
+        }
+        proof
+    }
+}
+
+impl BlockHeader {
+    /// The hash of a transaction as stored in the block
+    fn stib_hash(txn: &BlockTransaction, hash_type: HashType) -> Result<Byte32, AlgoModelsError> {
+        Ok(hash_type.hash(&[SIGNED_TXN_IN_BLOCK_PREFIX, &txn.encode_raw()?]))
+    }
+
+    fn txn_merkle_leaf(
+        &self,
+        txn: &BlockTransaction,
+        hash_type: HashType,
+    ) -> Result<Byte32, AlgoModelsError> {
+        let txid = hash_type.hash(&[&self.signed_transaction_bytes(txn)?]);
+
+        Ok(hash_type.hash(&[
+            TXN_MERKLE_LEAF_PREFIX,
+            &txid,
+            &Self::stib_hash(txn, hash_type)?,
+        ]))
+    }
+}
+
+impl Block {
+    fn txn_merkle_tree(&self, hash_type: HashType) -> Result<MerkleTree, AlgoModelsError> {
+        let leaves = self
+            .transactions
+            .iter()
+            .flatten()
+            .map(|txn| self.header.txn_merkle_leaf(txn, hash_type))
+            .collect::<Result<_, _>>()?;
+
+        Ok(MerkleTree::new(leaves, hash_type))
+    }
+
+    /// The root of the block's transactions, which is `txn_root` for SHA-512/256 and
+    /// `txn_root_sha256` for SHA-256
+    pub fn txn_commitment(&self, hash_type: HashType) -> Result<Byte32, AlgoModelsError> {
+        Ok(self.txn_merkle_tree(hash_type)?.root())
+    }
+
+    /// Check that both commitments in the header match the block's transactions
+    pub fn verify_txn_commitments(&self) -> Result<(), AlgoModelsError> {
+        for (hash_type, root) in [
+            (HashType::Sha512_256, &self.header.txn_root),
+            (HashType::Sha256, &self.header.txn_root_sha256),
+        ] {
+            if &self.txn_commitment(hash_type)? != root {
+                return Err(MerkleError::CommitmentMismatch(hash_type).into());
+            }
+        }
+        Ok(())
+    }
+
+    /// A proof that the transaction at `index` is in the block, like algod's
+    pub fn transaction_proof(
+        &self,
+        index: usize,
+        hash_type: HashType,
+    ) -> Result<TransactionProof, AlgoModelsError> {
+        let transactions = self.transactions.as_deref().unwrap_or_default();
+        let stxn = transactions
+            .get(index)
+            .ok_or(MerkleError::IndexOutOfRange {
+                index,
+                len: transactions.len(),
+            })?;
+        let tree = self.txn_merkle_tree(hash_type)?;
+
+        Ok(TransactionProof {
+            hash_type,
+            index: index as u64,
+            proof: tree.prove(index as u64),
+            stib_hash: BlockHeader::stib_hash(stxn, hash_type)?.to_vec(),
+            tree_depth: tree.depth(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const BLOCK_RESPONSE: &[u8] = include_bytes!("../test_data/block.msgpack");
+
+    // Proofs of the fourth and fifth transactions of the block fixture in algod's JSON format,
+    // computed independently of this module over all eight of its transactions
+    const SHA512_256_PROOF: &str = r#"{"hashtype": "sha512_256", "idx": 3, "proof": "Q5G/NAUbBYIEwsLj0Cy5yGgyfYFNIFXgnE/V2mgK1a8RdJ9sf7UnX1/tHRSw+RjTxR5WBqknf6mIQZFzdJKEkyuCRlWXRst3L6rxmo+qOKlnhdt8/qoDJ7PUlFN7FSW8", "stibhash": "TA/zrxSwKGOW4UIaHDp8fh/BnoUDtXjo9IbSZM/yemk=", "treedepth": 3}"#;
+    const SHA256_PROOF: &str = r#"{"hashtype": "sha256", "idx": 3, "proof": "JFXsLJMU75zbmyXfEU0u2dHaVdXRD1odLxBbHVwIT0z9aQzAajrvRT6hZrCih1mMMDFxrk+QsHmWz5AiFe4SmvGQ9gKneDFwRLloXkYBl8RJvbmKOCPj7Wp4qWWM15a2", "stibhash": "BeLSl7eZZSMI5Qa9p2wbnQ6e2uJUVHCtHZc9MzWq+9k=", "treedepth": 3}"#;
+    const SHA256_PROOF_LAST: &str = r#"{"hashtype": "sha256", "idx": 4, "proof": "bRyA515OydKpj8BXKuG3fh5F4rhts4qUDgtvgmDIig+e9Abygl0YIgAlqhTR6gqgXsLg6x3vUKFseMhgllEaOX8lGLSjOQLvPS0HvT7lxjykh+o0D1E9gQZv5zsbW5ba", "stibhash": "Di/34SHIVm4cYlFWpw9ADuXdb1mArQKwVUY9cFUEPqQ=", "treedepth": 3}"#;
+
+    fn block() -> Block {
+        Block::from_response(BLOCK_RESPONSE).unwrap()
+    }
+
+    fn signed_transaction(block: &Block, index: usize) -> Transaction {
+        let stxn = block.transactions.as_ref().unwrap()[index]
+            .decoded()
+            .unwrap();
+        block.header.signed_transaction(stxn).unwrap().transaction
+    }
+
+    #[test]
+    fn test_txn_commitments() {
+        let block = block();
+        assert!(block.verify_txn_commitments().is_ok());
+
+        let mut tampered = block.clone();
+        let BlockTransaction::Decoded(stxn) = &mut tampered.transactions.as_mut().unwrap()[1]
+        else {
+            panic!("expected a decoded transaction");
+        };
+        stxn.signed_transaction.apply_data.closing_amount.0 += 1;
+        assert!(matches!(
+            tampered.verify_txn_commitments(),
+            Err(AlgoModelsError::InvalidCommitment(
+                MerkleError::CommitmentMismatch(HashType::Sha512_256)
+            ))
+        ));
+
+        let empty = Block {
+            transactions: None,
+            ..block
+        };
+        assert_eq!(empty.txn_commitment(HashType::Sha256).unwrap(), [0u8; 32]);
+    }
+
+    #[test]
+    fn test_transaction_proofs() {
+        let block = block();
+
+        for (json, index, root) in [
+            (SHA512_256_PROOF, 3, &block.header.txn_root),
+            (SHA256_PROOF, 3, &block.header.txn_root_sha256),
+            (SHA256_PROOF_LAST, 4, &block.header.txn_root_sha256),
+        ] {
+            let proof: TransactionProof = serde_json::from_str(json).unwrap();
+            assert_eq!(
+                block.transaction_proof(index, proof.hash_type).unwrap(),
+                proof
+            );
+
+            let transaction = signed_transaction(&block, index);
+            assert!(proof.verify(&transaction, root).is_ok());
+
+            // The proof is only valid for its own transaction and position
+            let other = signed_transaction(&block, 0);
+            assert!(matches!(
+                proof.verify(&other, root),
+                Err(AlgoModelsError::InvalidCommitment(
+                    MerkleError::InvalidProof
+                ))
+            ));
+            let moved = TransactionProof {
+                index: proof.index + 8,
+                ..proof.clone()
+            };
+            assert!(moved.verify(&transaction, root).is_err());
+        }
+
+        assert!(matches!(
+            block.transaction_proof(8, HashType::Sha256),
+            Err(AlgoModelsError::InvalidCommitment(
+                MerkleError::IndexOutOfRange { index: 8, len: 8 }
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_proof_encoding() {
+        let proof: TransactionProof = serde_json::from_str(SHA256_PROOF).unwrap();
+        assert_eq!(proof.stib_hash.len(), 32);
+        assert_eq!(proof.proof.len(), 3 * 32);
+
+        // Bytes are base64 in JSON and binary in msgpack
+        let json = serde_json::to_value(&proof).unwrap();
+        assert_eq!(
+            json["stibhash"],
+            "BeLSl7eZZSMI5Qa9p2wbnQ6e2uJUVHCtHZc9MzWq+9k="
+        );
+        let msgpack = rmp_serde::to_vec_named(&proof).unwrap();
+        assert_eq!(
+            rmp_serde::from_slice::<TransactionProof>(&msgpack).unwrap(),
+            proof
+        );
+    }
+}
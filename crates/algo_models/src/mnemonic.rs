@@ -0,0 +1,203 @@
+//! Algorand 25-word mnemonics, which encode a 32-byte ed25519 seed as 24 words from the BIP-39
+//! English word list followed by a checksum word.
+//!
+//! Unlike BIP-39, the words are a plain 11-bit little-endian encoding of the seed and the
+//! checksum is derived from the SHA-512/256 hash of the seed.
+
+use sha2::{Digest, Sha512_256};
+use std::sync::OnceLock;
+use thiserror::Error;
+
+const BITS_PER_WORD: usize = 11;
+const SEED_BYTE_LENGTH: usize = 32;
+const CHECKSUM_BYTE_LENGTH: usize = 2;
+const MNEMONIC_WORD_COUNT: usize = 25;
+
+const ENGLISH: &str = include_str!("mnemonic/english.txt");
+
+#[derive(Debug, Error, PartialEq)]
+pub enum MnemonicError {
+    #[error("mnemonic must be {MNEMONIC_WORD_COUNT} words, but got {0}")]
+    InvalidLength(usize),
+
+    #[error("unknown word in mnemonic: {0}")]
+    UnknownWord(String),
+
+    #[error("mnemonic checksum word is invalid")]
+    InvalidChecksum,
+
+    #[error("mnemonic does not encode a 32-byte seed")]
+    InvalidSeed,
+}
+
+fn words() -> &'static [&'static str] {
+    static WORDS: OnceLock<Vec<&'static str>> = OnceLock::new();
+    WORDS.get_or_init(|| ENGLISH.lines().collect())
+}
+
+fn word_index(word: &str) -> Result<usize, MnemonicError> {
+    words()
+        .binary_search(&word)
+        .map_err(|_| MnemonicError::UnknownWord(word.to_string()))
+}
+
+/// Split bytes into 11-bit values, least significant bits first
+fn to_u11(bytes: &[u8]) -> Vec<usize> {
+    let mut out = Vec::with_capacity(bytes.len() * 8 / BITS_PER_WORD + 1);
+    let mut buffer = 0usize;
+    let mut bits = 0;
+
+    for byte in bytes {
+        buffer |= (*byte as usize) << bits;
+        bits += 8;
+
+        if bits >= BITS_PER_WORD {
+            out.push(buffer & 0x7ff);
+            buffer >>= BITS_PER_WORD;
+            bits -= BITS_PER_WORD;
+        }
+    }
+
+    if bits > 0 {
+        out.push(buffer & 0x7ff);
+    }
+
+    out
+}
+
+/// Join 11-bit values back into bytes, the inverse of `to_u11`
+fn from_u11(values: &[usize]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(values.len() * BITS_PER_WORD / 8 + 1);
+    let mut buffer = 0usize;
+    let mut bits = 0;
+
+    for value in values {
+        buffer |= value << bits;
+        bits += BITS_PER_WORD;
+
+        while bits >= 8 {
+            out.push((buffer & 0xff) as u8);
+            buffer >>= 8;
+            bits -= 8;
+        }
+    }
+
+    if bits > 0 {
+        out.push((buffer & 0xff) as u8);
+    }
+
+    out
+}
+
+fn checksum_word(seed: &[u8; SEED_BYTE_LENGTH]) -> &'static str {
+    let hash = Sha512_256::digest(seed);
+    words()[to_u11(&hash[..CHECKSUM_BYTE_LENGTH])[0]]
+}
+
+/// Encode a 32-byte seed as a 25-word mnemonic
+pub fn seed_to_mnemonic(seed: &[u8; SEED_BYTE_LENGTH]) -> String {
+    let mut mnemonic: Vec<&str> = to_u11(seed).into_iter().map(|i| words()[i]).collect();
+    mnemonic.push(checksum_word(seed));
+    mnemonic.join(" ")
+}
+
+/// Decode a 25-word mnemonic into the 32-byte seed it encodes.
+/// Words are matched case-insensitively and may be separated by any whitespace
+pub fn mnemonic_to_seed(mnemonic: &str) -> Result<[u8; SEED_BYTE_LENGTH], MnemonicError> {
+    let mnemonic = mnemonic.to_lowercase();
+    let mnemonic_words: Vec<&str> = mnemonic.split_whitespace().collect();
+
+    if mnemonic_words.len() != MNEMONIC_WORD_COUNT {
+        return Err(MnemonicError::InvalidLength(mnemonic_words.len()));
+    }
+
+    let (key_words, checksum) = mnemonic_words.split_at(MNEMONIC_WORD_COUNT - 1);
+
+    let indices = key_words
+        .iter()
+        .map(|w| word_index(w))
+        .collect::<Result<Vec<_>, _>>()?;
+    word_index(checksum[0])?;
+
+    // 24 words carry 264 bits, so the trailing byte must be zero padding
+    let mut bytes = from_u11(&indices);
+    if bytes.len() != SEED_BYTE_LENGTH + 1 || bytes.pop() != Some(0) {
+        return Err(MnemonicError::InvalidSeed);
+    }
+
+    let seed: [u8; SEED_BYTE_LENGTH] = bytes.try_into().map_err(|_| MnemonicError::InvalidSeed)?;
+
+    if checksum_word(&seed) != checksum[0] {
+        return Err(MnemonicError::InvalidChecksum);
+    }
+
+    Ok(seed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const ZERO_MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon invest";
+
+    const MNEMONIC: &str = "advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor";
+
+    #[test]
+    fn test_word_list() {
+        assert_eq!(words().len(), 2048);
+        assert!(words().windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_zero_seed() {
+        assert_eq!(seed_to_mnemonic(&[0; 32]), ZERO_MNEMONIC);
+        assert_eq!(mnemonic_to_seed(ZERO_MNEMONIC).unwrap(), [0; 32]);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let seed = mnemonic_to_seed(MNEMONIC).unwrap();
+        assert_eq!(seed_to_mnemonic(&seed), MNEMONIC);
+
+        let seed: [u8; 32] = core::array::from_fn(|i| i as u8 * 7);
+        assert_eq!(mnemonic_to_seed(&seed_to_mnemonic(&seed)).unwrap(), seed);
+
+        let shouting = MNEMONIC.to_uppercase().replace(' ', "  \n");
+        assert_eq!(
+            mnemonic_to_seed(&shouting).unwrap(),
+            mnemonic_to_seed(MNEMONIC).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_invalid_length() {
+        let short = MNEMONIC.rsplit_once(' ').unwrap().0;
+        assert_eq!(
+            mnemonic_to_seed(short),
+            Err(MnemonicError::InvalidLength(24))
+        );
+    }
+
+    #[test]
+    fn test_unknown_word() {
+        let bad = MNEMONIC.replace("pudding", "puddingg");
+        assert_eq!(
+            mnemonic_to_seed(&bad),
+            Err(MnemonicError::UnknownWord("puddingg".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_invalid_checksum() {
+        let bad = MNEMONIC.replace("sponsor", "abandon");
+        assert_eq!(mnemonic_to_seed(&bad), Err(MnemonicError::InvalidChecksum));
+    }
+
+    #[test]
+    fn test_invalid_seed() {
+        // The 24th word carries the highest bits, which must be zero padding
+        let bad = ZERO_MNEMONIC.replacen("abandon invest", "zoo invest", 1);
+        assert_eq!(mnemonic_to_seed(&bad), Err(MnemonicError::InvalidSeed));
+    }
+}
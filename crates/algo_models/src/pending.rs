@@ -0,0 +1,321 @@
+//! Transactions as returned by algod's `/v2/transactions/pending/{txid}`, in JSON or msgpack.
+//!
+//! The same model describes the result of each transaction in a simulation. Like accounts, it
+//! uses algod's kebab-case names in both encodings, while the transaction itself keeps its
+//! msgpack field names. State deltas are lists of base64 encoded keys rather than the maps of
+//! apply data, and `PendingTransactionResponse::apply_data` converts them back.
+
+use crate::apply_data::{
+    ApplyData, EvalDelta, SignedTransactionWithApplyData, StateDelta, ValueDelta, ValueDeltaAction,
+};
+use crate::{
+    Address, AddressString, AlgorandMsgpack, Base64Bytes, Base64String, MicroAlgos, Transaction,
+};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none};
+use std::collections::BTreeMap;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum PendingTransactionError {
+    #[error("invalid pending transaction JSON: {0}")]
+    InvalidJson(#[from] serde_json::Error),
+}
+
+/// The new value of a key in application state. Only the field matching the action is present
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct EvalDeltaValue {
+    pub action: ValueDeltaAction,
+
+    #[serde_as(as = "Option<Base64String>")]
+    #[serde(default)]
+    pub bytes: Option<Vec<u8>>,
+
+    #[serde(default)]
+    pub uint: Option<u64>,
+}
+
+/// A key in application state and how it changed
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct EvalDeltaKeyValue {
+    #[serde_as(as = "Base64String")]
+    pub key: Vec<u8>,
+
+    pub value: EvalDeltaValue,
+}
+
+fn state_delta(key_values: &[EvalDeltaKeyValue]) -> StateDelta {
+    key_values
+        .iter()
+        .map(|key_value| {
+            let value = &key_value.value;
+            let delta = ValueDelta {
+                action: value.action,
+                bytes: value.bytes.clone(),
+                uint: value.uint.unwrap_or_default(),
+            };
+            (key_value.key.clone(), delta)
+        })
+        .collect()
+}
+
+/// The changes to an account's local state in an application
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct AccountStateDelta {
+    #[serde_as(as = "AddressString")]
+    pub address: Address,
+
+    pub delta: Vec<EvalDeltaKeyValue>,
+}
+
+/// A transaction in the pool or a block, with its effects once it has been evaluated
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct PendingTransactionResponse {
+    /// The signed transaction. Its effects are reported in the fields of the response instead
+    pub txn: SignedTransactionWithApplyData,
+
+    /// Why the transaction was removed from the pool, or empty if it was not
+    #[serde(rename = "pool-error")]
+    pub pool_error: String,
+
+    /// The round the transaction was confirmed in, if it has been
+    #[serde(rename = "confirmed-round")]
+    #[serde(default)]
+    pub confirmed_round: Option<u64>,
+
+    /// The ID of the asset created by the transaction
+    #[serde(rename = "asset-index")]
+    #[serde(default)]
+    pub asset_index: Option<u64>,
+
+    /// The ID of the application created by the transaction
+    #[serde(rename = "application-index")]
+    #[serde(default)]
+    pub application_index: Option<u64>,
+
+    #[serde(rename = "close-rewards")]
+    #[serde(default)]
+    pub close_rewards: Option<MicroAlgos>,
+
+    #[serde(rename = "closing-amount")]
+    #[serde(default)]
+    pub closing_amount: Option<MicroAlgos>,
+
+    #[serde(rename = "asset-closing-amount")]
+    #[serde(default)]
+    pub asset_closing_amount: Option<u64>,
+
+    #[serde(rename = "receiver-rewards")]
+    #[serde(default)]
+    pub receiver_rewards: Option<MicroAlgos>,
+
+    #[serde(rename = "sender-rewards")]
+    #[serde(default)]
+    pub sender_rewards: Option<MicroAlgos>,
+
+    #[serde(rename = "global-state-delta")]
+    #[serde(default)]
+    pub global_state_delta: Option<Vec<EvalDeltaKeyValue>>,
+
+    #[serde(rename = "local-state-delta")]
+    #[serde(default)]
+    pub local_state_delta: Option<Vec<AccountStateDelta>>,
+
+    #[serde_as(as = "Option<Vec<Base64Bytes>>")]
+    #[serde(default)]
+    pub logs: Option<Vec<Vec<u8>>>,
+
+    #[serde(rename = "inner-txns")]
+    #[serde(default)]
+    pub inner_txns: Option<Vec<PendingTransactionResponse>>,
+}
+
+impl AlgorandMsgpack for PendingTransactionResponse {
+    const PREFIX: &'static [u8] = b"";
+}
+
+impl PendingTransactionResponse {
+    pub fn from_json(json: &str) -> Result<Self, PendingTransactionError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn to_json(&self) -> Result<String, PendingTransactionError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Whether the transaction has been committed to a block
+    pub fn is_confirmed(&self) -> bool {
+        self.confirmed_round.is_some()
+    }
+
+    /// Whether the transaction was removed from the pool without being committed
+    pub fn is_rejected(&self) -> bool {
+        !self.pool_error.is_empty()
+    }
+
+    /// The effects of the transaction as they are recorded in a block, including those of its
+    /// inner transactions
+    pub fn apply_data(&self) -> ApplyData {
+        ApplyData {
+            closing_amount: self.closing_amount.unwrap_or_default(),
+            asset_closing_amount: self.asset_closing_amount.unwrap_or_default(),
+            sender_rewards: self.sender_rewards.unwrap_or_default(),
+            receiver_rewards: self.receiver_rewards.unwrap_or_default(),
+            close_rewards: self.close_rewards.unwrap_or_default(),
+            eval_delta: self.eval_delta(),
+            config_asset: self.asset_index.unwrap_or_default(),
+            application_id: self.application_index.unwrap_or_default(),
+        }
+    }
+
+    /// The signed transaction together with `apply_data`, as it appears in a block
+    pub fn signed_transaction_with_apply_data(&self) -> SignedTransactionWithApplyData {
+        SignedTransactionWithApplyData {
+            apply_data: self.apply_data(),
+            ..self.txn.clone()
+        }
+    }
+
+    /// The changes made by an application call. Blocks key local state changes by the index of
+    /// the account in the sender followed by the transaction's accounts, and accounts in
+    /// neither are appended as shared accounts
+    fn eval_delta(&self) -> Option<EvalDelta> {
+        if self.global_state_delta.is_none()
+            && self.local_state_delta.is_none()
+            && self.logs.is_none()
+            && self.inner_txns.is_none()
+        {
+            return None;
+        }
+
+        let transaction = &self.txn.transaction;
+        let mut accounts = vec![transaction.header().sender.clone()];
+        if let Transaction::ApplicationCall(call) = transaction {
+            accounts.extend(call.accounts.iter().flatten().cloned());
+        }
+        let referenced = accounts.len();
+
+        let mut local_deltas = BTreeMap::new();
+        for account_delta in self.local_state_delta.iter().flatten() {
+            let index = match accounts.iter().position(|a| a == &account_delta.address) {
+                Some(index) => index,
+                None => {
+                    accounts.push(account_delta.address.clone());
+                    accounts.len() - 1
+                }
+            };
+            local_deltas.insert(index as u64, state_delta(&account_delta.delta));
+        }
+        let shared_accounts = accounts.split_off(referenced);
+
+        Some(EvalDelta {
+            global_delta: self.global_state_delta.as_deref().map(state_delta),
+            local_deltas: (!local_deltas.is_empty()).then_some(local_deltas),
+            shared_accounts: (!shared_accounts.is_empty()).then_some(shared_accounts),
+            logs: self.logs.clone(),
+            inner_transactions: self.inner_txns.as_ref().map(|inners| {
+                inners
+                    .iter()
+                    .map(Self::signed_transaction_with_apply_data)
+                    .collect()
+            }),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    // The same confirmed application call in both formats, with made-up accounts. It changed
+    // the local state of its sender, a referenced account and an unreferenced one, and sent an
+    // inner payment
+    const PENDING_JSON: &str = include_str!("../test_data/pending_transaction.json");
+    const PENDING_MSGPACK: &[u8] = include_bytes!("../test_data/pending_transaction.msgpack");
+
+    fn address(address: &str) -> Address {
+        Address::from_string(address).unwrap()
+    }
+
+    #[test]
+    fn test_pending_transaction() {
+        let pending = PendingTransactionResponse::decode(PENDING_MSGPACK).unwrap();
+        assert_eq!(
+            PendingTransactionResponse::from_json(PENDING_JSON).unwrap(),
+            pending
+        );
+        assert_eq!(pending.encode().unwrap(), PENDING_MSGPACK);
+        assert_eq!(
+            PendingTransactionResponse::from_json(&pending.to_json().unwrap()).unwrap(),
+            pending
+        );
+
+        assert!(pending.is_confirmed());
+        assert!(!pending.is_rejected());
+        assert_eq!(pending.confirmed_round, Some(46_000_003));
+        assert!(pending.txn.signature.is_some());
+
+        let inner = &pending.inner_txns.as_ref().unwrap()[0];
+        assert!(!inner.is_confirmed());
+        assert_eq!(inner.closing_amount, Some(MicroAlgos(5_000)));
+        assert_eq!(inner.txn.signature, None);
+
+        let rejected = PendingTransactionResponse {
+            confirmed_round: None,
+            pool_error: "transaction already in ledger".to_string(),
+            ..pending
+        };
+        assert!(!rejected.is_confirmed());
+        assert!(rejected.is_rejected());
+    }
+
+    #[test]
+    fn test_apply_data() {
+        let pending = PendingTransactionResponse::decode(PENDING_MSGPACK).unwrap();
+        let apply_data = pending.apply_data();
+        assert_eq!(apply_data.closing_amount, MicroAlgos::ZERO);
+        assert_eq!(apply_data.logs(), [b"settled".to_vec(), vec![0; 8]]);
+
+        let carol = address("A5GYGXI6K5GQ6WY6ML3BZ3KGPV6S4KODPTXXRZMONEFRCSYDPXKOG5G2GM");
+        let delta = apply_data.eval_delta.as_ref().unwrap();
+        let global_delta = delta.global_delta.as_ref().unwrap();
+        assert_eq!(
+            global_delta.get(b"owner".as_slice()),
+            Some(&ValueDelta {
+                action: ValueDeltaAction::SetBytes,
+                bytes: Some(carol.pub_key.to_vec()),
+                uint: 0
+            })
+        );
+        assert_eq!(
+            global_delta.get(b"pending".as_slice()).map(|d| d.action),
+            Some(ValueDeltaAction::Delete)
+        );
+
+        // The sender is account 0 and the referenced account 1, so the unreferenced account
+        // is shared as account 2
+        let local_deltas = delta.local_deltas.as_ref().unwrap();
+        let changed = |index: u64| local_deltas[&index].keys().next().unwrap().clone();
+        assert_eq!(local_deltas.len(), 3);
+        assert_eq!(changed(0), b"balance");
+        assert_eq!(changed(1), b"settled");
+        assert_eq!(local_deltas[&2][b"balance".as_slice()].uint, 250_000);
+        assert_eq!(delta.shared_accounts, Some(vec![carol]));
+
+        let inners = apply_data.inner_transactions();
+        assert_eq!(inners.len(), 1);
+        assert_eq!(inners[0].apply_data.closing_amount, MicroAlgos(5_000));
+        assert_eq!(inners[0].apply_data.eval_delta, None);
+
+        let with_apply_data = pending.signed_transaction_with_apply_data();
+        assert_eq!(with_apply_data.transaction, pending.txn.transaction);
+        assert_eq!(with_apply_data.apply_data, apply_data);
+    }
+}
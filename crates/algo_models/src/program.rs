@@ -0,0 +1,757 @@
+//! Compiled TEAL programs, as used by logic signatures and the approval and clear state programs
+//! of applications.
+//!
+//! A program starts with its version as a varuint, followed by the bytecode. `Program::new`
+//! checks the bytecode against the opcode table for that version, so a `Program` always holds
+//! well-formed bytecode whose instructions can be walked without further checks.
+
+use crate::consensus::ConsensusParams;
+use crate::Address;
+use sha2::{Digest, Sha512_256};
+use std::collections::HashSet;
+use thiserror::Error;
+
+mod assembler;
+mod disassembler;
+pub mod opcodes;
+use opcodes::{FieldSpec, Immediate, OpSpec};
+
+/// The highest program version supported by this crate
+pub const MAX_PROGRAM_VERSION: u64 = 11;
+
+/// The maximum size of a logic signature, which includes its program and arguments
+pub const LOGIC_SIG_MAX_SIZE: usize = 1000;
+
+/// The size of an application program page. The approval and clear state programs together
+/// may use one page, plus any extra pages requested when the application is created
+pub const APP_PAGE_MAX_SIZE: usize = 2048;
+
+pub const MAX_EXTRA_APP_PROGRAM_PAGES: u32 = 3;
+
+const PROGRAM_PREFIX: &[u8] = b"Program";
+
+/// Programs before this version can only branch forwards
+const BACKWARD_BRANCH_VERSION: u64 = 4;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum ProgramError {
+    #[error("program is empty")]
+    Empty,
+
+    #[error("program does not start with a valid version")]
+    InvalidVersion,
+
+    #[error("program version {0} is not supported, the maximum is {MAX_PROGRAM_VERSION}")]
+    UnsupportedVersion(u64),
+
+    #[error("unknown opcode 0x{opcode:02x} at pc {pc}")]
+    UnknownOpcode { opcode: u8, pc: usize },
+
+    #[error("{name} at pc {pc} requires version {required}, but the program is version {version}")]
+    OpcodeNotAvailable {
+        name: &'static str,
+        pc: usize,
+        required: u64,
+        version: u64,
+    },
+
+    #[error("{name} at pc {pc} refers to unknown field {field}")]
+    UnknownField {
+        name: &'static str,
+        pc: usize,
+        field: u8,
+    },
+
+    #[error("{name} {field} at pc {pc} requires version {required}, but the program is version {version}")]
+    FieldNotAvailable {
+        name: &'static str,
+        field: &'static str,
+        pc: usize,
+        required: u64,
+        version: u64,
+    },
+
+    #[error("{name} at pc {pc} has truncated immediate arguments")]
+    TruncatedImmediate { name: &'static str, pc: usize },
+
+    #[error("{name} at pc {pc} branches to {target}, which is not the start of an instruction")]
+    InvalidBranch {
+        name: &'static str,
+        pc: usize,
+        target: i64,
+    },
+
+    #[error(
+        "{name} at pc {pc} branches backwards, which requires version {BACKWARD_BRANCH_VERSION}"
+    )]
+    BackwardBranch { name: &'static str, pc: usize },
+
+    #[error("{kind} is {size} bytes, which exceeds the limit of {limit} bytes")]
+    TooLarge {
+        kind: &'static str,
+        size: usize,
+        limit: usize,
+    },
+
+    #[error("logic signatures are limited to version {max}, but the program is version {version}")]
+    LogicSigVersion { version: u64, max: u64 },
+
+    #[error("line {line}: {message}")]
+    Assembly { line: usize, message: String },
+}
+
+/// The decoded value of an immediate argument
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImmediateValue {
+    Uint8(u8),
+
+    Int8(i8),
+
+    Varuint(u64),
+
+    Bytes(Vec<u8>),
+
+    /// The absolute pc that the label refers to
+    Label(usize),
+
+    Varuints(Vec<u64>),
+
+    ByteStrings(Vec<Vec<u8>>),
+
+    Labels(Vec<usize>),
+
+    Field(&'static FieldSpec),
+}
+
+/// A single instruction of a program
+#[derive(Debug, Clone, PartialEq)]
+pub struct Instruction {
+    pub pc: usize,
+    pub op: &'static OpSpec,
+    pub immediates: Vec<ImmediateValue>,
+
+    /// The size of the opcode and its immediates in bytes
+    pub size: usize,
+}
+
+/// Read a varuint (unsigned LEB128) from the start of `bytes`, returning the value and the number
+/// of bytes read
+pub fn read_varuint(bytes: &[u8]) -> Option<(u64, usize)> {
+    let mut value = 0u64;
+
+    for (i, byte) in bytes.iter().enumerate().take(10) {
+        let bits = (*byte & 0x7f) as u64;
+
+        // The tenth byte can only hold the most significant bit
+        if i == 9 && *byte > 1 {
+            return None;
+        }
+
+        value |= bits << (7 * i);
+
+        if byte & 0x80 == 0 {
+            return Some((value, i + 1));
+        }
+    }
+
+    None
+}
+
+/// Encode a value as a varuint (unsigned LEB128)
+pub fn write_varuint(mut value: u64, out: &mut Vec<u8>) {
+    while value >= 0x80 {
+        out.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Reader<'_> {
+    fn take(&mut self, n: usize) -> Option<&[u8]> {
+        let end = self.pos.checked_add(n)?;
+        let taken = self.bytes.get(self.pos..end)?;
+        self.pos = end;
+        Some(taken)
+    }
+
+    fn byte(&mut self) -> Option<u8> {
+        self.take(1).map(|b| b[0])
+    }
+
+    fn varuint(&mut self) -> Option<u64> {
+        let (value, read) = read_varuint(self.bytes.get(self.pos..)?)?;
+        self.pos += read;
+        Some(value)
+    }
+
+    fn bytes(&mut self) -> Option<Vec<u8>> {
+        let length = self.varuint()?;
+        self.take(usize::try_from(length).ok()?).map(<[u8]>::to_vec)
+    }
+
+    fn offset(&mut self) -> Option<i16> {
+        self.take(2).map(|b| i16::from_be_bytes([b[0], b[1]]))
+    }
+
+    /// Read `count` values, checking that at least `count` bytes remain first so a bogus count
+    /// cannot cause a huge allocation
+    fn many<T>(
+        &mut self,
+        count: u64,
+        mut read: impl FnMut(&mut Self) -> Option<T>,
+    ) -> Option<Vec<T>> {
+        if count > (self.bytes.len() - self.pos) as u64 {
+            return None;
+        }
+        (0..count).map(|_| read(self)).collect()
+    }
+}
+
+/// Decode the instruction at `pc`, returning the instruction and the raw branch targets, which
+/// are only range checked by `Program::new`
+fn decode_instruction(
+    bytes: &[u8],
+    pc: usize,
+    version: u64,
+) -> Result<(Instruction, Vec<i64>), ProgramError> {
+    let opcode = bytes[pc];
+    let op = opcodes::op_by_code(opcode).ok_or(ProgramError::UnknownOpcode { opcode, pc })?;
+
+    if op.version > version {
+        return Err(ProgramError::OpcodeNotAvailable {
+            name: op.name,
+            pc,
+            required: op.version,
+            version,
+        });
+    }
+
+    let truncated = || ProgramError::TruncatedImmediate { name: op.name, pc };
+    let mut reader = Reader { bytes, pos: pc + 1 };
+    let mut immediates = Vec::with_capacity(op.immediates.len());
+    let mut offsets = vec![];
+
+    for immediate in op.immediates {
+        let value = match immediate {
+            Immediate::Uint8 => reader.byte().map(ImmediateValue::Uint8),
+            Immediate::Int8 => reader.byte().map(|b| ImmediateValue::Int8(b as i8)),
+            Immediate::Varuint => reader.varuint().map(ImmediateValue::Varuint),
+            Immediate::Bytes => reader.bytes().map(ImmediateValue::Bytes),
+            Immediate::Label => reader.offset().map(|offset| {
+                offsets.push(offset);
+                ImmediateValue::Label(0)
+            }),
+            Immediate::Varuints => reader
+                .varuint()
+                .and_then(|count| reader.many(count, Reader::varuint))
+                .map(ImmediateValue::Varuints),
+            Immediate::ByteStrings => reader
+                .varuint()
+                .and_then(|count| reader.many(count, Reader::bytes))
+                .map(ImmediateValue::ByteStrings),
+            Immediate::Labels => reader
+                .byte()
+                .and_then(|count| reader.many(count as u64, Reader::offset))
+                .map(|labels| {
+                    offsets.extend(&labels);
+                    ImmediateValue::Labels(vec![0; labels.len()])
+                }),
+            Immediate::Field(group) => match reader.byte() {
+                Some(index) => {
+                    let field = group.field(index).ok_or(ProgramError::UnknownField {
+                        name: op.name,
+                        pc,
+                        field: index,
+                    })?;
+
+                    if field.version > version {
+                        return Err(ProgramError::FieldNotAvailable {
+                            name: op.name,
+                            field: field.name,
+                            pc,
+                            required: field.version,
+                            version,
+                        });
+                    }
+
+                    Some(ImmediateValue::Field(field))
+                }
+                None => None,
+            },
+        };
+        immediates.push(value.ok_or_else(truncated)?);
+    }
+
+    // Offsets are relative to the end of the instruction
+    let end = reader.pos;
+    let targets: Vec<i64> = offsets.iter().map(|o| end as i64 + *o as i64).collect();
+    let mut resolved = targets.iter().map(|t| (*t).max(0) as usize);
+
+    for immediate in immediates.iter_mut() {
+        match immediate {
+            ImmediateValue::Label(target) => *target = resolved.next().unwrap_or_default(),
+            ImmediateValue::Labels(labels) => labels
+                .iter_mut()
+                .for_each(|target| *target = resolved.next().unwrap_or_default()),
+            _ => {}
+        }
+    }
+
+    Ok((
+        Instruction {
+            pc,
+            op,
+            immediates,
+            size: end - pc,
+        },
+        targets,
+    ))
+}
+
+/// A compiled TEAL program with well-formed bytecode
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Program {
+    bytes: Vec<u8>,
+    version: u64,
+    header_size: usize,
+}
+
+impl Program {
+    /// Parse the version of the program and check its bytecode against the opcode table for
+    /// that version
+    pub fn new(bytes: Vec<u8>) -> Result<Self, ProgramError> {
+        if bytes.is_empty() {
+            return Err(ProgramError::Empty);
+        }
+
+        let (version, header_size) = read_varuint(&bytes).ok_or(ProgramError::InvalidVersion)?;
+
+        if version == 0 || version > MAX_PROGRAM_VERSION {
+            return Err(ProgramError::UnsupportedVersion(version));
+        }
+
+        let mut starts = HashSet::new();
+        let mut branches = vec![];
+        let mut pc = header_size;
+
+        while pc < bytes.len() {
+            let (instruction, targets) = decode_instruction(&bytes, pc, version)?;
+            starts.insert(pc);
+            branches.extend(targets.into_iter().map(|t| (instruction.op.name, pc, t)));
+            pc += instruction.size;
+        }
+
+        // Branching to the end of the program is allowed and ends execution
+        starts.insert(bytes.len());
+
+        for (name, pc, target) in branches {
+            if target <= pc as i64 && version < BACKWARD_BRANCH_VERSION {
+                return Err(ProgramError::BackwardBranch { name, pc });
+            }
+
+            if target < 0 || !starts.contains(&(target as usize)) {
+                return Err(ProgramError::InvalidBranch { name, pc, target });
+            }
+        }
+
+        Ok(Program {
+            bytes,
+            version,
+            header_size,
+        })
+    }
+
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// The size of the program in bytes, including the version
+    pub fn size(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// The instructions of the program, in order
+    pub fn instructions(&self) -> Vec<Instruction> {
+        let mut instructions = vec![];
+        let mut pc = self.header_size;
+
+        while pc < self.bytes.len() {
+            let (instruction, _) = decode_instruction(&self.bytes, pc, self.version)
+                .expect("program bytecode is checked when it is created");
+            pc += instruction.size;
+            instructions.push(instruction);
+        }
+
+        instructions
+    }
+
+    /// The SHA-512/256 hash of "Program" followed by the program bytes
+    pub fn hash(&self) -> [u8; 32] {
+        let mut hasher = Sha512_256::new();
+        hasher.update(PROGRAM_PREFIX);
+        hasher.update(&self.bytes);
+        hasher.finalize().into()
+    }
+
+    /// The address of the contract account controlled by this program when it is used as a
+    /// logic signature
+    pub fn address(&self) -> Address {
+        Address {
+            pub_key: self.hash(),
+        }
+    }
+
+    /// Check that the program fits in a logic signature. The arguments of the logic signature
+    /// also count towards the limit, so pass their total size in `args_size`
+    pub fn check_logic_sig_size(&self, args_size: usize) -> Result<(), ProgramError> {
+        check_logic_sig_size(self.size() + args_size, LOGIC_SIG_MAX_SIZE)
+    }
+
+    /// Check that the program can be used in a logic signature under the rules of a consensus
+    /// version, which limit both its version and its size along with `args_size`
+    pub fn check_logic_sig(
+        &self,
+        args_size: usize,
+        params: &ConsensusParams,
+    ) -> Result<(), ProgramError> {
+        if self.version > params.logic_sig_version {
+            return Err(ProgramError::LogicSigVersion {
+                version: self.version,
+                max: params.logic_sig_version,
+            });
+        }
+        check_logic_sig_size(self.size() + args_size, params.logic_sig_max_size)
+    }
+
+    /// The number of extra program pages an application needs for these approval and clear
+    /// state programs
+    pub fn extra_pages(approval: &Program, clear_state: &Program) -> Result<u32, ProgramError> {
+        let size = approval.size() + clear_state.size();
+        let limit = APP_PAGE_MAX_SIZE * (1 + MAX_EXTRA_APP_PROGRAM_PAGES as usize);
+
+        if size > limit {
+            return Err(ProgramError::TooLarge {
+                kind: "application programs",
+                size,
+                limit,
+            });
+        }
+
+        Ok((size.saturating_sub(1) / APP_PAGE_MAX_SIZE) as u32)
+    }
+}
+
+fn check_logic_sig_size(size: usize, limit: usize) -> Result<(), ProgramError> {
+    if size > limit {
+        return Err(ProgramError::TooLarge {
+            kind: "logic signature",
+            size,
+            limit,
+        });
+    }
+    Ok(())
+}
+
+impl TryFrom<Vec<u8>> for Program {
+    type Error = ProgramError;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        Program::new(bytes)
+    }
+}
+
+impl TryFrom<&[u8]> for Program {
+    type Error = ProgramError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Program::new(bytes.to_vec())
+    }
+}
+
+impl From<Program> for Vec<u8> {
+    fn from(program: Program) -> Self {
+        program.bytes
+    }
+}
+
+impl AsRef<[u8]> for Program {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opcodes::FieldGroup;
+    use pretty_assertions::assert_eq;
+
+    // #pragma version 2; int 1
+    const INT_1: [u8; 5] = [0x02, 0x20, 0x01, 0x01, 0x22];
+
+    #[test]
+    fn test_varuint() {
+        for value in [0, 1, 127, 128, 300, u32::MAX as u64, u64::MAX] {
+            let mut bytes = vec![];
+            write_varuint(value, &mut bytes);
+            assert_eq!(read_varuint(&bytes), Some((value, bytes.len())));
+        }
+
+        assert_eq!(read_varuint(&[0xac, 0x02, 0xff]), Some((300, 2)));
+        assert_eq!(read_varuint(&[0x80]), None);
+        assert_eq!(read_varuint(&[0xff; 10]), None);
+    }
+
+    #[test]
+    fn test_instructions() {
+        // #pragma version 8; pushbytes "hi"; pushints 1 2; switch l1 l1; l1: frame_dig -1
+        let program = Program::new(vec![
+            0x08, 0x80, 0x02, b'h', b'i', 0x83, 0x02, 0x01, 0x02, 0x8d, 0x02, 0x00, 0x02, 0x00,
+            0x00, 0x8b, 0xff,
+        ])
+        .unwrap();
+
+        let instructions = program.instructions();
+        assert_eq!(
+            instructions
+                .iter()
+                .map(|i| (i.pc, i.op.name, i.immediates.clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                (1, "pushbytes", vec![ImmediateValue::Bytes(b"hi".to_vec())]),
+                (5, "pushints", vec![ImmediateValue::Varuints(vec![1, 2])]),
+                (9, "switch", vec![ImmediateValue::Labels(vec![17, 15])]),
+                (15, "frame_dig", vec![ImmediateValue::Int8(-1)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_programs() {
+        assert_eq!(Program::new(vec![]), Err(ProgramError::Empty));
+        assert_eq!(Program::new(vec![0x80]), Err(ProgramError::InvalidVersion));
+        assert_eq!(
+            Program::new(vec![12, 0x01]),
+            Err(ProgramError::UnsupportedVersion(12))
+        );
+        assert_eq!(
+            Program::new(vec![0x0b, 0x87]),
+            Err(ProgramError::UnknownOpcode {
+                opcode: 0x87,
+                pc: 1
+            })
+        );
+        assert_eq!(
+            Program::new(vec![0x05, 0x81, 0x01, 0xb9]),
+            Err(ProgramError::OpcodeNotAvailable {
+                name: "box_create",
+                pc: 3,
+                required: 8,
+                version: 5
+            })
+        );
+        assert_eq!(
+            Program::new(vec![0x0b, 0x32, 0x40]),
+            Err(ProgramError::UnknownField {
+                name: "global",
+                pc: 1,
+                field: 64
+            })
+        );
+        assert_eq!(
+            Program::new(vec![0x06, 0x31, 0x03]),
+            Err(ProgramError::FieldNotAvailable {
+                name: "txn",
+                field: "FirstValidTime",
+                pc: 1,
+                required: 7,
+                version: 6
+            })
+        );
+        assert_eq!(
+            Program::new(vec![0x03, 0x80, 0x05, 0x01]),
+            Err(ProgramError::TruncatedImmediate {
+                name: "pushbytes",
+                pc: 1
+            })
+        );
+        assert_eq!(
+            Program::new(vec![0x08, 0x26, 0xff, 0xff, 0x03]),
+            Err(ProgramError::TruncatedImmediate {
+                name: "bytecblock",
+                pc: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_fields() {
+        // #pragma version 2; gtxn 1 Sender; txna ApplicationArgs 0
+        let program = Program::new(vec![0x02, 0x33, 0x01, 0x00, 0x36, 0x1a, 0x00]).unwrap();
+        let field = |group: FieldGroup, name| group.field_by_name(name).unwrap().1;
+        assert_eq!(
+            program
+                .instructions()
+                .iter()
+                .map(|i| (i.op.name, i.immediates.clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    "gtxn",
+                    vec![
+                        ImmediateValue::Uint8(1),
+                        ImmediateValue::Field(field(FieldGroup::Txn, "Sender"))
+                    ]
+                ),
+                (
+                    "txna",
+                    vec![
+                        ImmediateValue::Field(field(FieldGroup::Txn, "ApplicationArgs")),
+                        ImmediateValue::Uint8(0)
+                    ]
+                ),
+            ]
+        );
+
+        // Fields are numbered as in go-algorand
+        assert_eq!(
+            FieldGroup::Txn.field_by_name("ApplicationArgs").unwrap().0,
+            26
+        );
+        assert_eq!(FieldGroup::Global.field_by_name("GroupID").unwrap().0, 11);
+        assert_eq!(
+            FieldGroup::Txn.field(3).map(|f| f.name),
+            Some("FirstValidTime")
+        );
+
+        // #pragma version 4; int 0; asset_params_get AssetCreator
+        assert_eq!(
+            Program::new(vec![0x04, 0x81, 0x00, 0x71, 0x0b]),
+            Err(ProgramError::FieldNotAvailable {
+                name: "asset_params_get",
+                field: "AssetCreator",
+                pc: 3,
+                required: 5,
+                version: 4
+            })
+        );
+        assert!(Program::new(vec![0x05, 0x81, 0x00, 0x71, 0x0b]).is_ok());
+
+        // #pragma version 11; int 0; acct_params_get 255
+        assert_eq!(
+            Program::new(vec![0x0b, 0x81, 0x00, 0x73, 0xff]),
+            Err(ProgramError::UnknownField {
+                name: "acct_params_get",
+                pc: 3,
+                field: 255
+            })
+        );
+        assert_eq!(
+            Program::new(vec![0x02, 0x31]),
+            Err(ProgramError::TruncatedImmediate { name: "txn", pc: 1 })
+        );
+    }
+
+    #[test]
+    fn test_branches() {
+        // b 1, which lands in the middle of pushint
+        assert_eq!(
+            Program::new(vec![0x04, 0x42, 0x00, 0x01, 0x81, 0x01]),
+            Err(ProgramError::InvalidBranch {
+                name: "b",
+                pc: 1,
+                target: 5
+            })
+        );
+        assert_eq!(
+            Program::new(vec![0x04, 0x42, 0xff, 0xf0]),
+            Err(ProgramError::InvalidBranch {
+                name: "b",
+                pc: 1,
+                target: -12
+            })
+        );
+
+        // l1: int 1; bnz l1
+        let backwards = |version| vec![version, 0x81, 0x01, 0x40, 0xff, 0xfb];
+        assert_eq!(
+            Program::new(backwards(3)),
+            Err(ProgramError::BackwardBranch { name: "bnz", pc: 3 })
+        );
+        assert_eq!(Program::new(backwards(4)).unwrap().version(), 4);
+
+        // Branching to the end of the program is allowed
+        assert!(Program::new(vec![0x02, 0x42, 0x00, 0x00]).is_ok());
+    }
+
+    #[test]
+    fn test_hash_and_address() {
+        let program = Program::new(INT_1.to_vec()).unwrap();
+
+        assert_eq!(program.version(), 2);
+        assert_eq!(
+            program.address().address(),
+            "YOE6C22GHCTKAN3HU4SE5PGIPN5UKXAJTXCQUPJ3KKF5HOAH646MKKCPDA"
+        );
+        assert_eq!(
+            program.address(),
+            Address::from_domain_hash(PROGRAM_PREFIX, &INT_1)
+        );
+    }
+
+    #[test]
+    fn test_size_limits() {
+        // #pragma version 2 followed by enough err opcodes to reach the size
+        let sized = |size: usize| {
+            let mut bytes = vec![0x02];
+            bytes.resize(size, 0x00);
+            Program::new(bytes).unwrap()
+        };
+
+        assert_eq!(sized(990).check_logic_sig_size(10), Ok(()));
+        assert_eq!(
+            sized(990).check_logic_sig_size(11),
+            Err(ProgramError::TooLarge {
+                kind: "logic signature",
+                size: 1001,
+                limit: 1000
+            })
+        );
+
+        // Under older consensus rules logic signatures were limited to earlier versions
+        let v18 = ConsensusParams::for_version("v18").unwrap();
+        assert_eq!(
+            sized(990).check_logic_sig(10, &ConsensusParams::default()),
+            Ok(())
+        );
+        assert_eq!(
+            sized(990).check_logic_sig(10, &v18),
+            Err(ProgramError::LogicSigVersion { version: 2, max: 1 })
+        );
+
+        assert_eq!(Program::extra_pages(&sized(2000), &sized(48)), Ok(0));
+        assert_eq!(Program::extra_pages(&sized(2000), &sized(49)), Ok(1));
+        assert_eq!(Program::extra_pages(&sized(8000), &sized(192)), Ok(3));
+        assert_eq!(
+            Program::extra_pages(&sized(8000), &sized(193)),
+            Err(ProgramError::TooLarge {
+                kind: "application programs",
+                size: 8193,
+                limit: 8192
+            })
+        );
+    }
+}
@@ -0,0 +1,1196 @@
+//! Assembly of TEAL source into program bytecode, producing the same bytes as algod's
+//! `/v2/teal/compile` and `goal clerk compile`.
+//!
+//! Like algod, the `int`, `byte`, `addr` and `method` pseudo-ops collect their constants into an
+//! `intcblock` and `bytecblock` at the start of the program. From version 4, constants that are
+//! only used once are pushed with `pushint`/`pushbytes` instead, and the rest are ordered by how
+//! often they are used.
+
+use super::opcodes::{self, FieldGroup, Immediate, OpSpec};
+use super::{write_varuint, Program, ProgramError, BACKWARD_BRANCH_VERSION, MAX_PROGRAM_VERSION};
+use crate::Address;
+use base64::{prelude::BASE64_STANDARD, Engine};
+use sha2::{Digest, Sha512_256};
+use std::collections::{HashMap, HashSet};
+
+/// The version of programs without a `#pragma version`
+const DEFAULT_VERSION: u64 = 1;
+
+/// From this version, constants are ordered by frequency and single-use constants are pushed
+const OPTIMIZE_CONSTANTS_VERSION: u64 = 4;
+
+const PSEUDO_OPS: [&str; 4] = ["int", "byte", "addr", "method"];
+
+/// Transaction fields that hold arrays, which are read with `txna` and friends
+const ARRAY_TXN_FIELDS: [&str; 7] = [
+    "ApplicationArgs",
+    "Accounts",
+    "Assets",
+    "Applications",
+    "Logs",
+    "ApprovalProgramPages",
+    "ClearStateProgramPages",
+];
+
+/// Named constants accepted by `int`, for transaction types and `OnCompletion` values
+const NAMED_INTS: [(&str, u64); 15] = [
+    ("unknown", 0),
+    ("pay", 1),
+    ("keyreg", 2),
+    ("acfg", 3),
+    ("axfer", 4),
+    ("afrz", 5),
+    ("appl", 6),
+    ("stpf", 7),
+    ("hb", 8),
+    ("NoOp", 0),
+    ("OptIn", 1),
+    ("CloseOut", 2),
+    ("ClearState", 3),
+    ("UpdateApplication", 4),
+    ("DeleteApplication", 5),
+];
+
+enum Item {
+    /// An encoded instruction, followed by a 2-byte offset for each label it references
+    Op {
+        bytes: Vec<u8>,
+        labels: Vec<String>,
+        line: usize,
+    },
+
+    /// A constant from the `int` pseudo-op
+    Int(u64),
+
+    /// A constant from the `byte`, `addr` or `method` pseudo-ops
+    Byte(Vec<u8>),
+
+    Label(String),
+}
+
+/// Constants used by pseudo-ops, in the order they are first used, or the explicit constant
+/// block if the program has one
+#[derive(Default)]
+struct Constants<T> {
+    values: Vec<T>,
+    uses: Vec<usize>,
+    explicit_blocks: usize,
+}
+
+impl<T: PartialEq + Clone> Constants<T> {
+    fn record(&mut self, value: &T) {
+        match self.values.iter().position(|v| v == value) {
+            Some(i) => self.uses[i] += 1,
+            None => {
+                self.values.push(value.clone());
+                self.uses.push(1);
+            }
+        }
+    }
+
+    /// The constants to put in the constant block at the start of the program
+    fn block(&self, version: u64) -> Vec<T> {
+        if self.explicit_blocks > 0 {
+            return vec![];
+        }
+
+        if version < OPTIMIZE_CONSTANTS_VERSION {
+            return self.values.clone();
+        }
+
+        // A stable sort keeps constants used equally often in the order they were first used
+        let mut by_uses: Vec<(usize, &T)> = self.uses.iter().copied().zip(&self.values).collect();
+        by_uses.sort_by(|a, b| b.0.cmp(&a.0));
+        by_uses
+            .into_iter()
+            .take_while(|(uses, _)| *uses > 1)
+            .map(|(_, value)| value.clone())
+            .collect()
+    }
+}
+
+fn error(line: usize, message: impl Into<String>) -> ProgramError {
+    ProgramError::Assembly {
+        line,
+        message: message.into(),
+    }
+}
+
+/// Split a line into tokens, keeping quoted strings together and `;` as its own token, and
+/// dropping comments
+fn tokenize(line: &str) -> Result<Vec<String>, String> {
+    let mut tokens = vec![];
+    let mut current = String::new();
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                current.push(c);
+                let mut closed = false;
+                while let Some(c) = chars.next() {
+                    current.push(c);
+                    match c {
+                        '\\' => current.extend(chars.next()),
+                        '"' => {
+                            closed = true;
+                            break;
+                        }
+                        _ => {}
+                    }
+                }
+                if !closed {
+                    return Err(format!("unterminated string: {current}"));
+                }
+            }
+            '/' if chars.peek() == Some(&'/') => break,
+            ';' => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+                tokens.push(";".to_string());
+            }
+            c if c.is_whitespace() => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+
+    Ok(tokens)
+}
+
+/// Parse an unsigned integer the way Go's `strconv.ParseUint(s, 0, 64)` does, with `0x`, `0o`,
+/// `0b` and leading `0` (octal) prefixes and `_` separators
+fn parse_uint(s: &str) -> Option<u64> {
+    let (digits, radix) = match s.get(..2).map(str::to_ascii_lowercase).as_deref() {
+        Some("0x") => (&s[2..], 16),
+        Some("0o") => (&s[2..], 8),
+        Some("0b") => (&s[2..], 2),
+        _ if s.len() > 1 && s.starts_with('0') => (&s[1..], 8),
+        _ => (s, 10),
+    };
+
+    if digits.starts_with('_') && radix == 10
+        || digits.ends_with('_')
+        || digits.contains("__")
+        || digits.is_empty()
+    {
+        return None;
+    }
+
+    let digits = digits.replace('_', "");
+    if !digits.chars().all(|c| c.is_digit(radix)) {
+        return None;
+    }
+
+    u64::from_str_radix(&digits, radix).ok()
+}
+
+fn parse_string_literal(token: &str) -> Result<Vec<u8>, String> {
+    let inner = token
+        .strip_prefix('"')
+        .and_then(|t| t.strip_suffix('"'))
+        .filter(|_| token.len() >= 2)
+        .ok_or_else(|| format!("string literal must be quoted: {token}"))?;
+
+    let mut out = vec![];
+    let mut chars = inner.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            let mut buf = [0; 4];
+            out.extend(c.encode_utf8(&mut buf).as_bytes());
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => out.push(b'\n'),
+            Some('r') => out.push(b'\r'),
+            Some('t') => out.push(b'\t'),
+            Some('\\') => out.push(b'\\'),
+            Some('"') => out.push(b'"'),
+            Some('x') => {
+                let hex: String = chars.by_ref().take(2).collect();
+                let byte = u8::from_str_radix(&hex, 16)
+                    .ok()
+                    .filter(|_| hex.len() == 2)
+                    .ok_or_else(|| format!("invalid escape sequence \\x{hex}"))?;
+                out.push(byte);
+            }
+            Some(c) => return Err(format!("invalid escape sequence \\{c}")),
+            None => return Err("string literal ends with a backslash".to_string()),
+        }
+    }
+
+    Ok(out)
+}
+
+fn parse_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 || !hex.is_ascii() {
+        return None;
+    }
+
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
+
+fn decode_base32(encoded: &str) -> Option<Vec<u8>> {
+    base32::decode(
+        base32::Alphabet::Rfc4648 { padding: false },
+        encoded.trim_end_matches('='),
+    )
+}
+
+/// Parse a byte constant from the start of `args`, returning the bytes and the number of tokens
+/// used. Constants can be `0x` hex, a quoted string, or base64/base32 in the `base64 ...`,
+/// `b64 ...`, `base64(...)` and `b64(...)` forms
+fn parse_bytes(args: &[String]) -> Result<(Vec<u8>, usize), String> {
+    let arg = args
+        .first()
+        .ok_or_else(|| "expected a byte constant".to_string())?;
+
+    let enclosed = |prefixes: [&str; 2]| {
+        prefixes.iter().find_map(|p| {
+            arg.strip_prefix(p)
+                .and_then(|a| a.strip_prefix('('))
+                .and_then(|a| a.strip_suffix(')'))
+        })
+    };
+
+    let (bytes, used) = if let Some(encoded) = enclosed(["base64", "b64"]) {
+        (BASE64_STANDARD.decode(encoded).ok(), 1)
+    } else if let Some(encoded) = enclosed(["base32", "b32"]) {
+        (decode_base32(encoded), 1)
+    } else if matches!(arg.as_str(), "base64" | "b64" | "base32" | "b32") {
+        let encoded = args
+            .get(1)
+            .ok_or_else(|| format!("{arg} needs an encoded argument"))?;
+        if arg.starts_with("b64") || arg.starts_with("base64") {
+            (BASE64_STANDARD.decode(encoded).ok(), 2)
+        } else {
+            (decode_base32(encoded), 2)
+        }
+    } else if let Some(hex) = arg.strip_prefix("0x") {
+        (parse_hex(hex), 1)
+    } else if arg.starts_with('"') {
+        (Some(parse_string_literal(arg)?), 1)
+    } else {
+        (None, 1)
+    };
+
+    bytes
+        .map(|bytes| (bytes, used))
+        .ok_or_else(|| format!("byte constant did not parse: {}", args[..used].join(" ")))
+}
+
+fn parse_uint8(arg: &str) -> Result<u8, String> {
+    let value = parse_uint(arg).ok_or_else(|| format!("unable to parse {arg:?} as an integer"))?;
+    u8::try_from(value).map_err(|_| format!("{value} is beyond 255"))
+}
+
+struct Assembler {
+    version: Option<u64>,
+    line: usize,
+    items: Vec<Item>,
+    ints: Constants<u64>,
+    bytes: Constants<Vec<u8>>,
+    labels: HashSet<String>,
+    macros: HashMap<String, Vec<String>>,
+}
+
+impl Assembler {
+    fn version(&mut self) -> u64 {
+        *self.version.get_or_insert(DEFAULT_VERSION)
+    }
+
+    fn op_spec(&mut self, name: &str) -> Result<&'static OpSpec, String> {
+        let spec = opcodes::op_by_name(name).ok_or_else(|| format!("unknown opcode: {name}"))?;
+        let version = self.version();
+
+        if spec.version > version {
+            return Err(format!(
+                "{name} requires version {}, but the program is version {version}",
+                spec.version
+            ));
+        }
+
+        Ok(spec)
+    }
+
+    fn push_op(&mut self, bytes: Vec<u8>, labels: Vec<String>) {
+        self.items.push(Item::Op {
+            bytes,
+            labels,
+            line: self.line,
+        });
+    }
+
+    fn expand_macros(&self, tokens: Vec<String>, line: usize) -> Result<Vec<String>, ProgramError> {
+        fn expand(
+            macros: &HashMap<String, Vec<String>>,
+            token: String,
+            seen: &mut Vec<String>,
+            out: &mut Vec<String>,
+        ) -> Result<(), String> {
+            let Some(body) = macros.get(&token) else {
+                out.push(token);
+                return Ok(());
+            };
+
+            if seen.contains(&token) {
+                return Err(format!("macro {token} expands to itself"));
+            }
+
+            seen.push(token);
+            for t in body {
+                expand(macros, t.clone(), seen, out)?;
+            }
+            seen.pop();
+            Ok(())
+        }
+
+        let mut out = vec![];
+        for token in tokens {
+            expand(&self.macros, token, &mut vec![], &mut out).map_err(|e| error(line, e))?;
+        }
+        Ok(out)
+    }
+
+    fn directive(&mut self, tokens: &[String], line: usize) -> Result<(), ProgramError> {
+        match tokens[0].as_str() {
+            "#pragma" => match tokens.get(1).map(String::as_str) {
+                Some("version") => {
+                    let version = tokens
+                        .get(2)
+                        .filter(|_| tokens.len() == 3)
+                        .and_then(|v| parse_uint(v))
+                        .ok_or_else(|| error(line, "#pragma version needs one integer"))?;
+
+                    if version == 0 || version > MAX_PROGRAM_VERSION {
+                        return Err(error(
+                            line,
+                            format!("unsupported version {version}, the maximum is {MAX_PROGRAM_VERSION}"),
+                        ));
+                    }
+
+                    match self.version {
+                        Some(current) if current == version && self.items.is_empty() => Ok(()),
+                        Some(_) if !self.items.is_empty() => Err(error(
+                            line,
+                            "#pragma version is only allowed before instructions",
+                        )),
+                        Some(current) => Err(error(
+                            line,
+                            format!("version mismatch: {current} and {version}"),
+                        )),
+                        None => {
+                            self.version = Some(version);
+                            Ok(())
+                        }
+                    }
+                }
+                Some("typetrack") => Ok(()),
+                _ => Err(error(
+                    line,
+                    format!("unknown directive: {}", tokens.join(" ")),
+                )),
+            },
+            "#define" => {
+                let name = tokens
+                    .get(1)
+                    .ok_or_else(|| error(line, "#define needs a name"))?;
+
+                let valid_name = name
+                    .chars()
+                    .next()
+                    .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+                    && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+                if !valid_name
+                    || opcodes::op_by_name(name).is_some()
+                    || PSEUDO_OPS.contains(&name.as_str())
+                {
+                    return Err(error(line, format!("invalid macro name: {name}")));
+                }
+
+                if tokens.len() < 3 {
+                    return Err(error(line, format!("macro {name} needs a value")));
+                }
+
+                self.macros.insert(name.clone(), tokens[2..].to_vec());
+                Ok(())
+            }
+            _ => Err(error(line, format!("unknown directive: {}", tokens[0]))),
+        }
+    }
+
+    fn line(&mut self, source: &str, line: usize) -> Result<(), ProgramError> {
+        self.line = line;
+        let tokens = tokenize(source).map_err(|e| error(line, e))?;
+
+        if tokens.first().is_some_and(|t| t.starts_with('#')) {
+            return self.directive(&tokens, line);
+        }
+
+        let tokens = self.expand_macros(tokens, line)?;
+
+        for statement in tokens.split(|t| t == ";") {
+            let mut statement = statement;
+
+            while let Some(label) = statement.first().and_then(|t| t.strip_suffix(':')) {
+                if label.is_empty() {
+                    break;
+                }
+                if !self.labels.insert(label.to_string()) {
+                    return Err(error(line, format!("duplicate label {label}")));
+                }
+                self.items.push(Item::Label(label.to_string()));
+                statement = &statement[1..];
+            }
+
+            if let Some((name, args)) = statement.split_first() {
+                self.op(name, args).map_err(|e| error(line, e))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn int_constant(&mut self, arg: &str) -> Result<(), String> {
+        let value = NAMED_INTS
+            .iter()
+            .find(|(name, _)| *name == arg)
+            .map(|(_, value)| *value)
+            .or_else(|| parse_uint(arg))
+            .ok_or_else(|| format!("unable to parse {arg:?} as an integer"))?;
+
+        let version = self.version();
+        if self.ints.explicit_blocks > 0 && version >= BACKWARD_BRANCH_VERSION {
+            // With back jumps we cannot know which explicit block is in effect, so push instead
+            let mut bytes = vec![self.op_spec("pushint")?.opcode];
+            write_varuint(value, &mut bytes);
+            self.push_op(bytes, vec![]);
+            return Ok(());
+        }
+
+        // Without back jumps one explicit block is in effect, but with several it depends on which
+        // branches are taken
+        if self.ints.explicit_blocks > 1 {
+            return Err(format!("int {arg} used with manual intcblocks, use intc"));
+        }
+
+        if self.ints.explicit_blocks > 0 {
+            let index = self
+                .ints
+                .values
+                .iter()
+                .position(|v| *v == value)
+                .ok_or_else(|| format!("int {value} is not in the intcblock, use intc"))?;
+            return self.constant_ref("intc", index);
+        }
+
+        self.ints.record(&value);
+        self.items.push(Item::Int(value));
+        Ok(())
+    }
+
+    fn byte_constant(&mut self, value: Vec<u8>) -> Result<(), String> {
+        let version = self.version();
+        if self.bytes.explicit_blocks > 0 && version >= BACKWARD_BRANCH_VERSION {
+            let mut bytes = vec![self.op_spec("pushbytes")?.opcode];
+            write_varuint(value.len() as u64, &mut bytes);
+            bytes.extend(value);
+            self.push_op(bytes, vec![]);
+            return Ok(());
+        }
+
+        if self.bytes.explicit_blocks > 1 {
+            return Err("byte constant used with manual bytecblocks, use bytec".to_string());
+        }
+
+        if self.bytes.explicit_blocks > 0 {
+            let index = self
+                .bytes
+                .values
+                .iter()
+                .position(|v| *v == value)
+                .ok_or_else(|| "byte constant is not in the bytecblock, use bytec".to_string())?;
+            return self.constant_ref("bytec", index);
+        }
+
+        self.bytes.record(&value);
+        self.items.push(Item::Byte(value));
+        Ok(())
+    }
+
+    /// Reference a constant by index, with `intc_N`/`bytec_N` for the first 4
+    fn constant_ref(&mut self, name: &str, index: usize) -> Result<(), String> {
+        let bytes = match index {
+            0..=3 => vec![self.op_spec(&format!("{name}_{index}"))?.opcode],
+            _ => vec![
+                self.op_spec(name)?.opcode,
+                u8::try_from(index).map_err(|_| format!("{name} index {index} is beyond 255"))?,
+            ],
+        };
+        self.push_op(bytes, vec![]);
+        Ok(())
+    }
+
+    fn constant_block(&mut self, name: &str, args: &[String]) -> Result<(), String> {
+        let spec = self.op_spec(name)?;
+        let mut bytes = vec![spec.opcode];
+
+        if name == "intcblock" {
+            if !self.ints.values.is_empty() && self.ints.explicit_blocks == 0 {
+                return Err("intcblock following int".to_string());
+            }
+            let values = args
+                .iter()
+                .map(|a| {
+                    parse_uint(a).ok_or_else(|| format!("unable to parse {a:?} as an integer"))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            write_varuint(values.len() as u64, &mut bytes);
+            values.iter().for_each(|v| write_varuint(*v, &mut bytes));
+            self.ints.values = values;
+            self.ints.explicit_blocks += 1;
+        } else {
+            if !self.bytes.values.is_empty() && self.bytes.explicit_blocks == 0 {
+                return Err("bytecblock following byte".to_string());
+            }
+            let values = self.byte_strings(args)?;
+            write_varuint(values.len() as u64, &mut bytes);
+            for value in &values {
+                write_varuint(value.len() as u64, &mut bytes);
+                bytes.extend(value);
+            }
+            self.bytes.values = values;
+            self.bytes.explicit_blocks += 1;
+        }
+
+        self.push_op(bytes, vec![]);
+        Ok(())
+    }
+
+    fn byte_strings(&self, mut args: &[String]) -> Result<Vec<Vec<u8>>, String> {
+        let mut values = vec![];
+        while !args.is_empty() {
+            let (value, used) = parse_bytes(args)?;
+            values.push(value);
+            args = &args[used..];
+        }
+        Ok(values)
+    }
+
+    fn op(&mut self, name: &str, args: &[String]) -> Result<(), String> {
+        let expect_args = |count: usize| {
+            if args.len() == count {
+                Ok(())
+            } else {
+                Err(format!(
+                    "{name} expects {count} immediate arguments, but got {}",
+                    args.len()
+                ))
+            }
+        };
+
+        match (name, args.len()) {
+            ("int", _) => {
+                expect_args(1)?;
+                self.int_constant(&args[0])
+            }
+            ("byte", _) => {
+                let (value, used) = parse_bytes(args)?;
+                if used != args.len() {
+                    return Err(format!("byte has extraneous arguments: {}", args.join(" ")));
+                }
+                self.byte_constant(value)
+            }
+            ("addr", _) => {
+                expect_args(1)?;
+                let address = Address::from_string(&args[0])
+                    .map_err(|e| format!("invalid address {}: {e}", args[0]))?;
+                self.byte_constant(address.pub_key.to_vec())
+            }
+            ("method", _) => {
+                expect_args(1)?;
+                // Like algod, the signature is not checked, since ARC-4 is not part of the protocol
+                let signature = parse_string_literal(&args[0])?;
+                self.byte_constant(Sha512_256::digest(signature)[..4].to_vec())
+            }
+            ("intcblock" | "bytecblock", _) => self.constant_block(name, args),
+            ("intc" | "bytec" | "arg", 1) => {
+                let index = parse_uint8(&args[0])?;
+                if name == "arg" && index > 3 {
+                    return self.generic_op(name, args);
+                }
+                if name != "arg" {
+                    let constants = match name {
+                        "intc" => self.ints.values.len(),
+                        _ => self.bytes.values.len(),
+                    };
+                    if index as usize >= constants || self.explicit_blocks(name) == 0 {
+                        return Err(format!("{name} {index} is not defined"));
+                    }
+                }
+                self.constant_ref(name, index as usize)
+            }
+            ("txn", 2) => self.generic_op("txna", args),
+            ("gtxn", 3) => self.generic_op("gtxna", args),
+            ("gtxns", 2) => self.generic_op("gtxnsa", args),
+            ("itxn", 2) => self.generic_op("itxna", args),
+            ("gitxn", 3) => self.generic_op("gitxna", args),
+            ("replace", 0) => self.generic_op("replace3", args),
+            ("replace", 1) => self.generic_op("replace2", args),
+            _ => self.generic_op(name, args),
+        }
+    }
+
+    fn explicit_blocks(&self, name: &str) -> usize {
+        match name {
+            "intc" => self.ints.explicit_blocks,
+            _ => self.bytes.explicit_blocks,
+        }
+    }
+
+    fn generic_op(&mut self, name: &str, args: &[String]) -> Result<(), String> {
+        let spec = self.op_spec(name)?;
+        let version = self.version();
+        let mut bytes = vec![spec.opcode];
+        let mut labels = vec![];
+
+        let variable = matches!(
+            spec.immediates,
+            [Immediate::Varuints | Immediate::ByteStrings | Immediate::Labels]
+        );
+        if !variable && spec.immediates.len() != args.len() && spec.immediates != [Immediate::Bytes]
+        {
+            return Err(format!(
+                "{name} expects {} immediate arguments, but got {}",
+                spec.immediates.len(),
+                args.len()
+            ));
+        }
+
+        for (i, immediate) in spec.immediates.iter().enumerate() {
+            match immediate {
+                Immediate::Uint8 => bytes.push(parse_uint8(&args[i])?),
+                Immediate::Int8 => {
+                    let value: i8 = match args[i].strip_prefix('-') {
+                        Some(magnitude) => {
+                            parse_uint(magnitude).and_then(|m| i8::try_from(-(m as i128)).ok())
+                        }
+                        None => parse_uint(&args[i]).and_then(|v| i8::try_from(v).ok()),
+                    }
+                    .ok_or_else(|| format!("{} is not a signed 8-bit integer", args[i]))?;
+                    bytes.push(value as u8);
+                }
+                Immediate::Varuint => {
+                    let value = parse_uint(&args[i])
+                        .ok_or_else(|| format!("unable to parse {:?} as an integer", args[i]))?;
+                    write_varuint(value, &mut bytes);
+                }
+                Immediate::Bytes => {
+                    let (value, used) = parse_bytes(args)?;
+                    if used != args.len() {
+                        return Err(format!(
+                            "{name} has extraneous arguments: {}",
+                            args.join(" ")
+                        ));
+                    }
+                    write_varuint(value.len() as u64, &mut bytes);
+                    bytes.extend(value);
+                }
+                Immediate::Label => labels.push(args[i].clone()),
+                Immediate::Varuints => {
+                    write_varuint(args.len() as u64, &mut bytes);
+                    for arg in args {
+                        let value = parse_uint(arg)
+                            .ok_or_else(|| format!("unable to parse {arg:?} as an integer"))?;
+                        write_varuint(value, &mut bytes);
+                    }
+                }
+                Immediate::ByteStrings => {
+                    let values = self.byte_strings(args)?;
+                    write_varuint(values.len() as u64, &mut bytes);
+                    for value in values {
+                        write_varuint(value.len() as u64, &mut bytes);
+                        bytes.extend(value);
+                    }
+                }
+                Immediate::Labels => {
+                    bytes.push(
+                        u8::try_from(args.len())
+                            .map_err(|_| format!("{name} cannot have more than 255 labels"))?,
+                    );
+                    labels.extend(args.iter().cloned());
+                }
+                Immediate::Field(group) => {
+                    let (index, field) = group
+                        .field_by_name(&args[i])
+                        .ok_or_else(|| format!("{name} unknown field: {}", args[i]))?;
+
+                    if field.version > version {
+                        return Err(format!(
+                            "{name} {} requires version {}, but the program is version {version}",
+                            field.name, field.version
+                        ));
+                    }
+
+                    if name == "itxn_field" {
+                        if field.itxn_version == 0 {
+                            return Err(format!("{name} {} is not allowed", field.name));
+                        }
+                        if field.itxn_version > version {
+                            return Err(format!(
+                                "{name} {} requires version {}, but the program is version {version}",
+                                field.name, field.itxn_version
+                            ));
+                        }
+                    }
+
+                    if *group == FieldGroup::Txn && name != "itxn_field" {
+                        let array_op = name.ends_with('a') || name.ends_with("as");
+                        if ARRAY_TXN_FIELDS.contains(&field.name) != array_op {
+                            return Err(format!("{name} cannot be used with {} field", field.name));
+                        }
+                    }
+
+                    bytes.push(index);
+                }
+            }
+        }
+
+        self.push_op(bytes, labels);
+        Ok(())
+    }
+
+    /// Lay out the program and resolve constants and labels
+    fn finish(mut self) -> Result<Vec<u8>, ProgramError> {
+        let version = self.version();
+        let int_block = self.ints.block(version);
+        let byte_block = self.bytes.block(version);
+
+        let opcode = |name: &str| {
+            opcodes::op_by_name(name)
+                .expect("opcode names used by the assembler are known")
+                .opcode
+        };
+        let reference = |name: &str, index: usize| -> Result<Vec<u8>, ProgramError> {
+            match index {
+                0..=3 => Ok(vec![opcode(&format!("{name}_{index}"))]),
+                _ => Ok(vec![
+                    opcode(name),
+                    u8::try_from(index).map_err(|_| {
+                        error(0, format!("cannot have more than 256 {name} constants"))
+                    })?,
+                ]),
+            }
+        };
+
+        let mut body = vec![];
+        let mut label_pcs = HashMap::new();
+        let mut references = vec![];
+
+        for item in self.items {
+            match item {
+                Item::Op {
+                    bytes,
+                    labels,
+                    line,
+                } => {
+                    let pc = body.len();
+                    body.extend(bytes);
+                    let end = body.len() + 2 * labels.len();
+                    for label in labels {
+                        references.push((label, pc, body.len(), end, line));
+                        body.extend([0, 0]);
+                    }
+                }
+                Item::Int(value) => match int_block.iter().position(|v| *v == value) {
+                    Some(index) => body.extend(reference("intc", index)?),
+                    None => {
+                        body.push(opcode("pushint"));
+                        write_varuint(value, &mut body);
+                    }
+                },
+                Item::Byte(value) => match byte_block.iter().position(|v| *v == value) {
+                    Some(index) => body.extend(reference("bytec", index)?),
+                    None => {
+                        body.push(opcode("pushbytes"));
+                        write_varuint(value.len() as u64, &mut body);
+                        body.extend(value);
+                    }
+                },
+                Item::Label(name) => {
+                    label_pcs.insert(name, body.len());
+                }
+            }
+        }
+
+        for (label, pc, position, end, line) in references {
+            let target = *label_pcs
+                .get(&label)
+                .ok_or_else(|| error(line, format!("reference to undefined label {label}")))?;
+
+            if target <= pc && version < BACKWARD_BRANCH_VERSION {
+                return Err(error(
+                    line,
+                    format!(
+                        "label {label} is a back reference, which requires version {BACKWARD_BRANCH_VERSION}"
+                    ),
+                ));
+            }
+
+            let offset = i16::try_from(target as i64 - end as i64)
+                .map_err(|_| error(line, format!("label {label} is too far away")))?;
+            body[position..position + 2].copy_from_slice(&offset.to_be_bytes());
+        }
+
+        let mut program = vec![];
+        write_varuint(version, &mut program);
+
+        if !int_block.is_empty() {
+            program.push(opcode("intcblock"));
+            write_varuint(int_block.len() as u64, &mut program);
+            int_block
+                .iter()
+                .for_each(|value| write_varuint(*value, &mut program));
+        }
+
+        if !byte_block.is_empty() {
+            program.push(opcode("bytecblock"));
+            write_varuint(byte_block.len() as u64, &mut program);
+            for value in &byte_block {
+                write_varuint(value.len() as u64, &mut program);
+                program.extend(value);
+            }
+        }
+
+        program.extend(body);
+        Ok(program)
+    }
+}
+
+impl Program {
+    /// Assemble TEAL source into a program, producing the same bytecode as algod.
+    ///
+    /// Supports `#pragma version`, `#define` macros, labels, the `int`, `byte`, `addr` and
+    /// `method` pseudo-ops and the shorthand forms of opcodes such as `txn ApplicationArgs 0`
+    pub fn assemble(source: &str) -> Result<Program, ProgramError> {
+        let mut assembler = Assembler {
+            version: None,
+            line: 0,
+            items: vec![],
+            ints: Constants::default(),
+            bytes: Constants::default(),
+            labels: HashSet::new(),
+            macros: HashMap::new(),
+        };
+
+        for (i, line) in source.lines().enumerate() {
+            assembler.line(line, i + 1)?;
+        }
+
+        Program::new(assembler.finish()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn assemble(source: &str) -> Vec<u8> {
+        Program::assemble(source).unwrap().into_bytes()
+    }
+
+    fn assemble_error(source: &str) -> String {
+        Program::assemble(source).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_parse_uint() {
+        assert_eq!(parse_uint("0"), Some(0));
+        assert_eq!(parse_uint("1_000"), Some(1000));
+        assert_eq!(parse_uint("0x10"), Some(16));
+        assert_eq!(parse_uint("0o17"), Some(15));
+        assert_eq!(parse_uint("017"), Some(15));
+        assert_eq!(parse_uint("0b101"), Some(5));
+        assert_eq!(parse_uint("18446744073709551615"), Some(u64::MAX));
+        assert_eq!(parse_uint("18446744073709551616"), None);
+        assert_eq!(parse_uint("_1"), None);
+        assert_eq!(parse_uint("1__0"), None);
+        assert_eq!(parse_uint("-1"), None);
+        assert_eq!(parse_uint("08"), None);
+    }
+
+    #[test]
+    fn test_parse_bytes() {
+        let parse = |args: &[&str]| {
+            parse_bytes(&args.iter().map(|a| a.to_string()).collect::<Vec<_>>())
+                .map(|(bytes, _)| bytes)
+        };
+
+        assert_eq!(parse(&["0x0102"]), Ok(vec![1, 2]));
+        assert_eq!(parse(&["\"a\\x00\\n\\\"\""]), Ok(b"a\x00\n\"".to_vec()));
+        assert_eq!(parse(&["base64", "AQI="]), Ok(vec![1, 2]));
+        assert_eq!(parse(&["b64(AQI=)"]), Ok(vec![1, 2]));
+        assert_eq!(parse(&["b32", "AEBA"]), Ok(vec![1, 2]));
+        assert_eq!(parse(&["base32(AEBA====)"]), Ok(vec![1, 2]));
+        assert!(parse(&["0x012"]).is_err());
+        assert!(parse(&["\"\\q\""]).is_err());
+        assert!(parse(&["hello"]).is_err());
+    }
+
+    #[test]
+    fn test_constant_block_before_v4() {
+        // Constants go in the blocks in the order they are first used
+        let source = "#pragma version 3
+int 5
+int 1
+int 5
+byte \"a\"
+byte 0x61
+addr AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA";
+
+        assert!(Program::assemble(source).is_err());
+
+        let source = "#pragma version 3
+int 5
+int 1
+int 5
+byte \"a\"
+byte 0x61
+addr AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAY5HFKQ";
+
+        let mut expected = vec![0x03, 0x20, 0x02, 0x05, 0x01, 0x26, 0x02, 0x01, b'a', 0x20];
+        expected.extend([0; 32]);
+        expected.extend([0x22, 0x23, 0x22, 0x28, 0x28, 0x29]);
+        assert_eq!(assemble(source), expected);
+    }
+
+    #[test]
+    fn test_constant_optimization() {
+        // From v4, constants are ordered by use and single-use constants are pushed
+        let source = "#pragma version 4
+int 7
+int 1
+int 1
+int 2
+int 2
+int 2
+byte \"x\"
+method \"add(uint64,uint64)uint128\"
+byte \"x\"";
+
+        assert_eq!(
+            assemble(source),
+            vec![
+                0x04, 0x20, 0x02, 0x02, 0x01, 0x26, 0x01, 0x01, b'x', 0x81, 0x07, 0x23, 0x23, 0x22,
+                0x22, 0x22, 0x28, 0x80, 0x04, 0x8a, 0xa3, 0xb6, 0x1f, 0x28,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_explicit_constant_blocks() {
+        let source = "#pragma version 4
+intcblock 10 20
+bytecblock 0xaa
+intc 1
+int 20
+bytec_0
+byte 0xaa";
+
+        assert_eq!(
+            assemble(source),
+            vec![
+                0x04, 0x20, 0x02, 0x0a, 0x14, 0x26, 0x01, 0x01, 0xaa, 0x23, 0x81, 0x14, 0x28, 0x80,
+                0x01, 0xaa,
+            ]
+        );
+
+        assert_eq!(
+            assemble("#pragma version 2\nintcblock 10 20\nint 20\nint 10"),
+            vec![0x02, 0x20, 0x02, 0x0a, 0x14, 0x23, 0x22]
+        );
+        assert_eq!(
+            assemble_error("#pragma version 2\nint 1\nintcblock 1"),
+            "line 3: intcblock following int"
+        );
+        assert_eq!(
+            assemble_error("#pragma version 3\nintcblock 1\nintcblock 2\nint 2"),
+            "line 4: int 2 used with manual intcblocks, use intc"
+        );
+        assert_eq!(
+            assemble_error("#pragma version 2\nintc 0"),
+            "line 2: intc 0 is not defined"
+        );
+    }
+
+    #[test]
+    fn test_labels() {
+        let source = "#pragma version 8
+main:
+    txn NumAppArgs
+    bz create   // no arguments
+    callsub sub; b end
+create: int 1; return
+sub:
+    retsub
+end:";
+
+        assert_eq!(
+            assemble(source),
+            vec![
+                0x08, 0x31, 0x1b, 0x41, 0x00, 0x06, 0x88, 0x00, 0x06, 0x42, 0x00, 0x04, 0x81, 0x01,
+                0x43, 0x89,
+            ]
+        );
+
+        assert_eq!(
+            assemble_error("#pragma version 3\nl1:\nint 1\nbnz l1"),
+            "line 4: label l1 is a back reference, which requires version 4"
+        );
+        assert_eq!(
+            assemble_error("#pragma version 3\nb missing"),
+            "line 2: reference to undefined label missing"
+        );
+        assert_eq!(
+            assemble_error("#pragma version 3\nl1:\nl1:"),
+            "line 3: duplicate label l1"
+        );
+    }
+
+    #[test]
+    fn test_shorthand_ops() {
+        let source = "#pragma version 8
+txn ApplicationArgs 1
+gtxn 0 Accounts 2
+gtxns Amount
+itxn Logs 0
+arg 0
+arg 5
+replace 1
+replace
+switch a b
+a:
+b:
+frame_dig -1
+global ZeroAddress";
+
+        assert_eq!(
+            assemble(source),
+            vec![
+                0x08, 0x36, 0x1a, 0x01, 0x37, 0x00, 0x1c, 0x02, 0x38, 0x08, 0xb5, 0x3a, 0x00, 0x2d,
+                0x2c, 0x05, 0x5c, 0x01, 0x5d, 0x8d, 0x02, 0x00, 0x00, 0x00, 0x00, 0x8b, 0xff, 0x32,
+                0x03,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_version() {
+        assert_eq!(assemble("int 1"), vec![0x01, 0x20, 0x01, 0x01, 0x22]);
+        assert_eq!(
+            assemble_error("#pragma version 3\nbox_create"),
+            "line 2: box_create requires version 8, but the program is version 3"
+        );
+        assert_eq!(
+            assemble_error("int 1\n#pragma version 2"),
+            "line 2: #pragma version is only allowed before instructions"
+        );
+        assert_eq!(
+            assemble_error("#pragma version 6\ntxn FirstValidTime"),
+            "line 2: txn FirstValidTime requires version 7, but the program is version 6"
+        );
+        assert_eq!(
+            assemble_error("#pragma version 12"),
+            "line 1: unsupported version 12, the maximum is 11"
+        );
+    }
+
+    #[test]
+    fn test_itxn_field() {
+        assert_eq!(
+            assemble("#pragma version 6\nitxn_field Note\nitxn_field Receiver"),
+            vec![0x06, 0xb2, 0x05, 0xb2, 0x07]
+        );
+        assert_eq!(
+            assemble_error("#pragma version 8\nitxn_field TxID"),
+            "line 2: itxn_field TxID is not allowed"
+        );
+        assert_eq!(
+            assemble_error("#pragma version 8\nitxn_field NumAppArgs"),
+            "line 2: itxn_field NumAppArgs is not allowed"
+        );
+        assert_eq!(
+            assemble_error("#pragma version 5\nitxn_field Note"),
+            "line 2: itxn_field Note requires version 6, but the program is version 5"
+        );
+        assert_eq!(
+            assemble_error("#pragma version 6\nitxn_field ApprovalProgramPages"),
+            "line 2: itxn_field ApprovalProgramPages requires version 7, but the program is version 6"
+        );
+    }
+
+    #[test]
+    fn test_macros() {
+        let source = "#pragma version 8
+#define ONE 1
+#define PUSH_TWO pushint 2; pushint ONE
+#define THREE 0x03
+PUSH_TWO
+byte THREE
+int ONE";
+
+        assert_eq!(
+            assemble(source),
+            vec![0x08, 0x81, 0x02, 0x81, 0x01, 0x80, 0x01, 0x03, 0x81, 0x01]
+        );
+
+        assert_eq!(
+            assemble_error("#define A B\n#define B A\nint A"),
+            "line 3: macro A expands to itself"
+        );
+        assert_eq!(
+            assemble_error("#define int 1"),
+            "line 1: invalid macro name: int"
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let source = "#pragma version 10
+intcblock 1 0
+bytecblock 0x6869
+txn Sender
+global CreatorAddress
+==
+assert
+pushbytes 0x00 // 0x00
+pushbytess 0x01 0x0203
+pushints 7 8
+proto 1 2
+ec_add BN254g1
+label1:
+intc_0 // 1
+bytec_0 // \"hi\"
+bnz label1
+";
+
+        let program = Program::assemble(source).unwrap();
+        assert_eq!(program.disassemble(), source);
+        assert_eq!(Program::assemble(&program.disassemble()).unwrap(), program);
+    }
+}
@@ -0,0 +1,236 @@
+//! Disassembly of program bytecode into TEAL source, matching the output of
+//! `goal clerk compile -D`.
+
+use super::{ImmediateValue, Program};
+use crate::Address;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::from("0x"), |mut hex, b| {
+        let _ = write!(hex, "{b:02x}");
+        hex
+    })
+}
+
+/// Guess the most readable form of a byte constant for a comment: an address for 32 bytes, a
+/// quoted string for printable ASCII and hex otherwise
+fn guess_byte_format(bytes: &[u8]) -> String {
+    if let Ok(pub_key) = bytes.try_into() {
+        return format!("addr {}", Address::from_pubkey(pub_key).address());
+    }
+
+    if bytes.iter().all(|b| (0x20..=0x7e).contains(b)) {
+        let mut quoted = String::from("\"");
+        for c in bytes.iter().map(|b| *b as char) {
+            if c == '"' || c == '\\' {
+                quoted.push('\\');
+            }
+            quoted.push(c);
+        }
+        quoted.push('"');
+        return quoted;
+    }
+
+    to_hex(bytes)
+}
+
+/// The index of the constant used by `intc_N`/`bytec_N`, or by `intc`/`bytec` with an immediate
+fn constant_index(name: &str, prefix: &str, immediates: &[ImmediateValue]) -> Option<usize> {
+    let suffix = name.strip_prefix(prefix)?;
+
+    match (suffix, immediates) {
+        ("", [ImmediateValue::Uint8(index)]) => Some(*index as usize),
+        (suffix, []) => suffix.strip_prefix('_')?.parse().ok(),
+        _ => None,
+    }
+}
+
+impl Program {
+    /// Disassemble the program into TEAL source, in the same form as `goal clerk compile -D`.
+    ///
+    /// Branch targets are named `label1`, `label2` and so on in the order they are first
+    /// referenced, and constants loaded from the `intcblock` and `bytecblock` are shown in
+    /// comments
+    pub fn disassemble(&self) -> String {
+        let instructions = self.instructions();
+
+        let mut labels: HashMap<usize, String> = HashMap::new();
+        for immediate in instructions.iter().flat_map(|i| &i.immediates) {
+            let targets = match immediate {
+                ImmediateValue::Label(target) => std::slice::from_ref(target),
+                ImmediateValue::Labels(targets) => targets.as_slice(),
+                _ => continue,
+            };
+
+            for target in targets {
+                let next = format!("label{}", labels.len() + 1);
+                labels.entry(*target).or_insert(next);
+            }
+        }
+
+        let mut out = format!("#pragma version {}\n", self.version);
+        let mut intc: &[u64] = &[];
+        let mut bytec: &[Vec<u8>] = &[];
+
+        for instruction in &instructions {
+            if let Some(label) = labels.get(&instruction.pc) {
+                let _ = writeln!(out, "{label}:");
+            }
+
+            out.push_str(instruction.op.name);
+
+            for immediate in &instruction.immediates {
+                out.push(' ');
+                match immediate {
+                    ImmediateValue::Uint8(value) => out.push_str(&value.to_string()),
+                    ImmediateValue::Int8(value) => out.push_str(&value.to_string()),
+                    ImmediateValue::Varuint(value) => out.push_str(&value.to_string()),
+                    ImmediateValue::Bytes(bytes) => out.push_str(&to_hex(bytes)),
+                    ImmediateValue::Label(target) => out.push_str(&labels[target]),
+                    ImmediateValue::Varuints(values) => out.push_str(
+                        &values
+                            .iter()
+                            .map(u64::to_string)
+                            .collect::<Vec<_>>()
+                            .join(" "),
+                    ),
+                    ImmediateValue::ByteStrings(values) => out.push_str(
+                        &values
+                            .iter()
+                            .map(|v| to_hex(v))
+                            .collect::<Vec<_>>()
+                            .join(" "),
+                    ),
+                    ImmediateValue::Labels(targets) => out.push_str(
+                        &targets
+                            .iter()
+                            .map(|t| labels[t].as_str())
+                            .collect::<Vec<_>>()
+                            .join(" "),
+                    ),
+                    ImmediateValue::Field(field) => out.push_str(field.name),
+                }
+            }
+
+            match (instruction.op.name, instruction.immediates.as_slice()) {
+                ("intcblock", [ImmediateValue::Varuints(values)]) => intc = values,
+                ("bytecblock", [ImmediateValue::ByteStrings(values)]) => bytec = values,
+                ("pushbytes", [ImmediateValue::Bytes(value)]) => {
+                    let _ = write!(out, " // {}", guess_byte_format(value));
+                }
+                (name, immediates) => {
+                    if let Some(value) =
+                        constant_index(name, "intc", immediates).and_then(|i| intc.get(i))
+                    {
+                        let _ = write!(out, " // {value}");
+                    } else if let Some(value) =
+                        constant_index(name, "bytec", immediates).and_then(|i| bytec.get(i))
+                    {
+                        let _ = write!(out, " // {}", guess_byte_format(value));
+                    }
+                }
+            }
+
+            out.push('\n');
+        }
+
+        if let Some(label) = labels.get(&self.bytes.len()) {
+            let _ = writeln!(out, "{label}:");
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn disassemble(bytes: &[u8]) -> String {
+        Program::new(bytes.to_vec()).unwrap().disassemble()
+    }
+
+    #[test]
+    fn test_constants() {
+        let mut bytes = vec![
+            0x02, 0x20, 0x02, 0x01, 0xe8, 0x07, 0x26, 0x03, 0x02, b'h', b'i', 0x02, 0xff, 0x00,
+            0x20,
+        ];
+        bytes.extend([0x07; 32]);
+        bytes.extend([
+            0x22, 0x21, 0x01, 0x21, 0x02, 0x28, 0x29, 0x27, 0x02, 0x31, 0x00,
+        ]);
+
+        assert_eq!(
+            disassemble(&bytes),
+            "#pragma version 2
+intcblock 1 1000
+bytecblock 0x6869 0xff00 0x0707070707070707070707070707070707070707070707070707070707070707
+intc_0 // 1
+intc 1 // 1000
+intc 2
+bytec_0 // \"hi\"
+bytec_1 // 0xff00
+bytec 2 // addr A4DQOBYHA4DQOBYHA4DQOBYHA4DQOBYHA4DQOBYHA4DQOBYHA4DVZ36IB4
+txn Sender
+"
+        );
+    }
+
+    #[test]
+    fn test_branches() {
+        // l1: pushint 1; bnz l2; b l1; callsub l1; l2: switch l2 end; end:
+        let bytes = [
+            0x08, 0x81, 0x01, 0x40, 0x00, 0x06, 0x42, 0xff, 0xf8, 0x88, 0xff, 0xf5, 0x8d, 0x02,
+            0xff, 0xfa, 0x00, 0x00,
+        ];
+
+        assert_eq!(
+            disassemble(&bytes),
+            "#pragma version 8
+label2:
+pushint 1
+bnz label1
+b label2
+callsub label2
+label1:
+switch label1 label3
+label3:
+"
+        );
+    }
+
+    #[test]
+    fn test_immediates() {
+        let bytes = [
+            0x0b, 0x80, 0x03, b'a', b'"', b'c', 0x82, 0x02, 0x00, 0x01, 0x01, 0x83, 0x02, 0x05,
+            0x80, 0x01, 0x8a, 0x02, 0x01, 0x8b, 0xfe, 0x37, 0x01, 0x1a, 0x00, 0x73, 0x0e, 0xd1,
+            0x06, 0xe6, 0x00, 0x20, 0x00,
+        ];
+
+        assert_eq!(
+            disassemble(&bytes),
+            "#pragma version 11
+pushbytes 0x612263 // \"a\\\"c\"
+pushbytess 0x 0x01
+pushints 5 128
+proto 2 1
+frame_dig -2
+gtxna 1 ApplicationArgs 0
+acct_params_get AcctLastHeartbeat
+block BlkProposer
+mimc BN254Mp110
+intcblock 
+"
+        );
+    }
+
+    #[test]
+    fn test_guess_byte_format() {
+        assert_eq!(guess_byte_format(b""), "\"\"");
+        assert_eq!(guess_byte_format(b"a\"b\\c"), "\"a\\\"b\\\\c\"");
+        assert_eq!(guess_byte_format(&[0x0a]), "0x0a");
+    }
+}
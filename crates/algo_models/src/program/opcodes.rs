@@ -0,0 +1,557 @@
+//! The AVM opcode table, listing every opcode with the version that introduced it and the
+//! immediate arguments that follow it in the bytecode, along with the named fields that some
+//! immediates refer to.
+
+/// The kind of an immediate argument that follows an opcode in the bytecode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Immediate {
+    /// A single unsigned byte, such as a scratch slot or a field index
+    Uint8,
+
+    /// A single signed byte, used for frame offsets
+    Int8,
+
+    /// A varuint, as used by `pushint`
+    Varuint,
+
+    /// A varuint length followed by that many bytes, as used by `pushbytes`
+    Bytes,
+
+    /// A 2-byte big-endian signed offset from the end of the instruction
+    Label,
+
+    /// A varuint count followed by that many varuints, as used by `intcblock` and `pushints`
+    Varuints,
+
+    /// A varuint count followed by that many length-prefixed byte strings, as used by
+    /// `bytecblock` and `pushbytess`
+    ByteStrings,
+
+    /// A 1-byte count followed by that many labels, as used by `switch` and `match`
+    Labels,
+
+    /// A single byte that indexes a named field, such as `Sender` for `txn`
+    Field(FieldGroup),
+}
+
+/// A named field that an immediate argument can refer to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldSpec {
+    pub name: &'static str,
+
+    /// The first program version in which the field is available
+    pub version: u64,
+
+    /// The first program version in which `itxn_field` can set the field, or 0 if it cannot be
+    /// set, like `TxID` or the effects of a transaction
+    pub itxn_version: u64,
+}
+
+const fn field(name: &'static str, version: u64) -> FieldSpec {
+    txn_field(name, version, 0)
+}
+
+const fn txn_field(name: &'static str, version: u64, itxn_version: u64) -> FieldSpec {
+    FieldSpec {
+        name,
+        version,
+        itxn_version,
+    }
+}
+
+/// A set of fields, where the immediate byte is the index of the field in the set
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldGroup {
+    Txn,
+    Global,
+    AssetHolding,
+    AssetParams,
+    AppParams,
+    AcctParams,
+    VoterParams,
+    EcdsaCurve,
+    Base64Encoding,
+    JsonRefType,
+    VrfStandard,
+    Block,
+    EcGroup,
+    MimcConfig,
+}
+
+impl FieldGroup {
+    pub fn fields(self) -> &'static [FieldSpec] {
+        match self {
+            FieldGroup::Txn => TXN_FIELDS,
+            FieldGroup::Global => GLOBAL_FIELDS,
+            FieldGroup::AssetHolding => ASSET_HOLDING_FIELDS,
+            FieldGroup::AssetParams => ASSET_PARAMS_FIELDS,
+            FieldGroup::AppParams => APP_PARAMS_FIELDS,
+            FieldGroup::AcctParams => ACCT_PARAMS_FIELDS,
+            FieldGroup::VoterParams => VOTER_PARAMS_FIELDS,
+            FieldGroup::EcdsaCurve => ECDSA_CURVE_FIELDS,
+            FieldGroup::Base64Encoding => BASE64_ENCODING_FIELDS,
+            FieldGroup::JsonRefType => JSON_REF_TYPE_FIELDS,
+            FieldGroup::VrfStandard => VRF_STANDARD_FIELDS,
+            FieldGroup::Block => BLOCK_FIELDS,
+            FieldGroup::EcGroup => EC_GROUP_FIELDS,
+            FieldGroup::MimcConfig => MIMC_CONFIG_FIELDS,
+        }
+    }
+
+    /// Look up a field by its index
+    pub fn field(self, index: u8) -> Option<&'static FieldSpec> {
+        self.fields().get(index as usize)
+    }
+
+    /// Look up a field by its name, returning its index and spec
+    pub fn field_by_name(self, name: &str) -> Option<(u8, &'static FieldSpec)> {
+        self.fields()
+            .iter()
+            .enumerate()
+            .find(|(_, f)| f.name == name)
+            .map(|(i, f)| (i as u8, f))
+    }
+}
+
+const TXN_FIELDS: &[FieldSpec] = &[
+    txn_field("Sender", 1, 5),
+    txn_field("Fee", 1, 5),
+    txn_field("FirstValid", 1, 0),
+    txn_field("FirstValidTime", 7, 0),
+    txn_field("LastValid", 1, 0),
+    txn_field("Note", 1, 6),
+    txn_field("Lease", 1, 0),
+    txn_field("Receiver", 1, 5),
+    txn_field("Amount", 1, 5),
+    txn_field("CloseRemainderTo", 1, 5),
+    txn_field("VotePK", 1, 6),
+    txn_field("SelectionPK", 1, 6),
+    txn_field("VoteFirst", 1, 6),
+    txn_field("VoteLast", 1, 6),
+    txn_field("VoteKeyDilution", 1, 6),
+    txn_field("Type", 1, 5),
+    txn_field("TypeEnum", 1, 5),
+    txn_field("XferAsset", 1, 5),
+    txn_field("AssetAmount", 1, 5),
+    txn_field("AssetSender", 1, 5),
+    txn_field("AssetReceiver", 1, 5),
+    txn_field("AssetCloseTo", 1, 5),
+    txn_field("GroupIndex", 1, 0),
+    txn_field("TxID", 1, 0),
+    txn_field("ApplicationID", 2, 6),
+    txn_field("OnCompletion", 2, 6),
+    txn_field("ApplicationArgs", 2, 6),
+    txn_field("NumAppArgs", 2, 0),
+    txn_field("Accounts", 2, 6),
+    txn_field("NumAccounts", 2, 0),
+    txn_field("ApprovalProgram", 2, 6),
+    txn_field("ClearStateProgram", 2, 6),
+    txn_field("RekeyTo", 2, 6),
+    txn_field("ConfigAsset", 2, 5),
+    txn_field("ConfigAssetTotal", 2, 5),
+    txn_field("ConfigAssetDecimals", 2, 5),
+    txn_field("ConfigAssetDefaultFrozen", 2, 5),
+    txn_field("ConfigAssetUnitName", 2, 5),
+    txn_field("ConfigAssetName", 2, 5),
+    txn_field("ConfigAssetURL", 2, 5),
+    txn_field("ConfigAssetMetadataHash", 2, 5),
+    txn_field("ConfigAssetManager", 2, 5),
+    txn_field("ConfigAssetReserve", 2, 5),
+    txn_field("ConfigAssetFreeze", 2, 5),
+    txn_field("ConfigAssetClawback", 2, 5),
+    txn_field("FreezeAsset", 2, 5),
+    txn_field("FreezeAssetAccount", 2, 5),
+    txn_field("FreezeAssetFrozen", 2, 5),
+    txn_field("Assets", 3, 6),
+    txn_field("NumAssets", 3, 0),
+    txn_field("Applications", 3, 6),
+    txn_field("NumApplications", 3, 0),
+    txn_field("GlobalNumUint", 3, 6),
+    txn_field("GlobalNumByteSlice", 3, 6),
+    txn_field("LocalNumUint", 3, 6),
+    txn_field("LocalNumByteSlice", 3, 6),
+    txn_field("ExtraProgramPages", 4, 6),
+    txn_field("Nonparticipation", 5, 6),
+    txn_field("Logs", 5, 0),
+    txn_field("NumLogs", 5, 0),
+    txn_field("CreatedAssetID", 5, 0),
+    txn_field("CreatedApplicationID", 5, 0),
+    txn_field("LastLog", 6, 0),
+    txn_field("StateProofPK", 6, 6),
+    txn_field("ApprovalProgramPages", 7, 7),
+    txn_field("NumApprovalProgramPages", 7, 0),
+    txn_field("ClearStateProgramPages", 7, 7),
+    txn_field("NumClearStateProgramPages", 7, 0),
+];
+
+const GLOBAL_FIELDS: &[FieldSpec] = &[
+    field("MinTxnFee", 1),
+    field("MinBalance", 1),
+    field("MaxTxnLife", 1),
+    field("ZeroAddress", 1),
+    field("GroupSize", 1),
+    field("LogicSigVersion", 2),
+    field("Round", 2),
+    field("LatestTimestamp", 2),
+    field("CurrentApplicationID", 2),
+    field("CreatorAddress", 3),
+    field("CurrentApplicationAddress", 5),
+    field("GroupID", 5),
+    field("OpcodeBudget", 6),
+    field("CallerApplicationID", 6),
+    field("CallerApplicationAddress", 6),
+    field("AssetCreateMinBalance", 10),
+    field("AssetOptInMinBalance", 10),
+    field("GenesisHash", 10),
+    field("PayoutsEnabled", 11),
+    field("PayoutsGoOnlineFee", 11),
+    field("PayoutsPercent", 11),
+    field("PayoutsMinBalance", 11),
+    field("PayoutsMaxBalance", 11),
+];
+
+const ASSET_PARAMS_FIELDS: &[FieldSpec] = &[
+    field("AssetTotal", 2),
+    field("AssetDecimals", 2),
+    field("AssetDefaultFrozen", 2),
+    field("AssetUnitName", 2),
+    field("AssetName", 2),
+    field("AssetURL", 2),
+    field("AssetMetadataHash", 2),
+    field("AssetManager", 2),
+    field("AssetReserve", 2),
+    field("AssetFreeze", 2),
+    field("AssetClawback", 2),
+    field("AssetCreator", 5),
+];
+
+const APP_PARAMS_FIELDS: &[FieldSpec] = &[
+    field("AppApprovalProgram", 5),
+    field("AppClearStateProgram", 5),
+    field("AppGlobalNumUint", 5),
+    field("AppGlobalNumByteSlice", 5),
+    field("AppLocalNumUint", 5),
+    field("AppLocalNumByteSlice", 5),
+    field("AppExtraProgramPages", 5),
+    field("AppCreator", 5),
+    field("AppAddress", 5),
+];
+
+const ACCT_PARAMS_FIELDS: &[FieldSpec] = &[
+    field("AcctBalance", 6),
+    field("AcctMinBalance", 6),
+    field("AcctAuthAddr", 6),
+    field("AcctTotalNumUint", 8),
+    field("AcctTotalNumByteSlice", 8),
+    field("AcctTotalExtraAppPages", 8),
+    field("AcctTotalAppsCreated", 8),
+    field("AcctTotalAppsOptedIn", 8),
+    field("AcctTotalAssetsCreated", 8),
+    field("AcctTotalAssets", 8),
+    field("AcctTotalBoxes", 8),
+    field("AcctTotalBoxBytes", 8),
+    field("AcctIncentiveEligible", 11),
+    field("AcctLastProposed", 11),
+    field("AcctLastHeartbeat", 11),
+];
+
+const BLOCK_FIELDS: &[FieldSpec] = &[
+    field("BlkSeed", 7),
+    field("BlkTimestamp", 7),
+    field("BlkBranch", 11),
+    field("BlkFeeSink", 11),
+    field("BlkProtocol", 11),
+    field("BlkTxnCounter", 11),
+    field("BlkProposer", 11),
+    field("BlkFeesCollected", 11),
+    field("BlkBonus", 11),
+    field("BlkProposerPayout", 11),
+];
+
+const ASSET_HOLDING_FIELDS: &[FieldSpec] = &[field("AssetBalance", 2), field("AssetFrozen", 2)];
+
+const VOTER_PARAMS_FIELDS: &[FieldSpec] = &[
+    field("VoterBalance", 11),
+    field("VoterIncentiveEligible", 11),
+];
+
+const ECDSA_CURVE_FIELDS: &[FieldSpec] = &[field("Secp256k1", 5), field("Secp256r1", 7)];
+
+const BASE64_ENCODING_FIELDS: &[FieldSpec] = &[field("URLEncoding", 7), field("StdEncoding", 7)];
+
+const JSON_REF_TYPE_FIELDS: &[FieldSpec] = &[
+    field("JSONString", 7),
+    field("JSONUint64", 7),
+    field("JSONObject", 7),
+];
+
+const VRF_STANDARD_FIELDS: &[FieldSpec] = &[field("VrfAlgorand", 7)];
+
+const EC_GROUP_FIELDS: &[FieldSpec] = &[
+    field("BN254g1", 10),
+    field("BN254g2", 10),
+    field("BLS12_381g1", 10),
+    field("BLS12_381g2", 10),
+];
+
+const MIMC_CONFIG_FIELDS: &[FieldSpec] = &[field("BN254Mp110", 11), field("BLS12_381Mp111", 11)];
+
+/// An AVM opcode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpSpec {
+    pub opcode: u8,
+    pub name: &'static str,
+
+    /// The first program version in which the opcode is available
+    pub version: u64,
+
+    pub immediates: &'static [Immediate],
+}
+
+const fn op(
+    opcode: u8,
+    name: &'static str,
+    version: u64,
+    immediates: &'static [Immediate],
+) -> OpSpec {
+    OpSpec {
+        opcode,
+        name,
+        version,
+        immediates,
+    }
+}
+
+const NONE: &[Immediate] = &[];
+const UINT8: &[Immediate] = &[Immediate::Uint8];
+const UINT8_UINT8: &[Immediate] = &[Immediate::Uint8, Immediate::Uint8];
+const INT8: &[Immediate] = &[Immediate::Int8];
+const VARUINT: &[Immediate] = &[Immediate::Varuint];
+const BYTES: &[Immediate] = &[Immediate::Bytes];
+const LABEL: &[Immediate] = &[Immediate::Label];
+const VARUINTS: &[Immediate] = &[Immediate::Varuints];
+const BYTE_STRINGS: &[Immediate] = &[Immediate::ByteStrings];
+const LABELS: &[Immediate] = &[Immediate::Labels];
+
+const TXN: &[Immediate] = &[Immediate::Field(FieldGroup::Txn)];
+const TXN_UINT8: &[Immediate] = &[Immediate::Field(FieldGroup::Txn), Immediate::Uint8];
+const UINT8_TXN: &[Immediate] = &[Immediate::Uint8, Immediate::Field(FieldGroup::Txn)];
+const UINT8_TXN_UINT8: &[Immediate] = &[
+    Immediate::Uint8,
+    Immediate::Field(FieldGroup::Txn),
+    Immediate::Uint8,
+];
+const GLOBAL: &[Immediate] = &[Immediate::Field(FieldGroup::Global)];
+const ASSET_HOLDING: &[Immediate] = &[Immediate::Field(FieldGroup::AssetHolding)];
+const ASSET_PARAMS: &[Immediate] = &[Immediate::Field(FieldGroup::AssetParams)];
+const APP_PARAMS: &[Immediate] = &[Immediate::Field(FieldGroup::AppParams)];
+const ACCT_PARAMS: &[Immediate] = &[Immediate::Field(FieldGroup::AcctParams)];
+const VOTER_PARAMS: &[Immediate] = &[Immediate::Field(FieldGroup::VoterParams)];
+const ECDSA_CURVE: &[Immediate] = &[Immediate::Field(FieldGroup::EcdsaCurve)];
+const BASE64_ENCODING: &[Immediate] = &[Immediate::Field(FieldGroup::Base64Encoding)];
+const JSON_REF_TYPE: &[Immediate] = &[Immediate::Field(FieldGroup::JsonRefType)];
+const VRF_STANDARD: &[Immediate] = &[Immediate::Field(FieldGroup::VrfStandard)];
+const BLOCK: &[Immediate] = &[Immediate::Field(FieldGroup::Block)];
+const EC_GROUP: &[Immediate] = &[Immediate::Field(FieldGroup::EcGroup)];
+const MIMC_CONFIG: &[Immediate] = &[Immediate::Field(FieldGroup::MimcConfig)];
+
+/// Every opcode up to `MAX_PROGRAM_VERSION`, ordered by opcode
+pub const OPCODES: &[OpSpec] = &[
+    op(0x00, "err", 1, NONE),
+    op(0x01, "sha256", 1, NONE),
+    op(0x02, "keccak256", 1, NONE),
+    op(0x03, "sha512_256", 1, NONE),
+    op(0x04, "ed25519verify", 1, NONE),
+    op(0x05, "ecdsa_verify", 5, ECDSA_CURVE),
+    op(0x06, "ecdsa_pk_decompress", 5, ECDSA_CURVE),
+    op(0x07, "ecdsa_pk_recover", 5, ECDSA_CURVE),
+    op(0x08, "+", 1, NONE),
+    op(0x09, "-", 1, NONE),
+    op(0x0a, "/", 1, NONE),
+    op(0x0b, "*", 1, NONE),
+    op(0x0c, "<", 1, NONE),
+    op(0x0d, ">", 1, NONE),
+    op(0x0e, "<=", 1, NONE),
+    op(0x0f, ">=", 1, NONE),
+    op(0x10, "&&", 1, NONE),
+    op(0x11, "||", 1, NONE),
+    op(0x12, "==", 1, NONE),
+    op(0x13, "!=", 1, NONE),
+    op(0x14, "!", 1, NONE),
+    op(0x15, "len", 1, NONE),
+    op(0x16, "itob", 1, NONE),
+    op(0x17, "btoi", 1, NONE),
+    op(0x18, "%", 1, NONE),
+    op(0x19, "|", 1, NONE),
+    op(0x1a, "&", 1, NONE),
+    op(0x1b, "^", 1, NONE),
+    op(0x1c, "~", 1, NONE),
+    op(0x1d, "mulw", 1, NONE),
+    op(0x1e, "addw", 2, NONE),
+    op(0x1f, "divmodw", 4, NONE),
+    op(0x20, "intcblock", 1, VARUINTS),
+    op(0x21, "intc", 1, UINT8),
+    op(0x22, "intc_0", 1, NONE),
+    op(0x23, "intc_1", 1, NONE),
+    op(0x24, "intc_2", 1, NONE),
+    op(0x25, "intc_3", 1, NONE),
+    op(0x26, "bytecblock", 1, BYTE_STRINGS),
+    op(0x27, "bytec", 1, UINT8),
+    op(0x28, "bytec_0", 1, NONE),
+    op(0x29, "bytec_1", 1, NONE),
+    op(0x2a, "bytec_2", 1, NONE),
+    op(0x2b, "bytec_3", 1, NONE),
+    op(0x2c, "arg", 1, UINT8),
+    op(0x2d, "arg_0", 1, NONE),
+    op(0x2e, "arg_1", 1, NONE),
+    op(0x2f, "arg_2", 1, NONE),
+    op(0x30, "arg_3", 1, NONE),
+    op(0x31, "txn", 1, TXN),
+    op(0x32, "global", 1, GLOBAL),
+    op(0x33, "gtxn", 1, UINT8_TXN),
+    op(0x34, "load", 1, UINT8),
+    op(0x35, "store", 1, UINT8),
+    op(0x36, "txna", 2, TXN_UINT8),
+    op(0x37, "gtxna", 2, UINT8_TXN_UINT8),
+    op(0x38, "gtxns", 3, TXN),
+    op(0x39, "gtxnsa", 3, TXN_UINT8),
+    op(0x3a, "gload", 4, UINT8_UINT8),
+    op(0x3b, "gloads", 4, UINT8),
+    op(0x3c, "gaid", 4, UINT8),
+    op(0x3d, "gaids", 4, NONE),
+    op(0x3e, "loads", 5, NONE),
+    op(0x3f, "stores", 5, NONE),
+    op(0x40, "bnz", 1, LABEL),
+    op(0x41, "bz", 2, LABEL),
+    op(0x42, "b", 2, LABEL),
+    op(0x43, "return", 2, NONE),
+    op(0x44, "assert", 3, NONE),
+    op(0x45, "bury", 8, UINT8),
+    op(0x46, "popn", 8, UINT8),
+    op(0x47, "dupn", 8, UINT8),
+    op(0x48, "pop", 1, NONE),
+    op(0x49, "dup", 1, NONE),
+    op(0x4a, "dup2", 2, NONE),
+    op(0x4b, "dig", 3, UINT8),
+    op(0x4c, "swap", 3, NONE),
+    op(0x4d, "select", 3, NONE),
+    op(0x4e, "cover", 5, UINT8),
+    op(0x4f, "uncover", 5, UINT8),
+    op(0x50, "concat", 2, NONE),
+    op(0x51, "substring", 2, UINT8_UINT8),
+    op(0x52, "substring3", 2, NONE),
+    op(0x53, "getbit", 3, NONE),
+    op(0x54, "setbit", 3, NONE),
+    op(0x55, "getbyte", 3, NONE),
+    op(0x56, "setbyte", 3, NONE),
+    op(0x57, "extract", 5, UINT8_UINT8),
+    op(0x58, "extract3", 5, NONE),
+    op(0x59, "extract_uint16", 5, NONE),
+    op(0x5a, "extract_uint32", 5, NONE),
+    op(0x5b, "extract_uint64", 5, NONE),
+    op(0x5c, "replace2", 7, UINT8),
+    op(0x5d, "replace3", 7, NONE),
+    op(0x5e, "base64_decode", 7, BASE64_ENCODING),
+    op(0x5f, "json_ref", 7, JSON_REF_TYPE),
+    op(0x60, "balance", 2, NONE),
+    op(0x61, "app_opted_in", 2, NONE),
+    op(0x62, "app_local_get", 2, NONE),
+    op(0x63, "app_local_get_ex", 2, NONE),
+    op(0x64, "app_global_get", 2, NONE),
+    op(0x65, "app_global_get_ex", 2, NONE),
+    op(0x66, "app_local_put", 2, NONE),
+    op(0x67, "app_global_put", 2, NONE),
+    op(0x68, "app_local_del", 2, NONE),
+    op(0x69, "app_global_del", 2, NONE),
+    op(0x70, "asset_holding_get", 2, ASSET_HOLDING),
+    op(0x71, "asset_params_get", 2, ASSET_PARAMS),
+    op(0x72, "app_params_get", 5, APP_PARAMS),
+    op(0x73, "acct_params_get", 6, ACCT_PARAMS),
+    op(0x74, "voter_params_get", 11, VOTER_PARAMS),
+    op(0x75, "online_stake", 11, NONE),
+    op(0x78, "min_balance", 3, NONE),
+    op(0x80, "pushbytes", 3, BYTES),
+    op(0x81, "pushint", 3, VARUINT),
+    op(0x82, "pushbytess", 8, BYTE_STRINGS),
+    op(0x83, "pushints", 8, VARUINTS),
+    op(0x84, "ed25519verify_bare", 7, NONE),
+    op(0x88, "callsub", 4, LABEL),
+    op(0x89, "retsub", 4, NONE),
+    op(0x8a, "proto", 8, UINT8_UINT8),
+    op(0x8b, "frame_dig", 8, INT8),
+    op(0x8c, "frame_bury", 8, INT8),
+    op(0x8d, "switch", 8, LABELS),
+    op(0x8e, "match", 8, LABELS),
+    op(0x90, "shl", 4, NONE),
+    op(0x91, "shr", 4, NONE),
+    op(0x92, "sqrt", 4, NONE),
+    op(0x93, "bitlen", 4, NONE),
+    op(0x94, "exp", 4, NONE),
+    op(0x95, "expw", 4, NONE),
+    op(0x96, "bsqrt", 6, NONE),
+    op(0x97, "divw", 6, NONE),
+    op(0x98, "sha3_256", 7, NONE),
+    op(0xa0, "b+", 4, NONE),
+    op(0xa1, "b-", 4, NONE),
+    op(0xa2, "b/", 4, NONE),
+    op(0xa3, "b*", 4, NONE),
+    op(0xa4, "b<", 4, NONE),
+    op(0xa5, "b>", 4, NONE),
+    op(0xa6, "b<=", 4, NONE),
+    op(0xa7, "b>=", 4, NONE),
+    op(0xa8, "b==", 4, NONE),
+    op(0xa9, "b!=", 4, NONE),
+    op(0xaa, "b%", 4, NONE),
+    op(0xab, "b|", 4, NONE),
+    op(0xac, "b&", 4, NONE),
+    op(0xad, "b^", 4, NONE),
+    op(0xae, "b~", 4, NONE),
+    op(0xaf, "bzero", 4, NONE),
+    op(0xb0, "log", 5, NONE),
+    op(0xb1, "itxn_begin", 5, NONE),
+    op(0xb2, "itxn_field", 5, TXN),
+    op(0xb3, "itxn_submit", 5, NONE),
+    op(0xb4, "itxn", 5, TXN),
+    op(0xb5, "itxna", 5, TXN_UINT8),
+    op(0xb6, "itxn_next", 6, NONE),
+    op(0xb7, "gitxn", 6, UINT8_TXN),
+    op(0xb8, "gitxna", 6, UINT8_TXN_UINT8),
+    op(0xb9, "box_create", 8, NONE),
+    op(0xba, "box_extract", 8, NONE),
+    op(0xbb, "box_replace", 8, NONE),
+    op(0xbc, "box_del", 8, NONE),
+    op(0xbd, "box_len", 8, NONE),
+    op(0xbe, "box_get", 8, NONE),
+    op(0xbf, "box_put", 8, NONE),
+    op(0xc0, "txnas", 5, TXN),
+    op(0xc1, "gtxnas", 5, UINT8_TXN),
+    op(0xc2, "gtxnsas", 5, TXN),
+    op(0xc3, "args", 5, NONE),
+    op(0xc4, "gloadss", 6, NONE),
+    op(0xc5, "itxnas", 6, TXN),
+    op(0xc6, "gitxnas", 6, UINT8_TXN),
+    op(0xd0, "vrf_verify", 7, VRF_STANDARD),
+    op(0xd1, "block", 7, BLOCK),
+    op(0xd2, "box_splice", 10, NONE),
+    op(0xd3, "box_resize", 10, NONE),
+    op(0xe0, "ec_add", 10, EC_GROUP),
+    op(0xe1, "ec_scalar_mul", 10, EC_GROUP),
+    op(0xe2, "ec_pairing_check", 10, EC_GROUP),
+    op(0xe3, "ec_multi_scalar_mul", 10, EC_GROUP),
+    op(0xe4, "ec_subgroup_check", 10, EC_GROUP),
+    op(0xe5, "ec_map_to", 10, EC_GROUP),
+    op(0xe6, "mimc", 11, MIMC_CONFIG),
+];
+
+/// Look up an opcode by its byte, regardless of the version that introduced it
+pub fn op_by_code(opcode: u8) -> Option<&'static OpSpec> {
+    OPCODES
+        .binary_search_by_key(&opcode, |spec| spec.opcode)
+        .ok()
+        .map(|i| &OPCODES[i])
+}
+
+/// Look up an opcode by its TEAL name, regardless of the version that introduced it
+pub fn op_by_name(name: &str) -> Option<&'static OpSpec> {
+    OPCODES.iter().find(|spec| spec.name == name)
+}
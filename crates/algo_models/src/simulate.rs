@@ -0,0 +1,723 @@
+//! Requests to and responses from algod's `/v2/transactions/simulate`, in JSON or msgpack.
+//!
+//! Simulation evaluates transaction groups against the current ledger without committing them.
+//! With `allow_empty_signatures` the transactions need not be signed (see
+//! `SignedTransaction::with_empty_signature`), and with an exec trace config the response
+//! records each opcode evaluated. These models use algod's kebab-case names in both encodings,
+//! and addresses outside transactions are base32 strings.
+
+use crate::account::TealValueType;
+use crate::pending::PendingTransactionResponse;
+use crate::{Address, AddressString, AlgorandMsgpack, Base64Bytes, SignedTransaction};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum SimulateError {
+    #[error("invalid simulate JSON: {0}")]
+    InvalidJson(#[from] serde_json::Error),
+}
+
+/// Which parts of the evaluation of each opcode to record
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct SimulateTraceConfig {
+    /// Record the program counter of each opcode. The other options require this one
+    #[serde(default)]
+    pub enable: Option<bool>,
+
+    #[serde(rename = "scratch-change")]
+    #[serde(default)]
+    pub scratch_change: Option<bool>,
+
+    #[serde(rename = "stack-change")]
+    #[serde(default)]
+    pub stack_change: Option<bool>,
+
+    #[serde(rename = "state-change")]
+    #[serde(default)]
+    pub state_change: Option<bool>,
+}
+
+/// A transaction group to simulate
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct SimulateRequestTransactionGroup {
+    pub txns: Vec<SignedTransaction>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct SimulateRequest {
+    #[serde(rename = "txn-groups")]
+    pub txn_groups: Vec<SimulateRequestTransactionGroup>,
+
+    /// The round to simulate in, rather than the latest
+    #[serde(default)]
+    pub round: Option<u64>,
+
+    /// Accept transactions with empty signatures, as if they were signed by the right account
+    #[serde(rename = "allow-empty-signatures")]
+    #[serde(default)]
+    pub allow_empty_signatures: Option<bool>,
+
+    /// Raise the limits on the number and size of logs
+    #[serde(rename = "allow-more-logging")]
+    #[serde(default)]
+    pub allow_more_logging: Option<bool>,
+
+    /// Allow programs to access resources that are not referenced by the transactions, and
+    /// report them in the response
+    #[serde(rename = "allow-unnamed-resources")]
+    #[serde(default)]
+    pub allow_unnamed_resources: Option<bool>,
+
+    /// Budget added to each group of application calls
+    #[serde(rename = "extra-opcode-budget")]
+    #[serde(default)]
+    pub extra_opcode_budget: Option<u64>,
+
+    #[serde(rename = "exec-trace-config")]
+    #[serde(default)]
+    pub exec_trace_config: Option<SimulateTraceConfig>,
+
+    /// Sign each transaction with the account the ledger expects, such as the auth address of
+    /// a rekeyed sender, and report it as `fixed_signer`
+    #[serde(rename = "fix-signers")]
+    #[serde(default)]
+    pub fix_signers: Option<bool>,
+}
+
+impl AlgorandMsgpack for SimulateRequest {
+    const PREFIX: &'static [u8] = b"";
+}
+
+impl SimulateRequest {
+    pub fn from_json(json: &str) -> Result<Self, SimulateError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn to_json(&self) -> Result<String, SimulateError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+/// The limits that the simulation changed from those of normal evaluation
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct SimulationEvalOverrides {
+    #[serde(rename = "allow-empty-signatures")]
+    #[serde(default)]
+    pub allow_empty_signatures: Option<bool>,
+
+    #[serde(rename = "allow-unnamed-resources")]
+    #[serde(default)]
+    pub allow_unnamed_resources: Option<bool>,
+
+    #[serde(rename = "extra-opcode-budget")]
+    #[serde(default)]
+    pub extra_opcode_budget: Option<u64>,
+
+    #[serde(rename = "fix-signers")]
+    #[serde(default)]
+    pub fix_signers: Option<bool>,
+
+    #[serde(rename = "max-log-calls")]
+    #[serde(default)]
+    pub max_log_calls: Option<u64>,
+
+    #[serde(rename = "max-log-size")]
+    #[serde(default)]
+    pub max_log_size: Option<u64>,
+}
+
+/// A value on the stack, in scratch space or in application state. Only the field matching the
+/// type is present
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct AvmValue {
+    #[serde(rename = "type")]
+    pub value_type: TealValueType,
+
+    #[serde_as(as = "Option<Base64Bytes>")]
+    #[serde(default)]
+    pub bytes: Option<Vec<u8>>,
+
+    #[serde(default)]
+    pub uint: Option<u64>,
+}
+
+/// A write to a scratch slot
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct ScratchChange {
+    pub slot: u64,
+
+    #[serde(rename = "new-value")]
+    pub new_value: AvmValue,
+}
+
+/// A write to or deletion from application state
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct ApplicationStateOperation {
+    /// `w` for a write or `d` for a deletion
+    pub operation: String,
+
+    /// `g` for global state, `l` for local state or `b` for a box
+    #[serde(rename = "app-state-type")]
+    pub app_state_type: String,
+
+    #[serde_as(as = "Base64Bytes")]
+    pub key: Vec<u8>,
+
+    /// The value written. Absent for deletions
+    #[serde(rename = "new-value")]
+    #[serde(default)]
+    pub new_value: Option<AvmValue>,
+
+    /// The account whose local state changed
+    #[serde_as(as = "Option<AddressString>")]
+    #[serde(default)]
+    pub account: Option<Address>,
+}
+
+/// The effects of evaluating an opcode
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct SimulationOpcodeTraceUnit {
+    /// The program counter of the opcode
+    pub pc: u64,
+
+    /// The indexes into `inner_trace` of the inner transactions the opcode sent
+    #[serde(rename = "spawned-inners")]
+    #[serde(default)]
+    pub spawned_inners: Option<Vec<u64>>,
+
+    #[serde(rename = "stack-pop-count")]
+    #[serde(default)]
+    pub stack_pop_count: Option<u64>,
+
+    #[serde(rename = "stack-additions")]
+    #[serde(default)]
+    pub stack_additions: Option<Vec<AvmValue>>,
+
+    #[serde(rename = "scratch-changes")]
+    #[serde(default)]
+    pub scratch_changes: Option<Vec<ScratchChange>>,
+
+    #[serde(rename = "state-changes")]
+    #[serde(default)]
+    pub state_changes: Option<Vec<ApplicationStateOperation>>,
+}
+
+/// The opcodes evaluated for a transaction and its inner transactions. Each program is
+/// identified by its SHA-512/256 hash
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct SimulationTransactionExecTrace {
+    #[serde(rename = "approval-program-trace")]
+    #[serde(default)]
+    pub approval_program_trace: Option<Vec<SimulationOpcodeTraceUnit>>,
+
+    #[serde(rename = "approval-program-hash")]
+    #[serde_as(as = "Option<Base64Bytes>")]
+    #[serde(default)]
+    pub approval_program_hash: Option<Vec<u8>>,
+
+    #[serde(rename = "clear-state-program-trace")]
+    #[serde(default)]
+    pub clear_state_program_trace: Option<Vec<SimulationOpcodeTraceUnit>>,
+
+    #[serde(rename = "clear-state-program-hash")]
+    #[serde_as(as = "Option<Base64Bytes>")]
+    #[serde(default)]
+    pub clear_state_program_hash: Option<Vec<u8>>,
+
+    /// Whether the clear state program failed, so its changes to state were rolled back
+    #[serde(rename = "clear-state-rollback")]
+    #[serde(default)]
+    pub clear_state_rollback: Option<bool>,
+
+    #[serde(rename = "clear-state-rollback-error")]
+    #[serde(default)]
+    pub clear_state_rollback_error: Option<String>,
+
+    #[serde(rename = "logic-sig-trace")]
+    #[serde(default)]
+    pub logic_sig_trace: Option<Vec<SimulationOpcodeTraceUnit>>,
+
+    #[serde(rename = "logic-sig-hash")]
+    #[serde_as(as = "Option<Base64Bytes>")]
+    #[serde(default)]
+    pub logic_sig_hash: Option<Vec<u8>>,
+
+    /// The traces of the inner transactions, in the order they were sent
+    #[serde(rename = "inner-trace")]
+    #[serde(default)]
+    pub inner_trace: Option<Vec<SimulationTransactionExecTrace>>,
+}
+
+/// A box accessed without being referenced
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct SimulateBoxReference {
+    pub app: u64,
+
+    #[serde_as(as = "Base64Bytes")]
+    pub name: Vec<u8>,
+}
+
+/// An account's local state in an application, accessed without being referenced
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct ApplicationLocalReference {
+    #[serde_as(as = "AddressString")]
+    pub account: Address,
+
+    pub app: u64,
+}
+
+/// An account's holding of an asset, accessed without being referenced
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct AssetHoldingReference {
+    #[serde_as(as = "AddressString")]
+    pub account: Address,
+
+    pub asset: u64,
+}
+
+/// The resources a transaction or group accessed without referencing them, which it would
+/// need to reference to be evaluated outside simulation
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct UnnamedResourcesAccessed {
+    #[serde_as(as = "Option<Vec<AddressString>>")]
+    #[serde(default)]
+    pub accounts: Option<Vec<Address>>,
+
+    #[serde(default)]
+    pub apps: Option<Vec<u64>>,
+
+    #[serde(default)]
+    pub assets: Option<Vec<u64>>,
+
+    #[serde(default)]
+    pub boxes: Option<Vec<SimulateBoxReference>>,
+
+    /// The number of empty box references needed for the box I/O budget
+    #[serde(rename = "extra-box-refs")]
+    #[serde(default)]
+    pub extra_box_refs: Option<u64>,
+
+    #[serde(rename = "app-locals")]
+    #[serde(default)]
+    pub app_locals: Option<Vec<ApplicationLocalReference>>,
+
+    #[serde(rename = "asset-holdings")]
+    #[serde(default)]
+    pub asset_holdings: Option<Vec<AssetHoldingReference>>,
+}
+
+/// The result of simulating a transaction
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct SimulateTransactionResult {
+    #[serde(rename = "txn-result")]
+    pub txn_result: PendingTransactionResponse,
+
+    /// The budget used by the application call and its inner transactions
+    #[serde(rename = "app-budget-consumed")]
+    #[serde(default)]
+    pub app_budget_consumed: Option<u64>,
+
+    #[serde(rename = "logic-sig-budget-consumed")]
+    #[serde(default)]
+    pub logic_sig_budget_consumed: Option<u64>,
+
+    #[serde(rename = "exec-trace")]
+    #[serde(default)]
+    pub exec_trace: Option<SimulationTransactionExecTrace>,
+
+    /// Resources accessed by this transaction alone. Resources that could be referenced by any
+    /// transaction in the group are reported with the group instead
+    #[serde(rename = "unnamed-resources-accessed")]
+    #[serde(default)]
+    pub unnamed_resources_accessed: Option<UnnamedResourcesAccessed>,
+
+    /// The account the transaction must be signed by, when `fix_signers` found it differs
+    #[serde(rename = "fixed-signer")]
+    #[serde_as(as = "Option<AddressString>")]
+    #[serde(default)]
+    pub fixed_signer: Option<Address>,
+}
+
+/// The result of simulating a transaction group
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct SimulateTransactionGroupResult {
+    #[serde(rename = "txn-results")]
+    pub txn_results: Vec<SimulateTransactionResult>,
+
+    /// The path to the transaction that failed: its index in the group, followed by the index
+    /// of the inner transaction at each level
+    #[serde(rename = "failed-at")]
+    #[serde(default)]
+    pub failed_at: Option<Vec<u64>>,
+
+    #[serde(rename = "failure-message")]
+    #[serde(default)]
+    pub failure_message: Option<String>,
+
+    /// The budget added by the group's application calls, which share it
+    #[serde(rename = "app-budget-added")]
+    #[serde(default)]
+    pub app_budget_added: Option<u64>,
+
+    #[serde(rename = "app-budget-consumed")]
+    #[serde(default)]
+    pub app_budget_consumed: Option<u64>,
+
+    #[serde(rename = "unnamed-resources-accessed")]
+    #[serde(default)]
+    pub unnamed_resources_accessed: Option<UnnamedResourcesAccessed>,
+}
+
+/// A key and its value in application state or a box
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct AvmKeyValue {
+    #[serde_as(as = "Base64Bytes")]
+    pub key: Vec<u8>,
+
+    pub value: AvmValue,
+}
+
+/// The values of some keys of an application's global state, boxes, or an account's local
+/// state
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct ApplicationKvStorage {
+    /// The account, for local state
+    #[serde_as(as = "Option<AddressString>")]
+    #[serde(default)]
+    pub account: Option<Address>,
+
+    pub kvs: Vec<AvmKeyValue>,
+}
+
+/// The state of an application before the simulation changed it
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct ApplicationInitialStates {
+    pub id: u64,
+
+    #[serde(rename = "app-locals")]
+    #[serde(default)]
+    pub app_locals: Option<Vec<ApplicationKvStorage>>,
+
+    #[serde(rename = "app-globals")]
+    #[serde(default)]
+    pub app_globals: Option<ApplicationKvStorage>,
+
+    #[serde(rename = "app-boxes")]
+    #[serde(default)]
+    pub app_boxes: Option<ApplicationKvStorage>,
+}
+
+/// The state the simulation started from, for the keys it changed. Present when state changes
+/// are traced
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct SimulateInitialStates {
+    #[serde(rename = "app-initial-states")]
+    #[serde(default)]
+    pub app_initial_states: Option<Vec<ApplicationInitialStates>>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct SimulateResponse {
+    /// The version of the response format
+    pub version: u64,
+
+    /// The round the simulation was evaluated after
+    #[serde(rename = "last-round")]
+    pub last_round: u64,
+
+    #[serde(rename = "txn-groups")]
+    pub txn_groups: Vec<SimulateTransactionGroupResult>,
+
+    #[serde(rename = "eval-overrides")]
+    #[serde(default)]
+    pub eval_overrides: Option<SimulationEvalOverrides>,
+
+    #[serde(rename = "exec-trace-config")]
+    #[serde(default)]
+    pub exec_trace_config: Option<SimulateTraceConfig>,
+
+    #[serde(rename = "initial-states")]
+    #[serde(default)]
+    pub initial_states: Option<SimulateInitialStates>,
+}
+
+impl AlgorandMsgpack for SimulateResponse {
+    const PREFIX: &'static [u8] = b"";
+}
+
+impl SimulateResponse {
+    pub fn from_json(json: &str) -> Result<Self, SimulateError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn to_json(&self) -> Result<String, SimulateError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Whether every group was evaluated without failing
+    pub fn would_succeed(&self) -> bool {
+        self.txn_groups
+            .iter()
+            .all(|group| group.failure_message.is_none())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::apply_data::ValueDeltaAction;
+    use crate::pending::{EvalDeltaKeyValue, EvalDeltaValue};
+    use crate::Transaction;
+    use pretty_assertions::assert_eq;
+
+    // The same simulation of a payment and an application call, in both formats, with
+    // made-up accounts and programs
+    const REQUEST_JSON: &str = include_str!("../test_data/simulate_request.json");
+    const REQUEST_MSGPACK: &[u8] = include_bytes!("../test_data/simulate_request.msgpack");
+    const RESPONSE_JSON: &str = include_str!("../test_data/simulate_response.json");
+    const RESPONSE_MSGPACK: &[u8] = include_bytes!("../test_data/simulate_response.msgpack");
+
+    #[test]
+    fn test_simulate_request() {
+        let request = SimulateRequest::decode(REQUEST_MSGPACK).unwrap();
+        assert_eq!(SimulateRequest::from_json(REQUEST_JSON).unwrap(), request);
+        assert_eq!(request.encode().unwrap(), REQUEST_MSGPACK);
+        assert_eq!(
+            SimulateRequest::from_json(&request.to_json().unwrap()).unwrap(),
+            request
+        );
+
+        assert_eq!(request.allow_empty_signatures, Some(true));
+        assert_eq!(request.round, None);
+        assert_eq!(
+            request.exec_trace_config.as_ref().unwrap().stack_change,
+            Some(true)
+        );
+
+        let txns = &request.txn_groups[0].txns;
+        assert!(txns.iter().all(SignedTransaction::has_empty_signature));
+        assert_eq!(
+            txns[0].transaction.id().unwrap(),
+            "IINGACHRGXTJTBYOERKT6LOLOSXSFRD5OPRBOI7HY7TCOIQ4NHBA"
+        );
+        assert!(matches!(
+            txns[1].transaction,
+            Transaction::ApplicationCall(_)
+        ));
+
+        // Wrapping the unsigned transactions again gives the same request
+        let rebuilt = SimulateRequest {
+            txn_groups: vec![SimulateRequestTransactionGroup {
+                txns: txns
+                    .iter()
+                    .map(|txn| SignedTransaction::with_empty_signature(txn.transaction.clone()))
+                    .collect(),
+            }],
+            allow_empty_signatures: Some(true),
+            allow_unnamed_resources: Some(true),
+            exec_trace_config: Some(SimulateTraceConfig {
+                enable: Some(true),
+                scratch_change: Some(true),
+                stack_change: Some(true),
+                state_change: Some(true),
+            }),
+            ..Default::default()
+        };
+        assert_eq!(rebuilt.encode().unwrap(), REQUEST_MSGPACK);
+    }
+
+    #[test]
+    fn test_simulate_request_signatures() {
+        // A group of a payment from an account rekeyed to a multisig, partially signed, and a
+        // payment approved by a delegated logic signature
+        let txns = [
+            include_bytes!("../test_data/multisig.stxn").as_slice(),
+            include_bytes!("../test_data/logicsig.stxn"),
+        ]
+        .iter()
+        .map(|bytes| SignedTransaction::decode(bytes).unwrap())
+        .collect();
+        let request = SimulateRequest {
+            txn_groups: vec![SimulateRequestTransactionGroup { txns }],
+            allow_empty_signatures: Some(true),
+            ..Default::default()
+        };
+
+        let decoded = SimulateRequest::decode(&request.encode().unwrap()).unwrap();
+        assert_eq!(decoded, request);
+        let txns = &decoded.txn_groups[0].txns;
+        assert!(txns[0].multisig_signature.is_some());
+        assert!(txns[0].auth_address.is_some());
+        assert!(txns[1].logic_signature.is_some());
+
+        // In JSON the auth address is base32 and signatures and programs are base64, like algod
+        let json: serde_json::Value = serde_json::from_str(&request.to_json().unwrap()).unwrap();
+        let txns = &json["txn-groups"][0]["txns"];
+        assert_eq!(
+            txns[0]["sgnr"],
+            "KUIRTXLNEOOP7FL34O2SWPD6MI3R7UN6T6HTXNGQS5R5S3PKUTFXCJE6LE"
+        );
+        assert_eq!(txns[0]["msig"]["thr"], 2);
+        assert!(txns[0]["msig"]["subsig"][0]["s"].is_string());
+        assert_eq!(txns[1]["lsig"]["l"], "CIEB");
+        assert_eq!(
+            SimulateRequest::from_json(&request.to_json().unwrap()).unwrap(),
+            request
+        );
+    }
+
+    #[test]
+    fn test_simulate_response() {
+        let response = SimulateResponse::decode(RESPONSE_MSGPACK).unwrap();
+        assert_eq!(
+            SimulateResponse::from_json(RESPONSE_JSON).unwrap(),
+            response
+        );
+        assert_eq!(response.encode().unwrap(), RESPONSE_MSGPACK);
+        assert_eq!(
+            SimulateResponse::from_json(&response.to_json().unwrap()).unwrap(),
+            response
+        );
+
+        assert_eq!(response.version, 2);
+        assert_eq!(response.last_round, 45_999_999);
+        assert!(response.would_succeed());
+
+        let group = &response.txn_groups[0];
+        assert_eq!(group.app_budget_added, Some(700));
+        assert_eq!(group.app_budget_consumed, Some(41));
+        assert_eq!(group.failed_at, None);
+
+        let app_call = &group.txn_results[1];
+        assert_eq!(app_call.app_budget_consumed, Some(41));
+        let result = &app_call.txn_result;
+        assert_eq!(result.pool_error, "");
+        assert_eq!(
+            result.global_state_delta,
+            Some(vec![EvalDeltaKeyValue {
+                key: b"counter".to_vec(),
+                value: EvalDeltaValue {
+                    action: ValueDeltaAction::SetUint,
+                    bytes: None,
+                    uint: Some(42)
+                }
+            }])
+        );
+        assert_eq!(result.logs.as_ref().unwrap()[0], b"withdrawn");
+
+        let inner = &result.inner_txns.as_ref().unwrap()[0];
+        assert_eq!(
+            inner.txn.transaction.header().sender,
+            Address::from_app_id(1002)
+        );
+    }
+
+    #[test]
+    fn test_exec_trace() {
+        let response = SimulateResponse::decode(RESPONSE_MSGPACK).unwrap();
+        let trace = response.txn_groups[0].txn_results[1]
+            .exec_trace
+            .as_ref()
+            .unwrap();
+        assert_eq!(trace.approval_program_hash.as_ref().map(Vec::len), Some(32));
+        assert_eq!(
+            trace.inner_trace,
+            Some(vec![SimulationTransactionExecTrace::default()])
+        );
+
+        let units = trace.approval_program_trace.as_ref().unwrap();
+        assert_eq!(units.len(), 13);
+        assert_eq!(
+            units[1].stack_additions,
+            Some(vec![AvmValue {
+                value_type: TealValueType::Bytes,
+                bytes: Some(b"counter".to_vec()),
+                uint: None
+            }])
+        );
+        assert_eq!(
+            units[5].scratch_changes.as_ref().unwrap()[0].new_value.uint,
+            Some(42)
+        );
+        assert_eq!(
+            units[8].state_changes,
+            Some(vec![ApplicationStateOperation {
+                operation: "w".to_string(),
+                app_state_type: "g".to_string(),
+                key: b"counter".to_vec(),
+                new_value: Some(AvmValue {
+                    value_type: TealValueType::Uint,
+                    bytes: None,
+                    uint: Some(42)
+                }),
+                account: None
+            }])
+        );
+        assert_eq!(units[10].spawned_inners, Some(vec![0]));
+
+        let initial = &response.initial_states.as_ref().unwrap().app_initial_states;
+        let globals = initial.as_ref().unwrap()[0].app_globals.as_ref().unwrap();
+        assert_eq!(globals.kvs[0].value.uint, Some(41));
+    }
+
+    #[test]
+    fn test_unnamed_resources() {
+        let response = SimulateResponse::decode(RESPONSE_MSGPACK).unwrap();
+        let group = &response.txn_groups[0];
+        assert!(group
+            .txn_results
+            .iter()
+            .all(|result| result.unnamed_resources_accessed.is_none()));
+
+        let carol =
+            Address::from_string("A5GYGXI6K5GQ6WY6ML3BZ3KGPV6S4KODPTXXRZMONEFRCSYDPXKOG5G2GM")
+                .unwrap();
+        assert_eq!(
+            group.unnamed_resources_accessed,
+            Some(UnnamedResourcesAccessed {
+                accounts: Some(vec![carol.clone()]),
+                apps: Some(vec![1003]),
+                assets: None,
+                boxes: Some(vec![SimulateBoxReference {
+                    app: 1002,
+                    name: b"user".to_vec()
+                }]),
+                extra_box_refs: Some(1),
+                app_locals: Some(vec![ApplicationLocalReference {
+                    account: carol.clone(),
+                    app: 1002
+                }]),
+                asset_holdings: Some(vec![AssetHoldingReference {
+                    account: carol,
+                    asset: 31_566_704
+                }]),
+            })
+        );
+    }
+}
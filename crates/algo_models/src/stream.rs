@@ -0,0 +1,196 @@
+//! Signed transactions concatenated without any framing.
+//!
+//! algod's `/v2/transactions` takes a transaction group as the msgpack encodings of its signed
+//! transactions one after another, and `goal` writes `.stxn` files the same way. Each encoding
+//! is a self-delimiting msgpack map, so the transactions are read back one at a time.
+
+use crate::{AlgoModelsError, AlgorandMsgpack, SignedTransaction};
+use serde::Deserialize;
+use std::io::{BufRead, BufReader, Read};
+
+/// The most bytes read for one signed transaction, far more than any transaction the protocol
+/// allows, so that a stream declaring huge lengths cannot make the reader consume it all
+pub const MAX_SIGNED_TRANSACTION_SIZE: u64 = 64 * 1024;
+
+/// Encode the signed transactions of a group for submission to algod
+pub fn encode_group(transactions: &[SignedTransaction]) -> Result<Vec<u8>, AlgoModelsError> {
+    let mut encoded = Vec::new();
+    for transaction in transactions {
+        encoded.extend_from_slice(&transaction.encode()?);
+    }
+    Ok(encoded)
+}
+
+/// Decode all of the concatenated signed transactions in `bytes`
+pub fn decode_group(bytes: &[u8]) -> Result<Vec<SignedTransaction>, AlgoModelsError> {
+    SignedTransactionReader::new(bytes).collect()
+}
+
+/// Reads concatenated signed transactions from a stream, decoding each as it is needed.
+/// Iteration ends at the end of the stream, or after the first error
+pub struct SignedTransactionReader<R: Read> {
+    reader: BufReader<R>,
+    failed: bool,
+}
+
+impl<R: Read> SignedTransactionReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader: BufReader::new(reader),
+            failed: false,
+        }
+    }
+
+    fn read_next(&mut self) -> Option<Result<SignedTransaction, AlgoModelsError>> {
+        match self.reader.fill_buf() {
+            Ok([]) => return None,
+            Ok(_) => {}
+            Err(e) => return Some(Err(rmp_serde::decode::Error::InvalidDataRead(e).into())),
+        }
+
+        let mut limited = (&mut self.reader).take(MAX_SIGNED_TRANSACTION_SIZE);
+        let mut deserializer = rmp_serde::Deserializer::new(&mut limited);
+        let result = SignedTransaction::deserialize(&mut deserializer);
+
+        Some(match result {
+            Err(_) if limited.limit() == 0 => Err(AlgoModelsError::InputError(format!(
+                "signed transaction exceeds the maximum of {MAX_SIGNED_TRANSACTION_SIZE} bytes"
+            ))),
+            result => result.map_err(AlgoModelsError::from),
+        })
+    }
+}
+
+impl<R: Read> Iterator for SignedTransactionReader<R> {
+    type Item = Result<SignedTransaction, AlgoModelsError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        let next = self.read_next();
+        self.failed = matches!(next, Some(Err(_)));
+        next
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulate::SimulateRequest;
+    use pretty_assertions::assert_eq;
+
+    const REQUEST_MSGPACK: &[u8] = include_bytes!("../test_data/simulate_request.msgpack");
+
+    /// The group of the simulate request fixture, with made-up signatures
+    fn signed_group() -> Vec<SignedTransaction> {
+        let request = SimulateRequest::decode(REQUEST_MSGPACK).unwrap();
+        let mut group = request.txn_groups[0].txns.clone();
+        for (i, transaction) in group.iter_mut().enumerate() {
+            transaction.signature = [i as u8 + 1; 64];
+        }
+        group
+    }
+
+    /// A payment signed by a single account, one from a rekeyed account signed by a multisig
+    /// and one approved by a logic signature, one after another
+    fn mixed_signatures() -> Vec<u8> {
+        [
+            include_bytes!("../test_data/payment.stxn").as_slice(),
+            include_bytes!("../test_data/multisig.stxn"),
+            include_bytes!("../test_data/logicsig.stxn"),
+        ]
+        .concat()
+    }
+
+    /// Returns a single byte from each read, like a slow network stream
+    struct OneByteAtATime<'a>(&'a [u8]);
+
+    impl Read for OneByteAtATime<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            match (self.0.split_first(), buf.first_mut()) {
+                (Some((byte, rest)), Some(first)) => {
+                    *first = *byte;
+                    self.0 = rest;
+                    Ok(1)
+                }
+                _ => Ok(0),
+            }
+        }
+    }
+
+    #[test]
+    fn test_encode_group() {
+        let group = signed_group();
+        let encoded = encode_group(&group).unwrap();
+
+        let first = group[0].encode().unwrap();
+        assert_eq!(encoded[..first.len()], first);
+        assert_eq!(encoded[first.len()..], group[1].encode().unwrap());
+
+        assert_eq!(decode_group(&encoded).unwrap(), group);
+        assert_eq!(encode_group(&[]).unwrap(), Vec::<u8>::new());
+        assert_eq!(decode_group(&[]).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_reader() {
+        let group = signed_group();
+        let encoded = encode_group(&group).unwrap();
+
+        let mut reader = SignedTransactionReader::new(OneByteAtATime(&encoded));
+        assert_eq!(reader.next().unwrap().unwrap(), group[0]);
+        assert_eq!(reader.next().unwrap().unwrap(), group[1]);
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_reader_truncated() {
+        let group = signed_group();
+        let encoded = encode_group(&group).unwrap();
+
+        // The first transaction is still read, then the error ends iteration
+        let mut reader = SignedTransactionReader::new(&encoded[..encoded.len() - 10]);
+        assert_eq!(reader.next().unwrap().unwrap(), group[0]);
+        assert!(reader.next().unwrap().is_err());
+        assert!(reader.next().is_none());
+
+        assert!(decode_group(&encoded[..encoded.len() - 10]).is_err());
+    }
+
+    #[test]
+    fn test_reader_oversized() {
+        // A note declared as 4 GiB long, followed by an endless stream, is not read to the end
+        let header = [
+            &[0x81, 0xa3][..],
+            b"txn",
+            &[0x81, 0xa4],
+            b"note",
+            &[0xc6, 0xff, 0xff, 0xff, 0xff],
+        ]
+        .concat();
+        let mut reader = SignedTransactionReader::new(header.chain(std::io::repeat(0)));
+        assert_eq!(
+            reader.next().unwrap().unwrap_err().to_string(),
+            "signed transaction exceeds the maximum of 65536 bytes"
+        );
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_mixed_signatures() {
+        let encoded = mixed_signatures();
+        let group: Vec<_> = SignedTransactionReader::new(OneByteAtATime(&encoded))
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(group.len(), 3);
+        assert!(!group[0].has_empty_signature());
+        assert!(group[1].multisig_signature.is_some());
+        assert!(group[1].auth_address.is_some());
+        assert!(group[2].logic_signature.is_some());
+
+        // Re-encoding for submission keeps every kind of signature
+        assert_eq!(encode_group(&group).unwrap(), encoded);
+    }
+}
@@ -0,0 +1,222 @@
+//! Transaction files as written and read by `goal clerk`.
+//!
+//! `.txn`, `.stxn` and `.gtxn` files all hold signed transactions concatenated without any
+//! framing, like the body of a submission to algod. `goal` writes an unsigned transaction as a
+//! signed transaction with an empty signature, so it is wrapped in `{"txn": ...}` too. A `.gtxn`
+//! file holds the transactions of a group, signed or not.
+
+use crate::stream::{encode_group, SignedTransactionReader};
+use crate::{Address, AlgoModelsError, MultisigSignature, SignedTransaction, Transaction};
+use std::io::{Read, Write};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum TransactionFileError {
+    #[error("error writing transaction file: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("error inspecting transaction file: {0}")]
+    InvalidJson(#[from] serde_json::Error),
+}
+
+/// Read all of the transactions in a transaction file
+pub fn read_transaction_file(reader: impl Read) -> Result<Vec<SignedTransaction>, AlgoModelsError> {
+    SignedTransactionReader::new(reader).collect()
+}
+
+/// Write signed transactions as a `.stxn` or `.gtxn` file
+pub fn write_transaction_file(
+    mut writer: impl Write,
+    transactions: &[SignedTransaction],
+) -> Result<(), AlgoModelsError> {
+    writer
+        .write_all(&encode_group(transactions)?)
+        .map_err(TransactionFileError::from)?;
+    Ok(())
+}
+
+/// Write unsigned transactions as a `.txn` or `.gtxn` file, each wrapped in `{"txn": ...}`
+pub fn write_unsigned_transaction_file(
+    writer: impl Write,
+    transactions: &[Transaction],
+) -> Result<(), AlgoModelsError> {
+    let wrapped: Vec<_> = transactions
+        .iter()
+        .cloned()
+        .map(SignedTransaction::with_empty_signature)
+        .collect();
+    write_transaction_file(writer, &wrapped)
+}
+
+/// Describe the transactions of a file like `goal clerk inspect`: each is headed by the file
+/// name and its index, followed by its JSON with sorted keys and a blank line
+pub fn inspect_transaction_file(
+    file_name: &str,
+    transactions: &[SignedTransaction],
+) -> Result<String, AlgoModelsError> {
+    let mut inspected = String::new();
+    for (index, transaction) in transactions.iter().enumerate() {
+        // Going through a `Value` sorts the keys of every object
+        let mut value = serde_json::to_value(transaction).map_err(TransactionFileError::from)?;
+        if let Some(msig) = &transaction.multisig_signature {
+            inspect_multisig(&mut value["msig"], msig);
+        }
+        if let Some(lsig) = &transaction.logic_signature {
+            if let Some(msig) = &lsig.multisig_signature {
+                inspect_multisig(&mut value["lsig"]["msig"], msig);
+            }
+            if let Some(lmsig) = &lsig.logic_multisig_signature {
+                inspect_multisig(&mut value["lsig"]["lmsig"], lmsig);
+            }
+        }
+
+        let json = serde_json::to_string_pretty(&value).map_err(TransactionFileError::from)?;
+        inspected.push_str(&format!("{}[{}]\n{}\n\n", file_name, index, json));
+    }
+    Ok(inspected)
+}
+
+/// `goal clerk inspect` shows the accounts of a multisig as addresses rather than public keys
+fn inspect_multisig(value: &mut serde_json::Value, msig: &MultisigSignature) {
+    if let Some(values) = value["subsig"].as_array_mut() {
+        for (subsig, value) in msig.subsignatures.iter().zip(values) {
+            value["pk"] = Address::from_pubkey(&subsig.public_key).address().into();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulate::SimulateRequest;
+    use crate::AlgorandMsgpack;
+    use pretty_assertions::assert_eq;
+
+    const REQUEST_MSGPACK: &[u8] = include_bytes!("../test_data/simulate_request.msgpack");
+
+    // A payment written by `goal clerk send -o` and then signed, with made-up accounts
+    const SIGNED_STXN: &[u8] = include_bytes!("../test_data/payment.stxn");
+    const SIGNED_INSPECT: &str = include_str!("../test_data/payment.stxn.inspect");
+
+    // A payment from an account rekeyed to a 2-of-3 multisig, signed by two of its accounts so
+    // far, and a payment from the multisig approved by a program it delegated, with made-up
+    // accounts
+    const MULTISIG_STXN: &[u8] = include_bytes!("../test_data/multisig.stxn");
+    const MULTISIG_INSPECT: &str = include_str!("../test_data/multisig.stxn.inspect");
+    const LOGICSIG_STXN: &[u8] = include_bytes!("../test_data/logicsig.stxn");
+    const LOGICSIG_INSPECT: &str = include_str!("../test_data/logicsig.stxn.inspect");
+
+    fn unsigned_group() -> Vec<Transaction> {
+        let request = SimulateRequest::decode(REQUEST_MSGPACK).unwrap();
+        request.txn_groups[0]
+            .txns
+            .iter()
+            .map(|txn| txn.transaction.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_unsigned_file() {
+        let group = unsigned_group();
+        let mut file = Vec::new();
+        write_unsigned_transaction_file(&mut file, &group).unwrap();
+
+        // Each transaction is a map with the single key "txn"
+        assert_eq!(file[..4], [0x81, 0xa3, b't', b'x']);
+
+        let read = read_transaction_file(file.as_slice()).unwrap();
+        assert_eq!(read.len(), 2);
+        assert!(read.iter().all(SignedTransaction::has_empty_signature));
+        assert_eq!(read[0].transaction, group[0]);
+        assert_eq!(read[1].transaction, group[1]);
+    }
+
+    #[test]
+    fn test_signed_file() {
+        let transactions = read_transaction_file(SIGNED_STXN).unwrap();
+        assert_eq!(transactions.len(), 1);
+        assert!(!transactions[0].has_empty_signature());
+
+        let mut file = Vec::new();
+        write_transaction_file(&mut file, &transactions).unwrap();
+        assert_eq!(file, SIGNED_STXN);
+
+        assert!(read_transaction_file(&SIGNED_STXN[..SIGNED_STXN.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_inspect() {
+        let transactions = read_transaction_file(SIGNED_STXN).unwrap();
+        assert_eq!(
+            inspect_transaction_file("payment.stxn", &transactions).unwrap(),
+            SIGNED_INSPECT
+        );
+
+        let unsigned = vec![SignedTransaction::with_empty_signature(
+            transactions[0].transaction.clone(),
+        )];
+        let inspected = inspect_transaction_file("payment.txn", &unsigned).unwrap();
+        assert!(inspected.starts_with("payment.txn[0]\n{\n  \"txn\": {\n    \"amt\": "));
+        assert!(!inspected.contains("\"sig\""));
+    }
+
+    #[test]
+    fn test_multisig_file() {
+        let transactions = read_transaction_file(MULTISIG_STXN).unwrap();
+        let msig = transactions[0].multisig_signature.as_ref().unwrap();
+        assert_eq!((msig.version, msig.threshold), (1, 2));
+        let signed: Vec<_> = msig
+            .subsignatures
+            .iter()
+            .map(|subsig| subsig.signature.is_some())
+            .collect();
+        assert_eq!(signed, [true, false, true]);
+        assert!(transactions[0].has_empty_signature());
+        assert_eq!(
+            msig.address().address(),
+            "KUIRTXLNEOOP7FL34O2SWPD6MI3R7UN6T6HTXNGQS5R5S3PKUTFXCJE6LE"
+        );
+        assert_eq!(transactions[0].auth_address, Some(msig.address()));
+
+        // Rewriting a partially signed file keeps its signatures
+        let mut file = Vec::new();
+        write_transaction_file(&mut file, &transactions).unwrap();
+        assert_eq!(file, MULTISIG_STXN);
+
+        assert_eq!(
+            inspect_transaction_file("multisig.stxn", &transactions).unwrap(),
+            MULTISIG_INSPECT
+        );
+    }
+
+    #[test]
+    fn test_logic_signature_file() {
+        let transactions = read_transaction_file(LOGICSIG_STXN).unwrap();
+        let lsig = transactions[0].logic_signature.as_ref().unwrap();
+        assert_eq!(lsig.logic, [8, 0x81, 1]);
+        assert_eq!(lsig.args.as_ref().unwrap()[0], b"secret");
+        assert_eq!(lsig.signature, None);
+        assert_eq!(
+            lsig.multisig_signature
+                .as_ref()
+                .unwrap()
+                .subsignatures
+                .len(),
+            3
+        );
+        assert_eq!(transactions[0].auth_address, None);
+        assert_eq!(
+            transactions[0].transaction.header().sender,
+            lsig.multisig_signature.as_ref().unwrap().address()
+        );
+
+        let mut file = Vec::new();
+        write_transaction_file(&mut file, &transactions).unwrap();
+        assert_eq!(file, LOGICSIG_STXN);
+
+        assert_eq!(
+            inspect_transaction_file("logicsig.stxn", &transactions).unwrap(),
+            LOGICSIG_INSPECT
+        );
+    }
+}
@@ -0,0 +1,598 @@
+//! Checks that a transaction is well formed before it is signed and sent.
+//!
+//! These are the checks algod makes on a transaction on its own, without looking at the ledger:
+//! the validity window, field sizes and the limits on application calls. They follow the limits
+//! in `ConsensusParams`, so they can be run against the rules of any protocol version.
+
+use crate::consensus::ConsensusParams;
+use crate::{
+    ApplicationCallTransactionFields, AssetTransferTransactionFields, OnApplicationComplete,
+    PayTransactionFields, StateSchema, Transaction, TransactionHeader, TransactionType,
+};
+use std::fmt;
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum ValidationError {
+    #[error("transaction type {found:?} does not match its {expected:?} fields")]
+    TypeMismatch {
+        expected: TransactionType,
+        found: TransactionType,
+    },
+
+    #[error("last valid round {last_valid} is before first valid round {first_valid}")]
+    InvalidValidityWindow { first_valid: u64, last_valid: u64 },
+
+    #[error("validity window of {rounds} rounds exceeds the maximum of {max}")]
+    ValidityWindowTooLong { rounds: u64, max: u64 },
+
+    #[error("note of {size} bytes exceeds the maximum of {max}")]
+    NoteTooLarge { size: usize, max: usize },
+
+    #[error("an account cannot be closed to its sender")]
+    CloseToSender,
+
+    #[error("asset transfers require a non-zero asset ID")]
+    ZeroAssetId,
+
+    #[error("programs can only be set when creating or updating an application")]
+    ProgramsNotAllowed,
+
+    #[error("{0} can only be set when creating an application")]
+    Immutable(&'static str),
+
+    #[error("{pages} extra program pages exceeds the maximum of {max}")]
+    TooManyExtraProgramPages { pages: u64, max: u64 },
+
+    #[error("{program} program of {size} bytes exceeds the maximum of {max}")]
+    ProgramTooLarge {
+        program: &'static str,
+        size: usize,
+        max: usize,
+    },
+
+    #[error("programs of {size} bytes exceed the maximum of {max}")]
+    ProgramsTooLarge { size: usize, max: usize },
+
+    #[error("{count} {kind} exceeds the maximum of {max}")]
+    TooMany {
+        kind: &'static str,
+        count: usize,
+        max: usize,
+    },
+
+    #[error("arguments of {size} bytes exceed the maximum of {max}")]
+    ArgsTooLarge { size: usize, max: usize },
+
+    #[error("box reference to application index {app_index} is out of range")]
+    InvalidBoxReference { app_index: u64, foreign_apps: usize },
+
+    #[error("box name of {size} bytes exceeds the maximum of {max}")]
+    BoxNameTooLong { size: usize, max: usize },
+
+    #[error("{scope} state schema of {entries} values exceeds the maximum of {max}")]
+    SchemaTooLarge {
+        scope: &'static str,
+        entries: u64,
+        max: u64,
+    },
+}
+
+/// Every problem found with a transaction
+#[derive(Debug, PartialEq)]
+pub struct ValidationErrors(pub Vec<ValidationError>);
+
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let messages: Vec<String> = self.0.iter().map(ToString::to_string).collect();
+        write!(f, "invalid transaction: {}", messages.join("; "))
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
+impl Transaction {
+    /// Check the transaction against the protocol rules, returning every violation found
+    pub fn validate(&self, params: &ConsensusParams) -> Result<(), ValidationErrors> {
+        let mut errors = vec![];
+
+        let expected = match self {
+            Transaction::Payment(_) => TransactionType::Payment,
+            Transaction::AssetTransfer(_) => TransactionType::AssetTransfer,
+            Transaction::ApplicationCall(_) => TransactionType::ApplicationCall,
+        };
+        let header = self.header();
+        if header.transaction_type != expected {
+            errors.push(ValidationError::TypeMismatch {
+                expected,
+                found: header.transaction_type.clone(),
+            });
+        }
+        validate_header(header, params, &mut errors);
+
+        match self {
+            Transaction::Payment(tx) => validate_payment(tx, &mut errors),
+            Transaction::AssetTransfer(tx) => validate_asset_transfer(tx, &mut errors),
+            Transaction::ApplicationCall(tx) => validate_application_call(tx, params, &mut errors),
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationErrors(errors))
+        }
+    }
+}
+
+fn validate_header(
+    header: &TransactionHeader,
+    params: &ConsensusParams,
+    errors: &mut Vec<ValidationError>,
+) {
+    if header.last_valid < header.first_valid {
+        errors.push(ValidationError::InvalidValidityWindow {
+            first_valid: header.first_valid,
+            last_valid: header.last_valid,
+        });
+    } else if header.last_valid - header.first_valid > params.max_txn_life {
+        errors.push(ValidationError::ValidityWindowTooLong {
+            rounds: header.last_valid - header.first_valid,
+            max: params.max_txn_life,
+        });
+    }
+
+    let note_size = header.note.as_ref().map_or(0, Vec::len);
+    if note_size > params.max_txn_note_bytes {
+        errors.push(ValidationError::NoteTooLarge {
+            size: note_size,
+            max: params.max_txn_note_bytes,
+        });
+    }
+}
+
+fn validate_payment(tx: &PayTransactionFields, errors: &mut Vec<ValidationError>) {
+    if tx.close_remainder_to.as_ref() == Some(&tx.header.sender) {
+        errors.push(ValidationError::CloseToSender);
+    }
+}
+
+fn validate_asset_transfer(tx: &AssetTransferTransactionFields, errors: &mut Vec<ValidationError>) {
+    if tx.asset_id == 0 {
+        errors.push(ValidationError::ZeroAssetId);
+    }
+}
+
+fn validate_application_call(
+    tx: &ApplicationCallTransactionFields,
+    params: &ConsensusParams,
+    errors: &mut Vec<ValidationError>,
+) {
+    let approval_size = tx.approval_program.as_ref().map_or(0, Vec::len);
+    let clear_state_size = tx.clear_state_program.as_ref().map_or(0, Vec::len);
+    let creating = tx.app_id == 0;
+
+    if !creating {
+        if tx.on_complete != OnApplicationComplete::UpdateApplication
+            && approval_size + clear_state_size > 0
+        {
+            errors.push(ValidationError::ProgramsNotAllowed);
+        }
+        if tx.extra_program_pages != 0 {
+            errors.push(ValidationError::Immutable("extra program pages"));
+        }
+        let schemas = [&tx.global_state_schema, &tx.local_state_schema];
+        if schemas
+            .iter()
+            .any(|schema| schema.as_ref().is_some_and(|schema| schema.entries() != 0))
+        {
+            errors.push(ValidationError::Immutable("state schemas"));
+        }
+    }
+
+    if tx.extra_program_pages > params.max_extra_app_program_pages {
+        errors.push(ValidationError::TooManyExtraProgramPages {
+            pages: tx.extra_program_pages,
+            max: params.max_extra_app_program_pages,
+        });
+    }
+
+    // When updating, the extra pages requested at creation are not part of the transaction,
+    // so only programs that could never fit are reported
+    let pages = if creating {
+        tx.extra_program_pages
+    } else {
+        params.max_extra_app_program_pages
+    };
+    let pages = 1 + pages.min(params.max_extra_app_program_pages) as usize;
+    let max_program_size = params.max_app_program_len.saturating_mul(pages);
+    for (program, size) in [
+        ("approval", approval_size),
+        ("clear state", clear_state_size),
+    ] {
+        if size > max_program_size {
+            errors.push(ValidationError::ProgramTooLarge {
+                program,
+                size,
+                max: max_program_size,
+            });
+        }
+    }
+
+    // Before v28 only each program was limited
+    if params.max_app_total_program_len != 0 {
+        let max_total_size = params.max_app_total_program_len.saturating_mul(pages);
+        if approval_size + clear_state_size > max_total_size {
+            errors.push(ValidationError::ProgramsTooLarge {
+                size: approval_size + clear_state_size,
+                max: max_total_size,
+            });
+        }
+    }
+
+    let args = tx.args.as_deref().unwrap_or_default();
+    let accounts = tx.accounts.as_ref().map_or(0, Vec::len);
+    let foreign_apps = tx.foreign_apps.as_ref().map_or(0, Vec::len);
+    let foreign_assets = tx.foreign_assets.as_ref().map_or(0, Vec::len);
+    let boxes = tx.boxes.as_deref().unwrap_or_default();
+
+    let counts = [
+        ("arguments", args.len(), params.max_app_args),
+        ("accounts", accounts, params.max_app_txn_accounts),
+        (
+            "foreign applications",
+            foreign_apps,
+            params.max_app_txn_foreign_apps,
+        ),
+        (
+            "foreign assets",
+            foreign_assets,
+            params.max_app_txn_foreign_assets,
+        ),
+        ("box references", boxes.len(), params.max_app_box_references),
+        (
+            "references",
+            accounts + foreign_apps + foreign_assets + boxes.len(),
+            params.max_app_total_txn_references,
+        ),
+    ];
+    for (kind, count, max) in counts {
+        if count > max {
+            errors.push(ValidationError::TooMany { kind, count, max });
+        }
+    }
+
+    let args_size: usize = args.iter().map(Vec::len).sum();
+    if args_size > params.max_app_total_arg_len {
+        errors.push(ValidationError::ArgsTooLarge {
+            size: args_size,
+            max: params.max_app_total_arg_len,
+        });
+    }
+
+    for reference in boxes {
+        if reference.app_index > foreign_apps as u64 {
+            errors.push(ValidationError::InvalidBoxReference {
+                app_index: reference.app_index,
+                foreign_apps,
+            });
+        }
+        if reference.name.len() > params.max_app_key_len {
+            errors.push(ValidationError::BoxNameTooLong {
+                size: reference.name.len(),
+                max: params.max_app_key_len,
+            });
+        }
+    }
+
+    let schemas = [
+        (
+            "global",
+            &tx.global_state_schema,
+            params.max_global_schema_entries,
+        ),
+        (
+            "local",
+            &tx.local_state_schema,
+            params.max_local_schema_entries,
+        ),
+    ];
+    for (scope, schema, max) in schemas {
+        let entries = schema.as_ref().map_or(0, StateSchema::entries);
+        if entries > max {
+            errors.push(ValidationError::SchemaTooLarge {
+                scope,
+                entries,
+                max,
+            });
+        }
+    }
+}
+
+impl StateSchema {
+    /// The total number of values the schema allows
+    fn entries(&self) -> u64 {
+        self.num_uints.saturating_add(self.num_byte_slices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Address, BoxReference, MicroAlgos};
+    use pretty_assertions::assert_eq;
+
+    fn header(transaction_type: TransactionType) -> TransactionHeader {
+        TransactionHeader {
+            transaction_type,
+            sender: Address::from_pubkey(&[1; 32]),
+            fee: MicroAlgos(1000),
+            first_valid: 1000,
+            last_valid: 2000,
+            genesis_hash: Some([7; 32]),
+            genesis_id: Some("testnet-v1.0".to_string()),
+            note: None,
+            rekey_to: None,
+            lease: None,
+            group: None,
+        }
+    }
+
+    fn asset_transfer() -> AssetTransferTransactionFields {
+        AssetTransferTransactionFields {
+            header: header(TransactionType::AssetTransfer),
+            asset_id: 1234,
+            amount: 5,
+            receiver: Address::from_pubkey(&[2; 32]),
+            asset_sender: None,
+            close_remainder_to: None,
+        }
+    }
+
+    fn application_call(app_id: u64) -> ApplicationCallTransactionFields {
+        ApplicationCallTransactionFields {
+            header: header(TransactionType::ApplicationCall),
+            app_id,
+            on_complete: OnApplicationComplete::NoOp,
+            approval_program: None,
+            clear_state_program: None,
+            global_state_schema: None,
+            local_state_schema: None,
+            extra_program_pages: 0,
+            args: None,
+            accounts: None,
+            foreign_apps: None,
+            foreign_assets: None,
+            boxes: None,
+        }
+    }
+
+    fn errors(transaction: Transaction) -> Vec<ValidationError> {
+        transaction
+            .validate(&ConsensusParams::default())
+            .map_or_else(|e| e.0, |_| vec![])
+    }
+
+    #[test]
+    fn test_valid_transactions() {
+        let params = ConsensusParams::default();
+
+        assert_eq!(
+            Transaction::AssetTransfer(asset_transfer()).validate(&params),
+            Ok(())
+        );
+
+        let mut create = application_call(0);
+        create.approval_program = Some(vec![0x0a; 4000]);
+        create.clear_state_program = Some(vec![0x0a, 0x81, 0x01]);
+        create.extra_program_pages = 1;
+        create.args = Some(vec![vec![0; 1024], vec![0; 1024]]);
+        create.global_state_schema = Some(StateSchema {
+            num_uints: 32,
+            num_byte_slices: 32,
+        });
+        assert_eq!(
+            Transaction::ApplicationCall(create).validate(&params),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_header_violations() {
+        let mut tx = asset_transfer();
+        tx.header.transaction_type = TransactionType::Payment;
+        tx.header.first_valid = 2001;
+        tx.header.note = Some(vec![0; 1025]);
+        tx.asset_id = 0;
+
+        let result = Transaction::AssetTransfer(tx.clone()).validate(&ConsensusParams::default());
+        assert_eq!(
+            result.as_ref().unwrap_err().0,
+            vec![
+                ValidationError::TypeMismatch {
+                    expected: TransactionType::AssetTransfer,
+                    found: TransactionType::Payment,
+                },
+                ValidationError::InvalidValidityWindow {
+                    first_valid: 2001,
+                    last_valid: 2000,
+                },
+                ValidationError::NoteTooLarge {
+                    size: 1025,
+                    max: 1024,
+                },
+                ValidationError::ZeroAssetId,
+            ]
+        );
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "invalid transaction: transaction type Payment does not match its AssetTransfer \
+             fields; last valid round 2000 is before first valid round 2001; note of 1025 bytes \
+             exceeds the maximum of 1024; asset transfers require a non-zero asset ID"
+        );
+
+        tx.header.first_valid = 999;
+        assert!(errors(Transaction::AssetTransfer(tx.clone())).contains(
+            &ValidationError::ValidityWindowTooLong {
+                rounds: 1001,
+                max: 1000
+            }
+        ));
+
+        // The limits follow the consensus params
+        let params = ConsensusParams {
+            max_txn_life: 2000,
+            max_txn_note_bytes: 2048,
+            ..Default::default()
+        };
+        tx.header.transaction_type = TransactionType::AssetTransfer;
+        tx.asset_id = 1234;
+        assert_eq!(Transaction::AssetTransfer(tx).validate(&params), Ok(()));
+    }
+
+    #[test]
+    fn test_payment_violations() {
+        let header = header(TransactionType::Payment);
+        let payment = Transaction::Payment(PayTransactionFields {
+            receiver: Address::from_pubkey(&[2; 32]),
+            amount: MicroAlgos(1000),
+            close_remainder_to: Some(header.sender.clone()),
+            header,
+        });
+
+        assert_eq!(errors(payment), vec![ValidationError::CloseToSender]);
+    }
+
+    #[test]
+    fn test_application_call_violations() {
+        let mut call = application_call(1234);
+        call.approval_program = Some(vec![0x0a]);
+        call.extra_program_pages = 4;
+        call.local_state_schema = Some(StateSchema {
+            num_uints: 10,
+            num_byte_slices: 10,
+        });
+        assert_eq!(
+            errors(Transaction::ApplicationCall(call)),
+            vec![
+                ValidationError::ProgramsNotAllowed,
+                ValidationError::Immutable("extra program pages"),
+                ValidationError::Immutable("state schemas"),
+                ValidationError::TooManyExtraProgramPages { pages: 4, max: 3 },
+                ValidationError::SchemaTooLarge {
+                    scope: "local",
+                    entries: 20,
+                    max: 16,
+                },
+            ]
+        );
+
+        let mut create = application_call(0);
+        create.approval_program = Some(vec![0x0a; 2048]);
+        create.clear_state_program = Some(vec![0x0a]);
+        create.args = Some(vec![vec![0; 2049]]);
+        create.accounts = Some(vec![Address::default(); 5]);
+        create.foreign_apps = Some(vec![1]);
+        create.boxes = Some(vec![BoxReference {
+            app_index: 2,
+            name: vec![0; 65],
+        }]);
+        assert_eq!(
+            errors(Transaction::ApplicationCall(create)),
+            vec![
+                ValidationError::ProgramsTooLarge {
+                    size: 2049,
+                    max: 2048,
+                },
+                ValidationError::TooMany {
+                    kind: "accounts",
+                    count: 5,
+                    max: 4,
+                },
+                ValidationError::ArgsTooLarge {
+                    size: 2049,
+                    max: 2048,
+                },
+                ValidationError::InvalidBoxReference {
+                    app_index: 2,
+                    foreign_apps: 1,
+                },
+                ValidationError::BoxNameTooLong { size: 65, max: 64 },
+            ]
+        );
+
+        // Empty schemas are the same as no schemas
+        let mut call = application_call(1234);
+        call.global_state_schema = Some(StateSchema::default());
+        assert_eq!(errors(Transaction::ApplicationCall(call)), vec![]);
+
+        // Updating an application may set programs, and the references add up across kinds
+        let mut update = application_call(1234);
+        update.on_complete = OnApplicationComplete::UpdateApplication;
+        update.approval_program = Some(vec![0x0a; 4000]);
+        update.clear_state_program = Some(vec![0x0a]);
+        update.accounts = Some(vec![Address::default(); 4]);
+        update.foreign_assets = Some(vec![1, 2, 3, 4, 5]);
+        assert_eq!(
+            errors(Transaction::ApplicationCall(update)),
+            vec![ValidationError::TooMany {
+                kind: "references",
+                count: 9,
+                max: 8,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_program_lengths() {
+        let mut create = application_call(0);
+        create.approval_program = Some(vec![0x0a; 1500]);
+        create.clear_state_program = Some(vec![0x0a; 600]);
+
+        // v24 limits each program to a page but not their total
+        let v24 = ConsensusParams::for_version(
+            "https://github.com/algorandfoundation/specs/tree/3a83c4c743f8b17adfd73944b4319c25722a6782",
+        )
+        .unwrap();
+        assert_eq!(
+            Transaction::ApplicationCall(create.clone()).validate(&v24),
+            Err(ValidationErrors(vec![ValidationError::ProgramTooLarge {
+                program: "approval",
+                size: 1500,
+                max: 1024,
+            }]))
+        );
+        create.approval_program = Some(vec![0x0a; 1000]);
+        create.clear_state_program = Some(vec![0x0a; 1000]);
+        assert_eq!(
+            Transaction::ApplicationCall(create.clone()).validate(&v24),
+            Ok(())
+        );
+
+        // From v28 the programs share the pages
+        create.approval_program = Some(vec![0x0a; 1500]);
+        create.clear_state_program = Some(vec![0x0a; 600]);
+        assert_eq!(
+            errors(Transaction::ApplicationCall(create.clone())),
+            vec![ValidationError::ProgramsTooLarge {
+                size: 2100,
+                max: 2048,
+            }]
+        );
+        create.extra_program_pages = 1;
+        create.clear_state_program = Some(vec![0x0a; 4097]);
+        assert_eq!(
+            errors(Transaction::ApplicationCall(create)),
+            vec![
+                ValidationError::ProgramTooLarge {
+                    program: "clear state",
+                    size: 4097,
+                    max: 4096,
+                },
+                ValidationError::ProgramsTooLarge {
+                    size: 5597,
+                    max: 4096,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,540 @@
+use base64::{prelude::BASE64_STANDARD, Engine};
+use clap::{Parser, Subcommand, ValueEnum};
+use std::{
+    fs,
+    io::{self, Read, Write},
+    path::{Path, PathBuf},
+    process,
+};
+
+use algo_models::{
+    compute_group_id, program::Program, transaction_file::read_transaction_file, Address,
+    AlgorandMsgpack, SignedTransaction, Transaction,
+};
+
+#[derive(Parser)]
+#[command(
+    name = "algo-models",
+    version,
+    about = "Inspect and convert Algorand data offline"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Disassemble compiled TEAL bytecode into TEAL source, like `goal clerk compile -D`
+    Disassemble {
+        #[command(flatten)]
+        input: Input,
+    },
+
+    /// Assemble TEAL source into bytecode, like `goal clerk compile`
+    Assemble {
+        /// The TEAL file to read, or `-` for stdin
+        #[arg(default_value = "-")]
+        file: PathBuf,
+
+        /// How to encode the bytecode
+        #[arg(long, short, value_enum, default_value_t = Encoding::Raw)]
+        encoding: Encoding,
+    },
+
+    /// Decode msgpack transactions, signed or not, into JSON like `goal clerk inspect`
+    Decode {
+        #[command(flatten)]
+        input: Input,
+    },
+
+    /// Encode transactions from JSON into canonical msgpack
+    Encode {
+        /// The JSON file to read, or `-` for stdin. It may hold several transactions, one after
+        /// another or in an array
+        #[arg(default_value = "-")]
+        file: PathBuf,
+
+        /// How to encode the msgpack
+        #[arg(long, short, value_enum, default_value_t = Encoding::Raw)]
+        encoding: Encoding,
+    },
+
+    /// Print the ID of each transaction
+    Id {
+        #[command(flatten)]
+        input: Input,
+    },
+
+    /// Print the group ID of the transactions in base64, as in their `grp` field
+    GroupId {
+        #[command(flatten)]
+        input: Input,
+    },
+
+    /// Check that msgpack transactions are encoded canonically, with their keys sorted and no
+    /// empty or unknown fields, as algod requires
+    Validate {
+        #[command(flatten)]
+        input: Input,
+    },
+
+    /// Print the address of a 32 byte public key
+    Address {
+        #[command(flatten)]
+        input: Input,
+    },
+
+    /// Print the public key of an address
+    PublicKey {
+        address: String,
+
+        /// How to encode the public key
+        #[arg(long, short, value_enum, default_value_t = Encoding::Hex)]
+        encoding: Encoding,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Encoding {
+    Raw,
+    Base64,
+    Hex,
+}
+
+#[derive(clap::Args)]
+struct Input {
+    /// The file to read, or `-` for stdin
+    #[arg(default_value = "-")]
+    file: PathBuf,
+
+    /// How the input bytes are encoded
+    #[arg(long, short, value_enum, default_value_t = Encoding::Raw)]
+    encoding: Encoding,
+}
+
+fn read_file(file: &Path) -> Result<Vec<u8>, String> {
+    if file.as_os_str() == "-" {
+        let mut bytes = vec![];
+        io::stdin()
+            .read_to_end(&mut bytes)
+            .map_err(|e| format!("could not read stdin: {e}"))?;
+        Ok(bytes)
+    } else {
+        fs::read(file).map_err(|e| format!("could not read {}: {e}", file.display()))
+    }
+}
+
+impl Encoding {
+    fn encode(self, bytes: Vec<u8>) -> Vec<u8> {
+        match self {
+            Encoding::Raw => bytes,
+            Encoding::Base64 => format!("{}\n", BASE64_STANDARD.encode(bytes)).into_bytes(),
+            Encoding::Hex => format!("{}\n", hex::encode(bytes)).into_bytes(),
+        }
+    }
+}
+
+impl Input {
+    fn read(&self) -> Result<Vec<u8>, String> {
+        let bytes = read_file(&self.file)?;
+
+        match self.encoding {
+            Encoding::Raw => Ok(bytes),
+            Encoding::Base64 => BASE64_STANDARD
+                .decode(String::from_utf8_lossy(&bytes).trim())
+                .map_err(|e| format!("input is not valid base64: {e}")),
+            Encoding::Hex => {
+                let text = String::from_utf8_lossy(&bytes);
+                let text = text.trim();
+                hex::decode(text.strip_prefix("0x").unwrap_or(text))
+                    .map_err(|e| format!("input is not valid hex: {e}"))
+            }
+        }
+    }
+}
+
+/// The transactions of the input: signed transactions one after another, as in `goal`
+/// transaction files and submissions to algod, or a single unsigned transaction, with or
+/// without its "TX" prefix
+enum Transactions {
+    Signed(Vec<SignedTransaction>),
+    Unsigned(Box<Transaction>),
+}
+
+impl Transactions {
+    fn decode(bytes: &[u8]) -> Result<Self, String> {
+        if bytes.is_empty() {
+            return Err("no transactions in input".to_string());
+        }
+
+        match read_transaction_file(bytes) {
+            Ok(signed) => Ok(Self::Signed(signed)),
+            Err(e) => Transaction::decode(bytes)
+                .map(|transaction| Self::Unsigned(Box::new(transaction)))
+                .map_err(|_| format!("input is not a transaction or signed transactions: {e}")),
+        }
+    }
+
+    fn transactions(&self) -> Vec<&Transaction> {
+        match self {
+            Self::Signed(signed) => signed.iter().map(|stx| &stx.transaction).collect(),
+            Self::Unsigned(transaction) => vec![transaction],
+        }
+    }
+
+    /// Each transaction as JSON with sorted keys, one after another
+    fn to_json(&self) -> Result<Vec<u8>, String> {
+        let values = match self {
+            Self::Signed(signed) => signed.iter().map(serde_json::to_value).collect(),
+            Self::Unsigned(transaction) => serde_json::to_value(transaction).map(|v| vec![v]),
+        };
+
+        let mut json = String::new();
+        for value in values.map_err(|e| e.to_string())? {
+            let pretty = serde_json::to_string_pretty(&value).map_err(|e| e.to_string())?;
+            json.push_str(&pretty);
+            json.push('\n');
+        }
+        Ok(json.into_bytes())
+    }
+
+    /// Re-encode the transactions and compare them with the input, one at a time
+    fn validate(&self, bytes: &[u8]) -> Result<Vec<u8>, String> {
+        let not_canonical = |index: usize| {
+            format!(
+                "transaction {index} is not canonical: re-encoding it with sorted keys and \
+                 without empty fields gives different bytes"
+            )
+        };
+
+        match self {
+            Self::Signed(signed) => {
+                let mut offset = 0;
+                for (index, stx) in signed.iter().enumerate() {
+                    let encoded = stx.encode().map_err(|e| e.to_string())?;
+                    if bytes.get(offset..offset + encoded.len()) != Some(encoded.as_slice()) {
+                        return Err(not_canonical(index));
+                    }
+                    offset += encoded.len();
+                }
+            }
+            Self::Unsigned(transaction) => {
+                let encoded = if bytes.starts_with(b"TX") {
+                    transaction.encode()
+                } else {
+                    transaction.encode_raw()
+                };
+                if encoded.map_err(|e| e.to_string())? != bytes {
+                    return Err(not_canonical(0));
+                }
+            }
+        }
+
+        let count = self.transactions().len();
+        Ok(format!("{count} canonically encoded transaction(s)\n").into_bytes())
+    }
+}
+
+/// Encode each JSON transaction, which is signed when it is wrapped in `{"txn": ...}`
+fn encode_json(json: &[u8]) -> Result<Vec<u8>, String> {
+    let mut values = vec![];
+    for value in serde_json::Deserializer::from_slice(json).into_iter::<serde_json::Value>() {
+        match value.map_err(|e| format!("input is not valid JSON: {e}"))? {
+            serde_json::Value::Array(array) => values.extend(array),
+            value => values.push(value),
+        }
+    }
+    if values.is_empty() {
+        return Err("no transactions in input".to_string());
+    }
+
+    let mut encoded = vec![];
+    for (index, value) in values.into_iter().enumerate() {
+        let invalid = |e: serde_json::Error| format!("transaction {index} is not valid: {e}");
+        let bytes = if value.get("txn").is_some() {
+            serde_json::from_value::<SignedTransaction>(value)
+                .map_err(invalid)?
+                .encode()
+        } else {
+            serde_json::from_value::<Transaction>(value)
+                .map_err(invalid)?
+                .encode_raw()
+        };
+        encoded.extend(bytes.map_err(|e| e.to_string())?);
+    }
+    Ok(encoded)
+}
+
+fn run(cli: Cli) -> Result<Vec<u8>, String> {
+    match cli.command {
+        Command::Disassemble { input } => Program::new(input.read()?)
+            .map(|program| program.disassemble().into_bytes())
+            .map_err(|e| e.to_string()),
+        Command::Assemble { file, encoding } => {
+            let source = String::from_utf8(read_file(&file)?)
+                .map_err(|_| "TEAL source is not valid UTF-8".to_string())?;
+            Program::assemble(&source)
+                .map(|program| encoding.encode(program.into_bytes()))
+                .map_err(|e| e.to_string())
+        }
+        Command::Decode { input } => Transactions::decode(&input.read()?)?.to_json(),
+        Command::Encode { file, encoding } => Ok(encoding.encode(encode_json(&read_file(&file)?)?)),
+        Command::Id { input } => {
+            let mut ids = String::new();
+            for transaction in Transactions::decode(&input.read()?)?.transactions() {
+                ids.push_str(&transaction.id().map_err(|e| e.to_string())?);
+                ids.push('\n');
+            }
+            Ok(ids.into_bytes())
+        }
+        Command::GroupId { input } => {
+            let transactions = Transactions::decode(&input.read()?)?;
+            let transactions: Vec<_> = transactions.transactions().into_iter().cloned().collect();
+            let group = compute_group_id(&transactions).map_err(|e| e.to_string())?;
+            Ok(Encoding::Base64.encode(group.to_vec()))
+        }
+        Command::Validate { input } => {
+            let bytes = input.read()?;
+            Transactions::decode(&bytes)?.validate(&bytes)
+        }
+        Command::Address { input } => {
+            let pub_key: [u8; 32] = input.read()?.try_into().map_err(|bytes: Vec<u8>| {
+                format!("a public key is 32 bytes, not {}", bytes.len())
+            })?;
+            Ok(format!("{}\n", Address::from_pubkey(&pub_key).address()).into_bytes())
+        }
+        Command::PublicKey { address, encoding } => Address::from_string(address.trim())
+            .map(|address| encoding.encode(address.pub_key.to_vec()))
+            .map_err(|e| e.to_string()),
+    }
+}
+
+fn main() {
+    match run(Cli::parse()) {
+        Ok(output) => {
+            if let Err(e) = io::stdout().write_all(&output) {
+                eprintln!("error: could not write output: {e}");
+                process::exit(1);
+            }
+        }
+        Err(e) => {
+            eprintln!("error: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALICE: &str = "S42GIXSHUKLVVFE537KRRLGFRWZSIRFD6LPCMG4QNNW5ZMHOAFMREKN6LQ";
+    const ALICE_PUB_KEY: &str = "9734645e47a2975a949ddfd518acc58db32444a3f2de261b906b6ddcb0ee0159";
+
+    fn test_data(name: &str) -> String {
+        format!(
+            "{}/../algo_models/test_data/{name}",
+            env!("CARGO_MANIFEST_DIR")
+        )
+    }
+
+    fn temp_file(name: &str, bytes: &[u8]) -> String {
+        let path = std::env::temp_dir().join(format!("algo-models-{}-{name}", process::id()));
+        fs::write(&path, bytes).unwrap();
+        path.display().to_string()
+    }
+
+    fn algo_models(args: &[&str]) -> Result<Vec<u8>, String> {
+        run(Cli::parse_from(
+            ["algo-models"].iter().chain(args).collect::<Vec<_>>(),
+        ))
+    }
+
+    fn algo_models_text(args: &[&str]) -> String {
+        String::from_utf8(algo_models(args).unwrap()).unwrap()
+    }
+
+    /// The payment of the `payment.stxn` fixture, unsigned and with its "TX" prefix
+    fn unsigned_payment() -> Vec<u8> {
+        let signed =
+            read_transaction_file(fs::read(test_data("payment.stxn")).unwrap().as_slice()).unwrap();
+        signed[0].transaction.encode().unwrap()
+    }
+
+    #[test]
+    fn test_decode() {
+        let json = algo_models_text(&["decode", &test_data("payment.stxn")]);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["txn"]["snd"], ALICE);
+        assert!(value["sig"].is_string());
+
+        // The multisig and the address it was signed by are kept
+        let json = algo_models_text(&["decode", &test_data("multisig.stxn")]);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["msig"]["thr"], 2);
+        assert_eq!(value["msig"]["subsig"].as_array().unwrap().len(), 3);
+        assert_eq!(
+            value["sgnr"],
+            "KUIRTXLNEOOP7FL34O2SWPD6MI3R7UN6T6HTXNGQS5R5S3PKUTFXCJE6LE"
+        );
+        assert!(value.get("sig").is_none());
+
+        // An unsigned transaction is not wrapped in "txn"
+        let file = temp_file("unsigned.txn", &unsigned_payment());
+        let json = algo_models_text(&["decode", &file]);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["snd"], ALICE);
+        assert!(value.get("txn").is_none());
+
+        let file = temp_file("empty.txn", b"");
+        assert!(algo_models(&["decode", &file]).is_err());
+    }
+
+    #[test]
+    fn test_encode() {
+        // Decoding and encoding again gives back the same file, signatures and all
+        for name in ["payment.stxn", "multisig.stxn", "logicsig.stxn"] {
+            let json = algo_models(&["decode", &test_data(name)]).unwrap();
+            let file = temp_file(&format!("{name}.json"), &json);
+            assert_eq!(
+                algo_models(&["encode", &file]).unwrap(),
+                fs::read(test_data(name)).unwrap()
+            );
+        }
+
+        // Unsigned transactions, here in an array, are encoded without their "TX" prefix
+        let json = algo_models(&["decode", &temp_file("encode.txn", &unsigned_payment())]).unwrap();
+        let array = format!("[{}]", String::from_utf8(json).unwrap());
+        let file = temp_file("encode.json", array.as_bytes());
+        assert_eq!(
+            algo_models(&["encode", &file]).unwrap(),
+            unsigned_payment()[2..]
+        );
+        assert_eq!(
+            algo_models_text(&["encode", &file, "--encoding", "hex"]),
+            format!("{}\n", hex::encode(&unsigned_payment()[2..]))
+        );
+
+        let file = temp_file(
+            "invalid.json",
+            br#"{"txn": {"type": "pay", "amt": "a lot"}}"#,
+        );
+        assert!(algo_models(&["encode", &file])
+            .unwrap_err()
+            .starts_with("transaction 0 is not valid"));
+    }
+
+    #[test]
+    fn test_id() {
+        assert_eq!(
+            algo_models_text(&["id", &test_data("payment.stxn")]),
+            "ICIUSAO4Y5LK3GAAS4H62LCQJQ5GDIONERG2AB7PFHARFZ2IHMTA\n"
+        );
+
+        // Signed or not, the ID is that of the transaction
+        let file = temp_file(
+            "id.txn",
+            &BASE64_STANDARD.encode(unsigned_payment()).into_bytes(),
+        );
+        assert_eq!(
+            algo_models_text(&["id", &file, "--encoding", "base64"]),
+            "ICIUSAO4Y5LK3GAAS4H62LCQJQ5GDIONERG2AB7PFHARFZ2IHMTA\n"
+        );
+
+        let files: Vec<u8> = ["payment.stxn", "multisig.stxn", "logicsig.stxn"]
+            .iter()
+            .flat_map(|name| fs::read(test_data(name)).unwrap())
+            .collect();
+        let ids = algo_models_text(&["id", &temp_file("ids.stxn", &files)]);
+        assert_eq!(ids.lines().count(), 3);
+    }
+
+    #[test]
+    fn test_group_id() {
+        // The unsigned group of the simulate request fixture, which already has its group ID
+        let request = algo_models::simulate::SimulateRequest::decode(
+            &fs::read(test_data("simulate_request.msgpack")).unwrap(),
+        )
+        .unwrap();
+        let transactions: Vec<_> = request.txn_groups[0]
+            .txns
+            .iter()
+            .map(|stxn| stxn.transaction.clone())
+            .collect();
+        let mut file = vec![];
+        algo_models::transaction_file::write_unsigned_transaction_file(&mut file, &transactions)
+            .unwrap();
+
+        assert_eq!(
+            algo_models_text(&["group-id", &temp_file("group.gtxn", &file)]),
+            "5X6ntOKp8Yu4956KndbVwS3o3S2eqPaXqeKO/6Kv0CU=\n"
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(
+            algo_models_text(&["validate", &test_data("multisig.stxn")]),
+            "1 canonically encoded transaction(s)\n"
+        );
+
+        let files: Vec<u8> = ["payment.stxn", "multisig.stxn", "logicsig.stxn"]
+            .iter()
+            .flat_map(|name| fs::read(test_data(name)).unwrap())
+            .collect();
+        assert_eq!(
+            algo_models_text(&["validate", &temp_file("valid.stxn", &files)]),
+            "3 canonically encoded transaction(s)\n"
+        );
+        assert_eq!(
+            algo_models_text(&["validate", &temp_file("valid.txn", &unsigned_payment())]),
+            "1 canonically encoded transaction(s)\n"
+        );
+
+        // The fee of the second transaction encoded as a uint32 rather than the smallest integer
+        let fee = [b"\xa3fee\xcd".as_slice(), &1000u16.to_be_bytes()].concat();
+        let wide_fee = [b"\xa3fee\xce".as_slice(), &1000u32.to_be_bytes()].concat();
+        let payment = fs::read(test_data("payment.stxn")).unwrap();
+        let position = payment
+            .windows(fee.len())
+            .position(|window| window == fee)
+            .unwrap();
+        let mut wide = payment[..position].to_vec();
+        wide.extend_from_slice(&wide_fee);
+        wide.extend_from_slice(&payment[position + fee.len()..]);
+
+        let file = temp_file("wide.stxn", &[payment, wide].concat());
+        assert!(algo_models(&["validate", &file])
+            .unwrap_err()
+            .starts_with("transaction 1 is not canonical"));
+    }
+
+    #[test]
+    fn test_address() {
+        let file = temp_file("pub_key.hex", format!("0x{ALICE_PUB_KEY}\n").as_bytes());
+        assert_eq!(
+            algo_models_text(&["address", &file, "--encoding", "hex"]),
+            format!("{ALICE}\n")
+        );
+
+        let file = temp_file("short.hex", b"0102");
+        assert_eq!(
+            algo_models(&["address", &file, "--encoding", "hex"]).unwrap_err(),
+            "a public key is 32 bytes, not 2"
+        );
+    }
+
+    #[test]
+    fn test_public_key() {
+        assert_eq!(
+            algo_models_text(&["public-key", ALICE]),
+            format!("{ALICE_PUB_KEY}\n")
+        );
+        assert_eq!(
+            algo_models(&["public-key", ALICE, "--encoding", "raw"]).unwrap(),
+            hex::decode(ALICE_PUB_KEY).unwrap()
+        );
+        assert!(algo_models(&["public-key", "not an address"]).is_err());
+    }
+}
@@ -1,7 +1,15 @@
+use algo_models::account;
+use algo_models::arc56;
+use algo_models::builder;
+use algo_models::consensus;
+use algo_models::fee;
+use algo_models::min_balance;
+use algo_models::transaction_file;
 use algo_models::AlgorandMsgpack;
 use ffi_macros::{ffi_func, ffi_record};
 use serde::{Deserialize, Serialize};
 use serde_bytes::ByteBuf;
+use std::collections::HashMap;
 
 // thiserror is used to easily create errors than can be propagated to the language bindings
 // UniFFI will create classes for errors (i.e. `MsgPackError.EncodingError` in Python)
@@ -12,6 +20,22 @@ pub enum AlgoModelsError {
     EncodingError(String),
     #[error("DecodingError: {0}")]
     DecodingError(String),
+    #[error("MnemonicError: {0}")]
+    MnemonicError(String),
+    #[error("KeyDerivationError: {0}")]
+    KeyDerivationError(String),
+    #[error("AbiError: {0}")]
+    AbiError(String),
+    #[error("AppSpecError: {0}")]
+    AppSpecError(String),
+    #[error("ProgramError: {0}")]
+    ProgramError(String),
+    #[error("BuilderError: {0}")]
+    BuilderError(String),
+    #[error("ValidationError: {0}")]
+    ValidationError(String),
+    #[error("AmountError: {0}")]
+    AmountError(String),
 }
 
 // For now, in WASM we just throw the string, hence the error
@@ -49,6 +73,38 @@ impl From<algo_models::AlgoModelsError> for AlgoModelsError {
             algo_models::AlgoModelsError::InvalidAddress(_) => {
                 AlgoModelsError::DecodingError(e.to_string())
             }
+            algo_models::AlgoModelsError::InvalidMnemonic(_) => {
+                AlgoModelsError::MnemonicError(e.to_string())
+            }
+            algo_models::AlgoModelsError::InvalidHdKey(_) => {
+                AlgoModelsError::KeyDerivationError(e.to_string())
+            }
+            algo_models::AlgoModelsError::AbiError(_) => AlgoModelsError::AbiError(e.to_string()),
+            algo_models::AlgoModelsError::AppSpecError(_) => {
+                AlgoModelsError::AppSpecError(e.to_string())
+            }
+            algo_models::AlgoModelsError::InvalidProgram(_) => {
+                AlgoModelsError::ProgramError(e.to_string())
+            }
+            algo_models::AlgoModelsError::BuilderError(_) => {
+                AlgoModelsError::BuilderError(e.to_string())
+            }
+            algo_models::AlgoModelsError::InvalidAccount(_)
+            | algo_models::AlgoModelsError::InvalidBlock(_)
+            | algo_models::AlgoModelsError::InvalidCommitment(_)
+            | algo_models::AlgoModelsError::InvalidSimulation(_)
+            | algo_models::AlgoModelsError::InvalidPendingTransaction(_) => {
+                AlgoModelsError::DecodingError(e.to_string())
+            }
+            algo_models::AlgoModelsError::InvalidAmount(_) => {
+                AlgoModelsError::AmountError(e.to_string())
+            }
+            algo_models::AlgoModelsError::InvalidTransactionFile(_) => {
+                AlgoModelsError::EncodingError(e.to_string())
+            }
+            algo_models::AlgoModelsError::InvalidTransaction(_) => {
+                AlgoModelsError::ValidationError(e.to_string())
+            }
         }
     }
 }
@@ -82,6 +138,45 @@ impl UniffiCustomTypeConverter for ByteBuf {
 #[cfg(feature = "ffi_uniffi")]
 uniffi::custom_type!(ByteBuf, Vec<u8>);
 
+/// An amount of Algos, in microAlgos. This is a u64 in the UniFFI bindings and a bigint in TS,
+/// but keeps its own name so amounts of Algos stand out from other integers
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+#[serde(transparent)]
+#[cfg_attr(feature = "ffi_wasm", derive(Tsify))]
+#[cfg_attr(
+    feature = "ffi_wasm",
+    tsify(into_wasm_abi, from_wasm_abi, large_number_types_as_bigints)
+)]
+pub struct MicroAlgos(u64);
+
+#[cfg(feature = "ffi_uniffi")]
+impl UniffiCustomTypeConverter for MicroAlgos {
+    type Builtin = u64;
+
+    fn into_custom(val: Self::Builtin) -> uniffi::Result<Self> {
+        Ok(MicroAlgos(val))
+    }
+
+    fn from_custom(obj: Self) -> Self::Builtin {
+        obj.0
+    }
+}
+
+#[cfg(feature = "ffi_uniffi")]
+uniffi::custom_type!(MicroAlgos, u64);
+
+impl From<algo_models::MicroAlgos> for MicroAlgos {
+    fn from(amount: algo_models::MicroAlgos) -> Self {
+        MicroAlgos(amount.0)
+    }
+}
+
+impl From<MicroAlgos> for algo_models::MicroAlgos {
+    fn from(amount: MicroAlgos) -> Self {
+        algo_models::MicroAlgos(amount.0)
+    }
+}
+
 // This becomes an enum in UniFFI language bindings and a
 // string literal union in TS
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
@@ -97,6 +192,178 @@ pub enum TransactionType {
     ApplicationCall,
 }
 
+/// The action an application call performs once its program has run
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "ffi_wasm", derive(Tsify))]
+#[cfg_attr(feature = "ffi_wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[cfg_attr(feature = "ffi_uniffi", derive(uniffi::Enum))]
+pub enum OnApplicationComplete {
+    NoOp,
+    OptIn,
+    CloseOut,
+    ClearState,
+    UpdateApplication,
+    DeleteApplication,
+}
+
+/// The ARC-52 derivation scheme, which determines how many bits of entropy are discarded
+/// when deriving child keys. ARC-52 recommends `Peikert`
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "ffi_wasm", derive(Tsify))]
+#[cfg_attr(feature = "ffi_wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[cfg_attr(feature = "ffi_uniffi", derive(uniffi::Enum))]
+pub enum DerivationType {
+    Khovratovich,
+    Peikert,
+}
+
+/// The ARC-52 key context, which determines the coin type of the BIP-44 path
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "ffi_wasm", derive(Tsify))]
+#[cfg_attr(feature = "ffi_wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[cfg_attr(feature = "ffi_uniffi", derive(uniffi::Enum))]
+pub enum KeyContext {
+    Address,
+    Identity,
+}
+
+/// A value that can be encoded as an ARC-4 ABI type.
+/// In TS this is an object with a `type` field, i.e. `{ type: "Uint", value: "1" }`
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "ffi_wasm", derive(Tsify))]
+#[cfg_attr(feature = "ffi_wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[cfg_attr(feature = "ffi_uniffi", derive(uniffi::Enum))]
+#[serde(tag = "type")]
+pub enum AbiValue {
+    /// The base 10 value of a `uintN`, or the unscaled integer of a `ufixedNxM`.
+    /// Strings are used because integers can be up to 512 bits
+    Uint {
+        value: String,
+    },
+    Bool {
+        value: bool,
+    },
+    Byte {
+        value: u8,
+    },
+    Address {
+        value: Address,
+    },
+    String {
+        value: String,
+    },
+    /// The elements of a static or dynamic array, or the fields of a tuple
+    Array {
+        values: Vec<AbiValue>,
+    },
+}
+
+/// The value of an ARC-4 method argument when building an application call.
+/// Reference arguments are added to the foreign arrays of the call and encoded as their index
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "ffi_wasm", derive(Tsify))]
+#[cfg_attr(feature = "ffi_wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[cfg_attr(feature = "ffi_uniffi", derive(uniffi::Enum))]
+#[serde(tag = "type")]
+pub enum AbiMethodArgValue {
+    AbiValue {
+        value: AbiValue,
+    },
+    /// Placeholder for a transaction argument, which is not encoded in the application
+    /// arguments but must precede the call in its group
+    Transaction,
+    Account {
+        value: Address,
+    },
+    Asset {
+        value: u64,
+    },
+    Application {
+        value: u64,
+    },
+}
+
+/// Where the default value of an ARC-56 method argument comes from
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "ffi_wasm", derive(Tsify))]
+#[cfg_attr(feature = "ffi_wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[cfg_attr(feature = "ffi_uniffi", derive(uniffi::Enum))]
+pub enum Arc56DefaultValueSource {
+    Box,
+    Global,
+    Local,
+    Literal,
+    Method,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "ffi_wasm", derive(Tsify))]
+#[cfg_attr(feature = "ffi_wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[cfg_attr(feature = "ffi_uniffi", derive(uniffi::Enum))]
+pub enum Arc56PcOffsetMethod {
+    None,
+    Cblocks,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "ffi_wasm", derive(Tsify))]
+#[cfg_attr(feature = "ffi_wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[cfg_attr(feature = "ffi_uniffi", derive(uniffi::Enum))]
+pub enum Arc56Compiler {
+    Algod,
+    Puya,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "ffi_wasm", derive(Tsify))]
+#[cfg_attr(feature = "ffi_wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[cfg_attr(feature = "ffi_uniffi", derive(uniffi::Enum))]
+pub enum AccountStatus {
+    Offline,
+    Online,
+    NotParticipating,
+}
+
+/// How the transactions of an account are signed
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "ffi_wasm", derive(Tsify))]
+#[cfg_attr(feature = "ffi_wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[cfg_attr(feature = "ffi_uniffi", derive(uniffi::Enum))]
+pub enum AccountSignatureType {
+    Single,
+    Multisig,
+    LogicSig,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "ffi_wasm", derive(Tsify))]
+#[cfg_attr(feature = "ffi_wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[cfg_attr(feature = "ffi_uniffi", derive(uniffi::Enum))]
+pub enum TealValueType {
+    Bytes,
+    Uint,
+}
+
+/// How a transaction will be signed, which determines the size of the signed transaction.
+/// In TS this is an object with a `type` field, i.e. `{ type: "Multisig", subsigs: 3 }`
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "ffi_wasm", derive(Tsify))]
+#[cfg_attr(feature = "ffi_wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[cfg_attr(feature = "ffi_uniffi", derive(uniffi::Enum))]
+#[cfg_attr(feature = "ffi_wasm", serde(rename_all_fields = "camelCase"))]
+#[serde(tag = "type")]
+pub enum SignatureKind {
+    Single,
+    /// A multisig signature, assuming all `subsigs` accounts sign
+    Multisig {
+        subsigs: u8,
+    },
+    LogicSig {
+        program_size: u32,
+        arg_sizes: Vec<u32>,
+    },
+}
+
 #[ffi_record]
 pub struct Address {
     address: String,
@@ -105,10 +372,10 @@ pub struct Address {
 
 impl From<algo_models::Address> for Address {
     fn from(value: algo_models::Address) -> Self {
-        return Self {
+        Self {
             address: value.address(),
             pub_key: value.pub_key.to_vec().into(),
-        };
+        }
     }
 }
 
@@ -120,7 +387,19 @@ impl TryFrom<Address> for algo_models::Address {
             AlgoModelsError::EncodingError("public key should be 32 bytes".to_string())
         })?;
 
-        Ok(algo_models::Address::from_pubkey(&pub_key))
+        let address = algo_models::Address::from_pubkey(&pub_key);
+
+        // The record carries both representations, so make sure they agree rather than
+        // silently trusting the public key
+        if address.address() != value.address {
+            return Err(AlgoModelsError::EncodingError(format!(
+                "address {} does not match public key (expected {})",
+                value.address,
+                address.address()
+            )));
+        }
+
+        Ok(address)
     }
 }
 
@@ -139,7 +418,7 @@ pub struct TransactionHeader {
     /// The sender of the transaction
     sender: Address,
 
-    fee: u64,
+    fee: MicroAlgos,
 
     first_valid: u64,
 
@@ -162,7 +441,7 @@ pub struct TransactionHeader {
 pub struct PayTransactionFields {
     receiver: Address,
 
-    amount: u64,
+    amount: MicroAlgos,
 
     close_remainder_to: Option<Address>,
 }
@@ -180,6 +459,49 @@ pub struct AssetTransferTransactionFields {
     close_remainder_to: Option<Address>,
 }
 
+#[ffi_record]
+pub struct StateSchema {
+    num_uints: u64,
+
+    num_byte_slices: u64,
+}
+
+#[ffi_record]
+pub struct BoxReference {
+    /// The index of the box's application in the foreign apps array, or 0 for the called application
+    app_index: u64,
+
+    name: ByteBuf,
+}
+
+#[ffi_record]
+pub struct ApplicationCallTransactionFields {
+    /// The application being called, or 0 when creating an application
+    app_id: u64,
+
+    on_complete: OnApplicationComplete,
+
+    approval_program: Option<ByteBuf>,
+
+    clear_state_program: Option<ByteBuf>,
+
+    global_state_schema: Option<StateSchema>,
+
+    local_state_schema: Option<StateSchema>,
+
+    extra_program_pages: u64,
+
+    args: Option<Vec<ByteBuf>>,
+
+    accounts: Option<Vec<Address>>,
+
+    foreign_apps: Option<Vec<u64>>,
+
+    foreign_assets: Option<Vec<u64>>,
+
+    boxes: Option<Vec<BoxReference>>,
+}
+
 #[ffi_record]
 pub struct Transaction {
     header: TransactionHeader,
@@ -187,241 +509,1830 @@ pub struct Transaction {
     pay_fields: Option<PayTransactionFields>,
 
     asset_transfer_fields: Option<AssetTransferTransactionFields>,
-}
 
-impl TryFrom<Transaction> for algo_models::Transaction {
-    type Error = AlgoModelsError;
+    application_call_fields: Option<ApplicationCallTransactionFields>,
+}
 
-    fn try_from(tx: Transaction) -> Result<Self, AlgoModelsError> {
-        // Ensure we only have pay fields or asset transfer fields
-        let fields: [bool; 2] = [tx.pay_fields.is_some(), tx.asset_transfer_fields.is_some()];
+/// A transaction and its signature, as stored in `goal` transaction files
+#[ffi_record]
+pub struct SignedTransaction {
+    transaction: Transaction,
 
-        // If fields has more than one true value, then we have an error
-        if fields.iter().filter(|&&x| x).count() > 1 {
-            return Err(AlgoModelsError::DecodingError(
-                "Multiple fields set".to_string(),
-            ));
-        }
+    /// The 64 byte signature, or none for an unsigned transaction
+    signature: Option<ByteBuf>,
 
-        if let Some(pay) = tx.pay_fields {
-            return Ok(algo_models::Transaction::Payment(
-                algo_models::PayTransactionFields {
-                    header: tx.header.try_into()?,
-                    amount: pay.amount,
-                    receiver: pay.receiver.try_into()?,
-                    close_remainder_to: pay.close_remainder_to.map(|a| a.try_into()).transpose()?,
-                },
-            ));
-        }
+    /// The signatures of a multisig account, in place of `signature`
+    multisig_signature: Option<MultisigSignature>,
 
-        if let Some(asset_transfer) = tx.asset_transfer_fields {
-            return Ok(algo_models::Transaction::AssetTransfer(
-                algo_models::AssetTransferTransactionFields {
-                    header: tx.header.try_into()?,
-                    asset_id: asset_transfer.asset_id,
-                    amount: asset_transfer.amount,
-                    receiver: asset_transfer.receiver.try_into()?,
-                    asset_sender: asset_transfer
-                        .asset_sender
-                        .map(|a| a.try_into())
-                        .transpose()?,
-                    close_remainder_to: asset_transfer
-                        .close_remainder_to
-                        .map(|a| a.try_into())
-                        .transpose()?,
-                },
-            ));
-        }
+    /// A program that approves the transaction, in place of `signature`
+    logic_signature: Option<LogicSignature>,
 
-        Err(AlgoModelsError::DecodingError(
-            "No transaction fields set".to_string(),
-        ))
-    }
+    /// The address that signed the transaction, when the sender has been rekeyed to it
+    auth_address: Option<Address>,
 }
 
-impl From<TransactionType> for algo_models::TransactionType {
-    fn from(tx: TransactionType) -> Self {
-        match tx {
-            TransactionType::Payment => algo_models::TransactionType::Payment,
-            TransactionType::AssetTransfer => algo_models::TransactionType::AssetTransfer,
-            TransactionType::AssetFreeze => algo_models::TransactionType::AssetFreeze,
-            TransactionType::AssetConfig => algo_models::TransactionType::AssetConfig,
-            TransactionType::KeyRegistration => algo_models::TransactionType::KeyRegistration,
-            TransactionType::ApplicationCall => algo_models::TransactionType::ApplicationCall,
-        }
-    }
+/// One of the accounts of a multisig account, and its signature if it has signed
+#[ffi_record]
+pub struct MultisigSubsignature {
+    public_key: ByteBuf,
+
+    signature: Option<ByteBuf>,
 }
 
-impl TryFrom<TransactionHeader> for algo_models::TransactionHeader {
-    type Error = AlgoModelsError;
+/// The signatures of a multisig account, with every account of the multisig in order
+#[ffi_record]
+pub struct MultisigSignature {
+    version: u8,
 
-    fn try_from(tx: TransactionHeader) -> Result<Self, AlgoModelsError> {
-        Ok(Self {
-            transaction_type: tx.transaction_type.into(),
-            sender: tx.sender.try_into()?,
-            fee: tx.fee,
-            first_valid: tx.first_valid,
-            last_valid: tx.last_valid,
-            genesis_id: tx.genesis_id,
-            genesis_hash: tx
-                .genesis_hash
-                .map(|b| {
-                    b.to_vec().try_into().map_err(|_| {
-                        AlgoModelsError::EncodingError(
-                            "genesis_hash should be 32 byte hash".to_string(),
-                        )
-                    })
-                })
-                .transpose()?,
-            note: tx.note.map(|b| b.to_vec()),
-            rekey_to: tx.rekey_to.map(|a| a.try_into()).transpose()?,
-            lease: tx
-                .lease
-                .map(|b| {
-                    b.to_vec().try_into().map_err(|_| {
-                        AlgoModelsError::EncodingError("lease should be 32 bytes".to_string())
-                    })
-                })
-                .transpose()?,
-            group: tx
-                .group
-                .map(|b| {
-                    b.to_vec().try_into().map_err(|_| {
-                        AlgoModelsError::EncodingError("group should be 32 byte hash".to_string())
-                    })
-                })
-                .transpose()?,
-        })
-    }
-}
+    threshold: u8,
 
-impl From<algo_models::TransactionHeader> for TransactionHeader {
-    fn from(tx: algo_models::TransactionHeader) -> Self {
-        Self {
-            transaction_type: tx.transaction_type.into(),
-            sender: tx.sender.into(),
-            fee: tx.fee,
-            first_valid: tx.first_valid,
-            last_valid: tx.last_valid,
-            genesis_id: tx.genesis_id,
-            genesis_hash: tx.genesis_hash.map(|b| ByteBuf::from(b.to_vec())),
-            note: tx.note.map(|b| ByteBuf::from(b.to_vec())),
-            rekey_to: tx.rekey_to.map(|a| a.into()),
-            lease: tx.lease.map(|b| ByteBuf::from(b.to_vec())),
-            group: tx.group.map(|b| ByteBuf::from(b.to_vec())),
-        }
-    }
+    subsignatures: Vec<MultisigSubsignature>,
 }
 
-impl From<algo_models::PayTransactionFields> for PayTransactionFields {
-    fn from(tx: algo_models::PayTransactionFields) -> Self {
-        Self {
-            receiver: tx.receiver.into(),
-            amount: tx.amount,
-            close_remainder_to: tx.close_remainder_to.map(|a| a.into()),
-        }
-    }
-}
+/// A program and its arguments that approve a transaction, signed when it is delegated
+#[ffi_record]
+pub struct LogicSignature {
+    logic: ByteBuf,
 
-impl TryFrom<PayTransactionFields> for algo_models::PayTransactionFields {
-    type Error = AlgoModelsError;
+    args: Option<Vec<ByteBuf>>,
+
+    signature: Option<ByteBuf>,
+
+    multisig_signature: Option<MultisigSignature>,
+
+    logic_multisig_signature: Option<MultisigSignature>,
+}
+
+/// The parameters for new transactions, as returned by algod's `/v2/transactions/params`
+#[ffi_record]
+pub struct SuggestedParams {
+    /// The fee per byte of the signed transaction, which is 0 unless the network is congested
+    fee_per_byte: u64,
+
+    min_fee: MicroAlgos,
+
+    first_valid: u64,
+
+    last_valid: u64,
+
+    genesis_id: String,
+
+    genesis_hash: ByteBuf,
+
+    consensus_version: String,
+}
+
+#[ffi_record]
+pub struct FeeOptions {
+    signature: SignatureKind,
+
+    /// Use this fee instead of estimating one from the size of the transaction
+    flat_fee: Option<MicroAlgos>,
+
+    /// The number of other transactions whose minimum fee this transaction pays
+    covered_transactions: u64,
+}
+
+/// The consensus limits that apply to transactions
+#[ffi_record]
+pub struct ConsensusParams {
+    max_txn_life: u64,
+
+    max_txn_note_bytes: u64,
+
+    min_txn_fee: MicroAlgos,
+
+    max_tx_group_size: u64,
+
+    /// The highest program version a logic signature may use, or 0 without logic signatures
+    logic_sig_version: u64,
+
+    logic_sig_max_size: u64,
+
+    max_app_args: u64,
+
+    max_app_total_arg_len: u64,
+
+    max_app_txn_accounts: u64,
+
+    max_app_txn_foreign_apps: u64,
+
+    max_app_txn_foreign_assets: u64,
+
+    max_app_total_txn_references: u64,
+
+    max_app_box_references: u64,
+
+    max_app_key_len: u64,
+
+    max_box_size: u64,
+
+    max_app_program_len: u64,
+
+    max_app_total_program_len: u64,
+
+    max_extra_app_program_pages: u64,
+
+    max_global_schema_entries: u64,
+
+    max_local_schema_entries: u64,
+
+    /// Whether transactions must have a genesis hash
+    require_genesis_hash: bool,
+
+    /// The minimum balance of an account, and the amount it increases by for each asset held
+    min_balance: MicroAlgos,
+
+    app_flat_params_min_balance: MicroAlgos,
+
+    app_flat_opt_in_min_balance: MicroAlgos,
+
+    schema_min_balance_per_entry: MicroAlgos,
+
+    schema_uint_min_balance: MicroAlgos,
+
+    schema_bytes_min_balance: MicroAlgos,
+
+    box_flat_min_balance: MicroAlgos,
+
+    box_byte_min_balance: MicroAlgos,
+}
+
+/// What an account holds and has created, which determines its minimum balance
+#[ffi_record]
+pub struct MinBalanceTotals {
+    /// The assets the account holds, including those it created
+    assets: u64,
+
+    created_apps: u64,
+
+    opted_in_apps: u64,
+
+    /// The global state schemas of created applications and the local state schemas of
+    /// applications opted in to, summed
+    schema: ApplicationStateSchema,
+
+    extra_app_pages: u64,
+
+    boxes: u64,
+
+    /// The lengths of the names and values of all boxes, summed
+    box_bytes: u64,
+}
+
+/// The header fields of a transaction being built. The genesis ID and hash and the validity
+/// window come from the suggested params unless the window is overridden here
+#[ffi_record]
+pub struct TransactionOptions {
+    sender: Address,
+
+    note: Option<ByteBuf>,
+
+    lease: Option<ByteBuf>,
+
+    rekey_to: Option<Address>,
+
+    first_valid: Option<u64>,
+
+    last_valid: Option<u64>,
+
+    /// How the fee is estimated, by default paying for a single signature
+    fee_options: Option<FeeOptions>,
+}
+
+/// An ARC-4 method argument. `arg_type` is an ABI type, a transaction type (`txn`, `pay`, ...)
+/// or a reference type (`account`, `asset` or `application`)
+#[ffi_record]
+pub struct AbiMethodArg {
+    arg_type: String,
+
+    name: Option<String>,
+
+    description: Option<String>,
+}
+
+#[ffi_record]
+pub struct AbiMethod {
+    name: String,
+
+    args: Vec<AbiMethodArg>,
+
+    /// The ABI return type, or `None` for `void`
+    returns: Option<String>,
+
+    description: Option<String>,
+}
+
+/// An ARC-56 application specification. ARC-32 specs are converted to this form when parsed
+#[ffi_record]
+pub struct Arc56Contract {
+    arcs: Vec<u32>,
+
+    name: String,
+
+    desc: Option<String>,
+
+    /// The application ID on each network, keyed by network name or genesis hash
+    networks: Option<HashMap<String, u64>>,
+
+    structs: HashMap<String, Vec<Arc56StructField>>,
+
+    methods: Vec<Arc56Method>,
+
+    state: Arc56State,
+
+    bare_actions: Arc56Actions,
+
+    source_info: Option<Arc56ProgramSourceInfos>,
+
+    /// The base64 encoded TEAL source of the programs
+    source: Option<Arc56Programs>,
+
+    /// The base64 encoded compiled programs
+    byte_code: Option<Arc56Programs>,
+
+    compiler_info: Option<Arc56CompilerInfo>,
+
+    events: Option<Vec<Arc56Event>>,
+
+    template_variables: Option<HashMap<String, Arc56TemplateVariable>>,
+
+    scratch_variables: Option<HashMap<String, Arc56ScratchVariable>>,
+}
+
+/// A struct field. Exactly one of `field_type` (an ABI type or struct name) and `fields`
+/// (an anonymous nested struct) is set
+#[ffi_record]
+pub struct Arc56StructField {
+    name: String,
+
+    field_type: Option<String>,
+
+    fields: Option<Vec<Arc56StructField>>,
+}
+
+#[ffi_record]
+pub struct Arc56Method {
+    name: String,
+
+    desc: Option<String>,
+
+    args: Vec<Arc56MethodArg>,
+
+    returns: Arc56MethodReturns,
+
+    actions: Arc56Actions,
+
+    readonly: Option<bool>,
+
+    events: Option<Vec<Arc56Event>>,
+
+    recommendations: Option<Arc56Recommendations>,
+}
+
+#[ffi_record]
+pub struct Arc56MethodArg {
+    arg_type: String,
+
+    struct_name: Option<String>,
+
+    name: Option<String>,
+
+    desc: Option<String>,
+
+    default_value: Option<Arc56DefaultValue>,
+}
+
+#[ffi_record]
+pub struct Arc56MethodReturns {
+    return_type: String,
+
+    struct_name: Option<String>,
+
+    desc: Option<String>,
+}
+
+#[ffi_record]
+pub struct Arc56DefaultValue {
+    data: String,
+
+    value_type: Option<String>,
+
+    source: Arc56DefaultValueSource,
+}
+
+#[ffi_record]
+pub struct Arc56Actions {
+    create: Vec<OnApplicationComplete>,
+
+    call: Vec<OnApplicationComplete>,
+}
+
+#[ffi_record]
+pub struct Arc56Recommendations {
+    inner_transaction_count: Option<u64>,
+
+    boxes: Option<Arc56BoxRecommendation>,
+
+    accounts: Option<Vec<String>>,
+
+    apps: Option<Vec<u64>>,
+
+    assets: Option<Vec<u64>>,
+}
+
+#[ffi_record]
+pub struct Arc56BoxRecommendation {
+    app: Option<u64>,
+
+    key: String,
+
+    read_bytes: u64,
+
+    write_bytes: u64,
+}
+
+#[ffi_record]
+pub struct Arc56State {
+    global_schema: StateSchema,
+
+    local_schema: StateSchema,
+
+    keys: Arc56StorageKeys,
+
+    maps: Arc56StorageMaps,
+}
+
+#[ffi_record]
+pub struct Arc56StorageKeys {
+    global_state: HashMap<String, Arc56StorageKey>,
+
+    local_state: HashMap<String, Arc56StorageKey>,
+
+    boxes: HashMap<String, Arc56StorageKey>,
+}
+
+#[ffi_record]
+pub struct Arc56StorageMaps {
+    global_state: HashMap<String, Arc56StorageMap>,
+
+    local_state: HashMap<String, Arc56StorageMap>,
+
+    boxes: HashMap<String, Arc56StorageMap>,
+}
+
+#[ffi_record]
+pub struct Arc56StorageKey {
+    desc: Option<String>,
+
+    key_type: String,
+
+    value_type: String,
+
+    key: String,
+}
+
+#[ffi_record]
+pub struct Arc56StorageMap {
+    desc: Option<String>,
+
+    key_type: String,
+
+    value_type: String,
+
+    prefix: Option<String>,
+}
+
+#[ffi_record]
+pub struct Arc56ProgramSourceInfos {
+    approval: Arc56ProgramSourceInfo,
+
+    clear: Arc56ProgramSourceInfo,
+}
+
+#[ffi_record]
+pub struct Arc56ProgramSourceInfo {
+    source_info: Vec<Arc56SourceInfo>,
+
+    pc_offset_method: Arc56PcOffsetMethod,
+}
+
+#[ffi_record]
+pub struct Arc56SourceInfo {
+    pc: Vec<u64>,
+
+    error_message: Option<String>,
+
+    teal: Option<u64>,
+
+    source: Option<String>,
+}
+
+#[ffi_record]
+pub struct Arc56Programs {
+    approval: String,
+
+    clear: String,
+}
+
+#[ffi_record]
+pub struct Arc56CompilerInfo {
+    compiler: Arc56Compiler,
+
+    major: u64,
+
+    minor: u64,
+
+    patch: u64,
+
+    commit_hash: Option<String>,
+}
+
+#[ffi_record]
+pub struct Arc56Event {
+    name: String,
+
+    desc: Option<String>,
+
+    args: Vec<Arc56EventArg>,
+}
+
+#[ffi_record]
+pub struct Arc56EventArg {
+    arg_type: String,
+
+    name: Option<String>,
+
+    desc: Option<String>,
+
+    struct_name: Option<String>,
+}
+
+#[ffi_record]
+pub struct Arc56TemplateVariable {
+    variable_type: String,
+
+    value: Option<String>,
+}
+
+#[ffi_record]
+pub struct Arc56ScratchVariable {
+    slot: u64,
+
+    variable_type: String,
+}
+
+/// An account as returned by algod's `/v2/accounts/{address}`
+#[ffi_record]
+pub struct Account {
+    address: Address,
+
+    /// The balance, including pending rewards
+    amount: MicroAlgos,
+
+    amount_without_pending_rewards: MicroAlgos,
+
+    min_balance: MicroAlgos,
+
+    pending_rewards: MicroAlgos,
+
+    rewards: MicroAlgos,
+
+    reward_base: Option<u64>,
+
+    round: u64,
+
+    status: AccountStatus,
+
+    signature_type: Option<AccountSignatureType>,
+
+    /// The address that signs for the account, when it has been rekeyed
+    auth_address: Option<Address>,
+
+    participation: Option<AccountParticipation>,
+
+    incentive_eligible: Option<bool>,
+
+    last_proposed: Option<u64>,
+
+    last_heartbeat: Option<u64>,
+
+    assets: Option<Vec<AssetHolding>>,
+
+    created_assets: Option<Vec<Asset>>,
+
+    apps_local_state: Option<Vec<ApplicationLocalState>>,
+
+    created_apps: Option<Vec<Application>>,
+
+    apps_total_schema: Option<ApplicationStateSchema>,
+
+    apps_total_extra_pages: Option<u64>,
+
+    total_apps_opted_in: u64,
+
+    total_assets_opted_in: u64,
+
+    total_created_apps: u64,
+
+    total_created_assets: u64,
+
+    total_boxes: Option<u64>,
+
+    total_box_bytes: Option<u64>,
+}
+
+#[ffi_record]
+pub struct AccountParticipation {
+    selection_participation_key: ByteBuf,
+
+    state_proof_key: Option<ByteBuf>,
+
+    vote_first_valid: u64,
+
+    vote_key_dilution: u64,
+
+    vote_last_valid: u64,
+
+    vote_participation_key: ByteBuf,
+}
+
+#[ffi_record]
+pub struct AssetHolding {
+    amount: u64,
+
+    asset_id: u64,
+
+    is_frozen: bool,
+}
+
+#[ffi_record]
+pub struct Asset {
+    id: u64,
+
+    params: AssetParams,
+}
+
+#[ffi_record]
+pub struct AssetParams {
+    clawback: Option<Address>,
+
+    creator: Address,
+
+    decimals: u64,
+
+    default_frozen: Option<bool>,
+
+    freeze: Option<Address>,
+
+    manager: Option<Address>,
+
+    metadata_hash: Option<ByteBuf>,
+
+    /// The name, if it is valid UTF-8
+    name: Option<String>,
+
+    name_bytes: Option<ByteBuf>,
+
+    reserve: Option<Address>,
+
+    total: u64,
+
+    unit_name: Option<String>,
+
+    unit_name_bytes: Option<ByteBuf>,
+
+    url: Option<String>,
+
+    url_bytes: Option<ByteBuf>,
+}
+
+#[ffi_record]
+pub struct ApplicationStateSchema {
+    num_uints: u64,
+
+    num_byte_slices: u64,
+}
+
+#[ffi_record]
+pub struct ApplicationLocalState {
+    id: u64,
+
+    schema: ApplicationStateSchema,
+
+    key_values: Option<Vec<TealKeyValue>>,
+}
+
+#[ffi_record]
+pub struct Application {
+    id: u64,
+
+    params: ApplicationParams,
+}
+
+#[ffi_record]
+pub struct ApplicationParams {
+    approval_program: ByteBuf,
+
+    clear_state_program: ByteBuf,
+
+    creator: Address,
+
+    extra_program_pages: Option<u64>,
+
+    global_state: Option<Vec<TealKeyValue>>,
+
+    global_state_schema: Option<ApplicationStateSchema>,
+
+    local_state_schema: Option<ApplicationStateSchema>,
+
+    version: Option<u64>,
+}
+
+#[ffi_record]
+pub struct TealKeyValue {
+    key: ByteBuf,
+
+    value: TealValue,
+}
+
+/// A value in application state. The field that does not match the type is empty
+#[ffi_record]
+pub struct TealValue {
+    value_type: TealValueType,
+
+    bytes: ByteBuf,
+
+    uint: u64,
+}
+
+impl TryFrom<Transaction> for algo_models::Transaction {
+    type Error = AlgoModelsError;
+
+    fn try_from(tx: Transaction) -> Result<Self, AlgoModelsError> {
+        // Ensure we only have one set of type-specific fields
+        let fields: [bool; 3] = [
+            tx.pay_fields.is_some(),
+            tx.asset_transfer_fields.is_some(),
+            tx.application_call_fields.is_some(),
+        ];
+
+        // If fields has more than one true value, then we have an error
+        if fields.iter().filter(|&&x| x).count() > 1 {
+            return Err(AlgoModelsError::DecodingError(
+                "Multiple fields set".to_string(),
+            ));
+        }
+
+        if let Some(pay) = tx.pay_fields {
+            return Ok(algo_models::Transaction::Payment(
+                algo_models::PayTransactionFields {
+                    header: tx.header.try_into()?,
+                    amount: pay.amount.into(),
+                    receiver: pay.receiver.try_into()?,
+                    close_remainder_to: pay.close_remainder_to.map(|a| a.try_into()).transpose()?,
+                },
+            ));
+        }
+
+        if let Some(asset_transfer) = tx.asset_transfer_fields {
+            return Ok(algo_models::Transaction::AssetTransfer(
+                algo_models::AssetTransferTransactionFields {
+                    header: tx.header.try_into()?,
+                    asset_id: asset_transfer.asset_id,
+                    amount: asset_transfer.amount,
+                    receiver: asset_transfer.receiver.try_into()?,
+                    asset_sender: asset_transfer
+                        .asset_sender
+                        .map(|a| a.try_into())
+                        .transpose()?,
+                    close_remainder_to: asset_transfer
+                        .close_remainder_to
+                        .map(|a| a.try_into())
+                        .transpose()?,
+                },
+            ));
+        }
+
+        if let Some(application_call) = tx.application_call_fields {
+            return Ok(algo_models::Transaction::ApplicationCall(
+                algo_models::ApplicationCallTransactionFields {
+                    header: tx.header.try_into()?,
+                    ..application_call.try_into()?
+                },
+            ));
+        }
+
+        Err(AlgoModelsError::DecodingError(
+            "No transaction fields set".to_string(),
+        ))
+    }
+}
+
+impl From<TransactionType> for algo_models::TransactionType {
+    fn from(tx: TransactionType) -> Self {
+        match tx {
+            TransactionType::Payment => algo_models::TransactionType::Payment,
+            TransactionType::AssetTransfer => algo_models::TransactionType::AssetTransfer,
+            TransactionType::AssetFreeze => algo_models::TransactionType::AssetFreeze,
+            TransactionType::AssetConfig => algo_models::TransactionType::AssetConfig,
+            TransactionType::KeyRegistration => algo_models::TransactionType::KeyRegistration,
+            TransactionType::ApplicationCall => algo_models::TransactionType::ApplicationCall,
+        }
+    }
+}
+
+impl TryFrom<TransactionHeader> for algo_models::TransactionHeader {
+    type Error = AlgoModelsError;
+
+    fn try_from(tx: TransactionHeader) -> Result<Self, AlgoModelsError> {
+        Ok(Self {
+            transaction_type: tx.transaction_type.into(),
+            sender: tx.sender.try_into()?,
+            fee: tx.fee.into(),
+            first_valid: tx.first_valid,
+            last_valid: tx.last_valid,
+            genesis_id: tx.genesis_id,
+            genesis_hash: tx
+                .genesis_hash
+                .map(|b| {
+                    b.to_vec().try_into().map_err(|_| {
+                        AlgoModelsError::EncodingError(
+                            "genesis_hash should be 32 byte hash".to_string(),
+                        )
+                    })
+                })
+                .transpose()?,
+            note: tx.note.map(|b| b.to_vec()),
+            rekey_to: tx.rekey_to.map(|a| a.try_into()).transpose()?,
+            lease: tx
+                .lease
+                .map(|b| {
+                    b.to_vec().try_into().map_err(|_| {
+                        AlgoModelsError::EncodingError("lease should be 32 bytes".to_string())
+                    })
+                })
+                .transpose()?,
+            group: tx
+                .group
+                .map(|b| {
+                    b.to_vec().try_into().map_err(|_| {
+                        AlgoModelsError::EncodingError("group should be 32 byte hash".to_string())
+                    })
+                })
+                .transpose()?,
+        })
+    }
+}
+
+impl From<algo_models::TransactionHeader> for TransactionHeader {
+    fn from(tx: algo_models::TransactionHeader) -> Self {
+        Self {
+            transaction_type: tx.transaction_type.into(),
+            sender: tx.sender.into(),
+            fee: tx.fee.into(),
+            first_valid: tx.first_valid,
+            last_valid: tx.last_valid,
+            genesis_id: tx.genesis_id,
+            genesis_hash: tx.genesis_hash.map(|b| ByteBuf::from(b.to_vec())),
+            note: tx.note.map(|b| ByteBuf::from(b.to_vec())),
+            rekey_to: tx.rekey_to.map(|a| a.into()),
+            lease: tx.lease.map(|b| ByteBuf::from(b.to_vec())),
+            group: tx.group.map(|b| ByteBuf::from(b.to_vec())),
+        }
+    }
+}
+
+impl From<algo_models::PayTransactionFields> for PayTransactionFields {
+    fn from(tx: algo_models::PayTransactionFields) -> Self {
+        Self {
+            receiver: tx.receiver.into(),
+            amount: tx.amount.into(),
+            close_remainder_to: tx.close_remainder_to.map(|a| a.into()),
+        }
+    }
+}
+
+impl TryFrom<PayTransactionFields> for algo_models::PayTransactionFields {
+    type Error = AlgoModelsError;
+
+    fn try_from(tx: PayTransactionFields) -> Result<Self, Self::Error> {
+        Ok(Self {
+            header: algo_models::TransactionHeader {
+                transaction_type: algo_models::TransactionType::Payment,
+                sender: algo_models::Address::from_pubkey(&[0; 32]), // This will be overridden by the Transaction conversion
+                fee: algo_models::MicroAlgos::ZERO,
+                first_valid: 0,
+                last_valid: 0,
+                genesis_id: None,
+                genesis_hash: None,
+                note: None,
+                rekey_to: None,
+                lease: None,
+                group: None,
+            },
+            amount: tx.amount.into(),
+            receiver: tx.receiver.try_into()?,
+            close_remainder_to: tx.close_remainder_to.map(|a| a.try_into()).transpose()?,
+        })
+    }
+}
+
+impl From<algo_models::AssetTransferTransactionFields> for AssetTransferTransactionFields {
+    fn from(tx: algo_models::AssetTransferTransactionFields) -> Self {
+        Self {
+            asset_id: tx.asset_id,
+            amount: tx.amount,
+            receiver: tx.receiver.into(),
+            asset_sender: tx.asset_sender.map(|a| a.into()),
+            close_remainder_to: tx.close_remainder_to.map(|a| a.into()),
+        }
+    }
+}
+
+impl TryFrom<AssetTransferTransactionFields> for algo_models::AssetTransferTransactionFields {
+    type Error = AlgoModelsError;
+
+    fn try_from(tx: AssetTransferTransactionFields) -> Result<Self, Self::Error> {
+        Ok(Self {
+            header: algo_models::TransactionHeader {
+                transaction_type: algo_models::TransactionType::AssetTransfer,
+                sender: algo_models::Address::from_pubkey(&[0; 32]), // This will be overridden by the Transaction conversion
+                fee: algo_models::MicroAlgos::ZERO,
+                first_valid: 0,
+                last_valid: 0,
+                genesis_id: None,
+                genesis_hash: None,
+                note: None,
+                rekey_to: None,
+                lease: None,
+                group: None,
+            },
+            asset_id: tx.asset_id,
+            amount: tx.amount,
+            receiver: tx.receiver.try_into()?,
+            asset_sender: tx.asset_sender.map(|a| a.try_into()).transpose()?,
+            close_remainder_to: tx.close_remainder_to.map(|a| a.try_into()).transpose()?,
+        })
+    }
+}
+
+impl From<OnApplicationComplete> for algo_models::OnApplicationComplete {
+    fn from(value: OnApplicationComplete) -> Self {
+        match value {
+            OnApplicationComplete::NoOp => algo_models::OnApplicationComplete::NoOp,
+            OnApplicationComplete::OptIn => algo_models::OnApplicationComplete::OptIn,
+            OnApplicationComplete::CloseOut => algo_models::OnApplicationComplete::CloseOut,
+            OnApplicationComplete::ClearState => algo_models::OnApplicationComplete::ClearState,
+            OnApplicationComplete::UpdateApplication => {
+                algo_models::OnApplicationComplete::UpdateApplication
+            }
+            OnApplicationComplete::DeleteApplication => {
+                algo_models::OnApplicationComplete::DeleteApplication
+            }
+        }
+    }
+}
+
+impl From<algo_models::OnApplicationComplete> for OnApplicationComplete {
+    fn from(value: algo_models::OnApplicationComplete) -> Self {
+        match value {
+            algo_models::OnApplicationComplete::NoOp => OnApplicationComplete::NoOp,
+            algo_models::OnApplicationComplete::OptIn => OnApplicationComplete::OptIn,
+            algo_models::OnApplicationComplete::CloseOut => OnApplicationComplete::CloseOut,
+            algo_models::OnApplicationComplete::ClearState => OnApplicationComplete::ClearState,
+            algo_models::OnApplicationComplete::UpdateApplication => {
+                OnApplicationComplete::UpdateApplication
+            }
+            algo_models::OnApplicationComplete::DeleteApplication => {
+                OnApplicationComplete::DeleteApplication
+            }
+        }
+    }
+}
+
+impl From<StateSchema> for algo_models::StateSchema {
+    fn from(value: StateSchema) -> Self {
+        Self {
+            num_uints: value.num_uints,
+            num_byte_slices: value.num_byte_slices,
+        }
+    }
+}
+
+impl From<algo_models::StateSchema> for StateSchema {
+    fn from(value: algo_models::StateSchema) -> Self {
+        Self {
+            num_uints: value.num_uints,
+            num_byte_slices: value.num_byte_slices,
+        }
+    }
+}
+
+impl From<BoxReference> for algo_models::BoxReference {
+    fn from(value: BoxReference) -> Self {
+        Self {
+            app_index: value.app_index,
+            name: value.name.to_vec(),
+        }
+    }
+}
+
+impl From<algo_models::BoxReference> for BoxReference {
+    fn from(value: algo_models::BoxReference) -> Self {
+        Self {
+            app_index: value.app_index,
+            name: ByteBuf::from(value.name),
+        }
+    }
+}
+
+impl From<algo_models::ApplicationCallTransactionFields> for ApplicationCallTransactionFields {
+    fn from(tx: algo_models::ApplicationCallTransactionFields) -> Self {
+        Self {
+            app_id: tx.app_id,
+            on_complete: tx.on_complete.into(),
+            approval_program: tx.approval_program.map(ByteBuf::from),
+            clear_state_program: tx.clear_state_program.map(ByteBuf::from),
+            global_state_schema: tx.global_state_schema.map(|s| s.into()),
+            local_state_schema: tx.local_state_schema.map(|s| s.into()),
+            extra_program_pages: tx.extra_program_pages,
+            args: tx
+                .args
+                .map(|args| args.into_iter().map(ByteBuf::from).collect()),
+            accounts: tx
+                .accounts
+                .map(|accounts| accounts.into_iter().map(|a| a.into()).collect()),
+            foreign_apps: tx.foreign_apps,
+            foreign_assets: tx.foreign_assets,
+            boxes: tx
+                .boxes
+                .map(|boxes| boxes.into_iter().map(|b| b.into()).collect()),
+        }
+    }
+}
+
+impl TryFrom<ApplicationCallTransactionFields> for algo_models::ApplicationCallTransactionFields {
+    type Error = AlgoModelsError;
+
+    fn try_from(tx: ApplicationCallTransactionFields) -> Result<Self, Self::Error> {
+        Ok(Self {
+            header: algo_models::TransactionHeader {
+                transaction_type: algo_models::TransactionType::ApplicationCall,
+                sender: algo_models::Address::from_pubkey(&[0; 32]), // This will be overridden by the Transaction conversion
+                fee: algo_models::MicroAlgos::ZERO,
+                first_valid: 0,
+                last_valid: 0,
+                genesis_id: None,
+                genesis_hash: None,
+                note: None,
+                rekey_to: None,
+                lease: None,
+                group: None,
+            },
+            app_id: tx.app_id,
+            on_complete: tx.on_complete.into(),
+            approval_program: tx.approval_program.map(|b| b.to_vec()),
+            clear_state_program: tx.clear_state_program.map(|b| b.to_vec()),
+            global_state_schema: tx.global_state_schema.map(|s| s.into()),
+            local_state_schema: tx.local_state_schema.map(|s| s.into()),
+            extra_program_pages: tx.extra_program_pages,
+            args: tx
+                .args
+                .map(|args| args.into_iter().map(|b| b.to_vec()).collect()),
+            accounts: tx
+                .accounts
+                .map(|accounts| {
+                    accounts
+                        .into_iter()
+                        .map(|a| a.try_into())
+                        .collect::<Result<_, _>>()
+                })
+                .transpose()?,
+            foreign_apps: tx.foreign_apps,
+            foreign_assets: tx.foreign_assets,
+            boxes: tx
+                .boxes
+                .map(|boxes| boxes.into_iter().map(|b| b.into()).collect()),
+        })
+    }
+}
+
+impl TryFrom<algo_models::Transaction> for Transaction {
+    type Error = AlgoModelsError;
+
+    fn try_from(tx: algo_models::Transaction) -> Result<Self, Self::Error> {
+        match tx {
+            algo_models::Transaction::Payment(payment) => {
+                let header = payment.header.into();
+                let pay_fields = PayTransactionFields {
+                    receiver: payment.receiver.into(),
+                    amount: payment.amount.into(),
+                    close_remainder_to: payment.close_remainder_to.map(|a| a.into()),
+                };
+
+                Ok(Self {
+                    header,
+                    pay_fields: Some(pay_fields),
+                    asset_transfer_fields: None,
+                    application_call_fields: None,
+                })
+            }
+            algo_models::Transaction::AssetTransfer(asset_transfer) => {
+                let header = asset_transfer.header.into();
+                let asset_fields = AssetTransferTransactionFields {
+                    asset_id: asset_transfer.asset_id,
+                    amount: asset_transfer.amount,
+                    receiver: asset_transfer.receiver.into(),
+                    asset_sender: asset_transfer.asset_sender.map(|a| a.into()),
+                    close_remainder_to: asset_transfer.close_remainder_to.map(|a| a.into()),
+                };
+
+                Ok(Self {
+                    header,
+                    pay_fields: None,
+                    asset_transfer_fields: Some(asset_fields),
+                    application_call_fields: None,
+                })
+            }
+            algo_models::Transaction::ApplicationCall(application_call) => Ok(Self {
+                header: application_call.header.clone().into(),
+                pay_fields: None,
+                asset_transfer_fields: None,
+                application_call_fields: Some(application_call.into()),
+            }),
+        }
+    }
+}
+
+fn signature_bytes(signature: ByteBuf) -> Result<[u8; 64], AlgoModelsError> {
+    signature
+        .to_vec()
+        .try_into()
+        .map_err(|_| AlgoModelsError::EncodingError("signature must be 64 bytes".to_string()))
+}
+
+impl TryFrom<MultisigSignature> for algo_models::MultisigSignature {
+    type Error = AlgoModelsError;
+
+    fn try_from(value: MultisigSignature) -> Result<Self, Self::Error> {
+        let subsignatures = value
+            .subsignatures
+            .into_iter()
+            .map(|subsig| {
+                Ok(algo_models::MultisigSubsignature {
+                    public_key: subsig.public_key.to_vec().try_into().map_err(|_| {
+                        AlgoModelsError::EncodingError("public key should be 32 bytes".to_string())
+                    })?,
+                    signature: subsig.signature.map(signature_bytes).transpose()?,
+                })
+            })
+            .collect::<Result<_, AlgoModelsError>>()?;
 
-    fn try_from(tx: PayTransactionFields) -> Result<Self, Self::Error> {
         Ok(Self {
-            header: algo_models::TransactionHeader {
-                transaction_type: algo_models::TransactionType::Payment,
-                sender: algo_models::Address::from_pubkey(&[0; 32]), // This will be overridden by the Transaction conversion
-                fee: 0,
-                first_valid: 0,
-                last_valid: 0,
-                genesis_id: None,
-                genesis_hash: None,
-                note: None,
-                rekey_to: None,
-                lease: None,
-                group: None,
-            },
-            amount: tx.amount,
-            receiver: tx.receiver.try_into()?,
-            close_remainder_to: tx.close_remainder_to.map(|a| a.try_into()).transpose()?,
+            version: value.version,
+            threshold: value.threshold,
+            subsignatures,
         })
     }
 }
 
-impl From<algo_models::AssetTransferTransactionFields> for AssetTransferTransactionFields {
-    fn from(tx: algo_models::AssetTransferTransactionFields) -> Self {
+impl From<algo_models::MultisigSignature> for MultisigSignature {
+    fn from(value: algo_models::MultisigSignature) -> Self {
         Self {
-            asset_id: tx.asset_id,
-            amount: tx.amount,
-            receiver: tx.receiver.into(),
-            asset_sender: tx.asset_sender.map(|a| a.into()),
-            close_remainder_to: tx.close_remainder_to.map(|a| a.into()),
+            version: value.version,
+            threshold: value.threshold,
+            subsignatures: value
+                .subsignatures
+                .into_iter()
+                .map(|subsig| MultisigSubsignature {
+                    public_key: ByteBuf::from(subsig.public_key.to_vec()),
+                    signature: subsig.signature.map(|s| ByteBuf::from(s.to_vec())),
+                })
+                .collect(),
         }
     }
 }
 
-impl TryFrom<AssetTransferTransactionFields> for algo_models::AssetTransferTransactionFields {
+impl TryFrom<LogicSignature> for algo_models::LogicSignature {
     type Error = AlgoModelsError;
 
-    fn try_from(tx: AssetTransferTransactionFields) -> Result<Self, Self::Error> {
+    fn try_from(value: LogicSignature) -> Result<Self, Self::Error> {
         Ok(Self {
-            header: algo_models::TransactionHeader {
-                transaction_type: algo_models::TransactionType::AssetTransfer,
-                sender: algo_models::Address::from_pubkey(&[0; 32]), // This will be overridden by the Transaction conversion
-                fee: 0,
-                first_valid: 0,
-                last_valid: 0,
-                genesis_id: None,
-                genesis_hash: None,
-                note: None,
-                rekey_to: None,
-                lease: None,
-                group: None,
+            logic: value.logic.into_vec(),
+            args: value
+                .args
+                .map(|args| args.into_iter().map(ByteBuf::into_vec).collect()),
+            signature: value.signature.map(signature_bytes).transpose()?,
+            multisig_signature: value
+                .multisig_signature
+                .map(TryInto::try_into)
+                .transpose()?,
+            logic_multisig_signature: value
+                .logic_multisig_signature
+                .map(TryInto::try_into)
+                .transpose()?,
+        })
+    }
+}
+
+impl From<algo_models::LogicSignature> for LogicSignature {
+    fn from(value: algo_models::LogicSignature) -> Self {
+        Self {
+            logic: ByteBuf::from(value.logic),
+            args: value
+                .args
+                .map(|args| args.into_iter().map(ByteBuf::from).collect()),
+            signature: value.signature.map(|s| ByteBuf::from(s.to_vec())),
+            multisig_signature: value.multisig_signature.map(Into::into),
+            logic_multisig_signature: value.logic_multisig_signature.map(Into::into),
+        }
+    }
+}
+
+impl TryFrom<SignedTransaction> for algo_models::SignedTransaction {
+    type Error = AlgoModelsError;
+
+    fn try_from(value: SignedTransaction) -> Result<Self, Self::Error> {
+        let signed = Self::with_empty_signature(value.transaction.try_into()?);
+        Ok(Self {
+            signature: match value.signature {
+                Some(signature) => signature_bytes(signature)?,
+                None => signed.signature,
             },
-            asset_id: tx.asset_id,
-            amount: tx.amount,
-            receiver: tx.receiver.try_into()?,
-            asset_sender: tx.asset_sender.map(|a| a.try_into()).transpose()?,
-            close_remainder_to: tx.close_remainder_to.map(|a| a.try_into()).transpose()?,
+            multisig_signature: value
+                .multisig_signature
+                .map(TryInto::try_into)
+                .transpose()?,
+            logic_signature: value.logic_signature.map(TryInto::try_into).transpose()?,
+            auth_address: value.auth_address.map(TryInto::try_into).transpose()?,
+            ..signed
+        })
+    }
+}
+
+impl TryFrom<algo_models::SignedTransaction> for SignedTransaction {
+    type Error = AlgoModelsError;
+
+    fn try_from(value: algo_models::SignedTransaction) -> Result<Self, Self::Error> {
+        let signature =
+            (!value.has_empty_signature()).then(|| ByteBuf::from(value.signature.to_vec()));
+        Ok(Self {
+            transaction: value.transaction.try_into()?,
+            signature,
+            multisig_signature: value.multisig_signature.map(Into::into),
+            logic_signature: value.logic_signature.map(Into::into),
+            auth_address: value.auth_address.map(Into::into),
+        })
+    }
+}
+
+impl From<SignatureKind> for fee::SignatureKind {
+    fn from(value: SignatureKind) -> Self {
+        match value {
+            SignatureKind::Single => fee::SignatureKind::Single,
+            SignatureKind::Multisig { subsigs } => fee::SignatureKind::Multisig { subsigs },
+            SignatureKind::LogicSig {
+                program_size,
+                arg_sizes,
+            } => fee::SignatureKind::LogicSig {
+                program_size: program_size as usize,
+                arg_sizes: arg_sizes.into_iter().map(|size| size as usize).collect(),
+            },
+        }
+    }
+}
+
+impl TryFrom<SuggestedParams> for fee::SuggestedParams {
+    type Error = AlgoModelsError;
+
+    fn try_from(params: SuggestedParams) -> Result<Self, Self::Error> {
+        Ok(Self {
+            fee_per_byte: params.fee_per_byte,
+            min_fee: params.min_fee.into(),
+            first_valid: params.first_valid,
+            last_valid: params.last_valid,
+            genesis_id: params.genesis_id,
+            genesis_hash: params.genesis_hash.to_vec().try_into().map_err(|_| {
+                AlgoModelsError::EncodingError("genesis_hash should be 32 byte hash".to_string())
+            })?,
+            consensus_version: params.consensus_version,
+        })
+    }
+}
+
+impl From<consensus::ConsensusParams> for ConsensusParams {
+    fn from(params: consensus::ConsensusParams) -> Self {
+        Self {
+            max_txn_life: params.max_txn_life,
+            max_txn_note_bytes: params.max_txn_note_bytes as u64,
+            min_txn_fee: params.min_txn_fee.into(),
+            max_tx_group_size: params.max_tx_group_size as u64,
+            logic_sig_version: params.logic_sig_version,
+            logic_sig_max_size: params.logic_sig_max_size as u64,
+            max_app_args: params.max_app_args as u64,
+            max_app_total_arg_len: params.max_app_total_arg_len as u64,
+            max_app_txn_accounts: params.max_app_txn_accounts as u64,
+            max_app_txn_foreign_apps: params.max_app_txn_foreign_apps as u64,
+            max_app_txn_foreign_assets: params.max_app_txn_foreign_assets as u64,
+            max_app_total_txn_references: params.max_app_total_txn_references as u64,
+            max_app_box_references: params.max_app_box_references as u64,
+            max_app_key_len: params.max_app_key_len as u64,
+            max_box_size: params.max_box_size,
+            max_app_program_len: params.max_app_program_len as u64,
+            max_app_total_program_len: params.max_app_total_program_len as u64,
+            max_extra_app_program_pages: params.max_extra_app_program_pages,
+            max_global_schema_entries: params.max_global_schema_entries,
+            max_local_schema_entries: params.max_local_schema_entries,
+            require_genesis_hash: params.require_genesis_hash,
+            min_balance: params.min_balance.into(),
+            app_flat_params_min_balance: params.app_flat_params_min_balance.into(),
+            app_flat_opt_in_min_balance: params.app_flat_opt_in_min_balance.into(),
+            schema_min_balance_per_entry: params.schema_min_balance_per_entry.into(),
+            schema_uint_min_balance: params.schema_uint_min_balance.into(),
+            schema_bytes_min_balance: params.schema_bytes_min_balance.into(),
+            box_flat_min_balance: params.box_flat_min_balance.into(),
+            box_byte_min_balance: params.box_byte_min_balance.into(),
+        }
+    }
+}
+
+impl From<ConsensusParams> for consensus::ConsensusParams {
+    fn from(params: ConsensusParams) -> Self {
+        // Limits too large for a usize can never be reached
+        let size = |limit: u64| usize::try_from(limit).unwrap_or(usize::MAX);
+
+        Self {
+            max_txn_life: params.max_txn_life,
+            max_txn_note_bytes: size(params.max_txn_note_bytes),
+            min_txn_fee: params.min_txn_fee.into(),
+            max_tx_group_size: size(params.max_tx_group_size),
+            logic_sig_version: params.logic_sig_version,
+            logic_sig_max_size: size(params.logic_sig_max_size),
+            max_app_args: size(params.max_app_args),
+            max_app_total_arg_len: size(params.max_app_total_arg_len),
+            max_app_txn_accounts: size(params.max_app_txn_accounts),
+            max_app_txn_foreign_apps: size(params.max_app_txn_foreign_apps),
+            max_app_txn_foreign_assets: size(params.max_app_txn_foreign_assets),
+            max_app_total_txn_references: size(params.max_app_total_txn_references),
+            max_app_box_references: size(params.max_app_box_references),
+            max_app_key_len: size(params.max_app_key_len),
+            max_box_size: params.max_box_size,
+            max_app_program_len: size(params.max_app_program_len),
+            max_app_total_program_len: size(params.max_app_total_program_len),
+            max_extra_app_program_pages: params.max_extra_app_program_pages,
+            max_global_schema_entries: params.max_global_schema_entries,
+            max_local_schema_entries: params.max_local_schema_entries,
+            require_genesis_hash: params.require_genesis_hash,
+            min_balance: params.min_balance.into(),
+            app_flat_params_min_balance: params.app_flat_params_min_balance.into(),
+            app_flat_opt_in_min_balance: params.app_flat_opt_in_min_balance.into(),
+            schema_min_balance_per_entry: params.schema_min_balance_per_entry.into(),
+            schema_uint_min_balance: params.schema_uint_min_balance.into(),
+            schema_bytes_min_balance: params.schema_bytes_min_balance.into(),
+            box_flat_min_balance: params.box_flat_min_balance.into(),
+            box_byte_min_balance: params.box_byte_min_balance.into(),
+        }
+    }
+}
+
+impl From<FeeOptions> for fee::FeeOptions {
+    fn from(options: FeeOptions) -> Self {
+        Self {
+            signature: options.signature.into(),
+            flat_fee: options.flat_fee.map(Into::into),
+            covered_transactions: options.covered_transactions,
+        }
+    }
+}
+
+impl From<DerivationType> for algo_models::hd::DerivationType {
+    fn from(value: DerivationType) -> Self {
+        match value {
+            DerivationType::Khovratovich => algo_models::hd::DerivationType::Khovratovich,
+            DerivationType::Peikert => algo_models::hd::DerivationType::Peikert,
+        }
+    }
+}
+
+impl From<KeyContext> for algo_models::hd::KeyContext {
+    fn from(value: KeyContext) -> Self {
+        match value {
+            KeyContext::Address => algo_models::hd::KeyContext::Address,
+            KeyContext::Identity => algo_models::hd::KeyContext::Identity,
+        }
+    }
+}
+
+impl From<algo_models::abi::AbiValue> for AbiValue {
+    fn from(value: algo_models::abi::AbiValue) -> Self {
+        match value {
+            algo_models::abi::AbiValue::Uint(n) => AbiValue::Uint {
+                value: n.to_string(),
+            },
+            algo_models::abi::AbiValue::Bool(value) => AbiValue::Bool { value },
+            algo_models::abi::AbiValue::Byte(value) => AbiValue::Byte { value },
+            algo_models::abi::AbiValue::Address(a) => AbiValue::Address { value: a.into() },
+            algo_models::abi::AbiValue::String(value) => AbiValue::String { value },
+            algo_models::abi::AbiValue::Array(values) => AbiValue::Array {
+                values: values.into_iter().map(|v| v.into()).collect(),
+            },
+        }
+    }
+}
+
+impl TryFrom<AbiValue> for algo_models::abi::AbiValue {
+    type Error = AlgoModelsError;
+
+    fn try_from(value: AbiValue) -> Result<Self, Self::Error> {
+        Ok(match value {
+            AbiValue::Uint { value } => {
+                algo_models::abi::AbiValue::Uint(value.parse().map_err(|_| {
+                    AlgoModelsError::AbiError(format!("{} is not an unsigned integer", value))
+                })?)
+            }
+            AbiValue::Bool { value } => algo_models::abi::AbiValue::Bool(value),
+            AbiValue::Byte { value } => algo_models::abi::AbiValue::Byte(value),
+            AbiValue::Address { value } => algo_models::abi::AbiValue::Address(value.try_into()?),
+            AbiValue::String { value } => algo_models::abi::AbiValue::String(value),
+            AbiValue::Array { values } => algo_models::abi::AbiValue::Array(
+                values
+                    .into_iter()
+                    .map(|v| v.try_into())
+                    .collect::<Result<_, _>>()?,
+            ),
+        })
+    }
+}
+
+impl From<algo_models::abi::Method> for AbiMethod {
+    fn from(value: algo_models::abi::Method) -> Self {
+        Self {
+            name: value.name,
+            args: value
+                .args
+                .into_iter()
+                .map(|arg| AbiMethodArg {
+                    arg_type: arg.arg_type.to_string(),
+                    name: arg.name,
+                    description: arg.description,
+                })
+                .collect(),
+            returns: value.returns.map(|r| r.to_string()),
+            description: value.description,
+        }
+    }
+}
+
+impl TryFrom<AbiMethodArgValue> for algo_models::abi::MethodArgValue {
+    type Error = AlgoModelsError;
+
+    fn try_from(value: AbiMethodArgValue) -> Result<Self, Self::Error> {
+        Ok(match value {
+            AbiMethodArgValue::AbiValue { value } => Self::Abi(value.try_into()?),
+            AbiMethodArgValue::Transaction => Self::Transaction,
+            AbiMethodArgValue::Account { value } => Self::Account(value.try_into()?),
+            AbiMethodArgValue::Asset { value } => Self::Asset(value),
+            AbiMethodArgValue::Application { value } => Self::Application(value),
         })
     }
 }
 
-impl TryFrom<algo_models::Transaction> for Transaction {
-    type Error = AlgoModelsError;
+impl From<arc56::StructField> for Arc56StructField {
+    fn from(value: arc56::StructField) -> Self {
+        let (field_type, fields) = match value.field_type {
+            arc56::StructFieldType::Type(t) => (Some(t), None),
+            arc56::StructFieldType::Fields(fields) => {
+                (None, Some(fields.into_iter().map(|f| f.into()).collect()))
+            }
+        };
+
+        Self {
+            name: value.name,
+            field_type,
+            fields,
+        }
+    }
+}
+
+impl From<arc56::Actions> for Arc56Actions {
+    fn from(value: arc56::Actions) -> Self {
+        Self {
+            create: value.create.into_iter().map(|a| a.into()).collect(),
+            call: value.call.into_iter().map(|a| a.into()).collect(),
+        }
+    }
+}
+
+impl From<arc56::DefaultValue> for Arc56DefaultValue {
+    fn from(value: arc56::DefaultValue) -> Self {
+        Self {
+            data: value.data,
+            value_type: value.value_type,
+            source: match value.source {
+                arc56::DefaultValueSource::Box => Arc56DefaultValueSource::Box,
+                arc56::DefaultValueSource::Global => Arc56DefaultValueSource::Global,
+                arc56::DefaultValueSource::Local => Arc56DefaultValueSource::Local,
+                arc56::DefaultValueSource::Literal => Arc56DefaultValueSource::Literal,
+                arc56::DefaultValueSource::Method => Arc56DefaultValueSource::Method,
+            },
+        }
+    }
+}
+
+impl From<arc56::Event> for Arc56Event {
+    fn from(value: arc56::Event) -> Self {
+        Self {
+            name: value.name,
+            desc: value.desc,
+            args: value
+                .args
+                .into_iter()
+                .map(|arg| Arc56EventArg {
+                    arg_type: arg.arg_type,
+                    name: arg.name,
+                    desc: arg.desc,
+                    struct_name: arg.struct_name,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<arc56::Method> for Arc56Method {
+    fn from(value: arc56::Method) -> Self {
+        Self {
+            name: value.name,
+            desc: value.desc,
+            args: value
+                .args
+                .into_iter()
+                .map(|arg| Arc56MethodArg {
+                    arg_type: arg.arg_type,
+                    struct_name: arg.struct_name,
+                    name: arg.name,
+                    desc: arg.desc,
+                    default_value: arg.default_value.map(|d| d.into()),
+                })
+                .collect(),
+            returns: Arc56MethodReturns {
+                return_type: value.returns.return_type,
+                struct_name: value.returns.struct_name,
+                desc: value.returns.desc,
+            },
+            actions: value.actions.into(),
+            readonly: value.readonly,
+            events: value
+                .events
+                .map(|events| events.into_iter().map(|e| e.into()).collect()),
+            recommendations: value.recommendations.map(|r| Arc56Recommendations {
+                inner_transaction_count: r.inner_transaction_count,
+                boxes: r.boxes.map(|b| Arc56BoxRecommendation {
+                    app: b.app,
+                    key: b.key,
+                    read_bytes: b.read_bytes,
+                    write_bytes: b.write_bytes,
+                }),
+                accounts: r.accounts,
+                apps: r.apps,
+                assets: r.assets,
+            }),
+        }
+    }
+}
+
+impl From<arc56::StorageKey> for Arc56StorageKey {
+    fn from(value: arc56::StorageKey) -> Self {
+        Self {
+            desc: value.desc,
+            key_type: value.key_type,
+            value_type: value.value_type,
+            key: value.key,
+        }
+    }
+}
+
+impl From<arc56::StorageMap> for Arc56StorageMap {
+    fn from(value: arc56::StorageMap) -> Self {
+        Self {
+            desc: value.desc,
+            key_type: value.key_type,
+            value_type: value.value_type,
+            prefix: value.prefix,
+        }
+    }
+}
+
+impl From<arc56::ProgramSourceInfo> for Arc56ProgramSourceInfo {
+    fn from(value: arc56::ProgramSourceInfo) -> Self {
+        Self {
+            source_info: value
+                .source_info
+                .into_iter()
+                .map(|info| Arc56SourceInfo {
+                    pc: info.pc,
+                    error_message: info.error_message,
+                    teal: info.teal,
+                    source: info.source,
+                })
+                .collect(),
+            pc_offset_method: match value.pc_offset_method {
+                arc56::PcOffsetMethod::None => Arc56PcOffsetMethod::None,
+                arc56::PcOffsetMethod::Cblocks => Arc56PcOffsetMethod::Cblocks,
+            },
+        }
+    }
+}
+
+impl From<arc56::Programs> for Arc56Programs {
+    fn from(value: arc56::Programs) -> Self {
+        Self {
+            approval: value.approval,
+            clear: value.clear,
+        }
+    }
+}
+
+fn into_hash_map<K: std::hash::Hash + Eq, V, T: From<V>>(
+    map: impl IntoIterator<Item = (K, V)>,
+) -> HashMap<K, T> {
+    map.into_iter().map(|(k, v)| (k, v.into())).collect()
+}
+
+impl From<arc56::Arc56Contract> for Arc56Contract {
+    fn from(value: arc56::Arc56Contract) -> Self {
+        let state = value.state;
+
+        Self {
+            arcs: value.arcs,
+            name: value.name,
+            desc: value.desc,
+            networks: value.networks.map(|networks| {
+                networks
+                    .into_iter()
+                    .map(|(name, network)| (name, network.app_id))
+                    .collect()
+            }),
+            structs: value
+                .structs
+                .into_iter()
+                .map(|(name, fields)| (name, fields.into_iter().map(|f| f.into()).collect()))
+                .collect(),
+            methods: value.methods.into_iter().map(|m| m.into()).collect(),
+            state: Arc56State {
+                global_schema: algo_models::StateSchema::from(&state.schema.global).into(),
+                local_schema: algo_models::StateSchema::from(&state.schema.local).into(),
+                keys: Arc56StorageKeys {
+                    global_state: into_hash_map(state.keys.global),
+                    local_state: into_hash_map(state.keys.local),
+                    boxes: into_hash_map(state.keys.boxes),
+                },
+                maps: Arc56StorageMaps {
+                    global_state: into_hash_map(state.maps.global),
+                    local_state: into_hash_map(state.maps.local),
+                    boxes: into_hash_map(state.maps.boxes),
+                },
+            },
+            bare_actions: value.bare_actions.into(),
+            source_info: value.source_info.map(|info| Arc56ProgramSourceInfos {
+                approval: info.approval.into(),
+                clear: info.clear.into(),
+            }),
+            source: value.source.map(|p| p.into()),
+            byte_code: value.byte_code.map(|p| p.into()),
+            compiler_info: value.compiler_info.map(|info| Arc56CompilerInfo {
+                compiler: match info.compiler {
+                    arc56::Compiler::Algod => Arc56Compiler::Algod,
+                    arc56::Compiler::Puya => Arc56Compiler::Puya,
+                },
+                major: info.compiler_version.major,
+                minor: info.compiler_version.minor,
+                patch: info.compiler_version.patch,
+                commit_hash: info.compiler_version.commit_hash,
+            }),
+            events: value
+                .events
+                .map(|events| events.into_iter().map(|e| e.into()).collect()),
+            template_variables: value.template_variables.map(|variables| {
+                variables
+                    .into_iter()
+                    .map(|(name, v)| {
+                        (
+                            name,
+                            Arc56TemplateVariable {
+                                variable_type: v.variable_type,
+                                value: v.value,
+                            },
+                        )
+                    })
+                    .collect()
+            }),
+            scratch_variables: value.scratch_variables.map(|variables| {
+                variables
+                    .into_iter()
+                    .map(|(name, v)| {
+                        (
+                            name,
+                            Arc56ScratchVariable {
+                                slot: v.slot,
+                                variable_type: v.variable_type,
+                            },
+                        )
+                    })
+                    .collect()
+            }),
+        }
+    }
+}
+
+impl From<account::AccountStatus> for AccountStatus {
+    fn from(status: account::AccountStatus) -> Self {
+        match status {
+            account::AccountStatus::Offline => AccountStatus::Offline,
+            account::AccountStatus::Online => AccountStatus::Online,
+            account::AccountStatus::NotParticipating => AccountStatus::NotParticipating,
+        }
+    }
+}
+
+impl From<account::SignatureType> for AccountSignatureType {
+    fn from(signature_type: account::SignatureType) -> Self {
+        match signature_type {
+            account::SignatureType::Single => AccountSignatureType::Single,
+            account::SignatureType::Multisig => AccountSignatureType::Multisig,
+            account::SignatureType::LogicSig => AccountSignatureType::LogicSig,
+        }
+    }
+}
+
+impl From<account::AccountParticipation> for AccountParticipation {
+    fn from(participation: account::AccountParticipation) -> Self {
+        Self {
+            selection_participation_key: participation.selection_participation_key.into(),
+            state_proof_key: participation.state_proof_key.map(Into::into),
+            vote_first_valid: participation.vote_first_valid,
+            vote_key_dilution: participation.vote_key_dilution,
+            vote_last_valid: participation.vote_last_valid,
+            vote_participation_key: participation.vote_participation_key.into(),
+        }
+    }
+}
+
+impl From<account::AssetHolding> for AssetHolding {
+    fn from(holding: account::AssetHolding) -> Self {
+        Self {
+            amount: holding.amount,
+            asset_id: holding.asset_id,
+            is_frozen: holding.is_frozen,
+        }
+    }
+}
+
+impl From<account::AssetParams> for AssetParams {
+    fn from(params: account::AssetParams) -> Self {
+        Self {
+            clawback: params.clawback.map(Into::into),
+            creator: params.creator.into(),
+            decimals: params.decimals,
+            default_frozen: params.default_frozen,
+            freeze: params.freeze.map(Into::into),
+            manager: params.manager.map(Into::into),
+            metadata_hash: params.metadata_hash.map(Into::into),
+            name: params.name,
+            name_bytes: params.name_bytes.map(Into::into),
+            reserve: params.reserve.map(Into::into),
+            total: params.total,
+            unit_name: params.unit_name,
+            unit_name_bytes: params.unit_name_bytes.map(Into::into),
+            url: params.url,
+            url_bytes: params.url_bytes.map(Into::into),
+        }
+    }
+}
+
+impl From<account::ApplicationStateSchema> for ApplicationStateSchema {
+    fn from(schema: account::ApplicationStateSchema) -> Self {
+        Self {
+            num_uints: schema.num_uints,
+            num_byte_slices: schema.num_byte_slices,
+        }
+    }
+}
+
+impl From<account::TealKeyValue> for TealKeyValue {
+    fn from(key_value: account::TealKeyValue) -> Self {
+        Self {
+            key: key_value.key.into(),
+            value: TealValue {
+                value_type: match key_value.value.value_type {
+                    account::TealValueType::Bytes => TealValueType::Bytes,
+                    account::TealValueType::Uint => TealValueType::Uint,
+                },
+                bytes: key_value.value.bytes.into(),
+                uint: key_value.value.uint,
+            },
+        }
+    }
+}
+
+impl From<ApplicationStateSchema> for account::ApplicationStateSchema {
+    fn from(schema: ApplicationStateSchema) -> Self {
+        Self {
+            num_uints: schema.num_uints,
+            num_byte_slices: schema.num_byte_slices,
+        }
+    }
+}
+
+impl From<MinBalanceTotals> for min_balance::MinBalanceTotals {
+    fn from(totals: MinBalanceTotals) -> Self {
+        Self {
+            assets: totals.assets,
+            created_apps: totals.created_apps,
+            opted_in_apps: totals.opted_in_apps,
+            schema: totals.schema.into(),
+            extra_app_pages: totals.extra_app_pages,
+            boxes: totals.boxes,
+            box_bytes: totals.box_bytes,
+        }
+    }
+}
 
-    fn try_from(tx: algo_models::Transaction) -> Result<Self, Self::Error> {
-        match tx {
-            algo_models::Transaction::Payment(payment) => {
-                let header = payment.header.into();
-                let pay_fields = PayTransactionFields {
-                    receiver: payment.receiver.into(),
-                    amount: payment.amount,
-                    close_remainder_to: payment.close_remainder_to.map(|a| a.into()),
-                };
+fn teal_key_values(key_values: Option<Vec<account::TealKeyValue>>) -> Option<Vec<TealKeyValue>> {
+    key_values.map(|key_values| key_values.into_iter().map(Into::into).collect())
+}
 
-                Ok(Self {
-                    header,
-                    pay_fields: Some(pay_fields),
-                    asset_transfer_fields: None,
-                })
-            }
-            algo_models::Transaction::AssetTransfer(asset_transfer) => {
-                let header = asset_transfer.header.into();
-                let asset_fields = AssetTransferTransactionFields {
-                    asset_id: asset_transfer.asset_id,
-                    amount: asset_transfer.amount,
-                    receiver: asset_transfer.receiver.into(),
-                    asset_sender: asset_transfer.asset_sender.map(|a| a.into()),
-                    close_remainder_to: asset_transfer.close_remainder_to.map(|a| a.into()),
-                };
+impl From<account::ApplicationParams> for ApplicationParams {
+    fn from(params: account::ApplicationParams) -> Self {
+        Self {
+            approval_program: params.approval_program.into(),
+            clear_state_program: params.clear_state_program.into(),
+            creator: params.creator.into(),
+            extra_program_pages: params.extra_program_pages,
+            global_state: teal_key_values(params.global_state),
+            global_state_schema: params.global_state_schema.map(Into::into),
+            local_state_schema: params.local_state_schema.map(Into::into),
+            version: params.version,
+        }
+    }
+}
 
-                Ok(Self {
-                    header,
-                    pay_fields: None,
-                    asset_transfer_fields: Some(asset_fields),
-                })
-            }
+impl From<account::Account> for Account {
+    fn from(account: account::Account) -> Self {
+        Self {
+            address: account.address.into(),
+            amount: account.amount.into(),
+            amount_without_pending_rewards: account.amount_without_pending_rewards.into(),
+            min_balance: account.min_balance.into(),
+            pending_rewards: account.pending_rewards.into(),
+            rewards: account.rewards.into(),
+            reward_base: account.reward_base,
+            round: account.round,
+            status: account.status.into(),
+            signature_type: account.signature_type.map(Into::into),
+            auth_address: account.auth_address.map(Into::into),
+            participation: account.participation.map(Into::into),
+            incentive_eligible: account.incentive_eligible,
+            last_proposed: account.last_proposed,
+            last_heartbeat: account.last_heartbeat,
+            assets: account
+                .assets
+                .map(|assets| assets.into_iter().map(Into::into).collect()),
+            created_assets: account.created_assets.map(|assets| {
+                assets
+                    .into_iter()
+                    .map(|asset| Asset {
+                        id: asset.id,
+                        params: asset.params.into(),
+                    })
+                    .collect()
+            }),
+            apps_local_state: account.apps_local_state.map(|states| {
+                states
+                    .into_iter()
+                    .map(|state| ApplicationLocalState {
+                        id: state.id,
+                        schema: state.schema.into(),
+                        key_values: teal_key_values(state.key_values),
+                    })
+                    .collect()
+            }),
+            created_apps: account.created_apps.map(|apps| {
+                apps.into_iter()
+                    .map(|app| Application {
+                        id: app.id,
+                        params: app.params.into(),
+                    })
+                    .collect()
+            }),
+            apps_total_schema: account.apps_total_schema.map(Into::into),
+            apps_total_extra_pages: account.apps_total_extra_pages,
+            total_apps_opted_in: account.total_apps_opted_in,
+            total_assets_opted_in: account.total_assets_opted_in,
+            total_created_apps: account.total_created_apps,
+            total_created_assets: account.total_created_assets,
+            total_boxes: account.total_boxes,
+            total_box_bytes: account.total_box_bytes,
         }
     }
 }
@@ -451,6 +2362,7 @@ pub fn get_encoded_transaction_type(bytes: &[u8]) -> Result<TransactionType, Alg
     match decoded {
         algo_models::Transaction::Payment(_) => Ok(TransactionType::Payment),
         algo_models::Transaction::AssetTransfer(_) => Ok(TransactionType::AssetTransfer),
+        algo_models::Transaction::ApplicationCall(_) => Ok(TransactionType::ApplicationCall),
     }
 }
 
@@ -463,19 +2375,285 @@ pub fn encode_transaction(tx: Transaction) -> Result<Vec<u8>, AlgoModelsError> {
 #[ffi_func]
 pub fn decode_transaction(bytes: &[u8]) -> Result<Transaction, AlgoModelsError> {
     let ctx: algo_models::Transaction = algo_models::Transaction::decode(bytes)?;
-    Ok(ctx.try_into()?)
+    ctx.try_into()
 }
 
 #[ffi_func]
 pub fn attach_signature(encoded_tx: &[u8], signature: &[u8]) -> Result<Vec<u8>, AlgoModelsError> {
     let encoded_tx = algo_models::Transaction::decode(encoded_tx)?;
     let signed_tx = algo_models::SignedTransaction {
-        transaction: encoded_tx,
         signature: signature.try_into().expect("signature should be 64 bytes"),
+        ..algo_models::SignedTransaction::with_empty_signature(encoded_tx)
     };
     Ok(signed_tx.encode()?)
 }
 
+/// Read the transactions of a `goal` `.txn`, `.stxn` or `.gtxn` file
+#[ffi_func]
+pub fn read_transaction_file(bytes: &[u8]) -> Result<Vec<SignedTransaction>, AlgoModelsError> {
+    transaction_file::read_transaction_file(bytes)?
+        .into_iter()
+        .map(SignedTransaction::try_from)
+        .collect()
+}
+
+/// Write transactions as a `goal` `.stxn` or `.gtxn` file. Unsigned transactions are wrapped in
+/// `{"txn": ...}` as `goal` does
+#[ffi_func]
+pub fn write_transaction_file(
+    transactions: Vec<SignedTransaction>,
+) -> Result<Vec<u8>, AlgoModelsError> {
+    let transactions = transactions
+        .into_iter()
+        .map(algo_models::SignedTransaction::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut file = Vec::new();
+    transaction_file::write_transaction_file(&mut file, &transactions)?;
+    Ok(file)
+}
+
+/// Write unsigned transactions as a `goal` `.txn` or `.gtxn` file
+#[ffi_func]
+pub fn write_unsigned_transaction_file(
+    transactions: Vec<Transaction>,
+) -> Result<Vec<u8>, AlgoModelsError> {
+    let transactions = transactions
+        .into_iter()
+        .map(algo_models::Transaction::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut file = Vec::new();
+    transaction_file::write_unsigned_transaction_file(&mut file, &transactions)?;
+    Ok(file)
+}
+
+/// Describe the transactions of a `goal` transaction file like `goal clerk inspect`
+#[ffi_func]
+pub fn inspect_transaction_file(file_name: &str, bytes: &[u8]) -> Result<String, AlgoModelsError> {
+    let transactions = transaction_file::read_transaction_file(bytes)?;
+    Ok(transaction_file::inspect_transaction_file(
+        file_name,
+        &transactions,
+    )?)
+}
+
+/// Get the size in bytes of a transaction once it is signed
+#[ffi_func]
+pub fn estimate_signed_transaction_size(
+    tx: Transaction,
+    signature: SignatureKind,
+) -> Result<u64, AlgoModelsError> {
+    let ctx: algo_models::Transaction = tx.try_into()?;
+    Ok(ctx.estimate_signed_size(&signature.into())? as u64)
+}
+
+/// Get the fee a transaction needs, which is the larger of the minimum fee and the fee per byte
+/// times the signed size, plus the minimum fee of each covered transaction
+#[ffi_func]
+pub fn estimate_transaction_fee(
+    tx: Transaction,
+    params: SuggestedParams,
+    options: FeeOptions,
+) -> Result<MicroAlgos, AlgoModelsError> {
+    let ctx: algo_models::Transaction = tx.try_into()?;
+    Ok(ctx
+        .estimate_fee(&params.try_into()?, &options.into())?
+        .into())
+}
+
+/// Get the consensus limits of the current protocol
+#[ffi_func]
+pub fn current_consensus_params() -> ConsensusParams {
+    consensus::ConsensusParams::default().into()
+}
+
+/// Get the consensus limits of a version, given its URL or its short name such as `v40`.
+/// Returns nothing for unknown versions
+#[ffi_func]
+pub fn consensus_params(version: &str) -> Option<ConsensusParams> {
+    consensus::ConsensusParams::for_version(version).map(Into::into)
+}
+
+/// Get the URLs of every known consensus version, oldest first
+#[ffi_func]
+pub fn consensus_versions() -> Vec<String> {
+    consensus::ConsensusParams::versions()
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
+/// Check a transaction against the protocol rules in `params`.
+/// Returns a description of each violation, which is empty for a valid transaction
+#[ffi_func]
+pub fn validate_transaction(
+    tx: Transaction,
+    params: ConsensusParams,
+) -> Result<Vec<String>, AlgoModelsError> {
+    let ctx: algo_models::Transaction = tx.try_into()?;
+    Ok(match ctx.validate(&params.into()) {
+        Ok(()) => vec![],
+        Err(errors) => errors.0.iter().map(ToString::to_string).collect(),
+    })
+}
+
+/// Apply the header fields in `TransactionOptions` to one of the builders
+macro_rules! with_options {
+    ($builder:expr, $options:expr) => {{
+        let options: TransactionOptions = $options;
+        let mut builder = $builder.sender(options.sender.try_into()?);
+
+        if let Some(note) = options.note {
+            builder = builder.note(note.to_vec());
+        }
+        if let Some(lease) = options.lease {
+            builder = builder.lease(lease.to_vec().try_into().map_err(|_| {
+                AlgoModelsError::EncodingError("lease should be 32 bytes".to_string())
+            })?);
+        }
+        if let Some(rekey_to) = options.rekey_to {
+            builder = builder.rekey_to(rekey_to.try_into()?);
+        }
+        if let Some(first_valid) = options.first_valid {
+            builder = builder.first_valid(first_valid);
+        }
+        if let Some(last_valid) = options.last_valid {
+            builder = builder.last_valid(last_valid);
+        }
+        if let Some(fee_options) = options.fee_options {
+            builder = builder.fee_options(fee_options.into());
+        }
+
+        builder
+    }};
+}
+
+/// Build a payment, filling in the header and fee from the suggested params
+#[ffi_func]
+pub fn build_payment(
+    params: SuggestedParams,
+    options: TransactionOptions,
+    fields: PayTransactionFields,
+) -> Result<Transaction, AlgoModelsError> {
+    let fields: algo_models::PayTransactionFields = fields.try_into()?;
+    let mut builder = with_options!(builder::PaymentBuilder::new(&params.try_into()?), options)
+        .receiver(fields.receiver)
+        .amount(fields.amount);
+
+    if let Some(close_remainder_to) = fields.close_remainder_to {
+        builder = builder.close_remainder_to(close_remainder_to);
+    }
+
+    builder.build()?.try_into()
+}
+
+/// Build an asset transfer, filling in the header and fee from the suggested params
+#[ffi_func]
+pub fn build_asset_transfer(
+    params: SuggestedParams,
+    options: TransactionOptions,
+    fields: AssetTransferTransactionFields,
+) -> Result<Transaction, AlgoModelsError> {
+    let fields: algo_models::AssetTransferTransactionFields = fields.try_into()?;
+    let mut builder = with_options!(
+        builder::AssetTransferBuilder::new(&params.try_into()?),
+        options
+    )
+    .asset_id(fields.asset_id)
+    .receiver(fields.receiver)
+    .amount(fields.amount);
+
+    if let Some(asset_sender) = fields.asset_sender {
+        builder = builder.asset_sender(asset_sender);
+    }
+    if let Some(close_remainder_to) = fields.close_remainder_to {
+        builder = builder.close_remainder_to(close_remainder_to);
+    }
+
+    builder.build()?.try_into()
+}
+
+/// Build an application call, filling in the header and fee from the suggested params.
+/// When creating an application the extra program pages are at least what the programs need
+#[ffi_func]
+pub fn build_application_call(
+    params: SuggestedParams,
+    options: TransactionOptions,
+    fields: ApplicationCallTransactionFields,
+) -> Result<Transaction, AlgoModelsError> {
+    let fields: algo_models::ApplicationCallTransactionFields = fields.try_into()?;
+    let mut builder = with_options!(
+        builder::ApplicationCallBuilder::new(&params.try_into()?),
+        options
+    )
+    .app_id(fields.app_id)
+    .on_complete(fields.on_complete)
+    .extra_program_pages(u32::try_from(fields.extra_program_pages).unwrap_or(u32::MAX));
+
+    match (fields.approval_program, fields.clear_state_program) {
+        (Some(approval), Some(clear_state)) => {
+            builder = builder.programs(parse_program(&approval)?, parse_program(&clear_state)?);
+        }
+        (None, None) => {}
+        _ => {
+            return Err(AlgoModelsError::BuilderError(
+                "approval_program and clear_state_program must be set together".to_string(),
+            ))
+        }
+    }
+    if let Some(schema) = fields.global_state_schema {
+        builder = builder.global_state_schema(schema);
+    }
+    if let Some(schema) = fields.local_state_schema {
+        builder = builder.local_state_schema(schema);
+    }
+    if let Some(args) = fields.args {
+        builder = builder.args(args);
+    }
+    if let Some(accounts) = fields.accounts {
+        builder = builder.accounts(accounts);
+    }
+    if let Some(foreign_apps) = fields.foreign_apps {
+        builder = builder.foreign_apps(foreign_apps);
+    }
+    if let Some(foreign_assets) = fields.foreign_assets {
+        builder = builder.foreign_assets(foreign_assets);
+    }
+    if let Some(boxes) = fields.boxes {
+        builder = builder.boxes(boxes);
+    }
+
+    builder.build()?.try_into()
+}
+
+/// Parse a decimal amount of Algos, such as "1.5", into microAlgos
+#[ffi_func]
+pub fn micro_algos_from_algos(algos: &str) -> Result<MicroAlgos, AlgoModelsError> {
+    algo_models::MicroAlgos::from_algos(algos)
+        .map(Into::into)
+        .map_err(|e| algo_models::AlgoModelsError::from(e).into())
+}
+
+/// Format microAlgos as a decimal amount of Algos without trailing zeros, such as "1.5"
+#[ffi_func]
+pub fn micro_algos_to_algos(amount: MicroAlgos) -> String {
+    algo_models::MicroAlgos::from(amount).to_algos()
+}
+
+/// Format an asset amount in base units using the asset's decimals, so 1050 with 2 decimals
+/// is "10.5"
+#[ffi_func]
+pub fn format_asset_amount(amount: u64, decimals: u8) -> String {
+    algo_models::amount::format_units(amount, decimals)
+}
+
+/// Parse a decimal asset amount into base units using the asset's decimals, so "10.5" with 2
+/// decimals is 1050
+#[ffi_func]
+pub fn parse_asset_amount(amount: &str, decimals: u8) -> Result<u64, AlgoModelsError> {
+    algo_models::amount::parse_units(amount, decimals)
+        .map_err(|e| algo_models::AlgoModelsError::from(e).into())
+}
+
 #[ffi_func]
 pub fn address_from_pub_key(pub_key: &[u8]) -> Result<Address, AlgoModelsError> {
     Ok(
@@ -493,6 +2671,349 @@ pub fn address_from_string(address: &str) -> Result<Address, AlgoModelsError> {
         .map_err(|e| AlgoModelsError::EncodingError(e.to_string()))
 }
 
+/// Get the account address of an application
+#[ffi_func]
+pub fn address_from_app_id(app_id: u64) -> Address {
+    algo_models::Address::from_app_id(app_id).into()
+}
+
+/// Derive an address from the SHA-512/256 hash of the prefix followed by the given bytes
+#[ffi_func]
+pub fn address_from_domain_hash(prefix: &[u8], bytes: &[u8]) -> Address {
+    algo_models::Address::from_domain_hash(prefix, bytes).into()
+}
+
+/// Check if the given string is a valid Algorand address.
+/// Unlike `address_from_string`, this never throws
+#[ffi_func]
+pub fn is_valid_address(address: &str) -> bool {
+    algo_models::Address::from_string(address).is_ok()
+}
+
+/// The zero address, which corresponds to a public key of 32 zero bytes
+#[ffi_func]
+pub fn zero_address() -> Address {
+    algo_models::Address::default().into()
+}
+
+/// Get the 4-byte checksum that is appended to the public key in the address string
+#[ffi_func]
+pub fn address_checksum(address: Address) -> Result<Vec<u8>, AlgoModelsError> {
+    let address: algo_models::Address = address.try_into()?;
+    Ok(address.checksum().to_vec())
+}
+
+/// Check if two addresses refer to the same public key
+#[ffi_func]
+pub fn address_equals(a: Address, b: Address) -> Result<bool, AlgoModelsError> {
+    let a: algo_models::Address = a.try_into()?;
+    let b: algo_models::Address = b.try_into()?;
+    Ok(a == b)
+}
+
+/// Encode a 32-byte ed25519 seed as a 25-word Algorand mnemonic
+#[ffi_func]
+pub fn seed_to_mnemonic(seed: &[u8]) -> Result<String, AlgoModelsError> {
+    let seed: &[u8; 32] = seed.try_into().map_err(|_| {
+        AlgoModelsError::MnemonicError(format!("seed must be 32 bytes, but got {}", seed.len()))
+    })?;
+    Ok(algo_models::mnemonic::seed_to_mnemonic(seed))
+}
+
+/// Decode a 25-word Algorand mnemonic into the 32-byte ed25519 seed it encodes
+#[ffi_func]
+pub fn mnemonic_to_seed(mnemonic: &str) -> Result<Vec<u8>, AlgoModelsError> {
+    algo_models::mnemonic::mnemonic_to_seed(mnemonic)
+        .map(|seed| seed.to_vec())
+        .map_err(|e| algo_models::AlgoModelsError::from(e).into())
+}
+
+fn parse_abi_type(abi_type: &str) -> Result<algo_models::abi::AbiType, AlgoModelsError> {
+    abi_type
+        .parse()
+        .map_err(|e: algo_models::abi::AbiError| algo_models::AlgoModelsError::from(e).into())
+}
+
+/// Encode a value as the given ARC-4 ABI type, such as `uint64` or `(address,string[])`
+#[ffi_func]
+pub fn abi_encode(abi_type: &str, value: AbiValue) -> Result<Vec<u8>, AlgoModelsError> {
+    let abi_type = parse_abi_type(abi_type)?;
+    let value: algo_models::abi::AbiValue = value.try_into()?;
+    abi_type
+        .encode(&value)
+        .map_err(|e| algo_models::AlgoModelsError::from(e).into())
+}
+
+/// Decode ARC-4 ABI encoded bytes as the given type
+#[ffi_func]
+pub fn abi_decode(abi_type: &str, bytes: &[u8]) -> Result<AbiValue, AlgoModelsError> {
+    let abi_type = parse_abi_type(abi_type)?;
+    abi_type
+        .decode(bytes)
+        .map(|v| v.into())
+        .map_err(|e| algo_models::AlgoModelsError::from(e).into())
+}
+
+/// Check if the given ARC-4 ABI type is dynamic, meaning its encoded length depends on the value
+#[ffi_func]
+pub fn abi_type_is_dynamic(abi_type: &str) -> Result<bool, AlgoModelsError> {
+    Ok(parse_abi_type(abi_type)?.is_dynamic())
+}
+
+fn parse_abi_method(signature: &str) -> Result<algo_models::abi::Method, AlgoModelsError> {
+    signature
+        .parse()
+        .map_err(|e: algo_models::abi::AbiError| algo_models::AlgoModelsError::from(e).into())
+}
+
+/// Parse an ARC-4 method from its signature, such as `add(uint64,uint64)uint128`
+#[ffi_func]
+pub fn abi_method_from_signature(signature: &str) -> Result<AbiMethod, AlgoModelsError> {
+    Ok(parse_abi_method(signature)?.into())
+}
+
+/// Get the 4-byte selector of an ARC-4 method, which is the first application argument of a call
+#[ffi_func]
+pub fn abi_method_selector(signature: &str) -> Result<Vec<u8>, AlgoModelsError> {
+    Ok(parse_abi_method(signature)?.selector().to_vec())
+}
+
+/// Set the application arguments of an application call to a call of the given ARC-4 method.
+/// Reference arguments are added to the foreign arrays of the call
+#[ffi_func]
+pub fn abi_method_call(
+    transaction: Transaction,
+    signature: &str,
+    args: Vec<AbiMethodArgValue>,
+) -> Result<Transaction, AlgoModelsError> {
+    let method = parse_abi_method(signature)?;
+    let args = args
+        .into_iter()
+        .map(|a| a.try_into())
+        .collect::<Result<Vec<algo_models::abi::MethodArgValue>, _>>()?;
+
+    let algo_models::Transaction::ApplicationCall(mut call) = transaction.try_into()? else {
+        return Err(AlgoModelsError::AbiError(
+            "method calls must be application call transactions".to_string(),
+        ));
+    };
+
+    method
+        .apply_args(&mut call, &args)
+        .map_err(|e| AlgoModelsError::from(algo_models::AlgoModelsError::from(e)))?;
+    algo_models::Transaction::ApplicationCall(call).try_into()
+}
+
+/// Decode the return value of an ARC-4 method from the last log of the application call.
+/// Returns `None` for `void` methods
+#[ffi_func]
+pub fn abi_method_decode_return(
+    signature: &str,
+    last_log: &[u8],
+) -> Result<Option<AbiValue>, AlgoModelsError> {
+    parse_abi_method(signature)?
+        .decode_return(&[last_log.to_vec()])
+        .map(|v| v.map(|v| v.into()))
+        .map_err(|e| algo_models::AlgoModelsError::from(e).into())
+}
+
+fn parse_app_spec(json: &str) -> Result<arc56::Arc56Contract, AlgoModelsError> {
+    arc56::Arc56Contract::from_json(json).map_err(|e| algo_models::AlgoModelsError::from(e).into())
+}
+
+/// Parse an ARC-56 app spec, or an ARC-32 `application.json` which is converted to ARC-56
+#[ffi_func]
+pub fn app_spec_from_json(json: &str) -> Result<Arc56Contract, AlgoModelsError> {
+    Ok(parse_app_spec(json)?.into())
+}
+
+/// Convert an ARC-32 or ARC-56 app spec to ARC-56 JSON
+#[ffi_func]
+pub fn app_spec_to_arc56_json(json: &str) -> Result<String, AlgoModelsError> {
+    parse_app_spec(json)?
+        .to_json()
+        .map_err(|e| algo_models::AlgoModelsError::from(e).into())
+}
+
+/// Check an app spec for inconsistencies, such as unknown types or structs.
+/// Returns a description of each problem, which is empty for a valid spec
+#[ffi_func]
+pub fn app_spec_validate(json: &str) -> Result<Vec<String>, AlgoModelsError> {
+    Ok(parse_app_spec(json)?.validate())
+}
+
+/// Decode an account from the body of algod's `/v2/accounts/{address}?format=msgpack`
+#[ffi_func]
+pub fn decode_account(bytes: &[u8]) -> Result<Account, AlgoModelsError> {
+    Ok(account::Account::decode(bytes)?.into())
+}
+
+/// Parse an account from the JSON body of algod's `/v2/accounts/{address}`
+#[ffi_func]
+pub fn account_from_json(json: &str) -> Result<Account, AlgoModelsError> {
+    account::Account::from_json(json)
+        .map(Into::into)
+        .map_err(|e| algo_models::AlgoModelsError::from(e).into())
+}
+
+/// Get the minimum balance of an account with the given totals under the consensus `params`
+#[ffi_func]
+pub fn min_balance(totals: MinBalanceTotals, params: ConsensusParams) -> MicroAlgos {
+    min_balance::MinBalanceTotals::from(totals)
+        .min_balance(&params.into())
+        .into()
+}
+
+/// Get the totals of an account that determine its minimum balance, to adjust them for the
+/// assets, applications and boxes it will add
+#[ffi_func]
+pub fn account_min_balance_totals(account: Account) -> MinBalanceTotals {
+    MinBalanceTotals {
+        assets: account.total_assets_opted_in,
+        created_apps: account.total_created_apps,
+        opted_in_apps: account.total_apps_opted_in,
+        schema: account.apps_total_schema.unwrap_or(ApplicationStateSchema {
+            num_uints: 0,
+            num_byte_slices: 0,
+        }),
+        extra_app_pages: account.apps_total_extra_pages.unwrap_or_default(),
+        boxes: account.total_boxes.unwrap_or_default(),
+        box_bytes: account.total_box_bytes.unwrap_or_default(),
+    }
+}
+
+fn parse_program(program: &[u8]) -> Result<algo_models::program::Program, AlgoModelsError> {
+    algo_models::program::Program::try_from(program)
+        .map_err(|e| algo_models::AlgoModelsError::from(e).into())
+}
+
+/// Get the version of a compiled TEAL program, checking that its bytecode is well formed
+#[ffi_func]
+pub fn program_version(program: &[u8]) -> Result<u64, AlgoModelsError> {
+    Ok(parse_program(program)?.version())
+}
+
+/// Get the SHA-512/256 hash of "Program" followed by the program bytes
+#[ffi_func]
+pub fn program_hash(program: &[u8]) -> Result<Vec<u8>, AlgoModelsError> {
+    Ok(parse_program(program)?.hash().to_vec())
+}
+
+/// Get the address of the contract account controlled by a logic signature program
+#[ffi_func]
+pub fn address_from_program(program: &[u8]) -> Result<Address, AlgoModelsError> {
+    Ok(parse_program(program)?.address().into())
+}
+
+/// Disassemble a compiled TEAL program into TEAL source, in the same form as
+/// `goal clerk compile -D`
+#[ffi_func]
+pub fn program_disassemble(program: &[u8]) -> Result<String, AlgoModelsError> {
+    Ok(parse_program(program)?.disassemble())
+}
+
+/// Check that a program and logic signature arguments of `args_size` bytes fit in the
+/// 1000-byte logic signature limit
+#[ffi_func]
+pub fn program_check_logic_sig_size(program: &[u8], args_size: u64) -> Result<(), AlgoModelsError> {
+    parse_program(program)?
+        .check_logic_sig_size(args_size as usize)
+        .map_err(|e| algo_models::AlgoModelsError::from(e).into())
+}
+
+/// Get the number of extra program pages an application needs for its approval and clear
+/// state programs
+#[ffi_func]
+pub fn program_extra_pages(approval: &[u8], clear_state: &[u8]) -> Result<u32, AlgoModelsError> {
+    algo_models::program::Program::extra_pages(
+        &parse_program(approval)?,
+        &parse_program(clear_state)?,
+    )
+    .map_err(|e| algo_models::AlgoModelsError::from(e).into())
+}
+
+/// Assemble TEAL source into program bytecode, producing the same bytes as algod's compile
+/// endpoint
+#[ffi_func]
+pub fn program_assemble(source: &str) -> Result<Vec<u8>, AlgoModelsError> {
+    algo_models::program::Program::assemble(source)
+        .map(|program| program.into_bytes())
+        .map_err(|e| algo_models::AlgoModelsError::from(e).into())
+}
+
+fn hd_error(e: algo_models::hd::HdError) -> AlgoModelsError {
+    algo_models::AlgoModelsError::from(e).into()
+}
+
+/// Create the 96-byte ARC-52 root key (kL, kR and chain code) from a BIP-39 seed
+#[ffi_func]
+pub fn hd_root_key_from_seed(seed: &[u8]) -> Vec<u8> {
+    algo_models::hd::ExtendedPrivateKey::from_seed(seed)
+        .to_bytes()
+        .to_vec()
+}
+
+/// The BIP-44 path `m/44'/coin_type'/account'/change/key_index` for the given ARC-52 context.
+/// Hardened indexes have the 0x80000000 offset applied
+#[ffi_func]
+pub fn hd_bip44_path(context: KeyContext, account: u32, change: u32, key_index: u32) -> Vec<u32> {
+    algo_models::hd::bip44_path(context.into(), account, change, key_index).to_vec()
+}
+
+/// Derive the 96-byte extended private key at the given path from an extended private key
+#[ffi_func]
+pub fn hd_derive_private_key(
+    extended_private_key: &[u8],
+    path: Vec<u32>,
+    derivation_type: DerivationType,
+) -> Result<Vec<u8>, AlgoModelsError> {
+    let key =
+        algo_models::hd::ExtendedPrivateKey::from_bytes(extended_private_key).map_err(hd_error)?;
+
+    Ok(key
+        .derive_path(&path, derivation_type.into())
+        .map_err(hd_error)?
+        .to_bytes()
+        .to_vec())
+}
+
+/// Get the 64-byte extended public key (public key and chain code) of an extended private key.
+/// The first 32 bytes are the ed25519 public key
+#[ffi_func]
+pub fn hd_extended_public_key(extended_private_key: &[u8]) -> Result<Vec<u8>, AlgoModelsError> {
+    let key =
+        algo_models::hd::ExtendedPrivateKey::from_bytes(extended_private_key).map_err(hd_error)?;
+
+    Ok(key.extended_public_key().to_bytes().to_vec())
+}
+
+/// Derive the 64-byte extended public key at the given non-hardened path from an extended
+/// public key, which allows watch-only wallets to generate addresses
+#[ffi_func]
+pub fn hd_derive_public_key(
+    extended_public_key: &[u8],
+    path: Vec<u32>,
+    derivation_type: DerivationType,
+) -> Result<Vec<u8>, AlgoModelsError> {
+    let key =
+        algo_models::hd::ExtendedPublicKey::from_bytes(extended_public_key).map_err(hd_error)?;
+
+    Ok(key
+        .derive_path(&path, derivation_type.into())
+        .map_err(hd_error)?
+        .to_bytes()
+        .to_vec())
+}
+
+/// Sign the data with a derived extended private key, producing a 64-byte ed25519 signature
+#[ffi_func]
+pub fn hd_sign(extended_private_key: &[u8], data: &[u8]) -> Result<Vec<u8>, AlgoModelsError> {
+    let key =
+        algo_models::hd::ExtendedPrivateKey::from_bytes(extended_private_key).map_err(hd_error)?;
+
+    Ok(key.sign(data).to_vec())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -505,7 +3026,7 @@ mod tests {
         let header = TransactionHeader {
             transaction_type: TransactionType::Payment,
             sender: address_from_string(&addr.address()).unwrap(),
-            fee: 1000,
+            fee: MicroAlgos(1000),
             first_valid: 1000,
             last_valid: 2000,
             genesis_hash: None,
@@ -518,7 +3039,7 @@ mod tests {
 
         let pay_fields = PayTransactionFields {
             receiver: address_from_pub_key(&addr.pub_key).unwrap(),
-            amount: 1000000,
+            amount: MicroAlgos(1000000),
             close_remainder_to: None,
         };
 
@@ -526,6 +3047,7 @@ mod tests {
             header,
             pay_fields: Some(pay_fields),
             asset_transfer_fields: None,
+            application_call_fields: None,
         };
 
         // Encode the transaction
@@ -535,4 +3057,13 @@ mod tests {
         let tx_type = get_encoded_transaction_type(&encoded).unwrap();
         assert_eq!(tx_type, TransactionType::Payment);
     }
+
+    #[test]
+    fn test_inconsistent_address_is_rejected() {
+        let mut addr = zero_address();
+        addr.pub_key = ByteBuf::from(vec![1; 32]);
+
+        let result: Result<algo_models::Address, _> = addr.try_into();
+        assert!(result.is_err());
+    }
 }
@@ -0,0 +1,232 @@
+import pytest
+from algo_models import (
+    AbiMethodArgValue,
+    AbiValue,
+    AlgoModelsError,
+    ApplicationCallTransactionFields,
+    OnApplicationComplete,
+    Transaction,
+    TransactionHeader,
+    TransactionType,
+    abi_decode,
+    abi_encode,
+    abi_method_call,
+    abi_method_decode_return,
+    abi_method_from_signature,
+    abi_method_selector,
+    abi_type_is_dynamic,
+    address_from_pub_key,
+)
+
+
+def uint(n):
+    return AbiValue.UINT(value=str(n))
+
+
+def string(s):
+    return AbiValue.STRING(value=s)
+
+
+def boolean(b):
+    return AbiValue.BOOL(value=b)
+
+
+def array(*values):
+    return AbiValue.ARRAY(values=list(values))
+
+
+# (string,bool,bool,bool,bool,string) from the reference SDK test suites
+BOOL_TUPLE = array(
+    string("AB"), boolean(True), boolean(False), boolean(True), boolean(False), string("DE")
+)
+BOOL_TUPLE_BYTES = bytes([0x00, 0x05, 0xA0, 0x00, 0x09, 0x00, 0x02]) + b"AB" + bytes([0x00, 0x02]) + b"DE"
+
+# Polytest Suite: ABI
+
+# Polytest Group: ABI Tests
+
+
+@pytest.mark.group_abi_tests
+def test_invalid_value():
+    """A value that does not match its type is rejected"""
+    with pytest.raises(AlgoModelsError.AbiError, match="does not fit in 8 bits"):
+        abi_encode("uint8", uint(256))
+
+    with pytest.raises(AlgoModelsError.AbiError, match="expected 2 fields"):
+        abi_encode("(uint64,bool)", array(uint(1)))
+
+    with pytest.raises(AlgoModelsError.AbiError, match="not an unsigned integer"):
+        abi_encode("uint64", uint(-1))
+
+
+@pytest.mark.group_abi_tests
+def test_invalid_type():
+    """An invalid type string is rejected"""
+    for invalid in ["uint7", "ufixed64x0", "(uint64", "int64", "byte[01]"]:
+        with pytest.raises(AlgoModelsError.AbiError, match="invalid ABI type"):
+            abi_type_is_dynamic(invalid)
+
+
+@pytest.mark.group_abi_tests
+def test_is_dynamic():
+    """Dynamic types are identified"""
+    assert abi_type_is_dynamic("string")
+    assert abi_type_is_dynamic("(uint64,string[])")
+    assert abi_type_is_dynamic("bool[]")
+    assert not abi_type_is_dynamic("(uint64,byte[32])")
+    assert not abi_type_is_dynamic("address")
+
+
+@pytest.mark.group_abi_tests
+def test_decode_tuple():
+    """An encoded tuple is decoded into its fields"""
+    assert abi_decode("(string,bool,bool,bool,bool,string)", BOOL_TUPLE_BYTES) == BOOL_TUPLE
+
+
+@pytest.mark.group_abi_tests
+def test_encode_dynamic_tuple():
+    """Dynamic tuple fields are referenced by offsets in the head"""
+    assert abi_encode("(string,bool,bool,bool,bool,string)", BOOL_TUPLE) == BOOL_TUPLE_BYTES
+    assert abi_encode("(uint64,string[])", array(uint(1), array(string("a"), string("bc")))) == bytes(
+        [0, 0, 0, 0, 0, 0, 0, 1, 0x00, 0x0A, 0x00, 0x02, 0x00, 0x04, 0x00, 0x07]
+    ) + bytes([0x00, 0x01]) + b"a" + bytes([0x00, 0x02]) + b"bc"
+
+
+@pytest.mark.group_abi_tests
+def test_encode_bool_packing():
+    """Consecutive bools are packed into single bytes"""
+    assert abi_encode("bool", boolean(True)) == bytes([0x80])
+    assert abi_encode("bool[]", array(boolean(True), boolean(False), boolean(True))) == bytes(
+        [0x00, 0x03, 0xA0]
+    )
+    nine = array(*[boolean(i % 2 == 0) for i in range(9)])
+    assert abi_encode("bool[9]", nine) == bytes([0xAA, 0x80])
+
+
+@pytest.mark.group_abi_tests
+def test_encode_uint():
+    """Unsigned integers are encoded as fixed-size big-endian bytes"""
+    assert abi_encode("uint64", uint(1)) == (1).to_bytes(8, "big")
+    assert abi_encode("ufixed128x10", uint(15_000_000_000)) == (15_000_000_000).to_bytes(16, "big")
+    assert abi_encode("uint512", uint(2**512 - 1)) == bytes([0xFF] * 64)
+    assert abi_decode("uint512", bytes([0xFF] * 64)) == uint(2**512 - 1)
+
+
+RETURN_PREFIX = bytes.fromhex("151f7c75")
+SENDER = address_from_pub_key(bytes([1] * 32))
+OTHER = address_from_pub_key(bytes([2] * 32))
+
+
+def app_call(app_id):
+    return Transaction(
+        header=TransactionHeader(
+            transaction_type=TransactionType.APPLICATION_CALL,
+            sender=SENDER,
+            fee=1000,
+            first_valid=1,
+            last_valid=1000,
+            genesis_hash=None,
+            genesis_id=None,
+        ),
+        application_call_fields=ApplicationCallTransactionFields(
+            app_id=app_id,
+            on_complete=OnApplicationComplete.NO_OP,
+            extra_program_pages=0,
+        ),
+    )
+
+
+def abi_arg(value):
+    return AbiMethodArgValue.ABI_VALUE(value=value)
+
+
+# Polytest Group: ABI Method Tests
+
+
+@pytest.mark.group_abi_method_tests
+def test_missing_return_value():
+    """A log without the return prefix is rejected"""
+    with pytest.raises(AlgoModelsError.AbiError, match="no return value found"):
+        abi_method_decode_return("add(uint64,uint64)uint128", b"debug")
+
+
+@pytest.mark.group_abi_method_tests
+def test_decode_return_value():
+    """The return value is decoded from the last log of the call"""
+    log = RETURN_PREFIX + (3).to_bytes(16, "big")
+    assert abi_method_decode_return("add(uint64,uint64)uint128", log) == uint(3)
+    assert abi_method_decode_return("noop()void", b"") is None
+
+
+@pytest.mark.group_abi_method_tests
+def test_more_than_15_arguments():
+    """Arguments after the 14th are packed into a tuple in the last application argument"""
+    signature = f"many({','.join(['uint64'] * 17)})void"
+    txn = abi_method_call(app_call(1), signature, [abi_arg(uint(i)) for i in range(17)])
+    args = txn.application_call_fields.args
+
+    assert len(args) == 16
+    assert args[14] == (13).to_bytes(8, "big")
+    assert args[15] == b"".join(i.to_bytes(8, "big") for i in (14, 15, 16))
+
+
+@pytest.mark.group_abi_method_tests
+def test_reference_arguments():
+    """Reference arguments are added to the foreign arrays and encoded as their index"""
+    signature = "refs(account,account,asset,application,application,pay)void"
+    txn = abi_method_call(
+        app_call(1234),
+        signature,
+        [
+            AbiMethodArgValue.ACCOUNT(value=SENDER),
+            AbiMethodArgValue.ACCOUNT(value=OTHER),
+            AbiMethodArgValue.ASSET(value=10),
+            AbiMethodArgValue.APPLICATION(value=1234),
+            AbiMethodArgValue.APPLICATION(value=99),
+            AbiMethodArgValue.TRANSACTION(),
+        ],
+    )
+    fields = txn.application_call_fields
+
+    assert [a.address for a in fields.accounts] == [OTHER.address]
+    assert fields.foreign_assets == [10]
+    assert fields.foreign_apps == [99]
+    assert fields.args[1:] == [bytes([0]), bytes([1]), bytes([0]), bytes([0]), bytes([1])]
+
+
+@pytest.mark.group_abi_method_tests
+def test_method_call():
+    """Method arguments are encoded into the application arguments of an application call"""
+    txn = abi_method_call(
+        app_call(1234), "add(uint64,uint64)uint128", [abi_arg(uint(1)), abi_arg(uint(2))]
+    )
+
+    assert txn.application_call_fields.args == [
+        bytes.fromhex("8aa3b61f"),
+        (1).to_bytes(8, "big"),
+        (2).to_bytes(8, "big"),
+    ]
+    assert txn.header == app_call(1234).header
+
+    with pytest.raises(AlgoModelsError.AbiError, match="expected 2 arguments, but got 1"):
+        abi_method_call(app_call(1234), "add(uint64,uint64)uint128", [abi_arg(uint(1))])
+
+
+@pytest.mark.group_abi_method_tests
+def test_parse_signature():
+    """A method signature is parsed into its name, argument types and return type"""
+    method = abi_method_from_signature("foo(string,(uint64,bool[]),pay,account)void")
+
+    assert method.name == "foo"
+    assert [a.arg_type for a in method.args] == ["string", "(uint64,bool[])", "pay", "account"]
+    assert method.returns is None
+    assert abi_method_from_signature("add(uint64,uint64)uint128").returns == "uint128"
+
+    with pytest.raises(AlgoModelsError.AbiError, match="invalid ARC-4 method signature"):
+        abi_method_from_signature("foo(uint64")
+
+
+@pytest.mark.group_abi_method_tests
+def test_method_selector():
+    """The selector is the first 4 bytes of the hash of the method signature"""
+    assert abi_method_selector("add(uint64,uint64)uint128") == bytes.fromhex("8aa3b61f")
@@ -0,0 +1,62 @@
+from pathlib import Path
+
+import pytest
+from algo_models import (
+    AccountSignatureType,
+    AccountStatus,
+    AlgoModelsError,
+    AssetHolding,
+    TealValueType,
+    account_from_json,
+    decode_account,
+)
+
+TEST_DATA_DIR = (
+    Path(__file__).parent.parent.parent.parent.parent / "crates" / "algo_models" / "test_data"
+)
+ACCOUNT_JSON = (TEST_DATA_DIR / "account.json").read_text()
+ACCOUNT_MSGPACK = (TEST_DATA_DIR / "account.msgpack").read_bytes()
+
+# Polytest Suite: Account
+
+# Polytest Group: Account Tests
+
+
+@pytest.mark.group_account_tests
+def test_invalid_json():
+    """JSON that is not an account is rejected with a decoding error"""
+    with pytest.raises(AlgoModelsError.DecodingError, match="invalid account JSON"):
+        account_from_json('{"address": "not an address"}')
+
+
+@pytest.mark.group_account_tests
+def test_decode_json():
+    """JSON and msgpack account responses decode to the same account"""
+    assert account_from_json(ACCOUNT_JSON) == decode_account(ACCOUNT_MSGPACK)
+
+
+@pytest.mark.group_account_tests
+def test_decode_msgpack():
+    """An algod msgpack account response decodes to balances, assets and apps"""
+    account = decode_account(ACCOUNT_MSGPACK)
+
+    assert account.address.address == "S42GIXSHUKLVVFE537KRRLGFRWZSIRFD6LPCMG4QNNW5ZMHOAFMREKN6LQ"
+    assert (account.amount, account.min_balance) == (12_345_678_901, 1_528_500)
+    assert account.status == AccountStatus.ONLINE
+    assert account.signature_type == AccountSignatureType.SINGLE
+    assert (
+        account.auth_address.address
+        == "XLHDWUMLF27E3SDXO7ZOXF3HORHONT72NWCXCCGPYSXFZM2QF4JOBRWH7U"
+    )
+
+    assert account.assets[1] == AssetHolding(amount=0, asset_id=3_000_000_001, is_frozen=True)
+    assert account.created_assets[0].params.unit_name == "EXT"
+
+    key_value = account.apps_local_state[0].key_values[1]
+    assert key_value.key == bytes([0, 0, 0, 0, 0, 0, 0, 7])
+    assert key_value.value.value_type == TealValueType.BYTES
+    assert key_value.value.bytes == bytes([0xFF, 0xFE, 1])
+
+    params = account.created_apps[0].params
+    assert params.approval_program == bytes([0x0A, 0x81, 0x01, 0x43])
+    assert params.global_state[0].value.uint == 42
@@ -0,0 +1,110 @@
+import pytest
+from . import TEST_DATA
+from algo_models import (
+    Address,
+    AlgoModelsError,
+    PayTransactionFields,
+    Transaction,
+    TransactionHeader,
+    TransactionType,
+    address_checksum,
+    address_equals,
+    address_from_app_id,
+    address_from_domain_hash,
+    address_from_pub_key,
+    address_from_string,
+    encode_transaction,
+    is_valid_address,
+    zero_address,
+)
+
+ZERO_ADDRESS = "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAY5HFKQ"
+APP_77_ADDRESS = "PCYUFPA2ZTOYWTP43MX2MOX2OWAIAXUDNC2WFCXAGMRUZ3DYD6BWFDL5YM"
+
+# Polytest Suite: Address
+
+# Polytest Group: Address Tests
+
+
+@pytest.mark.group_address_tests
+def test_from_domain_hash():
+    """An address is derived from the hash of a domain prefix and bytes"""
+    addr = address_from_domain_hash(b"appID", (77).to_bytes(8, "big"))
+    assert addr.address == APP_77_ADDRESS
+
+
+@pytest.mark.group_address_tests
+def test_from_app_id():
+    """The account address of an application is derived from its ID"""
+    assert address_from_app_id(77).address == APP_77_ADDRESS
+    assert (
+        address_from_app_id(1284326447).address
+        == "JP3ENKDQC2BOYRMLFGKBS7RB2IVNF7VNHCFHVTRNHOENRQ6R4UN7MCNXPI"
+    )
+
+
+@pytest.mark.group_address_tests
+def test_reject_inconsistent_address():
+    """An address whose string does not match its public key is rejected"""
+    sender = TEST_DATA["transaction"].header.sender
+    receiver = TEST_DATA["transaction"].pay_fields.receiver
+    inconsistent = Address(address=sender.address, pub_key=receiver.pub_key)
+
+    with pytest.raises(AlgoModelsError.EncodingError, match="does not match"):
+        address_checksum(inconsistent)
+
+    txn = Transaction(
+        header=TransactionHeader(
+            transaction_type=TransactionType.PAYMENT,
+            sender=inconsistent,
+            fee=1000,
+            first_valid=1337,
+            last_valid=1347,
+            genesis_hash=None,
+            genesis_id=None,
+        ),
+        pay_fields=PayTransactionFields(amount=1337, receiver=receiver),
+    )
+    with pytest.raises(AlgoModelsError.EncodingError, match="does not match"):
+        encode_transaction(txn)
+
+
+@pytest.mark.group_address_tests
+def test_compare_addresses():
+    """Addresses are equal only when they refer to the same public key"""
+    sender = TEST_DATA["transaction"].header.sender
+    receiver = TEST_DATA["transaction"].pay_fields.receiver
+
+    assert address_equals(sender, address_from_string(sender.address))
+    assert address_equals(sender, address_from_pub_key(sender.pub_key))
+    assert not address_equals(sender, receiver)
+
+
+@pytest.mark.group_address_tests
+def test_get_checksum():
+    """The 4-byte checksum of an address can be retrieved"""
+    assert address_checksum(zero_address()) == bytes([12, 116, 229, 84])
+
+
+@pytest.mark.group_address_tests
+def test_zero_address():
+    """The zero address has an all-zero public key and the expected string form"""
+    zero = zero_address()
+    assert zero.address == ZERO_ADDRESS
+    assert zero.pub_key == bytes(32)
+
+
+@pytest.mark.group_address_tests
+def test_is_invalid_address():
+    """Malformed addresses (bad length, bad base32, bad checksum) are reported as invalid without throwing"""
+    assert not is_valid_address("")
+    assert not is_valid_address(ZERO_ADDRESS[:-1])
+    assert not is_valid_address(ZERO_ADDRESS.lower())
+    assert not is_valid_address(ZERO_ADDRESS[:-1] + "A")
+
+
+@pytest.mark.group_address_tests
+def test_is_valid_address():
+    """A well-formed address is reported as valid"""
+    assert is_valid_address(ZERO_ADDRESS)
+    assert is_valid_address(TEST_DATA["transaction"].header.sender.address)
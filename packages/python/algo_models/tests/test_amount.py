@@ -0,0 +1,47 @@
+import pytest
+from algo_models import (
+    AlgoModelsError,
+    format_asset_amount,
+    micro_algos_from_algos,
+    micro_algos_to_algos,
+    parse_asset_amount,
+)
+
+# Polytest Suite: Amount
+
+# Polytest Group: Amount Tests
+
+
+@pytest.mark.group_amount_tests
+def test_asset_amounts():
+    """Asset amounts are formatted and parsed using the decimals of the asset"""
+    assert format_asset_amount(1050, 2) == "10.5"
+    assert format_asset_amount(5, 0) == "5"
+    assert parse_asset_amount("10.5", 2) == 1050
+
+    with pytest.raises(
+        AlgoModelsError.AmountError, match="has more than 0 decimal places"
+    ):
+        parse_asset_amount("5.5", 0)
+
+
+@pytest.mark.group_amount_tests
+def test_invalid_algos():
+    """Malformed, too precise or too large Algo amounts are rejected"""
+    with pytest.raises(AlgoModelsError.AmountError, match="is not a decimal amount"):
+        micro_algos_from_algos("1.5 ALGO")
+    with pytest.raises(
+        AlgoModelsError.AmountError, match="has more than 6 decimal places"
+    ):
+        micro_algos_from_algos("0.0000001")
+    with pytest.raises(AlgoModelsError.AmountError, match="is too large"):
+        micro_algos_from_algos("18446744073709.551616")
+
+
+@pytest.mark.group_amount_tests
+def test_algos():
+    """Algo amounts convert between microAlgos and decimal Algo strings"""
+    assert micro_algos_from_algos("1.5") == 1_500_000
+    assert micro_algos_from_algos("0.000001") == 1
+    assert micro_algos_to_algos(1_500_000) == "1.5"
+    assert micro_algos_to_algos(2_000_000) == "2"
@@ -0,0 +1,109 @@
+import json
+from pathlib import Path
+
+import pytest
+from algo_models import (
+    AlgoModelsError,
+    Arc56Compiler,
+    Arc56DefaultValueSource,
+    OnApplicationComplete,
+    app_spec_from_json,
+    app_spec_to_arc56_json,
+    app_spec_validate,
+)
+
+TEST_DATA_DIR = (
+    Path(__file__).parent.parent.parent.parent.parent / "crates" / "algo_models" / "test_data"
+)
+ARC56_JSON = (TEST_DATA_DIR / "app_spec.arc56.json").read_text()
+ARC32_JSON = (TEST_DATA_DIR / "app_spec.arc32.json").read_text()
+
+# Polytest Suite: App Spec
+
+# Polytest Group: App Spec Tests
+
+
+@pytest.mark.group_app_spec_tests
+def test_invalid_json():
+    """JSON that is not an app spec is rejected"""
+    with pytest.raises(AlgoModelsError.AppSpecError, match="invalid app spec JSON"):
+        app_spec_from_json("{}")
+
+    with pytest.raises(AlgoModelsError.AppSpecError, match="invalid app spec JSON"):
+        app_spec_validate("not json")
+
+
+@pytest.mark.group_app_spec_tests
+def test_validate():
+    """A valid spec has no issues and an inconsistent spec reports each issue"""
+    assert app_spec_validate(ARC56_JSON) == []
+    assert app_spec_validate(ARC32_JSON) == []
+
+    spec = json.loads(ARC56_JSON)
+    spec["methods"][2]["args"][0]["type"] = "uint65"
+    spec["state"]["schema"]["local"]["ints"] = 0
+    assert app_spec_validate(json.dumps(spec)) == [
+        "argument 0 of method vote(uint65,bool,account)(uint64,uint64): invalid ABI type: uint65",
+        "local state has 1 keys, but the schema only allows 0",
+    ]
+
+
+@pytest.mark.group_app_spec_tests
+def test_convert_to_arc56_json():
+    """An ARC-32 app spec is converted to ARC-56 JSON that parses to the same spec"""
+    arc56_json = app_spec_to_arc56_json(ARC32_JSON)
+
+    assert json.loads(arc56_json)["bareActions"] == {
+        "create": ["NoOp"],
+        "call": ["DeleteApplication"],
+    }
+    assert app_spec_from_json(arc56_json) == app_spec_from_json(ARC32_JSON)
+
+
+@pytest.mark.group_app_spec_tests
+def test_parse_arc32():
+    """An ARC-32 application.json is converted to ARC-56"""
+    spec = app_spec_from_json(ARC32_JSON)
+
+    assert spec.name == "Calculator"
+    assert spec.networks == {"wGHE2Pwdvd7S12BL5FaOP20EGYesN73ktiC1qzkkit8=": 1234}
+    assert [f.name for f in spec.structs["Point"]] == ["x", "y"]
+    assert spec.state.global_schema.num_uints == 1
+    assert spec.state.keys.global_state["last"].value_type == "AVMUint64"
+
+    add = next(m for m in spec.methods if m.name == "add")
+    assert add.readonly
+    assert add.actions.call == [OnApplicationComplete.NO_OP]
+    assert add.args[1].default_value.source == Arc56DefaultValueSource.GLOBAL
+
+    scale = next(m for m in spec.methods if m.name == "scale")
+    assert scale.args[0].struct_name == "Point"
+    assert scale.returns.struct_name == "Point"
+
+
+@pytest.mark.group_app_spec_tests
+def test_parse_arc56():
+    """An ARC-56 app spec is parsed into its methods, structs, state and programs"""
+    spec = app_spec_from_json(ARC56_JSON)
+
+    assert spec.name == "Voting"
+    assert spec.arcs == [4, 56]
+    assert [m.name for m in spec.methods] == [
+        "createApplication",
+        "propose",
+        "vote",
+        "optIn",
+        "tally",
+        "latest",
+    ]
+    assert spec.methods[0].actions.create == [OnApplicationComplete.NO_OP]
+    assert spec.bare_actions.call == [OnApplicationComplete.DELETE_APPLICATION]
+
+    window = spec.structs["Proposal"][2]
+    assert window.field_type is None
+    assert [f.name for f in window.fields] == ["start", "end"]
+
+    assert spec.state.maps.boxes["proposals"].value_type == "Proposal"
+    assert spec.source_info.approval.source_info[0].error_message == "proposal does not exist"
+    assert spec.compiler_info.compiler == Arc56Compiler.PUYA
+    assert spec.template_variables["VOTING_PERIOD"].variable_type == "uint64"
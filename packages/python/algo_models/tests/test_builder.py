@@ -0,0 +1,119 @@
+import pytest
+from algo_models import (
+    AlgoModelsError,
+    ApplicationCallTransactionFields,
+    AssetTransferTransactionFields,
+    FeeOptions,
+    OnApplicationComplete,
+    PayTransactionFields,
+    SignatureKind,
+    SuggestedParams,
+    TransactionOptions,
+    TransactionType,
+    address_from_pub_key,
+    build_application_call,
+    build_asset_transfer,
+    build_payment,
+)
+
+ALICE = address_from_pub_key(bytes([1] * 32))
+BOB = address_from_pub_key(bytes([2] * 32))
+
+PARAMS = SuggestedParams(
+    fee_per_byte=0,
+    min_fee=1000,
+    first_valid=1000,
+    last_valid=2000,
+    genesis_id="testnet-v1.0",
+    genesis_hash=bytes([7] * 32),
+    consensus_version="future",
+)
+
+
+def payment(options=TransactionOptions(sender=ALICE)):
+    return build_payment(
+        PARAMS, options, PayTransactionFields(receiver=BOB, amount=1000)
+    )
+
+
+# Polytest Suite: Builder
+
+# Polytest Group: Builder Tests
+
+
+@pytest.mark.group_builder_tests
+def test_validity_window():
+    """The validity window can be overridden but cannot exceed 1000 rounds"""
+    txn = payment(TransactionOptions(sender=ALICE, first_valid=1500, last_valid=1510))
+    assert (txn.header.first_valid, txn.header.last_valid) == (1500, 1510)
+
+    with pytest.raises(
+        AlgoModelsError.BuilderError,
+        match="validity window of 1001 rounds exceeds the maximum of 1000",
+    ):
+        payment(TransactionOptions(sender=ALICE, first_valid=999))
+
+
+@pytest.mark.group_builder_tests
+def test_application_call():
+    """Creating an application reserves the extra program pages its programs need"""
+    # #pragma version 10, followed by enough pushint 1 to need an extra page
+    approval = bytes([0x0A]) + bytes([0x81, 0x01]) * 1100
+    clear_state = bytes([0x0A, 0x81, 0x01])
+
+    txn = build_application_call(
+        PARAMS,
+        TransactionOptions(sender=ALICE),
+        ApplicationCallTransactionFields(
+            app_id=0,
+            on_complete=OnApplicationComplete.NO_OP,
+            approval_program=approval,
+            clear_state_program=clear_state,
+            extra_program_pages=0,
+        ),
+    )
+
+    assert txn.header.transaction_type == TransactionType.APPLICATION_CALL
+    assert txn.application_call_fields.extra_program_pages == 1
+
+    with pytest.raises(AlgoModelsError.BuilderError, match="programs is required"):
+        build_application_call(
+            PARAMS,
+            TransactionOptions(sender=ALICE),
+            ApplicationCallTransactionFields(
+                app_id=0, on_complete=OnApplicationComplete.NO_OP, extra_program_pages=0
+            ),
+        )
+
+
+@pytest.mark.group_builder_tests
+def test_asset_transfer():
+    """Asset transfers can pay the fees of other transactions in their group"""
+    fee_options = FeeOptions(
+        signature=SignatureKind.SINGLE(), flat_fee=None, covered_transactions=2
+    )
+
+    txn = build_asset_transfer(
+        PARAMS,
+        TransactionOptions(sender=ALICE, fee_options=fee_options),
+        AssetTransferTransactionFields(asset_id=1234, amount=5, receiver=BOB),
+    )
+
+    assert txn.header.transaction_type == TransactionType.ASSET_TRANSFER
+    assert txn.header.fee == 3000
+    assert txn.asset_transfer_fields.asset_id == 1234
+
+
+@pytest.mark.group_builder_tests
+def test_payment():
+    """Payments take their type, genesis, validity and fee from the suggested params"""
+    txn = payment()
+
+    assert txn.header.transaction_type == TransactionType.PAYMENT
+    assert txn.header.sender == ALICE
+    assert txn.header.fee == 1000
+    assert (txn.header.first_valid, txn.header.last_valid) == (1000, 2000)
+    assert txn.header.genesis_id == "testnet-v1.0"
+    assert txn.header.genesis_hash == bytes([7] * 32)
+    assert txn.pay_fields.receiver == BOB
+    assert txn.pay_fields.amount == 1000
@@ -0,0 +1,42 @@
+import pytest
+from algo_models import consensus_params, consensus_versions, current_consensus_params
+
+CURRENT = (
+    "https://github.com/algorandfoundation/specs/tree/"
+    "953304de35264fc3ef91bcd05c123242015eeaed"
+)
+
+# Polytest Suite: Consensus
+
+# Polytest Group: Consensus Tests
+
+
+@pytest.mark.group_consensus_tests
+def test_upgrades():
+    """The limits change across protocol upgrades"""
+    v17 = consensus_params("v17")
+    assert (v17.max_tx_group_size, v17.logic_sig_version) == (1, 0)
+
+    v28 = consensus_params("v28")
+    assert (v28.max_app_program_len, v28.max_extra_app_program_pages) == (2048, 3)
+    assert v28.max_box_size == 0
+
+    current = current_consensus_params()
+    assert (current.max_tx_group_size, current.max_box_size) == (16, 32768)
+
+
+@pytest.mark.group_consensus_tests
+def test_version_lookup():
+    """Versions are found by URL or short name, and unknown versions return nothing"""
+    assert consensus_params(CURRENT) == current_consensus_params()
+    assert consensus_params("v41") == current_consensus_params()
+    assert consensus_params("v6") is None
+    assert consensus_params("unknown") is None
+
+
+@pytest.mark.group_consensus_tests
+def test_versions():
+    """Every published version is listed oldest first, ending with the current version"""
+    versions = consensus_versions()
+    assert versions[0] == "v7"
+    assert versions[-1] == CURRENT
@@ -0,0 +1,84 @@
+import pytest
+from . import TEST_DATA
+from algo_models import (
+    FeeOptions,
+    SignatureKind,
+    SuggestedParams,
+    estimate_signed_transaction_size,
+    estimate_transaction_fee,
+)
+
+TRANSACTION = TEST_DATA["transaction"]
+
+
+def params(fee_per_byte):
+    return SuggestedParams(
+        fee_per_byte=fee_per_byte,
+        min_fee=1000,
+        first_valid=49265002,
+        last_valid=49266002,
+        genesis_id="testnet-v1.0",
+        genesis_hash=TRANSACTION.header.genesis_hash,
+        consensus_version="future",
+    )
+
+
+def options(flat_fee=None, covered_transactions=0):
+    return FeeOptions(
+        signature=SignatureKind.SINGLE(),
+        flat_fee=flat_fee,
+        covered_transactions=covered_transactions,
+    )
+
+
+# Polytest Suite: Fee
+
+# Polytest Group: Fee Tests
+
+
+@pytest.mark.group_fee_tests
+def test_fee_pooling():
+    """A transaction can pay the minimum fee of other transactions in its group"""
+    covering = options(covered_transactions=3)
+
+    assert estimate_transaction_fee(TRANSACTION, params(0), covering) == 4000
+    assert estimate_transaction_fee(TRANSACTION, params(10), covering) == 5450
+
+
+@pytest.mark.group_fee_tests
+def test_flat_fee():
+    """A flat fee is used as is"""
+    assert (
+        estimate_transaction_fee(TRANSACTION, params(10), options(flat_fee=5000))
+        == 5000
+    )
+
+
+@pytest.mark.group_fee_tests
+def test_fee_per_byte():
+    """When congested, the fee is the fee per byte times the signed size"""
+    assert estimate_transaction_fee(TRANSACTION, params(10), options()) == 2450
+
+
+@pytest.mark.group_fee_tests
+def test_minimum_fee():
+    """Without congestion the fee is the minimum fee"""
+    assert estimate_transaction_fee(TRANSACTION, params(0), options()) == 1000
+
+
+@pytest.mark.group_fee_tests
+def test_signed_size():
+    """The estimated signed size accounts for single, multisig and logic signatures"""
+    assert estimate_signed_transaction_size(TRANSACTION, SignatureKind.SINGLE()) == len(
+        TEST_DATA["expected_signed_txn"]
+    )
+    assert (
+        estimate_signed_transaction_size(TRANSACTION, SignatureKind.MULTISIG(subsigs=2))
+        == 409
+    )
+    assert (
+        estimate_signed_transaction_size(
+            TRANSACTION, SignatureKind.LOGIC_SIG(program_size=100, arg_sizes=[])
+        )
+        == 285
+    )
@@ -0,0 +1,98 @@
+import pytest
+from algo_models import (
+    AlgoModelsError,
+    DerivationType,
+    KeyContext,
+    hd_bip44_path,
+    hd_derive_private_key,
+    hd_derive_public_key,
+    hd_extended_public_key,
+    hd_root_key_from_seed,
+    hd_sign,
+)
+from nacl.signing import VerifyKey
+
+# BIP-39 seed of the ARC-52 test mnemonic "salon zoo engage submit smile frost later decide
+# wing sight chaos renew lizard rely canal coral scene hobby scare step bus leaf tobacco slice"
+SEED = bytes.fromhex(
+    "3aff2db416b895ec3cf9a4f8d1e970bc9819920e7bf44a5e350477af0ef557b1"
+    "511b0986debf78dd38c7c520cd44ff7c7231618f958e21ef0250733a8c1915ea"
+)
+ROOT_KEY = bytes.fromhex(
+    "a8ba80028922d9fcfa055c78aede55b5c575bcd8d5a53168edf45f36d9ec8f46"
+    "94592b4bc892907583e22669ecdf1b0409a9f3bd5549f2dd751b51360909cd05"
+    "796b9206ec30e142e94b790a98805bf999042b55046963174ee6cee2d0375946"
+)
+HARDENED = 0x80000000
+
+
+def public_key(context, account, key_index):
+    path = hd_bip44_path(context, account, 0, key_index)
+    key = hd_derive_private_key(ROOT_KEY, path, DerivationType.PEIKERT)
+    return hd_extended_public_key(key)[:32]
+
+
+# Polytest Suite: HD Keys
+
+# Polytest Group: HD Key Tests
+
+
+@pytest.mark.group_hd_key_tests
+def test_sign_with_derived_key():
+    """A signature from a derived key verifies against its public key"""
+    path = hd_bip44_path(KeyContext.ADDRESS, 0, 0, 0)
+    key = hd_derive_private_key(ROOT_KEY, path, DerivationType.PEIKERT)
+    data = b"arc52 signing test"
+
+    sig = hd_sign(key, data)
+    VerifyKey(hd_extended_public_key(key)[:32]).verify(data, sig)
+
+
+@pytest.mark.group_hd_key_tests
+def test_hardened_public_derivation():
+    """Deriving a hardened index from an extended public key is rejected"""
+    xpub = hd_extended_public_key(ROOT_KEY)
+    with pytest.raises(AlgoModelsError.KeyDerivationError, match="hardened index"):
+        hd_derive_public_key(xpub, [HARDENED], DerivationType.PEIKERT)
+
+
+@pytest.mark.group_hd_key_tests
+def test_public_derivation():
+    """Non-hardened keys derived from an extended public key match those derived from the private key"""
+    account_path = hd_bip44_path(KeyContext.ADDRESS, 0, 0, 0)[:3]
+    account = hd_derive_private_key(ROOT_KEY, account_path, DerivationType.PEIKERT)
+    xpub = hd_extended_public_key(account)
+
+    from_private = hd_extended_public_key(
+        hd_derive_private_key(account, [0, 7], DerivationType.PEIKERT)
+    )
+    from_public = hd_derive_public_key(xpub, [0, 7], DerivationType.PEIKERT)
+    assert from_private == from_public
+
+
+@pytest.mark.group_hd_key_tests
+def test_derive_identity_key():
+    """Identity keys match the published ARC-52 test vectors"""
+    assert public_key(KeyContext.IDENTITY, 0, 0) == bytes.fromhex(
+        "ff8b1863ef5e40d0a48c245f26a6dbdf5da94dc75a1851f51d8a04e547bd5f5a"
+    )
+
+
+@pytest.mark.group_hd_key_tests
+def test_derive_address_keys():
+    """Address keys match the published ARC-52 test vectors"""
+    assert public_key(KeyContext.ADDRESS, 0, 0) == bytes.fromhex(
+        "7bda7ac12627b2c259f1df6875d30c10b35f55b33ad2cc8ea2736eaa3ebcfab9"
+    )
+    assert public_key(KeyContext.ADDRESS, 0, 1) == bytes.fromhex(
+        "5bae8828f111064637ac5061bd63bc4fcfe4a833252305f25eeab9c64ecdf519"
+    )
+    assert public_key(KeyContext.ADDRESS, 1, 0) == bytes.fromhex(
+        "358d8c4382992849a764438e02b1c45c2ca4e86bbcfe10fd5b963f3610012bc9"
+    )
+
+
+@pytest.mark.group_hd_key_tests
+def test_root_key_from_seed():
+    """The ARC-52 root key is derived from a BIP-39 seed"""
+    assert hd_root_key_from_seed(SEED) == ROOT_KEY
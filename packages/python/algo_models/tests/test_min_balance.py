@@ -0,0 +1,71 @@
+from pathlib import Path
+
+import pytest
+from algo_models import (
+    ApplicationStateSchema,
+    MinBalanceTotals,
+    account_min_balance_totals,
+    consensus_params,
+    current_consensus_params,
+    decode_account,
+    min_balance,
+)
+
+TEST_DATA_DIR = (
+    Path(__file__).parent.parent.parent.parent.parent / "crates" / "algo_models" / "test_data"
+)
+# An application's account holding an asset and two boxes, whose minimum balance was worked out by
+# hand from go-algorand's formula
+APP_ACCOUNT = decode_account((TEST_DATA_DIR / "app_account.msgpack").read_bytes())
+
+
+def box_totals(name_length: int, size: int) -> MinBalanceTotals:
+    return MinBalanceTotals(
+        assets=0,
+        created_apps=0,
+        opted_in_apps=0,
+        schema=ApplicationStateSchema(num_uints=0, num_byte_slices=0),
+        extra_app_pages=0,
+        boxes=1,
+        box_bytes=name_length + size,
+    )
+
+
+# Polytest Suite: Min Balance
+
+# Polytest Group: Min Balance Tests
+
+
+@pytest.mark.group_min_balance_tests
+def test_boxes():
+    """Each box costs a flat amount plus an amount per byte of name and size"""
+    totals = box_totals(4, 1024)
+    assert min_balance(totals, current_consensus_params()) == 100_000 + 2_500 + 400 * 1028
+
+    # Boxes cost nothing before they were introduced
+    assert min_balance(totals, consensus_params("v34")) == 100_000
+
+
+@pytest.mark.group_min_balance_tests
+def test_opt_ins_and_apps():
+    """Assets, apps, their schemas and extra pages raise the minimum balance"""
+    totals = account_min_balance_totals(APP_ACCOUNT)
+    totals.assets += 2
+    assert min_balance(totals, current_consensus_params()) == 830_600
+
+    # 100,000 for the app, 4 * 28,500 for the integers, 2 * 50,000 for the byte slices and
+    # 100,000 for the extra page
+    totals.created_apps += 1
+    totals.schema.num_uints += 4
+    totals.schema.num_byte_slices += 2
+    totals.extra_app_pages += 1
+    assert min_balance(totals, current_consensus_params()) == 1_244_600
+
+
+@pytest.mark.group_min_balance_tests
+def test_account():
+    """The minimum balance computed for an application account with boxes matches the one algod reports"""
+    totals = account_min_balance_totals(APP_ACCOUNT)
+    assert (totals.assets, totals.boxes, totals.box_bytes) == (1, 2, 1064)
+    assert APP_ACCOUNT.min_balance == 630_600
+    assert min_balance(totals, current_consensus_params()) == APP_ACCOUNT.min_balance
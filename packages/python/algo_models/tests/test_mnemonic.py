@@ -0,0 +1,59 @@
+import pytest
+from algo_models import (
+    AlgoModelsError,
+    mnemonic_to_seed,
+    seed_to_mnemonic,
+)
+
+ZERO_MNEMONIC = " ".join(["abandon"] * 24 + ["invest"])
+MNEMONIC = (
+    "advice pudding treat near rule blouse same whisper inner electric quit surface "
+    "sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor"
+)
+SEED = bytes.fromhex("2048abcf3999de60dc97faa3db915fa3bd6cfdd0ef1712064b61fcf4b6958856")
+
+# Polytest Suite: Mnemonic
+
+# Polytest Group: Mnemonic Tests
+
+
+@pytest.mark.group_mnemonic_tests
+def test_invalid_seed_length():
+    """A seed that is not 32 bytes is rejected"""
+    with pytest.raises(AlgoModelsError.MnemonicError, match="seed must be 32 bytes"):
+        seed_to_mnemonic(bytes(31))
+
+
+@pytest.mark.group_mnemonic_tests
+def test_invalid_checksum():
+    """A mnemonic whose checksum word does not match the seed is rejected"""
+    with pytest.raises(AlgoModelsError.MnemonicError, match="checksum"):
+        mnemonic_to_seed(MNEMONIC.replace("sponsor", "abandon"))
+
+
+@pytest.mark.group_mnemonic_tests
+def test_unknown_word():
+    """A mnemonic containing a word outside the word list is rejected"""
+    with pytest.raises(AlgoModelsError.MnemonicError, match="unknown word"):
+        mnemonic_to_seed(MNEMONIC.replace("pudding", "puddingg"))
+
+
+@pytest.mark.group_mnemonic_tests
+def test_wrong_length():
+    """A mnemonic without exactly 25 words is rejected"""
+    with pytest.raises(AlgoModelsError.MnemonicError, match="but got 24"):
+        mnemonic_to_seed(MNEMONIC.rsplit(" ", 1)[0])
+
+
+@pytest.mark.group_mnemonic_tests
+def test_mnemonic_to_seed():
+    """A 25-word mnemonic is decoded into the seed it encodes"""
+    assert mnemonic_to_seed(MNEMONIC) == SEED
+    assert mnemonic_to_seed(ZERO_MNEMONIC) == bytes(32)
+
+
+@pytest.mark.group_mnemonic_tests
+def test_seed_to_mnemonic():
+    """A 32-byte seed is encoded as the expected 25-word mnemonic"""
+    assert seed_to_mnemonic(SEED) == MNEMONIC
+    assert seed_to_mnemonic(bytes(32)) == ZERO_MNEMONIC
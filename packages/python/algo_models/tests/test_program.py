@@ -0,0 +1,214 @@
+import pytest
+from algo_models import (
+    AlgoModelsError,
+    address_from_program,
+    program_assemble,
+    program_check_logic_sig_size,
+    program_disassemble,
+    program_extra_pages,
+    program_hash,
+    program_version,
+)
+
+# #pragma version 2; int 1
+INT_1 = bytes([0x02, 0x20, 0x01, 0x01, 0x22])
+INT_1_ADDRESS = "YOE6C22GHCTKAN3HU4SE5PGIPN5UKXAJTXCQUPJ3KKF5HOAH646MKKCPDA"
+
+
+def sized(size):
+    """A version 2 program padded out to the given size with err opcodes"""
+    return bytes([0x02]) + bytes(size - 1)
+
+
+# Polytest Suite: Program
+
+# Polytest Group: Program Tests
+
+
+@pytest.mark.group_program_tests
+def test_extra_pages():
+    """Programs larger than one page need extra program pages"""
+    assert program_extra_pages(sized(2000), sized(48)) == 0
+    assert program_extra_pages(sized(2000), sized(49)) == 1
+    assert program_extra_pages(sized(8000), sized(192)) == 3
+
+    with pytest.raises(AlgoModelsError.ProgramError, match="exceeds the limit of 8192 bytes"):
+        program_extra_pages(sized(8000), sized(193))
+
+
+@pytest.mark.group_program_tests
+def test_logic_sig_size():
+    """A logic signature larger than 1000 bytes is rejected"""
+    program_check_logic_sig_size(sized(990), 10)
+
+    with pytest.raises(
+        AlgoModelsError.ProgramError,
+        match="logic signature is 1001 bytes, which exceeds the limit of 1000 bytes",
+    ):
+        program_check_logic_sig_size(sized(990), 11)
+
+
+@pytest.mark.group_program_tests
+def test_address():
+    """The contract account address is the hash of Program followed by the program bytes"""
+    assert address_from_program(INT_1).address == INT_1_ADDRESS
+    assert program_hash(INT_1) == address_from_program(INT_1).pub_key
+
+
+@pytest.mark.group_program_tests
+def test_branch_targets():
+    """Branches must land on the start of an instruction"""
+    with pytest.raises(AlgoModelsError.ProgramError, match="not the start of an instruction"):
+        # b 1, which lands in the middle of pushint 1
+        program_version(bytes([0x04, 0x42, 0x00, 0x01, 0x81, 0x01]))
+
+    # l1: pushint 1; bnz l1
+    with pytest.raises(AlgoModelsError.ProgramError, match="branches backwards"):
+        program_version(bytes([0x03, 0x81, 0x01, 0x40, 0xFF, 0xFB]))
+    assert program_version(bytes([0x04, 0x81, 0x01, 0x40, 0xFF, 0xFB])) == 4
+
+
+@pytest.mark.group_program_tests
+def test_unavailable_opcode():
+    """Opcodes introduced after the program version are rejected"""
+    # pushint 1; box_create
+    with pytest.raises(
+        AlgoModelsError.ProgramError, match="box_create at pc 3 requires version 8"
+    ):
+        program_version(bytes([0x05, 0x81, 0x01, 0xB9]))
+
+    assert program_version(bytes([0x08, 0x81, 0x01, 0xB9])) == 8
+
+
+@pytest.mark.group_program_tests
+def test_invalid_bytecode():
+    """Unknown opcodes and truncated immediate arguments are rejected"""
+    with pytest.raises(AlgoModelsError.ProgramError, match="unknown opcode 0x87 at pc 1"):
+        program_version(bytes([0x0B, 0x87]))
+
+    with pytest.raises(AlgoModelsError.ProgramError, match="truncated immediate arguments"):
+        program_version(bytes([0x03, 0x80, 0x05, 0x01]))
+
+    with pytest.raises(AlgoModelsError.ProgramError, match="program is empty"):
+        program_version(b"")
+
+
+@pytest.mark.group_program_tests
+def test_version():
+    """The version is read from the varuint at the start of the program"""
+    assert program_version(INT_1) == 2
+    assert program_version(bytes([0x0B])) == 11
+
+    with pytest.raises(AlgoModelsError.ProgramError, match="version 12 is not supported"):
+        program_version(bytes([0x0C]))
+
+
+# Polytest Group: Disassembler Tests
+
+
+@pytest.mark.group_disassembler_tests
+def test_fields():
+    """Field immediates are shown by name"""
+    # gtxna 1 ApplicationArgs 0; acct_params_get AcctBalance; block BlkProposer
+    program = bytes([0x0B, 0x37, 0x01, 0x1A, 0x00, 0x73, 0x00, 0xD1, 0x06])
+
+    assert program_disassemble(program) == (
+        "#pragma version 11\n"
+        "gtxna 1 ApplicationArgs 0\n"
+        "acct_params_get AcctBalance\n"
+        "block BlkProposer\n"
+    )
+
+
+@pytest.mark.group_disassembler_tests
+def test_labels():
+    """Branch targets are replaced with labels in the order they are referenced"""
+    # l1: pushint 1; bnz l2; b l1; l2: retsub
+    program = bytes([0x04, 0x81, 0x01, 0x40, 0x00, 0x03, 0x42, 0xFF, 0xF8, 0x89])
+
+    assert program_disassemble(program) == (
+        "#pragma version 4\n"
+        "label2:\n"
+        "pushint 1\n"
+        "bnz label1\n"
+        "b label2\n"
+        "label1:\n"
+        "retsub\n"
+    )
+
+
+@pytest.mark.group_disassembler_tests
+def test_constants():
+    """Constants loaded from the constant blocks are shown in comments"""
+    # intcblock 1 1000; bytecblock "hi" 0xff00; intc_1; bytec_0; bytec 1
+    program = bytes(
+        [0x02, 0x20, 0x02, 0x01, 0xE8, 0x07, 0x26, 0x02, 0x02, 0x68, 0x69, 0x02, 0xFF, 0x00]
+        + [0x23, 0x28, 0x27, 0x01]
+    )
+
+    assert program_disassemble(program) == (
+        "#pragma version 2\n"
+        "intcblock 1 1000\n"
+        "bytecblock 0x6869 0xff00\n"
+        "intc_1 // 1000\n"
+        'bytec_0 // "hi"\n'
+        "bytec 1 // 0xff00\n"
+    )
+
+
+# Polytest Group: Assembler Tests
+
+
+@pytest.mark.group_assembler_tests
+def test_assembly_errors():
+    """Invalid source is rejected with the line number of the error"""
+    with pytest.raises(
+        AlgoModelsError.ProgramError,
+        match="line 2: box_create requires version 8, but the program is version 3",
+    ):
+        program_assemble("#pragma version 3\nbox_create")
+
+    with pytest.raises(
+        AlgoModelsError.ProgramError, match="line 2: reference to undefined label missing"
+    ):
+        program_assemble("#pragma version 8\nb missing")
+
+
+@pytest.mark.group_assembler_tests
+def test_labels_and_macros():
+    """Labels resolve to branch offsets and #define macros are expanded"""
+    source = """#pragma version 8
+#define ARGS txn NumAppArgs
+ARGS
+bz create
+int 0; return
+create:
+int 1
+return
+"""
+
+    assert program_assemble(source) == bytes(
+        [0x08, 0x31, 0x1B, 0x41, 0x00, 0x03, 0x81, 0x00, 0x43, 0x81, 0x01, 0x43]
+    )
+
+
+@pytest.mark.group_assembler_tests
+def test_constant_optimization():
+    """From version 4, repeated constants are placed in constant blocks by frequency and
+    single-use constants are pushed"""
+    source = """#pragma version 4
+int 7
+int 1
+int 1
+int 2
+int 2
+int 2
+byte "x"
+method "add(uint64,uint64)uint128"
+byte "x"
+"""
+
+    assert program_assemble(source) == bytes(
+        [0x04, 0x20, 0x02, 0x02, 0x01, 0x26, 0x01, 0x01, 0x78, 0x81, 0x07, 0x23, 0x23]
+        + [0x22, 0x22, 0x22, 0x28, 0x80, 0x04, 0x8A, 0xA3, 0xB6, 0x1F, 0x28]
+    )
@@ -0,0 +1,64 @@
+from pathlib import Path
+
+import pytest
+from algo_models import (
+    inspect_transaction_file,
+    read_transaction_file,
+    write_transaction_file,
+    write_unsigned_transaction_file,
+)
+
+TEST_DATA_DIR = (
+    Path(__file__).parent.parent.parent.parent.parent / "crates" / "algo_models" / "test_data"
+)
+PAYMENT_STXN = (TEST_DATA_DIR / "payment.stxn").read_bytes()
+MULTISIG_STXN = (TEST_DATA_DIR / "multisig.stxn").read_bytes()
+
+# Polytest Suite: Transaction File
+
+# Polytest Group: Transaction File Tests
+
+
+@pytest.mark.group_transaction_file_tests
+def test_multisig_file():
+    """Reading and rewriting a partially signed multisig file from a rekeyed account keeps its signatures and auth address"""
+    [stxn] = read_transaction_file(MULTISIG_STXN)
+    assert stxn.signature is None
+    assert stxn.multisig_signature.threshold == 2
+    assert [s.signature is not None for s in stxn.multisig_signature.subsignatures] == [
+        True,
+        False,
+        True,
+    ]
+    assert stxn.auth_address.address == "KUIRTXLNEOOP7FL34O2SWPD6MI3R7UN6T6HTXNGQS5R5S3PKUTFXCJE6LE"
+
+    assert write_transaction_file([stxn]) == MULTISIG_STXN
+
+@pytest.mark.group_transaction_file_tests
+def test_inspect():
+    """Inspecting a file gives the same output as goal clerk inspect"""
+    expected = (TEST_DATA_DIR / "payment.stxn.inspect").read_text()
+    assert inspect_transaction_file("payment.stxn", PAYMENT_STXN) == expected
+
+
+@pytest.mark.group_transaction_file_tests
+def test_unsigned_file():
+    """Unsigned transactions are written wrapped in txn without a signature"""
+    [signed] = read_transaction_file(PAYMENT_STXN)
+    file = write_unsigned_transaction_file([signed.transaction])
+    assert file[:5] == b"\x81\xa3txn"
+
+    [unsigned] = read_transaction_file(file)
+    assert unsigned.signature is None
+    assert unsigned.transaction == signed.transaction
+
+
+@pytest.mark.group_transaction_file_tests
+def test_read_signed_file():
+    """A signed transaction file decodes to its transactions and encodes back to the same bytes"""
+    transactions = read_transaction_file(PAYMENT_STXN)
+    assert len(transactions) == 1
+    assert len(transactions[0].signature) == 64
+    assert transactions[0].transaction.pay_fields.amount == 10_000_000
+
+    assert write_transaction_file(transactions) == PAYMENT_STXN
@@ -0,0 +1,71 @@
+import copy
+import pytest
+from . import TEST_DATA
+from algo_models import (
+    AssetTransferTransactionFields,
+    Transaction,
+    TransactionType,
+    current_consensus_params,
+    validate_transaction,
+)
+
+TRANSACTION = TEST_DATA["transaction"]
+
+
+def with_header(**fields):
+    txn = copy.deepcopy(TRANSACTION)
+    for name, value in fields.items():
+        setattr(txn.header, name, value)
+    return txn
+
+
+# Polytest Suite: Validation
+
+# Polytest Group: Validation Tests
+
+
+@pytest.mark.group_validation_tests
+def test_consensus_params():
+    """The limits come from the consensus params"""
+    txn = with_header(note=bytes(2000))
+    params = current_consensus_params()
+    assert params.max_txn_note_bytes == 1024
+    assert len(validate_transaction(txn, params)) == 1
+
+    params.max_txn_note_bytes = 2048
+    assert validate_transaction(txn, params) == []
+
+
+@pytest.mark.group_validation_tests
+def test_asset_id():
+    """Asset transfers require a non-zero asset ID"""
+    header = copy.deepcopy(TRANSACTION.header)
+    header.transaction_type = TransactionType.ASSET_TRANSFER
+    txn = Transaction(
+        header=header,
+        asset_transfer_fields=AssetTransferTransactionFields(
+            asset_id=0, amount=1, receiver=TRANSACTION.pay_fields.receiver
+        ),
+    )
+
+    assert validate_transaction(txn, current_consensus_params()) == [
+        "asset transfers require a non-zero asset ID"
+    ]
+
+
+@pytest.mark.group_validation_tests
+def test_violations():
+    """All violations are reported, like an inverted validity window and a long note"""
+    txn = with_header(first_valid=TRANSACTION.header.last_valid + 1, note=bytes(1025))
+
+    assert validate_transaction(txn, current_consensus_params()) == [
+        f"last valid round {txn.header.last_valid} is before first valid round "
+        f"{txn.header.first_valid}",
+        "note of 1025 bytes exceeds the maximum of 1024",
+    ]
+
+
+@pytest.mark.group_validation_tests
+def test_valid_transaction():
+    """A well formed transaction has no violations"""
+    assert validate_transaction(TRANSACTION, current_consensus_params()) == []
@@ -33,7 +33,15 @@ let package = Package(
                 "ed25519swift",
             ],
             resources: [
-                .process("Resources/test_data.json")
+                .process("Resources/test_data.json"),
+                .process("Resources/app_spec.arc32.json"),
+                .process("Resources/app_spec.arc56.json"),
+                .process("Resources/account.json"),
+                .process("Resources/account.msgpack"),
+                .process("Resources/app_account.msgpack"),
+                .process("Resources/payment.stxn"),
+                .process("Resources/payment.stxn.inspect"),
+                .process("Resources/multisig.stxn"),
             ]
         ),
     ]
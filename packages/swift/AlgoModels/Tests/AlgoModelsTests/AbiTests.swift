@@ -0,0 +1,232 @@
+import Foundation
+import Testing
+
+@testable import AlgoModels
+
+func abiUint(_ n: UInt64) -> AbiValue { .uint(value: String(n)) }
+func abiString(_ s: String) -> AbiValue { .string(value: s) }
+func abiBool(_ b: Bool) -> AbiValue { .bool(value: b) }
+func abiArray(_ values: AbiValue...) -> AbiValue { .array(values: values) }
+
+// (string,bool,bool,bool,bool,string) from the reference SDK test suites
+let boolTuple = abiArray(
+    abiString("AB"), abiBool(true), abiBool(false), abiBool(true), abiBool(false), abiString("DE"))
+let boolTupleBytes = Data([0x00, 0x05, 0xA0, 0x00, 0x09, 0x00, 0x02, 0x41, 0x42, 0x00, 0x02, 0x44, 0x45])
+
+// Polytest Suite: ABI
+
+// Polytest Group: ABI Tests
+
+@Test("ABI: invalid value")
+func aBIInvalidValue() throws {
+    do {
+        _ = try abiEncode(abiType: "uint8", value: abiUint(256))
+        #expect(Bool(false), "Expected AbiError to be thrown")
+    } catch AlgoModelsError.AbiError(let message) {
+        #expect(message.contains("does not fit in 8 bits"))
+    }
+
+    do {
+        _ = try abiEncode(abiType: "(uint64,bool)", value: abiArray(abiUint(1)))
+        #expect(Bool(false), "Expected AbiError to be thrown")
+    } catch AlgoModelsError.AbiError(let message) {
+        #expect(message.contains("expected 2 fields"))
+    }
+}
+
+@Test("ABI: invalid type")
+func aBIInvalidType() throws {
+    for invalid in ["uint7", "ufixed64x0", "(uint64", "int64", "byte[01]"] {
+        do {
+            _ = try abiTypeIsDynamic(abiType: invalid)
+            #expect(Bool(false), "Expected AbiError to be thrown")
+        } catch AlgoModelsError.AbiError(let message) {
+            #expect(message == "invalid ABI type: \(invalid)")
+        }
+    }
+}
+
+@Test("ABI: is dynamic")
+func aBIIsDynamic() throws {
+    #expect(try abiTypeIsDynamic(abiType: "string"))
+    #expect(try abiTypeIsDynamic(abiType: "(uint64,string[])"))
+    #expect(try abiTypeIsDynamic(abiType: "bool[]"))
+    #expect(try !abiTypeIsDynamic(abiType: "(uint64,byte[32])"))
+    #expect(try !abiTypeIsDynamic(abiType: "address"))
+}
+
+@Test("ABI: decode tuple")
+func aBIDecodeTuple() throws {
+    let decoded = try abiDecode(abiType: "(string,bool,bool,bool,bool,string)", bytes: boolTupleBytes)
+    #expect(decoded == boolTuple)
+}
+
+@Test("ABI: encode dynamic tuple")
+func aBIEncodeDynamicTuple() throws {
+    #expect(
+        try abiEncode(abiType: "(string,bool,bool,bool,bool,string)", value: boolTuple)
+            == boolTupleBytes)
+
+    let encoded = try abiEncode(
+        abiType: "(uint64,string[])",
+        value: abiArray(abiUint(1), abiArray(abiString("a"), abiString("bc"))))
+    #expect(
+        [UInt8](encoded) == [
+            0, 0, 0, 0, 0, 0, 0, 1, 0x00, 0x0A, 0x00, 0x02, 0x00, 0x04, 0x00, 0x07,
+            0x00, 0x01, 0x61, 0x00, 0x02, 0x62, 0x63,
+        ])
+}
+
+@Test("ABI: encode bool packing")
+func aBIEncodeBoolPacking() throws {
+    #expect(try abiEncode(abiType: "bool", value: abiBool(true)) == Data([0x80]))
+    #expect(
+        try abiEncode(abiType: "bool[]", value: abiArray(abiBool(true), abiBool(false), abiBool(true)))
+            == Data([0x00, 0x03, 0xA0]))
+
+    let nine = AbiValue.array(values: (0..<9).map { abiBool($0 % 2 == 0) })
+    #expect(try abiEncode(abiType: "bool[9]", value: nine) == Data([0xAA, 0x80]))
+}
+
+@Test("ABI: encode uint")
+func aBIEncodeUint() throws {
+    #expect(try abiEncode(abiType: "uint64", value: abiUint(1)) == Data([0, 0, 0, 0, 0, 0, 0, 1]))
+
+    let max512 = AbiValue.uint(
+        value:
+            "13407807929942597099574024998205846127479365820592393377723561443721764030073546976801874298166903427690031858186486050853753882811946569946433649006084095"
+    )
+    #expect(try abiEncode(abiType: "uint512", value: max512) == Data(repeating: 0xFF, count: 64))
+    #expect(try abiDecode(abiType: "uint512", bytes: Data(repeating: 0xFF, count: 64)) == max512)
+}
+
+let returnPrefix = Data([0x15, 0x1F, 0x7C, 0x75])
+let abiSender = try! addressFromPubKey(pubKey: Data(repeating: 1, count: 32))
+let abiOther = try! addressFromPubKey(pubKey: Data(repeating: 2, count: 32))
+
+func uint64Bytes(_ n: UInt64) -> Data {
+    withUnsafeBytes(of: n.bigEndian) { Data($0) }
+}
+
+func abiAppCall(appId: UInt64) -> Transaction {
+    Transaction(
+        header: TransactionHeader(
+            transactionType: .applicationCall,
+            sender: abiSender,
+            fee: 1000,
+            firstValid: 1,
+            lastValid: 1000,
+            genesisHash: nil,
+            genesisId: nil
+        ),
+        applicationCallFields: ApplicationCallTransactionFields(
+            appId: appId,
+            onComplete: .noOp,
+            extraProgramPages: 0
+        )
+    )
+}
+
+// Polytest Group: ABI Method Tests
+
+@Test("ABI: missing return value")
+func aBIMissingReturnValue() throws {
+    do {
+        _ = try abiMethodDecodeReturn(
+            signature: "add(uint64,uint64)uint128", lastLog: "debug".data(using: .utf8)!)
+        #expect(Bool(false), "Expected AbiError to be thrown")
+    } catch AlgoModelsError.AbiError(let message) {
+        #expect(message.contains("no return value found"))
+    }
+}
+
+@Test("ABI: decode return value")
+func aBIDecodeReturnValue() throws {
+    let log = returnPrefix + Data(repeating: 0, count: 15) + Data([3])
+    #expect(try abiMethodDecodeReturn(signature: "add(uint64,uint64)uint128", lastLog: log) == abiUint(3))
+    #expect(try abiMethodDecodeReturn(signature: "noop()void", lastLog: Data()) == nil)
+}
+
+@Test("ABI: more than 15 arguments")
+func aBIMoreThan15Arguments() throws {
+    let signature = "many(\(Array(repeating: "uint64", count: 17).joined(separator: ",")))void"
+    let txn = try abiMethodCall(
+        transaction: abiAppCall(appId: 1),
+        signature: signature,
+        args: (0..<17).map { .abiValue(value: abiUint(UInt64($0))) }
+    )
+    let args = txn.applicationCallFields!.args!
+
+    #expect(args.count == 16)
+    #expect(args[14] == uint64Bytes(13))
+    #expect(args[15] == uint64Bytes(14) + uint64Bytes(15) + uint64Bytes(16))
+}
+
+@Test("ABI: reference arguments")
+func aBIReferenceArguments() throws {
+    let txn = try abiMethodCall(
+        transaction: abiAppCall(appId: 1234),
+        signature: "refs(account,account,asset,application,application,pay)void",
+        args: [
+            .account(value: abiSender),
+            .account(value: abiOther),
+            .asset(value: 10),
+            .application(value: 1234),
+            .application(value: 99),
+            .transaction,
+        ]
+    )
+    let fields = txn.applicationCallFields!
+
+    #expect(fields.accounts == [abiOther])
+    #expect(fields.foreignAssets == [10])
+    #expect(fields.foreignApps == [99])
+    #expect(Array(fields.args!.dropFirst()) == [0, 1, 0, 0, 1].map { Data([$0]) })
+}
+
+@Test("ABI: method call")
+func aBIMethodCall() throws {
+    let txn = try abiMethodCall(
+        transaction: abiAppCall(appId: 1234),
+        signature: "add(uint64,uint64)uint128",
+        args: [.abiValue(value: abiUint(1)), .abiValue(value: abiUint(2))]
+    )
+
+    #expect(
+        txn.applicationCallFields!.args
+            == [Data([0x8A, 0xA3, 0xB6, 0x1F]), uint64Bytes(1), uint64Bytes(2)])
+    #expect(txn.header == abiAppCall(appId: 1234).header)
+
+    do {
+        _ = try abiMethodCall(
+            transaction: abiAppCall(appId: 1234),
+            signature: "add(uint64,uint64)uint128",
+            args: [.abiValue(value: abiUint(1))]
+        )
+        #expect(Bool(false), "Expected AbiError to be thrown")
+    } catch AlgoModelsError.AbiError(let message) {
+        #expect(message.contains("expected 2 arguments, but got 1"))
+    }
+}
+
+@Test("ABI: parse signature")
+func aBIParseSignature() throws {
+    let method = try abiMethodFromSignature(signature: "foo(string,(uint64,bool[]),pay,account)void")
+
+    #expect(method.name == "foo")
+    #expect(method.args.map { $0.argType } == ["string", "(uint64,bool[])", "pay", "account"])
+    #expect(method.returns == nil)
+    #expect(try abiMethodFromSignature(signature: "add(uint64,uint64)uint128").returns == "uint128")
+
+    do {
+        _ = try abiMethodFromSignature(signature: "foo(uint64")
+        #expect(Bool(false), "Expected AbiError to be thrown")
+    } catch AlgoModelsError.AbiError(let message) {
+        #expect(message.contains("invalid ARC-4 method signature"))
+    }
+}
+
+@Test("ABI: method selector")
+func aBIMethodSelector() throws {
+    #expect(try abiMethodSelector(signature: "add(uint64,uint64)uint128") == Data([0x8A, 0xA3, 0xB6, 0x1F]))
+}
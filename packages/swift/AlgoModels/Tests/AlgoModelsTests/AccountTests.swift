@@ -0,0 +1,55 @@
+import Foundation
+import Testing
+
+@testable import AlgoModels
+
+func loadAccountResponse(_ fileExtension: String) throws -> Data {
+    let url = Bundle.module.url(forResource: "account", withExtension: fileExtension)!
+    return try Data(contentsOf: url)
+}
+
+// Polytest Suite: Account
+
+// Polytest Group: Account Tests
+
+@Test("Account: decode msgpack")
+func accountDecodeMsgpack() throws {
+    let account = try decodeAccount(bytes: loadAccountResponse("msgpack"))
+
+    #expect(account.address.address == "S42GIXSHUKLVVFE537KRRLGFRWZSIRFD6LPCMG4QNNW5ZMHOAFMREKN6LQ")
+    #expect(account.amount == 12_345_678_901)
+    #expect(account.minBalance == 1_528_500)
+    #expect(account.status == .online)
+    #expect(account.signatureType == .single)
+    #expect(
+        account.authAddress?.address
+            == "XLHDWUMLF27E3SDXO7ZOXF3HORHONT72NWCXCCGPYSXFZM2QF4JOBRWH7U")
+
+    #expect(account.assets?[1] == AssetHolding(amount: 0, assetId: 3_000_000_001, isFrozen: true))
+    #expect(account.createdAssets?[0].params.unitName == "EXT")
+
+    let keyValue = try #require(account.appsLocalState?[0].keyValues?[1])
+    #expect(keyValue.key == Data([0, 0, 0, 0, 0, 0, 0, 7]))
+    #expect(keyValue.value.valueType == .bytes)
+    #expect(keyValue.value.bytes == Data([0xFF, 0xFE, 1]))
+
+    let params = try #require(account.createdApps?[0].params)
+    #expect(params.approvalProgram == Data([0x0A, 0x81, 0x01, 0x43]))
+    #expect(params.globalState?[0].value.uint == 42)
+}
+
+@Test("Account: decode json")
+func accountDecodeJson() throws {
+    let json = String(decoding: try loadAccountResponse("json"), as: UTF8.self)
+    #expect(try accountFromJson(json: json) == decodeAccount(bytes: loadAccountResponse("msgpack")))
+}
+
+@Test("Account: invalid json")
+func accountInvalidJson() throws {
+    do {
+        _ = try accountFromJson(json: "{\"address\": \"not an address\"}")
+        #expect(Bool(false), "Expected DecodingError to be thrown")
+    } catch AlgoModelsError.DecodingError(let message) {
+        #expect(message.contains("invalid account JSON"))
+    }
+}
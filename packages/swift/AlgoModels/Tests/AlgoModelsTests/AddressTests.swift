@@ -0,0 +1,95 @@
+import Foundation
+import Testing
+
+@testable import AlgoModels
+
+let zeroAddressString = "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAY5HFKQ"
+let app77AddressString = "PCYUFPA2ZTOYWTP43MX2MOX2OWAIAXUDNC2WFCXAGMRUZ3DYD6BWFDL5YM"
+
+// Polytest Suite: Address
+
+// Polytest Group: Address Tests
+
+@Test("Address: from domain hash")
+func addressFromDomainHashTest() throws {
+    var appId = UInt64(77).bigEndian
+    let addr = addressFromDomainHash(
+        prefix: "appID".data(using: .utf8)!,
+        bytes: Data(bytes: &appId, count: 8)
+    )
+    #expect(addr.address == app77AddressString)
+}
+
+@Test("Address: from app id")
+func addressFromAppIdTest() throws {
+    #expect(addressFromAppId(appId: 77).address == app77AddressString)
+    #expect(
+        addressFromAppId(appId: 1_284_326_447).address
+            == "JP3ENKDQC2BOYRMLFGKBS7RB2IVNF7VNHCFHVTRNHOENRQ6R4UN7MCNXPI")
+}
+
+@Test("Address: reject inconsistent address")
+func addressRejectInconsistentAddress() throws {
+    let testData = try loadTestData()
+    let transaction = makeTransaction(from: testData)
+    let inconsistent = Address(
+        address: transaction.header.sender.address,
+        pubKey: transaction.payFields!.receiver.pubKey
+    )
+
+    do {
+        _ = try addressChecksum(address: inconsistent)
+        #expect(Bool(false), "Expected EncodingError to be thrown")
+    } catch AlgoModelsError.EncodingError(let message) {
+        #expect(message.contains("does not match"))
+    }
+
+    var badTransaction = transaction
+    badTransaction.header.sender = inconsistent
+    do {
+        _ = try encodeTransaction(tx: badTransaction)
+        #expect(Bool(false), "Expected EncodingError to be thrown")
+    } catch AlgoModelsError.EncodingError(let message) {
+        #expect(message.contains("does not match"))
+    }
+}
+
+@Test("Address: compare addresses")
+func addressCompareAddresses() throws {
+    let testData = try loadTestData()
+    let transaction = makeTransaction(from: testData)
+    let sender = transaction.header.sender
+    let receiver = transaction.payFields!.receiver
+
+    #expect(try addressEquals(a: sender, b: try addressFromString(address: sender.address)))
+    #expect(try addressEquals(a: sender, b: try addressFromPubKey(pubKey: sender.pubKey)))
+    #expect(try !addressEquals(a: sender, b: receiver))
+}
+
+@Test("Address: get checksum")
+func addressGetChecksum() throws {
+    let checksum = try addressChecksum(address: zeroAddress())
+    #expect([UInt8](checksum) == [12, 116, 229, 84])
+}
+
+@Test("Address: zero address")
+func addressZeroAddress() throws {
+    let zero = zeroAddress()
+    #expect(zero.address == zeroAddressString)
+    #expect(zero.pubKey == Data(repeating: 0, count: 32))
+}
+
+@Test("Address: is invalid address")
+func addressIsInvalidAddress() throws {
+    #expect(!isValidAddress(address: ""))
+    #expect(!isValidAddress(address: String(zeroAddressString.dropLast())))
+    #expect(!isValidAddress(address: zeroAddressString.lowercased()))
+    #expect(!isValidAddress(address: String(zeroAddressString.dropLast()) + "A"))
+}
+
+@Test("Address: is valid address")
+func addressIsValidAddress() throws {
+    let testData = try loadTestData()
+    #expect(isValidAddress(address: zeroAddressString))
+    #expect(isValidAddress(address: testData.transaction.header.sender.address))
+}
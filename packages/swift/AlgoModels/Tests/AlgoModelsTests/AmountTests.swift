@@ -0,0 +1,51 @@
+import Foundation
+import Testing
+
+@testable import AlgoModels
+
+func expectAmountError(_ expected: String, _ body: () throws -> Void) {
+    do {
+        try body()
+        #expect(Bool(false), "Expected AmountError to be thrown")
+    } catch AlgoModelsError.AmountError(let message) {
+        #expect(message.contains(expected))
+    } catch {
+        #expect(Bool(false), "Unexpected error: \(error)")
+    }
+}
+
+// Polytest Suite: Amount
+
+// Polytest Group: Amount Tests
+
+@Test("Amount: asset amounts")
+func amountAssetAmounts() throws {
+    #expect(formatAssetAmount(amount: 1050, decimals: 2) == "10.5")
+    #expect(formatAssetAmount(amount: 5, decimals: 0) == "5")
+    #expect(try parseAssetAmount(amount: "10.5", decimals: 2) == 1050)
+
+    expectAmountError("has more than 0 decimal places") {
+        _ = try parseAssetAmount(amount: "5.5", decimals: 0)
+    }
+}
+
+@Test("Amount: invalid algos")
+func amountInvalidAlgos() throws {
+    expectAmountError("is not a decimal amount") {
+        _ = try microAlgosFromAlgos(algos: "1.5 ALGO")
+    }
+    expectAmountError("has more than 6 decimal places") {
+        _ = try microAlgosFromAlgos(algos: "0.0000001")
+    }
+    expectAmountError("is too large") {
+        _ = try microAlgosFromAlgos(algos: "18446744073709.551616")
+    }
+}
+
+@Test("Amount: algos")
+func amountAlgos() throws {
+    #expect(try microAlgosFromAlgos(algos: "1.5") == 1_500_000)
+    #expect(try microAlgosFromAlgos(algos: "0.000001") == 1)
+    #expect(microAlgosToAlgos(amount: 1_500_000) == "1.5")
+    #expect(microAlgosToAlgos(amount: 2_000_000) == "2")
+}
@@ -0,0 +1,110 @@
+import Foundation
+import Testing
+
+@testable import AlgoModels
+
+func loadAppSpec(_ name: String) throws -> String {
+    let url = Bundle.module.url(forResource: name, withExtension: "json")!
+    return try String(contentsOf: url, encoding: .utf8)
+}
+
+// Polytest Suite: App Spec
+
+// Polytest Group: App Spec Tests
+
+@Test("App Spec: invalid json")
+func appSpecInvalidJson() throws {
+    do {
+        _ = try appSpecFromJson(json: "{}")
+        #expect(Bool(false), "Expected AppSpecError to be thrown")
+    } catch AlgoModelsError.AppSpecError(let message) {
+        #expect(message.contains("invalid app spec JSON"))
+    }
+
+    do {
+        _ = try appSpecValidate(json: "not json")
+        #expect(Bool(false), "Expected AppSpecError to be thrown")
+    } catch AlgoModelsError.AppSpecError(let message) {
+        #expect(message.contains("invalid app spec JSON"))
+    }
+}
+
+@Test("App Spec: validate")
+func appSpecValidateTest() throws {
+    let arc56Json = try loadAppSpec("app_spec.arc56")
+    #expect(try appSpecValidate(json: arc56Json) == [])
+    #expect(try appSpecValidate(json: try loadAppSpec("app_spec.arc32")) == [])
+
+    var spec = try JSONSerialization.jsonObject(with: Data(arc56Json.utf8)) as! [String: Any]
+    var methods = spec["methods"] as! [[String: Any]]
+    var args = methods[2]["args"] as! [[String: Any]]
+    args[0]["type"] = "uint65"
+    methods[2]["args"] = args
+    spec["methods"] = methods
+    var state = spec["state"] as! [String: Any]
+    var schema = state["schema"] as! [String: Any]
+    var local = schema["local"] as! [String: Any]
+    local["ints"] = 0
+    schema["local"] = local
+    state["schema"] = schema
+    spec["state"] = state
+    let invalid = String(data: try JSONSerialization.data(withJSONObject: spec), encoding: .utf8)!
+
+    #expect(
+        try appSpecValidate(json: invalid) == [
+            "argument 0 of method vote(uint65,bool,account)(uint64,uint64): invalid ABI type: uint65",
+            "local state has 1 keys, but the schema only allows 0",
+        ])
+}
+
+@Test("App Spec: convert to arc56 json")
+func appSpecConvertToArc56Json() throws {
+    let arc32Json = try loadAppSpec("app_spec.arc32")
+    let converted = try appSpecToArc56Json(json: arc32Json)
+
+    #expect(try appSpecFromJson(json: converted) == appSpecFromJson(json: arc32Json))
+    #expect(converted.contains("\"bareActions\""))
+}
+
+@Test("App Spec: parse arc32")
+func appSpecParseArc32() throws {
+    let spec = try appSpecFromJson(json: try loadAppSpec("app_spec.arc32"))
+
+    #expect(spec.name == "Calculator")
+    #expect(spec.networks == ["wGHE2Pwdvd7S12BL5FaOP20EGYesN73ktiC1qzkkit8=": 1234])
+    #expect(spec.structs["Point"]!.map { $0.name } == ["x", "y"])
+    #expect(spec.state.globalSchema.numUints == 1)
+    #expect(spec.state.keys.globalState["last"]!.valueType == "AVMUint64")
+
+    let add = spec.methods.first { $0.name == "add" }!
+    #expect(add.readonly == true)
+    #expect(add.actions.call == [.noOp])
+    #expect(add.args[1].defaultValue!.source == .global)
+
+    let scale = spec.methods.first { $0.name == "scale" }!
+    #expect(scale.args[0].structName == "Point")
+    #expect(scale.returns.structName == "Point")
+}
+
+@Test("App Spec: parse arc56")
+func appSpecParseArc56() throws {
+    let spec = try appSpecFromJson(json: try loadAppSpec("app_spec.arc56"))
+
+    #expect(spec.name == "Voting")
+    #expect(spec.arcs == [4, 56])
+    #expect(
+        spec.methods.map { $0.name } == [
+            "createApplication", "propose", "vote", "optIn", "tally", "latest",
+        ])
+    #expect(spec.methods[0].actions.create == [.noOp])
+    #expect(spec.bareActions.call == [.deleteApplication])
+
+    let window = spec.structs["Proposal"]![2]
+    #expect(window.fieldType == nil)
+    #expect(window.fields!.map { $0.name } == ["start", "end"])
+
+    #expect(spec.state.maps.boxes["proposals"]!.valueType == "Proposal")
+    #expect(spec.sourceInfo!.approval.sourceInfo[0].errorMessage == "proposal does not exist")
+    #expect(spec.compilerInfo!.compiler == .puya)
+    #expect(spec.templateVariables!["VOTING_PERIOD"]!.variableType == "uint64")
+}
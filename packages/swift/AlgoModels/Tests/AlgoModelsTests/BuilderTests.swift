@@ -0,0 +1,124 @@
+import Foundation
+import Testing
+
+@testable import AlgoModels
+
+let builderParams = SuggestedParams(
+    feePerByte: 0,
+    minFee: 1000,
+    firstValid: 1000,
+    lastValid: 2000,
+    genesisId: "testnet-v1.0",
+    genesisHash: Data(repeating: 7, count: 32),
+    consensusVersion: "future"
+)
+
+func builderAddresses() throws -> (Address, Address) {
+    (
+        try addressFromPubKey(pubKey: Data(repeating: 1, count: 32)),
+        try addressFromPubKey(pubKey: Data(repeating: 2, count: 32))
+    )
+}
+
+func expectBuilderError(_ expected: String, _ body: () throws -> Void) {
+    do {
+        try body()
+        #expect(Bool(false), "Expected BuilderError to be thrown")
+    } catch AlgoModelsError.BuilderError(let message) {
+        #expect(message.contains(expected))
+    } catch {
+        #expect(Bool(false), "Unexpected error: \(error)")
+    }
+}
+
+// Polytest Suite: Builder
+
+// Polytest Group: Builder Tests
+
+@Test("Builder: validity window")
+func builderValidityWindow() throws {
+    let (alice, bob) = try builderAddresses()
+    let fields = PayTransactionFields(receiver: bob, amount: 1000)
+
+    let txn = try buildPayment(
+        params: builderParams,
+        options: TransactionOptions(sender: alice, firstValid: 1500, lastValid: 1510),
+        fields: fields)
+    #expect(txn.header.firstValid == 1500)
+    #expect(txn.header.lastValid == 1510)
+
+    expectBuilderError("validity window of 1001 rounds exceeds the maximum of 1000") {
+        _ = try buildPayment(
+            params: builderParams,
+            options: TransactionOptions(sender: alice, firstValid: 999),
+            fields: fields)
+    }
+}
+
+@Test("Builder: application call")
+func builderApplicationCall() throws {
+    let (alice, _) = try builderAddresses()
+    // #pragma version 10, followed by enough pushint 1 to need an extra page
+    var approval = Data([0x0A])
+    for _ in 0..<1100 {
+        approval.append(contentsOf: [0x81, 0x01])
+    }
+
+    let txn = try buildApplicationCall(
+        params: builderParams,
+        options: TransactionOptions(sender: alice),
+        fields: ApplicationCallTransactionFields(
+            appId: 0,
+            onComplete: .noOp,
+            approvalProgram: approval,
+            clearStateProgram: Data([0x0A, 0x81, 0x01]),
+            extraProgramPages: 0
+        ))
+
+    #expect(txn.header.transactionType == .applicationCall)
+    #expect(txn.applicationCallFields?.extraProgramPages == 1)
+
+    expectBuilderError("programs is required") {
+        _ = try buildApplicationCall(
+            params: builderParams,
+            options: TransactionOptions(sender: alice),
+            fields: ApplicationCallTransactionFields(
+                appId: 0, onComplete: .noOp, extraProgramPages: 0))
+    }
+}
+
+@Test("Builder: asset transfer")
+func builderAssetTransfer() throws {
+    let (alice, bob) = try builderAddresses()
+
+    let txn = try buildAssetTransfer(
+        params: builderParams,
+        options: TransactionOptions(
+            sender: alice,
+            feeOptions: FeeOptions(signature: .single, coveredTransactions: 2)),
+        fields: AssetTransferTransactionFields(assetId: 1234, amount: 5, receiver: bob))
+
+    #expect(txn.header.transactionType == .assetTransfer)
+    #expect(txn.header.fee == 3000)
+    #expect(txn.assetTransferFields?.assetId == 1234)
+}
+
+@Test("Builder: payment")
+func builderPayment() throws {
+    let (alice, bob) = try builderAddresses()
+
+    let txn = try buildPayment(
+        params: builderParams,
+        options: TransactionOptions(sender: alice),
+        fields: PayTransactionFields(receiver: bob, amount: 1000))
+
+    #expect(txn.header.transactionType == .payment)
+    #expect(txn.header.sender == alice)
+    #expect(txn.header.fee == 1000)
+    #expect(txn.header.firstValid == 1000)
+    #expect(txn.header.lastValid == 2000)
+    #expect(txn.header.genesisId == "testnet-v1.0")
+    #expect(txn.header.genesisHash == Data(repeating: 7, count: 32))
+    #expect(txn.payFields?.receiver == bob)
+    #expect(txn.payFields?.amount == 1000)
+}
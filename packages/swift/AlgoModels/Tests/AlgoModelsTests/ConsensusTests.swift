@@ -0,0 +1,42 @@
+import Foundation
+import Testing
+
+@testable import AlgoModels
+
+let currentConsensusVersion =
+    "https://github.com/algorandfoundation/specs/tree/953304de35264fc3ef91bcd05c123242015eeaed"
+
+// Polytest Suite: Consensus
+
+// Polytest Group: Consensus Tests
+
+@Test("Consensus: upgrades")
+func consensusUpgrades() throws {
+    let v17 = try #require(consensusParams(version: "v17"))
+    #expect(v17.maxTxGroupSize == 1)
+    #expect(v17.logicSigVersion == 0)
+
+    let v28 = try #require(consensusParams(version: "v28"))
+    #expect(v28.maxAppProgramLen == 2048)
+    #expect(v28.maxExtraAppProgramPages == 3)
+    #expect(v28.maxBoxSize == 0)
+
+    let current = currentConsensusParams()
+    #expect(current.maxTxGroupSize == 16)
+    #expect(current.maxBoxSize == 32768)
+}
+
+@Test("Consensus: version lookup")
+func consensusVersionLookup() throws {
+    #expect(consensusParams(version: currentConsensusVersion) == currentConsensusParams())
+    #expect(consensusParams(version: "v41") == currentConsensusParams())
+    #expect(consensusParams(version: "v6") == nil)
+    #expect(consensusParams(version: "unknown") == nil)
+}
+
+@Test("Consensus: versions")
+func consensusVersions() throws {
+    let versions = AlgoModels.consensusVersions()
+    #expect(versions.first == "v7")
+    #expect(versions.last == currentConsensusVersion)
+}
@@ -0,0 +1,89 @@
+import Foundation
+import Testing
+
+@testable import AlgoModels
+
+func suggestedParams(feePerByte: UInt64, genesisHash: Data) -> SuggestedParams {
+    SuggestedParams(
+        feePerByte: feePerByte,
+        minFee: 1000,
+        firstValid: 49_265_002,
+        lastValid: 49_266_002,
+        genesisId: "testnet-v1.0",
+        genesisHash: genesisHash,
+        consensusVersion: "future"
+    )
+}
+
+func feeOptions(flatFee: UInt64? = nil, coveredTransactions: UInt64 = 0) -> FeeOptions {
+    FeeOptions(signature: .single, flatFee: flatFee, coveredTransactions: coveredTransactions)
+}
+
+// Polytest Suite: Fee
+
+// Polytest Group: Fee Tests
+
+@Test("Fee: fee pooling")
+func feeFeePooling() throws {
+    let transaction = makeTransaction(from: try loadTestData())
+    let genesisHash = transaction.header.genesisHash!
+    let covering = feeOptions(coveredTransactions: 3)
+
+    #expect(
+        try estimateTransactionFee(
+            tx: transaction, params: suggestedParams(feePerByte: 0, genesisHash: genesisHash),
+            options: covering) == 4000)
+    #expect(
+        try estimateTransactionFee(
+            tx: transaction, params: suggestedParams(feePerByte: 10, genesisHash: genesisHash),
+            options: covering) == 5450)
+}
+
+@Test("Fee: flat fee")
+func feeFlatFee() throws {
+    let transaction = makeTransaction(from: try loadTestData())
+
+    #expect(
+        try estimateTransactionFee(
+            tx: transaction,
+            params: suggestedParams(feePerByte: 10, genesisHash: transaction.header.genesisHash!),
+            options: feeOptions(flatFee: 5000)) == 5000)
+}
+
+@Test("Fee: fee per byte")
+func feeFeePerByte() throws {
+    let transaction = makeTransaction(from: try loadTestData())
+
+    #expect(
+        try estimateTransactionFee(
+            tx: transaction,
+            params: suggestedParams(feePerByte: 10, genesisHash: transaction.header.genesisHash!),
+            options: feeOptions()) == 2450)
+}
+
+@Test("Fee: minimum fee")
+func feeMinimumFee() throws {
+    let transaction = makeTransaction(from: try loadTestData())
+
+    #expect(
+        try estimateTransactionFee(
+            tx: transaction,
+            params: suggestedParams(feePerByte: 0, genesisHash: transaction.header.genesisHash!),
+            options: feeOptions()) == 1000)
+}
+
+@Test("Fee: signed size")
+func feeSignedSize() throws {
+    let testData = try loadTestData()
+    let transaction = makeTransaction(from: testData)
+
+    #expect(
+        try estimateSignedTransactionSize(tx: transaction, signature: .single)
+            == UInt64(testData.expectedSignedTxn.count))
+    #expect(
+        try estimateSignedTransactionSize(tx: transaction, signature: .multisig(subsigs: 2))
+            == 409)
+    #expect(
+        try estimateSignedTransactionSize(
+            tx: transaction, signature: .logicSig(programSize: 100, argSizes: [])) == 285)
+}
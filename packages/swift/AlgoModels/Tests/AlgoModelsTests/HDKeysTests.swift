@@ -0,0 +1,102 @@
+import Foundation
+import Testing
+import ed25519swift
+
+@testable import AlgoModels
+
+func hexData(_ hex: String) -> Data {
+    var data = Data()
+    var index = hex.startIndex
+    while index < hex.endIndex {
+        let next = hex.index(index, offsetBy: 2)
+        data.append(UInt8(hex[index..<next], radix: 16)!)
+        index = next
+    }
+    return data
+}
+
+// BIP-39 seed of the ARC-52 test mnemonic "salon zoo engage submit smile frost later decide
+// wing sight chaos renew lizard rely canal coral scene hobby scare step bus leaf tobacco slice"
+let arc52Seed = hexData(
+    "3aff2db416b895ec3cf9a4f8d1e970bc9819920e7bf44a5e350477af0ef557b1"
+        + "511b0986debf78dd38c7c520cd44ff7c7231618f958e21ef0250733a8c1915ea")
+let arc52RootKey = hexData(
+    "a8ba80028922d9fcfa055c78aede55b5c575bcd8d5a53168edf45f36d9ec8f46"
+        + "94592b4bc892907583e22669ecdf1b0409a9f3bd5549f2dd751b51360909cd05"
+        + "796b9206ec30e142e94b790a98805bf999042b55046963174ee6cee2d0375946")
+let hardenedOffset: UInt32 = 0x8000_0000
+
+func arc52PublicKey(context: KeyContext, account: UInt32, keyIndex: UInt32) throws -> Data {
+    let path = hdBip44Path(context: context, account: account, change: 0, keyIndex: keyIndex)
+    let key = try hdDerivePrivateKey(
+        extendedPrivateKey: arc52RootKey, path: path, derivationType: .peikert)
+    return try hdExtendedPublicKey(extendedPrivateKey: key).prefix(32)
+}
+
+// Polytest Suite: HD Keys
+
+// Polytest Group: HD Key Tests
+
+@Test("HD Keys: sign with derived key")
+func hDKeysSignWithDerivedKey() throws {
+    let path = hdBip44Path(context: .address, account: 0, change: 0, keyIndex: 0)
+    let key = try hdDerivePrivateKey(
+        extendedPrivateKey: arc52RootKey, path: path, derivationType: .peikert)
+    let data = "arc52 signing test".data(using: .utf8)!
+
+    let sig = try hdSign(extendedPrivateKey: key, data: data)
+    let pubKey = try hdExtendedPublicKey(extendedPrivateKey: key).prefix(32)
+    #expect(Ed25519.verify(signature: [UInt8](sig), message: [UInt8](data), publicKey: [UInt8](pubKey)))
+}
+
+@Test("HD Keys: hardened public derivation")
+func hDKeysHardenedPublicDerivation() throws {
+    let xpub = try hdExtendedPublicKey(extendedPrivateKey: arc52RootKey)
+    do {
+        _ = try hdDerivePublicKey(
+            extendedPublicKey: xpub, path: [hardenedOffset], derivationType: .peikert)
+        #expect(Bool(false), "Expected KeyDerivationError to be thrown")
+    } catch AlgoModelsError.KeyDerivationError(let message) {
+        #expect(message.contains("hardened index"))
+    }
+}
+
+@Test("HD Keys: public derivation")
+func hDKeysPublicDerivation() throws {
+    let accountPath = Array(hdBip44Path(context: .address, account: 0, change: 0, keyIndex: 0).prefix(3))
+    let account = try hdDerivePrivateKey(
+        extendedPrivateKey: arc52RootKey, path: accountPath, derivationType: .peikert)
+    let xpub = try hdExtendedPublicKey(extendedPrivateKey: account)
+
+    let fromPrivate = try hdExtendedPublicKey(
+        extendedPrivateKey: try hdDerivePrivateKey(
+            extendedPrivateKey: account, path: [0, 7], derivationType: .peikert))
+    let fromPublic = try hdDerivePublicKey(
+        extendedPublicKey: xpub, path: [0, 7], derivationType: .peikert)
+    #expect(fromPrivate == fromPublic)
+}
+
+@Test("HD Keys: derive identity key")
+func hDKeysDeriveIdentityKey() throws {
+    #expect(
+        try arc52PublicKey(context: .identity, account: 0, keyIndex: 0)
+            == hexData("ff8b1863ef5e40d0a48c245f26a6dbdf5da94dc75a1851f51d8a04e547bd5f5a"))
+}
+
+@Test("HD Keys: derive address keys")
+func hDKeysDeriveAddressKeys() throws {
+    #expect(
+        try arc52PublicKey(context: .address, account: 0, keyIndex: 0)
+            == hexData("7bda7ac12627b2c259f1df6875d30c10b35f55b33ad2cc8ea2736eaa3ebcfab9"))
+    #expect(
+        try arc52PublicKey(context: .address, account: 0, keyIndex: 1)
+            == hexData("5bae8828f111064637ac5061bd63bc4fcfe4a833252305f25eeab9c64ecdf519"))
+    #expect(
+        try arc52PublicKey(context: .address, account: 1, keyIndex: 0)
+            == hexData("358d8c4382992849a764438e02b1c45c2ca4e86bbcfe10fd5b963f3610012bc9"))
+}
+
+@Test("HD Keys: root key from seed")
+func hDKeysRootKeyFromSeed() throws {
+    #expect(hdRootKeyFromSeed(seed: arc52Seed) == arc52RootKey)
+}
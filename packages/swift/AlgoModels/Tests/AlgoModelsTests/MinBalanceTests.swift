@@ -0,0 +1,62 @@
+import Foundation
+import Testing
+
+@testable import AlgoModels
+
+// An application's account holding an asset and two boxes, whose minimum balance was worked out by
+// hand from go-algorand's formula
+func loadAppAccountResponse() throws -> Data {
+    let url = Bundle.module.url(forResource: "app_account", withExtension: "msgpack")!
+    return try Data(contentsOf: url)
+}
+
+// Polytest Suite: Min Balance
+
+// Polytest Group: Min Balance Tests
+
+@Test("Min Balance: account")
+func minBalanceAccount() throws {
+    let account = try decodeAccount(bytes: loadAppAccountResponse())
+    let totals = accountMinBalanceTotals(account: account)
+    #expect(totals.assets == 1)
+    #expect(totals.boxes == 2)
+    #expect(totals.boxBytes == 1064)
+    #expect(account.minBalance == 630_600)
+    #expect(minBalance(totals: totals, params: currentConsensusParams()) == account.minBalance)
+}
+
+@Test("Min Balance: opt ins and apps")
+func minBalanceOptInsAndApps() throws {
+    let account = try decodeAccount(bytes: loadAppAccountResponse())
+    var totals = accountMinBalanceTotals(account: account)
+    totals.assets += 2
+    #expect(minBalance(totals: totals, params: currentConsensusParams()) == 830_600)
+
+    // 100,000 for the app, 4 * 28,500 for the integers, 2 * 50,000 for the byte slices and
+    // 100,000 for the extra page
+    totals.createdApps += 1
+    totals.schema.numUints += 4
+    totals.schema.numByteSlices += 2
+    totals.extraAppPages += 1
+    #expect(minBalance(totals: totals, params: currentConsensusParams()) == 1_244_600)
+}
+
+@Test("Min Balance: boxes")
+func minBalanceBoxes() throws {
+    let totals = MinBalanceTotals(
+        assets: 0,
+        createdApps: 0,
+        optedInApps: 0,
+        schema: ApplicationStateSchema(numUints: 0, numByteSlices: 0),
+        extraAppPages: 0,
+        boxes: 1,
+        boxBytes: 4 + 1024
+    )
+    #expect(
+        minBalance(totals: totals, params: currentConsensusParams()) == 100_000 + 2_500 + 400 * 1028
+    )
+
+    // Boxes cost nothing before they were introduced
+    let v34 = try #require(consensusParams(version: "v34"))
+    #expect(minBalance(totals: totals, params: v34) == 100_000)
+}
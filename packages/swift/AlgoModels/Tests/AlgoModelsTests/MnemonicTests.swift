@@ -0,0 +1,72 @@
+import Foundation
+import Testing
+
+@testable import AlgoModels
+
+let zeroMnemonic = (Array(repeating: "abandon", count: 24) + ["invest"]).joined(separator: " ")
+let testMnemonic =
+    "advice pudding treat near rule blouse same whisper inner electric quit surface "
+    + "sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor"
+let testSeed: [UInt8] = [
+    32, 72, 171, 207, 57, 153, 222, 96, 220, 151, 250, 163, 219, 145, 95, 163,
+    189, 108, 253, 208, 239, 23, 18, 6, 75, 97, 252, 244, 182, 149, 136, 86,
+]
+
+// Polytest Suite: Mnemonic
+
+// Polytest Group: Mnemonic Tests
+
+@Test("Mnemonic: invalid seed length")
+func mnemonicInvalidSeedLength() throws {
+    do {
+        _ = try seedToMnemonic(seed: Data(count: 31))
+        #expect(Bool(false), "Expected MnemonicError to be thrown")
+    } catch AlgoModelsError.MnemonicError(let message) {
+        #expect(message == "seed must be 32 bytes, but got 31")
+    }
+}
+
+@Test("Mnemonic: invalid checksum")
+func mnemonicInvalidChecksum() throws {
+    do {
+        _ = try mnemonicToSeed(
+            mnemonic: testMnemonic.replacingOccurrences(of: "sponsor", with: "abandon"))
+        #expect(Bool(false), "Expected MnemonicError to be thrown")
+    } catch AlgoModelsError.MnemonicError(let message) {
+        #expect(message == "mnemonic checksum word is invalid")
+    }
+}
+
+@Test("Mnemonic: unknown word")
+func mnemonicUnknownWord() throws {
+    do {
+        _ = try mnemonicToSeed(
+            mnemonic: testMnemonic.replacingOccurrences(of: "pudding", with: "puddingg"))
+        #expect(Bool(false), "Expected MnemonicError to be thrown")
+    } catch AlgoModelsError.MnemonicError(let message) {
+        #expect(message == "unknown word in mnemonic: puddingg")
+    }
+}
+
+@Test("Mnemonic: wrong length")
+func mnemonicWrongLength() throws {
+    let short = testMnemonic.split(separator: " ").dropLast().joined(separator: " ")
+    do {
+        _ = try mnemonicToSeed(mnemonic: short)
+        #expect(Bool(false), "Expected MnemonicError to be thrown")
+    } catch AlgoModelsError.MnemonicError(let message) {
+        #expect(message == "mnemonic must be 25 words, but got 24")
+    }
+}
+
+@Test("Mnemonic: mnemonic to seed")
+func mnemonicMnemonicToSeed() throws {
+    #expect([UInt8](try mnemonicToSeed(mnemonic: testMnemonic)) == testSeed)
+    #expect(try mnemonicToSeed(mnemonic: zeroMnemonic) == Data(count: 32))
+}
+
+@Test("Mnemonic: seed to mnemonic")
+func mnemonicSeedToMnemonic() throws {
+    #expect(try seedToMnemonic(seed: Data(testSeed)) == testMnemonic)
+    #expect(try seedToMnemonic(seed: Data(count: 32)) == zeroMnemonic)
+}
@@ -0,0 +1,214 @@
+import Foundation
+import Testing
+
+@testable import AlgoModels
+
+// #pragma version 2; int 1
+let int1Program = Data([0x02, 0x20, 0x01, 0x01, 0x22])
+let int1ProgramAddress = "YOE6C22GHCTKAN3HU4SE5PGIPN5UKXAJTXCQUPJ3KKF5HOAH646MKKCPDA"
+
+/// A version 2 program padded out to the given size with err opcodes
+func sizedProgram(_ size: Int) -> Data {
+    var program = Data(count: size)
+    program[0] = 0x02
+    return program
+}
+
+func expectProgramError(_ expected: String, _ body: () throws -> Void) {
+    do {
+        try body()
+        #expect(Bool(false), "Expected ProgramError to be thrown")
+    } catch AlgoModelsError.ProgramError(let message) {
+        #expect(message.contains(expected))
+    } catch {
+        #expect(Bool(false), "Unexpected error: \(error)")
+    }
+}
+
+// Polytest Suite: Program
+
+// Polytest Group: Program Tests
+
+@Test("Program: extra pages")
+func programExtraPagesTest() throws {
+    #expect(try programExtraPages(approval: sizedProgram(2000), clearState: sizedProgram(48)) == 0)
+    #expect(try programExtraPages(approval: sizedProgram(2000), clearState: sizedProgram(49)) == 1)
+    #expect(try programExtraPages(approval: sizedProgram(8000), clearState: sizedProgram(192)) == 3)
+
+    expectProgramError("exceeds the limit of 8192 bytes") {
+        _ = try programExtraPages(approval: sizedProgram(8000), clearState: sizedProgram(193))
+    }
+}
+
+@Test("Program: logic sig size")
+func programLogicSigSize() throws {
+    try programCheckLogicSigSize(program: sizedProgram(990), argsSize: 10)
+
+    expectProgramError("logic signature is 1001 bytes, which exceeds the limit of 1000 bytes") {
+        try programCheckLogicSigSize(program: sizedProgram(990), argsSize: 11)
+    }
+}
+
+@Test("Program: address")
+func programAddress() throws {
+    #expect(try addressFromProgram(program: int1Program).address == int1ProgramAddress)
+    #expect(try programHash(program: int1Program) == addressFromProgram(program: int1Program).pubKey)
+}
+
+@Test("Program: branch targets")
+func programBranchTargets() throws {
+    // b 1, which lands in the middle of pushint 1
+    expectProgramError("not the start of an instruction") {
+        _ = try programVersion(program: Data([0x04, 0x42, 0x00, 0x01, 0x81, 0x01]))
+    }
+
+    // l1: pushint 1; bnz l1
+    expectProgramError("branches backwards") {
+        _ = try programVersion(program: Data([0x03, 0x81, 0x01, 0x40, 0xFF, 0xFB]))
+    }
+    #expect(try programVersion(program: Data([0x04, 0x81, 0x01, 0x40, 0xFF, 0xFB])) == 4)
+}
+
+@Test("Program: unavailable opcode")
+func programUnavailableOpcode() throws {
+    // pushint 1; box_create
+    expectProgramError("box_create at pc 3 requires version 8") {
+        _ = try programVersion(program: Data([0x05, 0x81, 0x01, 0xB9]))
+    }
+
+    #expect(try programVersion(program: Data([0x08, 0x81, 0x01, 0xB9])) == 8)
+}
+
+@Test("Program: invalid bytecode")
+func programInvalidBytecode() throws {
+    expectProgramError("unknown opcode 0x87 at pc 1") {
+        _ = try programVersion(program: Data([0x0B, 0x87]))
+    }
+    expectProgramError("truncated immediate arguments") {
+        _ = try programVersion(program: Data([0x03, 0x80, 0x05, 0x01]))
+    }
+    expectProgramError("program is empty") {
+        _ = try programVersion(program: Data())
+    }
+}
+
+@Test("Program: version")
+func programVersionTest() throws {
+    #expect(try programVersion(program: int1Program) == 2)
+    #expect(try programVersion(program: Data([0x0B])) == 11)
+
+    expectProgramError("version 12 is not supported") {
+        _ = try programVersion(program: Data([0x0C]))
+    }
+}
+
+// Polytest Group: Disassembler Tests
+
+@Test("Program: fields")
+func programFields() throws {
+    // gtxna 1 ApplicationArgs 0; acct_params_get AcctBalance; block BlkProposer
+    let program = Data([0x0B, 0x37, 0x01, 0x1A, 0x00, 0x73, 0x00, 0xD1, 0x06])
+
+    #expect(
+        try programDisassemble(program: program) == """
+            #pragma version 11
+            gtxna 1 ApplicationArgs 0
+            acct_params_get AcctBalance
+            block BlkProposer
+
+            """)
+}
+
+@Test("Program: labels")
+func programLabels() throws {
+    // l1: pushint 1; bnz l2; b l1; l2: retsub
+    let program = Data([0x04, 0x81, 0x01, 0x40, 0x00, 0x03, 0x42, 0xFF, 0xF8, 0x89])
+
+    #expect(
+        try programDisassemble(program: program) == """
+            #pragma version 4
+            label2:
+            pushint 1
+            bnz label1
+            b label2
+            label1:
+            retsub
+
+            """)
+}
+
+@Test("Program: constants")
+func programConstants() throws {
+    // intcblock 1 1000; bytecblock "hi" 0xff00; intc_1; bytec_0; bytec 1
+    let program = Data([
+        0x02, 0x20, 0x02, 0x01, 0xE8, 0x07, 0x26, 0x02, 0x02, 0x68, 0x69, 0x02, 0xFF, 0x00,
+        0x23, 0x28, 0x27, 0x01,
+    ])
+
+    #expect(
+        try programDisassemble(program: program) == """
+            #pragma version 2
+            intcblock 1 1000
+            bytecblock 0x6869 0xff00
+            intc_1 // 1000
+            bytec_0 // "hi"
+            bytec 1 // 0xff00
+
+            """)
+}
+
+// Polytest Group: Assembler Tests
+
+@Test("Program: assembly errors")
+func programAssemblyErrors() throws {
+    expectProgramError("line 2: box_create requires version 8, but the program is version 3") {
+        _ = try programAssemble(source: "#pragma version 3\nbox_create")
+    }
+
+    expectProgramError("line 2: reference to undefined label missing") {
+        _ = try programAssemble(source: "#pragma version 8\nb missing")
+    }
+}
+
+@Test("Program: labels and macros")
+func programLabelsAndMacros() throws {
+    let source = """
+        #pragma version 8
+        #define ARGS txn NumAppArgs
+        ARGS
+        bz create
+        int 0; return
+        create:
+        int 1
+        return
+
+        """
+
+    #expect(
+        try programAssemble(source: source)
+            == Data([0x08, 0x31, 0x1B, 0x41, 0x00, 0x03, 0x81, 0x00, 0x43, 0x81, 0x01, 0x43]))
+}
+
+@Test("Program: constant optimization")
+func programConstantOptimization() throws {
+    let source = """
+        #pragma version 4
+        int 7
+        int 1
+        int 1
+        int 2
+        int 2
+        int 2
+        byte "x"
+        method "add(uint64,uint64)uint128"
+        byte "x"
+
+        """
+
+    #expect(
+        try programAssemble(source: source)
+            == Data([
+                0x04, 0x20, 0x02, 0x02, 0x01, 0x26, 0x01, 0x01, 0x78, 0x81, 0x07, 0x23, 0x23,
+                0x22, 0x22, 0x22, 0x28, 0x80, 0x04, 0x8A, 0xA3, 0xB6, 0x1F, 0x28,
+            ]))
+}
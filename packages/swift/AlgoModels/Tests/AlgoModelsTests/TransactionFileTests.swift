@@ -0,0 +1,58 @@
+import Foundation
+import Testing
+
+@testable import AlgoModels
+
+func loadPaymentStxn(_ fileExtension: String = "stxn") throws -> Data {
+    let url = Bundle.module.url(forResource: "payment", withExtension: fileExtension)!
+    return try Data(contentsOf: url)
+}
+
+func loadMultisigStxn() throws -> Data {
+    let url = Bundle.module.url(forResource: "multisig", withExtension: "stxn")!
+    return try Data(contentsOf: url)
+}
+
+// Polytest Suite: Transaction File
+
+// Polytest Group: Transaction File Tests
+
+@Test("Transaction File: read signed file")
+func transactionFileReadSignedFile() throws {
+    let file = try loadPaymentStxn()
+    let transactions = try readTransactionFile(bytes: file)
+    #expect(transactions.count == 1)
+    #expect(transactions[0].signature?.count == 64)
+    #expect(transactions[0].transaction.payFields?.amount == 10_000_000)
+
+    #expect(try writeTransactionFile(transactions: transactions) == file)
+}
+
+@Test("Transaction File: unsigned file")
+func transactionFileUnsignedFile() throws {
+    let signed = try readTransactionFile(bytes: loadPaymentStxn())[0]
+    let file = try writeUnsignedTransactionFile(transactions: [signed.transaction])
+    #expect(Array(file.prefix(5)) == [0x81, 0xa3, 0x74, 0x78, 0x6e])
+
+    let unsigned = try readTransactionFile(bytes: file)[0]
+    #expect(unsigned.signature == nil)
+    #expect(unsigned.transaction == signed.transaction)
+}
+
+@Test("Transaction File: inspect")
+func transactionFileInspect() throws {
+    let expected = String(decoding: try loadPaymentStxn("stxn.inspect"), as: UTF8.self)
+    #expect(try inspectTransactionFile(fileName: "payment.stxn", bytes: loadPaymentStxn()) == expected)
+}
+
+@Test("Transaction File: multisig file")
+func transactionFileMultisigFile() throws {
+    let file = try loadMultisigStxn()
+    let stxn = try readTransactionFile(bytes: file)[0]
+    #expect(stxn.signature == nil)
+    #expect(stxn.multisigSignature?.threshold == 2)
+    #expect(stxn.multisigSignature?.subsignatures.map { $0.signature != nil } == [true, false, true])
+    #expect(stxn.authAddress?.address == "KUIRTXLNEOOP7FL34O2SWPD6MI3R7UN6T6HTXNGQS5R5S3PKUTFXCJE6LE")
+
+    #expect(try writeTransactionFile(transactions: [stxn]) == file)
+}
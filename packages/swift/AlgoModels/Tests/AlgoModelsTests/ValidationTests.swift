@@ -0,0 +1,57 @@
+import Foundation
+import Testing
+
+@testable import AlgoModels
+
+// Polytest Suite: Validation
+
+// Polytest Group: Validation Tests
+
+@Test("Validation: consensus params")
+func validationConsensusParams() throws {
+    var transaction = makeTransaction(from: try loadTestData())
+    transaction.header.note = Data(count: 2000)
+    var params = currentConsensusParams()
+    #expect(params.maxTxnNoteBytes == 1024)
+    #expect(try validateTransaction(tx: transaction, params: params).count == 1)
+
+    params.maxTxnNoteBytes = 2048
+    #expect(try validateTransaction(tx: transaction, params: params).isEmpty)
+}
+
+@Test("Validation: asset id")
+func validationAssetId() throws {
+    let payment = makeTransaction(from: try loadTestData())
+    var header = payment.header
+    header.transactionType = .assetTransfer
+    let transaction = Transaction(
+        header: header,
+        assetTransferFields: AssetTransferTransactionFields(
+            assetId: 0, amount: 1, receiver: payment.payFields!.receiver)
+    )
+
+    #expect(
+        try validateTransaction(tx: transaction, params: currentConsensusParams())
+            == ["asset transfers require a non-zero asset ID"])
+}
+
+@Test("Validation: violations")
+func validationViolations() throws {
+    var transaction = makeTransaction(from: try loadTestData())
+    let lastValid = transaction.header.lastValid
+    transaction.header.firstValid = lastValid + 1
+    transaction.header.note = Data(count: 1025)
+
+    #expect(
+        try validateTransaction(tx: transaction, params: currentConsensusParams()) == [
+            "last valid round \(lastValid) is before first valid round \(lastValid + 1)",
+            "note of 1025 bytes exceeds the maximum of 1024",
+        ])
+}
+
+@Test("Validation: valid transaction")
+func validationValidTransaction() throws {
+    let transaction = makeTransaction(from: try loadTestData())
+
+    #expect(try validateTransaction(tx: transaction, params: currentConsensusParams()).isEmpty)
+}
@@ -0,0 +1,267 @@
+import { expect, test, describe } from "bun:test";
+import {
+  AbiMethodArgValue,
+  AbiValue,
+  Transaction,
+  abiDecode,
+  abiEncode,
+  abiMethodCall,
+  abiMethodDecodeReturn,
+  abiMethodFromSignature,
+  abiMethodSelector,
+  abiTypeIsDynamic,
+  addressFromPubKey,
+} from "../src/index";
+
+const uint = (n: bigint | number): AbiValue => ({
+  type: "Uint",
+  value: n.toString(),
+});
+const string = (value: string): AbiValue => ({ type: "String", value });
+const bool = (value: boolean): AbiValue => ({ type: "Bool", value });
+const array = (...values: AbiValue[]): AbiValue => ({ type: "Array", values });
+
+const bytes = (...parts: (number[] | string)[]) =>
+  new Uint8Array(
+    parts.flatMap((p) =>
+      typeof p === "string" ? [...new TextEncoder().encode(p)] : p,
+    ),
+  );
+
+// (string,bool,bool,bool,bool,string) from the reference SDK test suites
+const BOOL_TUPLE = array(
+  string("AB"),
+  bool(true),
+  bool(false),
+  bool(true),
+  bool(false),
+  string("DE"),
+);
+const BOOL_TUPLE_BYTES = bytes(
+  [0x00, 0x05, 0xa0, 0x00, 0x09, 0x00, 0x02],
+  "AB",
+  [0x00, 0x02],
+  "DE",
+);
+
+const RETURN_PREFIX = [0x15, 0x1f, 0x7c, 0x75];
+const SENDER = addressFromPubKey(new Uint8Array(32).fill(1));
+const OTHER = addressFromPubKey(new Uint8Array(32).fill(2));
+
+const uint64 = (n: number) => {
+  const b = new Uint8Array(8);
+  new DataView(b.buffer).setBigUint64(0, BigInt(n));
+  return b;
+};
+
+const appCall = (appId: bigint): Transaction => ({
+  header: {
+    transactionType: "ApplicationCall",
+    sender: SENDER,
+    fee: 1000n,
+    firstValid: 1n,
+    lastValid: 1000n,
+  },
+  applicationCallFields: {
+    appId,
+    onComplete: "NoOp",
+    extraProgramPages: 0n,
+  },
+});
+
+const abiArg = (value: AbiValue): AbiMethodArgValue => ({
+  type: "AbiValue",
+  value,
+});
+
+describe("ABI", () => {
+  // Polytest Suite: ABI
+
+  describe("ABI Tests", () => {
+    // Polytest Group: ABI Tests
+
+    test("invalid value", () => {
+      expect(() => abiEncode("uint8", uint(256))).toThrow(
+        "AbiError: invalid value for ABI type uint8: 256 does not fit in 8 bits",
+      );
+      expect(() => abiEncode("(uint64,bool)", array(uint(1)))).toThrow(
+        "expected 2 fields",
+      );
+      expect(() => abiEncode("uint64", uint(-1))).toThrow(
+        "not an unsigned integer",
+      );
+    });
+
+    test("invalid type", () => {
+      for (const invalid of ["uint7", "ufixed64x0", "(uint64", "int64", "byte[01]"]) {
+        expect(() => abiTypeIsDynamic(invalid)).toThrow(
+          `AbiError: invalid ABI type: ${invalid}`,
+        );
+      }
+    });
+
+    test("is dynamic", () => {
+      expect(abiTypeIsDynamic("string")).toBe(true);
+      expect(abiTypeIsDynamic("(uint64,string[])")).toBe(true);
+      expect(abiTypeIsDynamic("bool[]")).toBe(true);
+      expect(abiTypeIsDynamic("(uint64,byte[32])")).toBe(false);
+      expect(abiTypeIsDynamic("address")).toBe(false);
+    });
+
+    test("decode tuple", () => {
+      expect(
+        abiDecode("(string,bool,bool,bool,bool,string)", BOOL_TUPLE_BYTES),
+      ).toEqual(BOOL_TUPLE);
+    });
+
+    test("encode dynamic tuple", () => {
+      expect(
+        abiEncode("(string,bool,bool,bool,bool,string)", BOOL_TUPLE),
+      ).toEqual(BOOL_TUPLE_BYTES);
+      expect(
+        abiEncode(
+          "(uint64,string[])",
+          array(uint(1), array(string("a"), string("bc"))),
+        ),
+      ).toEqual(
+        bytes(
+          [0, 0, 0, 0, 0, 0, 0, 1, 0x00, 0x0a, 0x00, 0x02, 0x00, 0x04, 0x00, 0x07],
+          [0x00, 0x01],
+          "a",
+          [0x00, 0x02],
+          "bc",
+        ),
+      );
+    });
+
+    test("encode bool packing", () => {
+      expect(abiEncode("bool", bool(true))).toEqual(bytes([0x80]));
+      expect(
+        abiEncode("bool[]", array(bool(true), bool(false), bool(true))),
+      ).toEqual(bytes([0x00, 0x03, 0xa0]));
+
+      const nine = array(...[...Array(9).keys()].map((i) => bool(i % 2 === 0)));
+      expect(abiEncode("bool[9]", nine)).toEqual(bytes([0xaa, 0x80]));
+    });
+
+    test("encode uint", () => {
+      expect(abiEncode("uint64", uint(1))).toEqual(
+        bytes([0, 0, 0, 0, 0, 0, 0, 1]),
+      );
+      expect(abiEncode("uint512", uint(2n ** 512n - 1n))).toEqual(
+        new Uint8Array(64).fill(0xff),
+      );
+      expect(abiDecode("uint512", new Uint8Array(64).fill(0xff))).toEqual(
+        uint(2n ** 512n - 1n),
+      );
+    });
+  });
+
+  describe("ABI Method Tests", () => {
+    // Polytest Group: ABI Method Tests
+
+    test("missing return value", () => {
+      expect(() =>
+        abiMethodDecodeReturn(
+          "add(uint64,uint64)uint128",
+          new TextEncoder().encode("debug"),
+        ),
+      ).toThrow("AbiError: no return value found");
+    });
+
+    test("decode return value", () => {
+      const log = bytes(RETURN_PREFIX, new Array(15).fill(0), [3]);
+      expect(abiMethodDecodeReturn("add(uint64,uint64)uint128", log)).toEqual(
+        uint(3),
+      );
+      expect(abiMethodDecodeReturn("noop()void", new Uint8Array())).toBe(
+        undefined,
+      );
+    });
+
+    test("more than 15 arguments", () => {
+      const signature = `many(${new Array(17).fill("uint64").join(",")})void`;
+      const txn = abiMethodCall(
+        appCall(1n),
+        signature,
+        [...Array(17).keys()].map((i) => abiArg(uint(i))),
+      );
+      const args = txn.applicationCallFields!.args!;
+
+      expect(args.length).toBe(16);
+      expect(args[14]).toEqual(uint64(13));
+      expect(args[15]).toEqual(bytes([...uint64(14), ...uint64(15), ...uint64(16)]));
+    });
+
+    test("reference arguments", () => {
+      const txn = abiMethodCall(
+        appCall(1234n),
+        "refs(account,account,asset,application,application,pay)void",
+        [
+          { type: "Account", value: SENDER },
+          { type: "Account", value: OTHER },
+          { type: "Asset", value: 10n },
+          { type: "Application", value: 1234n },
+          { type: "Application", value: 99n },
+          { type: "Transaction" },
+        ],
+      );
+      const fields = txn.applicationCallFields!;
+
+      expect(fields.accounts).toEqual([OTHER]);
+      expect(fields.foreignAssets).toEqual([10n]);
+      expect(fields.foreignApps).toEqual([99n]);
+      expect(fields.args!.slice(1)).toEqual(
+        [0, 1, 0, 0, 1].map((i) => bytes([i])),
+      );
+    });
+
+    test("method call", () => {
+      const txn = abiMethodCall(appCall(1234n), "add(uint64,uint64)uint128", [
+        abiArg(uint(1)),
+        abiArg(uint(2)),
+      ]);
+
+      expect(txn.applicationCallFields!.args).toEqual([
+        bytes([0x8a, 0xa3, 0xb6, 0x1f]),
+        uint64(1),
+        uint64(2),
+      ]);
+      expect(txn.header).toEqual(appCall(1234n).header);
+
+      expect(() =>
+        abiMethodCall(appCall(1234n), "add(uint64,uint64)uint128", [
+          abiArg(uint(1)),
+        ]),
+      ).toThrow("expected 2 arguments, but got 1");
+    });
+
+    test("parse signature", () => {
+      const method = abiMethodFromSignature(
+        "foo(string,(uint64,bool[]),pay,account)void",
+      );
+
+      expect(method.name).toBe("foo");
+      expect(method.args.map((a) => a.argType)).toEqual([
+        "string",
+        "(uint64,bool[])",
+        "pay",
+        "account",
+      ]);
+      expect(method.returns).toBeUndefined();
+      expect(abiMethodFromSignature("add(uint64,uint64)uint128").returns).toBe(
+        "uint128",
+      );
+
+      expect(() => abiMethodFromSignature("foo(uint64")).toThrow(
+        "AbiError: invalid ARC-4 method signature",
+      );
+    });
+
+    test("method selector", () => {
+      expect(abiMethodSelector("add(uint64,uint64)uint128")).toEqual(
+        bytes([0x8a, 0xa3, 0xb6, 0x1f]),
+      );
+    });
+  });
+});
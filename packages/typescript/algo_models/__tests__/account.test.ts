@@ -0,0 +1,57 @@
+import { expect, test, describe } from "bun:test";
+import * as path from "path";
+import { accountFromJson, decodeAccount } from "../src/index";
+
+const testDataDir = path.join(__dirname, "../../../../crates/algo_models/test_data");
+const accountJson = await Bun.file(path.join(testDataDir, "account.json")).text();
+const accountMsgpack = new Uint8Array(
+  await Bun.file(path.join(testDataDir, "account.msgpack")).arrayBuffer(),
+);
+
+describe("Account", () => {
+  // Polytest Suite: Account
+
+  describe("Account Tests", () => {
+    // Polytest Group: Account Tests
+
+    test("decode msgpack", () => {
+      const account = decodeAccount(accountMsgpack);
+
+      expect(account.address.address).toBe(
+        "S42GIXSHUKLVVFE537KRRLGFRWZSIRFD6LPCMG4QNNW5ZMHOAFMREKN6LQ",
+      );
+      expect([account.amount, account.minBalance]).toEqual([12345678901n, 1528500n]);
+      expect(account.status).toBe("Online");
+      expect(account.signatureType).toBe("Single");
+      expect(account.authAddress?.address).toBe(
+        "XLHDWUMLF27E3SDXO7ZOXF3HORHONT72NWCXCCGPYSXFZM2QF4JOBRWH7U",
+      );
+
+      expect(account.assets?.[1]).toEqual({
+        amount: 0n,
+        assetId: 3000000001n,
+        isFrozen: true,
+      });
+      expect(account.createdAssets?.[0].params.unitName).toBe("EXT");
+
+      const keyValue = account.appsLocalState![0].keyValues![1];
+      expect(keyValue.key).toEqual(new Uint8Array([0, 0, 0, 0, 0, 0, 0, 7]));
+      expect(keyValue.value.valueType).toBe("Bytes");
+      expect(keyValue.value.bytes).toEqual(new Uint8Array([0xff, 0xfe, 1]));
+
+      const params = account.createdApps![0].params;
+      expect(params.approvalProgram).toEqual(new Uint8Array([0x0a, 0x81, 0x01, 0x43]));
+      expect(params.globalState?.[0].value.uint).toBe(42n);
+    });
+
+    test("decode json", () => {
+      expect(accountFromJson(accountJson)).toEqual(decodeAccount(accountMsgpack));
+    });
+
+    test("invalid json", () => {
+      expect(() => accountFromJson('{"address": "not an address"}')).toThrow(
+        "DecodingError: invalid account JSON",
+      );
+    });
+  });
+});
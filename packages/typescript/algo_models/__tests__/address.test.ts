@@ -0,0 +1,97 @@
+import { expect, test, describe } from "bun:test";
+import { testData } from "./common.ts";
+import {
+  Address,
+  Transaction,
+  addressChecksum,
+  addressEquals,
+  addressFromAppId,
+  addressFromDomainHash,
+  addressFromPubKey,
+  addressFromString,
+  encodeTransaction,
+  isValidAddress,
+  zeroAddress,
+} from "../src/index";
+
+const ZERO_ADDRESS = "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAY5HFKQ";
+const APP_77_ADDRESS =
+  "PCYUFPA2ZTOYWTP43MX2MOX2OWAIAXUDNC2WFCXAGMRUZ3DYD6BWFDL5YM";
+
+const transaction: Transaction = testData.transaction;
+const sender: Address = transaction.header.sender;
+const receiver: Address = transaction.payFields!.receiver;
+
+describe("Address", () => {
+  // Polytest Suite: Address
+
+  describe("Address Tests", () => {
+    // Polytest Group: Address Tests
+
+    test("from domain hash", () => {
+      const appId = new Uint8Array(8);
+      new DataView(appId.buffer).setBigUint64(0, 77n);
+      const addr = addressFromDomainHash(
+        new TextEncoder().encode("appID"),
+        appId,
+      );
+      expect(addr.address).toBe(APP_77_ADDRESS);
+    });
+
+    test("from app id", () => {
+      expect(addressFromAppId(77n).address).toBe(APP_77_ADDRESS);
+      expect(addressFromAppId(1284326447n).address).toBe(
+        "JP3ENKDQC2BOYRMLFGKBS7RB2IVNF7VNHCFHVTRNHOENRQ6R4UN7MCNXPI",
+      );
+    });
+
+    test("reject inconsistent address", () => {
+      const inconsistent: Address = {
+        address: sender.address,
+        pubKey: receiver.pubKey,
+      };
+
+      expect(() => addressChecksum(inconsistent)).toThrow("does not match");
+      expect(() =>
+        encodeTransaction({
+          ...transaction,
+          header: { ...transaction.header, sender: inconsistent },
+        }),
+      ).toThrow("EncodingError");
+    });
+
+    test("compare addresses", () => {
+      expect(addressEquals(sender, addressFromString(sender.address))).toBe(
+        true,
+      );
+      expect(addressEquals(sender, addressFromPubKey(sender.pubKey))).toBe(
+        true,
+      );
+      expect(addressEquals(sender, receiver)).toBe(false);
+    });
+
+    test("get checksum", () => {
+      expect(addressChecksum(zeroAddress())).toEqual(
+        new Uint8Array([12, 116, 229, 84]),
+      );
+    });
+
+    test("zero address", () => {
+      const zero = zeroAddress();
+      expect(zero.address).toBe(ZERO_ADDRESS);
+      expect(zero.pubKey).toEqual(new Uint8Array(32));
+    });
+
+    test("is invalid address", () => {
+      expect(isValidAddress("")).toBe(false);
+      expect(isValidAddress(ZERO_ADDRESS.slice(0, -1))).toBe(false);
+      expect(isValidAddress(ZERO_ADDRESS.toLowerCase())).toBe(false);
+      expect(isValidAddress(ZERO_ADDRESS.slice(0, -1) + "A")).toBe(false);
+    });
+
+    test("is valid address", () => {
+      expect(isValidAddress(ZERO_ADDRESS)).toBe(true);
+      expect(isValidAddress(sender.address)).toBe(true);
+    });
+  });
+});
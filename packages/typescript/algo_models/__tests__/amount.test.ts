@@ -0,0 +1,44 @@
+import { expect, test, describe } from "bun:test";
+import {
+  formatAssetAmount,
+  microAlgosFromAlgos,
+  microAlgosToAlgos,
+  parseAssetAmount,
+} from "../src/index";
+
+describe("Amount", () => {
+  // Polytest Suite: Amount
+
+  describe("Amount Tests", () => {
+    // Polytest Group: Amount Tests
+
+    test("asset amounts", () => {
+      expect(formatAssetAmount(1050n, 2)).toBe("10.5");
+      expect(formatAssetAmount(5n, 0)).toBe("5");
+      expect(parseAssetAmount("10.5", 2)).toBe(1050n);
+
+      expect(() => parseAssetAmount("5.5", 0)).toThrow(
+        "has more than 0 decimal places",
+      );
+    });
+
+    test("invalid algos", () => {
+      expect(() => microAlgosFromAlgos("1.5 ALGO")).toThrow(
+        "is not a decimal amount",
+      );
+      expect(() => microAlgosFromAlgos("0.0000001")).toThrow(
+        "has more than 6 decimal places",
+      );
+      expect(() => microAlgosFromAlgos("18446744073709.551616")).toThrow(
+        "is too large",
+      );
+    });
+
+    test("algos", () => {
+      expect(microAlgosFromAlgos("1.5")).toBe(1_500_000n);
+      expect(microAlgosFromAlgos("0.000001")).toBe(1n);
+      expect(microAlgosToAlgos(1_500_000n)).toBe("1.5");
+      expect(microAlgosToAlgos(2_000_000n)).toBe("2");
+    });
+  });
+});
@@ -0,0 +1,102 @@
+import { expect, test, describe } from "bun:test";
+import * as path from "path";
+import {
+  appSpecFromJson,
+  appSpecToArc56Json,
+  appSpecValidate,
+} from "../src/index";
+
+const testDataDir = path.join(__dirname, "../../../../crates/algo_models/test_data");
+const arc56Json = await Bun.file(path.join(testDataDir, "app_spec.arc56.json")).text();
+const arc32Json = await Bun.file(path.join(testDataDir, "app_spec.arc32.json")).text();
+
+describe("App Spec", () => {
+  // Polytest Suite: App Spec
+
+  describe("App Spec Tests", () => {
+    // Polytest Group: App Spec Tests
+
+    test("invalid json", () => {
+      expect(() => appSpecFromJson("{}")).toThrow(
+        "AppSpecError: invalid app spec JSON",
+      );
+      expect(() => appSpecValidate("not json")).toThrow(
+        "AppSpecError: invalid app spec JSON",
+      );
+    });
+
+    test("validate", () => {
+      expect(appSpecValidate(arc56Json)).toEqual([]);
+      expect(appSpecValidate(arc32Json)).toEqual([]);
+
+      const spec = JSON.parse(arc56Json);
+      spec.methods[2].args[0].type = "uint65";
+      spec.state.schema.local.ints = 0;
+      expect(appSpecValidate(JSON.stringify(spec))).toEqual([
+        "argument 0 of method vote(uint65,bool,account)(uint64,uint64): invalid ABI type: uint65",
+        "local state has 1 keys, but the schema only allows 0",
+      ]);
+    });
+
+    test("convert to arc56 json", () => {
+      const converted = appSpecToArc56Json(arc32Json);
+
+      expect(JSON.parse(converted).bareActions).toEqual({
+        create: ["NoOp"],
+        call: ["DeleteApplication"],
+      });
+      expect(appSpecFromJson(converted)).toEqual(appSpecFromJson(arc32Json));
+    });
+
+    test("parse arc32", () => {
+      const spec = appSpecFromJson(arc32Json);
+
+      expect(spec.name).toBe("Calculator");
+      expect(spec.networks!.get("wGHE2Pwdvd7S12BL5FaOP20EGYesN73ktiC1qzkkit8=")).toBe(
+        1234n,
+      );
+      expect(spec.structs.get("Point")!.map((f) => f.name)).toEqual(["x", "y"]);
+      expect(spec.state.globalSchema.numUints).toBe(1n);
+      expect(spec.state.keys.globalState.get("last")!.valueType).toBe("AVMUint64");
+
+      const add = spec.methods.find((m) => m.name === "add")!;
+      expect(add.readonly).toBe(true);
+      expect(add.actions.call).toEqual(["NoOp"]);
+      expect(add.args[1].defaultValue!.source).toBe("Global");
+
+      const scale = spec.methods.find((m) => m.name === "scale")!;
+      expect(scale.args[0].structName).toBe("Point");
+      expect(scale.returns.structName).toBe("Point");
+    });
+
+    test("parse arc56", () => {
+      const spec = appSpecFromJson(arc56Json);
+
+      expect(spec.name).toBe("Voting");
+      expect(spec.arcs).toEqual([4, 56]);
+      expect(spec.methods.map((m) => m.name)).toEqual([
+        "createApplication",
+        "propose",
+        "vote",
+        "optIn",
+        "tally",
+        "latest",
+      ]);
+      expect(spec.methods[0].actions.create).toEqual(["NoOp"]);
+      expect(spec.bareActions.call).toEqual(["DeleteApplication"]);
+
+      const window = spec.structs.get("Proposal")![2];
+      expect(window.fieldType).toBeUndefined();
+      expect(window.fields!.map((f) => f.name)).toEqual(["start", "end"]);
+
+      expect(spec.state.maps.boxes.get("proposals")!.valueType).toBe("Proposal");
+      expect(spec.sourceInfo!.approval.sourceInfo[0].errorMessage).toBe(
+        "proposal does not exist",
+      );
+      expect(spec.compilerInfo!.compiler).toBe("Puya");
+      expect(spec.templateVariables!.get("VOTING_PERIOD")!.variableType).toBe(
+        "uint64",
+      );
+    });
+  });
+});
@@ -0,0 +1,111 @@
+import { expect, test, describe } from "bun:test";
+import {
+  addressFromPubKey,
+  buildApplicationCall,
+  buildAssetTransfer,
+  buildPayment,
+  SuggestedParams,
+  TransactionOptions,
+} from "../src/index";
+
+const alice = addressFromPubKey(new Uint8Array(32).fill(1));
+const bob = addressFromPubKey(new Uint8Array(32).fill(2));
+
+const params: SuggestedParams = {
+  feePerByte: 0n,
+  minFee: 1000n,
+  firstValid: 1000n,
+  lastValid: 2000n,
+  genesisId: "testnet-v1.0",
+  genesisHash: new Uint8Array(32).fill(7),
+  consensusVersion: "future",
+};
+
+const payment = (options: TransactionOptions = { sender: alice }) =>
+  buildPayment(params, options, { receiver: bob, amount: 1000n });
+
+describe("Builder", () => {
+  // Polytest Suite: Builder
+
+  describe("Builder Tests", () => {
+    // Polytest Group: Builder Tests
+
+    test("validity window", () => {
+      const txn = payment({ sender: alice, firstValid: 1500n, lastValid: 1510n });
+      expect([txn.header.firstValid, txn.header.lastValid]).toEqual([
+        1500n,
+        1510n,
+      ]);
+
+      expect(() => payment({ sender: alice, firstValid: 999n })).toThrow(
+        "validity window of 1001 rounds exceeds the maximum of 1000",
+      );
+    });
+
+    test("application call", () => {
+      // #pragma version 10, followed by enough pushint 1 to need an extra page
+      const approval = new Uint8Array(2201);
+      approval[0] = 0x0a;
+      for (let i = 1; i < approval.length; i += 2) {
+        approval.set([0x81, 0x01], i);
+      }
+
+      const txn = buildApplicationCall(
+        params,
+        { sender: alice },
+        {
+          appId: 0n,
+          onComplete: "NoOp",
+          approvalProgram: approval,
+          clearStateProgram: new Uint8Array([0x0a, 0x81, 0x01]),
+          extraProgramPages: 0n,
+        },
+      );
+
+      expect(txn.header.transactionType).toBe("ApplicationCall");
+      expect(txn.applicationCallFields?.extraProgramPages).toBe(1n);
+
+      expect(() =>
+        buildApplicationCall(
+          params,
+          { sender: alice },
+          { appId: 0n, onComplete: "NoOp", extraProgramPages: 0n },
+        ),
+      ).toThrow("programs is required");
+    });
+
+    test("asset transfer", () => {
+      const txn = buildAssetTransfer(
+        params,
+        {
+          sender: alice,
+          feeOptions: {
+            signature: { type: "Single" },
+            coveredTransactions: 2n,
+          },
+        },
+        { assetId: 1234n, amount: 5n, receiver: bob },
+      );
+
+      expect(txn.header.transactionType).toBe("AssetTransfer");
+      expect(txn.header.fee).toBe(3000n);
+      expect(txn.assetTransferFields?.assetId).toBe(1234n);
+    });
+
+    test("payment", () => {
+      const txn = payment();
+
+      expect(txn.header.transactionType).toBe("Payment");
+      expect(txn.header.sender).toEqual(alice);
+      expect(txn.header.fee).toBe(1000n);
+      expect([txn.header.firstValid, txn.header.lastValid]).toEqual([
+        1000n,
+        2000n,
+      ]);
+      expect(txn.header.genesisId).toBe("testnet-v1.0");
+      expect(txn.header.genesisHash).toEqual(new Uint8Array(32).fill(7));
+      expect(txn.payFields?.receiver).toEqual(bob);
+      expect(txn.payFields?.amount).toBe(1000n);
+    });
+  });
+});
@@ -0,0 +1,45 @@
+import { expect, test, describe } from "bun:test";
+import {
+  consensusParams,
+  consensusVersions,
+  currentConsensusParams,
+} from "../src/index";
+
+const current =
+  "https://github.com/algorandfoundation/specs/tree/953304de35264fc3ef91bcd05c123242015eeaed";
+
+describe("Consensus", () => {
+  // Polytest Suite: Consensus
+
+  describe("Consensus Tests", () => {
+    // Polytest Group: Consensus Tests
+
+    test("upgrades", () => {
+      const v17 = consensusParams("v17")!;
+      expect([v17.maxTxGroupSize, v17.logicSigVersion]).toEqual([1n, 0n]);
+
+      const v28 = consensusParams("v28")!;
+      expect([v28.maxAppProgramLen, v28.maxExtraAppProgramPages]).toEqual([
+        2048n,
+        3n,
+      ]);
+      expect(v28.maxBoxSize).toBe(0n);
+
+      const params = currentConsensusParams();
+      expect([params.maxTxGroupSize, params.maxBoxSize]).toEqual([16n, 32768n]);
+    });
+
+    test("version lookup", () => {
+      expect(consensusParams(current)).toEqual(currentConsensusParams());
+      expect(consensusParams("v41")).toEqual(currentConsensusParams());
+      expect(consensusParams("v6")).toBeUndefined();
+      expect(consensusParams("unknown")).toBeUndefined();
+    });
+
+    test("versions", () => {
+      const versions = consensusVersions();
+      expect(versions[0]).toBe("v7");
+      expect(versions[versions.length - 1]).toBe(current);
+    });
+  });
+});
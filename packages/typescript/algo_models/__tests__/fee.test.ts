@@ -0,0 +1,87 @@
+import { expect, test, describe } from "bun:test";
+import { testData } from "./common.ts";
+import {
+  estimateSignedTransactionSize,
+  estimateTransactionFee,
+  FeeOptions,
+  SuggestedParams,
+  Transaction,
+} from "../src/index";
+
+const transaction: Transaction = testData.transaction;
+
+const params = (feePerByte: bigint): SuggestedParams => ({
+  feePerByte,
+  minFee: 1000n,
+  firstValid: 49265002n,
+  lastValid: 49266002n,
+  genesisId: "testnet-v1.0",
+  genesisHash: transaction.header.genesisHash!,
+  consensusVersion: "future",
+});
+
+const options = (overrides: Partial<FeeOptions> = {}): FeeOptions => ({
+  signature: { type: "Single" },
+  coveredTransactions: 0n,
+  ...overrides,
+});
+
+describe("Fee", () => {
+  // Polytest Suite: Fee
+
+  describe("Fee Tests", () => {
+    // Polytest Group: Fee Tests
+
+    test("fee pooling", () => {
+      const covering = options({ coveredTransactions: 3n });
+
+      expect(estimateTransactionFee(transaction, params(0n), covering)).toBe(
+        4000n,
+      );
+      expect(estimateTransactionFee(transaction, params(10n), covering)).toBe(
+        5450n,
+      );
+    });
+
+    test("flat fee", () => {
+      expect(
+        estimateTransactionFee(
+          transaction,
+          params(10n),
+          options({ flatFee: 5000n }),
+        ),
+      ).toBe(5000n);
+    });
+
+    test("fee per byte", () => {
+      expect(estimateTransactionFee(transaction, params(10n), options())).toBe(
+        2450n,
+      );
+    });
+
+    test("minimum fee", () => {
+      expect(estimateTransactionFee(transaction, params(0n), options())).toBe(
+        1000n,
+      );
+    });
+
+    test("signed size", () => {
+      expect(estimateSignedTransactionSize(transaction, { type: "Single" })).toBe(
+        BigInt(testData.expectedSignedTxn.length),
+      );
+      expect(
+        estimateSignedTransactionSize(transaction, {
+          type: "Multisig",
+          subsigs: 2,
+        }),
+      ).toBe(409n);
+      expect(
+        estimateSignedTransactionSize(transaction, {
+          type: "LogicSig",
+          programSize: 100,
+          argSizes: [],
+        }),
+      ).toBe(285n);
+    });
+  });
+});
@@ -0,0 +1,95 @@
+import { expect, test, describe } from "bun:test";
+import * as ed from "@noble/ed25519";
+import {
+  KeyContext,
+  hdBip44Path,
+  hdDerivePrivateKey,
+  hdDerivePublicKey,
+  hdExtendedPublicKey,
+  hdRootKeyFromSeed,
+  hdSign,
+} from "../src/index";
+
+const hex = (s: string) => new Uint8Array(Buffer.from(s, "hex"));
+
+// BIP-39 seed of the ARC-52 test mnemonic "salon zoo engage submit smile frost later decide
+// wing sight chaos renew lizard rely canal coral scene hobby scare step bus leaf tobacco slice"
+const SEED = hex(
+  "3aff2db416b895ec3cf9a4f8d1e970bc9819920e7bf44a5e350477af0ef557b1" +
+    "511b0986debf78dd38c7c520cd44ff7c7231618f958e21ef0250733a8c1915ea",
+);
+const ROOT_KEY = hex(
+  "a8ba80028922d9fcfa055c78aede55b5c575bcd8d5a53168edf45f36d9ec8f46" +
+    "94592b4bc892907583e22669ecdf1b0409a9f3bd5549f2dd751b51360909cd05" +
+    "796b9206ec30e142e94b790a98805bf999042b55046963174ee6cee2d0375946",
+);
+const HARDENED = 0x80000000;
+
+const publicKey = (context: KeyContext, account: number, keyIndex: number) => {
+  const path = hdBip44Path(context, account, 0, keyIndex);
+  const key = hdDerivePrivateKey(ROOT_KEY, path, "Peikert");
+  return hdExtendedPublicKey(key).slice(0, 32);
+};
+
+describe("HD Keys", () => {
+  // Polytest Suite: HD Keys
+
+  describe("HD Key Tests", () => {
+    // Polytest Group: HD Key Tests
+
+    test("sign with derived key", async () => {
+      const path = hdBip44Path("Address", 0, 0, 0);
+      const key = hdDerivePrivateKey(ROOT_KEY, path, "Peikert");
+      const data = new TextEncoder().encode("arc52 signing test");
+
+      const sig = hdSign(key, data);
+      const pubKey = hdExtendedPublicKey(key).slice(0, 32);
+      expect(await ed.verifyAsync(sig, data, pubKey)).toBe(true);
+    });
+
+    test("hardened public derivation", () => {
+      const xpub = hdExtendedPublicKey(ROOT_KEY);
+      expect(() =>
+        hdDerivePublicKey(xpub, new Uint32Array([HARDENED]), "Peikert"),
+      ).toThrow("KeyDerivationError: hardened index");
+    });
+
+    test("public derivation", () => {
+      const accountPath = hdBip44Path("Address", 0, 0, 0).slice(0, 3);
+      const account = hdDerivePrivateKey(ROOT_KEY, accountPath, "Peikert");
+      const xpub = hdExtendedPublicKey(account);
+
+      const fromPrivate = hdExtendedPublicKey(
+        hdDerivePrivateKey(account, new Uint32Array([0, 7]), "Peikert"),
+      );
+      const fromPublic = hdDerivePublicKey(
+        xpub,
+        new Uint32Array([0, 7]),
+        "Peikert",
+      );
+      expect(fromPublic).toEqual(fromPrivate);
+    });
+
+    test("derive identity key", () => {
+      expect(publicKey("Identity", 0, 0)).toEqual(
+        hex("ff8b1863ef5e40d0a48c245f26a6dbdf5da94dc75a1851f51d8a04e547bd5f5a"),
+      );
+    });
+
+    test("derive address keys", () => {
+      expect(publicKey("Address", 0, 0)).toEqual(
+        hex("7bda7ac12627b2c259f1df6875d30c10b35f55b33ad2cc8ea2736eaa3ebcfab9"),
+      );
+      expect(publicKey("Address", 0, 1)).toEqual(
+        hex("5bae8828f111064637ac5061bd63bc4fcfe4a833252305f25eeab9c64ecdf519"),
+      );
+      expect(publicKey("Address", 1, 0)).toEqual(
+        hex("358d8c4382992849a764438e02b1c45c2ca4e86bbcfe10fd5b963f3610012bc9"),
+      );
+    });
+
+    test("root key from seed", () => {
+      expect(hdRootKeyFromSeed(SEED)).toEqual(ROOT_KEY);
+    });
+  });
+});
@@ -0,0 +1,64 @@
+import { expect, test, describe } from "bun:test";
+import * as path from "path";
+import {
+  accountMinBalanceTotals,
+  consensusParams,
+  currentConsensusParams,
+  decodeAccount,
+  minBalance,
+  type MinBalanceTotals,
+} from "../src/index";
+
+const testDataDir = path.join(__dirname, "../../../../crates/algo_models/test_data");
+// An application's account holding an asset and two boxes, whose minimum balance was worked out by
+// hand from go-algorand's formula
+const appAccount = decodeAccount(
+  new Uint8Array(await Bun.file(path.join(testDataDir, "app_account.msgpack")).arrayBuffer()),
+);
+
+describe("Min Balance", () => {
+  // Polytest Suite: Min Balance
+
+  describe("Min Balance Tests", () => {
+    // Polytest Group: Min Balance Tests
+
+    test("account", () => {
+      const totals = accountMinBalanceTotals(appAccount);
+      expect([totals.assets, totals.boxes, totals.boxBytes]).toEqual([1n, 2n, 1064n]);
+      expect(appAccount.minBalance).toBe(630600n);
+      expect(minBalance(totals, currentConsensusParams())).toBe(appAccount.minBalance);
+    });
+
+    test("opt ins and apps", () => {
+      const totals = accountMinBalanceTotals(appAccount);
+      totals.assets += 2n;
+      expect(minBalance(totals, currentConsensusParams())).toBe(830600n);
+
+      // 100,000 for the app, 4 * 28,500 for the integers, 2 * 50,000 for the byte slices and
+      // 100,000 for the extra page
+      totals.createdApps += 1n;
+      totals.schema.numUints += 4n;
+      totals.schema.numByteSlices += 2n;
+      totals.extraAppPages += 1n;
+      expect(minBalance(totals, currentConsensusParams())).toBe(1244600n);
+    });
+
+    test("boxes", () => {
+      const totals: MinBalanceTotals = {
+        assets: 0n,
+        createdApps: 0n,
+        optedInApps: 0n,
+        schema: { numUints: 0n, numByteSlices: 0n },
+        extraAppPages: 0n,
+        boxes: 1n,
+        boxBytes: 4n + 1024n,
+      };
+      expect(minBalance(totals, currentConsensusParams())).toBe(
+        100000n + 2500n + 400n * 1028n,
+      );
+
+      // Boxes cost nothing before they were introduced
+      expect(minBalance(totals, consensusParams("v34")!)).toBe(100000n);
+    });
+  });
+});
@@ -0,0 +1,56 @@
+import { expect, test, describe } from "bun:test";
+import { mnemonicToSeed, seedToMnemonic } from "../src/index";
+
+const ZERO_MNEMONIC = [...Array(24).fill("abandon"), "invest"].join(" ");
+const MNEMONIC =
+  "advice pudding treat near rule blouse same whisper inner electric quit surface " +
+  "sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor";
+const SEED = new Uint8Array(
+  Buffer.from(
+    "2048abcf3999de60dc97faa3db915fa3bd6cfdd0ef1712064b61fcf4b6958856",
+    "hex",
+  ),
+);
+
+describe("Mnemonic", () => {
+  // Polytest Suite: Mnemonic
+
+  describe("Mnemonic Tests", () => {
+    // Polytest Group: Mnemonic Tests
+
+    test("invalid seed length", () => {
+      expect(() => seedToMnemonic(new Uint8Array(31))).toThrow(
+        "MnemonicError: seed must be 32 bytes",
+      );
+    });
+
+    test("invalid checksum", () => {
+      expect(() =>
+        mnemonicToSeed(MNEMONIC.replace("sponsor", "abandon")),
+      ).toThrow("MnemonicError: mnemonic checksum word is invalid");
+    });
+
+    test("unknown word", () => {
+      expect(() =>
+        mnemonicToSeed(MNEMONIC.replace("pudding", "puddingg")),
+      ).toThrow("MnemonicError: unknown word in mnemonic: puddingg");
+    });
+
+    test("wrong length", () => {
+      const short = MNEMONIC.split(" ").slice(0, 24).join(" ");
+      expect(() => mnemonicToSeed(short)).toThrow(
+        "MnemonicError: mnemonic must be 25 words, but got 24",
+      );
+    });
+
+    test("mnemonic to seed", () => {
+      expect(mnemonicToSeed(MNEMONIC)).toEqual(SEED);
+      expect(mnemonicToSeed(ZERO_MNEMONIC)).toEqual(new Uint8Array(32));
+    });
+
+    test("seed to mnemonic", () => {
+      expect(seedToMnemonic(SEED)).toBe(MNEMONIC);
+      expect(seedToMnemonic(new Uint8Array(32))).toBe(ZERO_MNEMONIC);
+    });
+  });
+});
@@ -0,0 +1,202 @@
+import { expect, test, describe } from "bun:test";
+import {
+  addressFromProgram,
+  programAssemble,
+  programCheckLogicSigSize,
+  programDisassemble,
+  programExtraPages,
+  programHash,
+  programVersion,
+} from "../src/index";
+
+// #pragma version 2; int 1
+const INT_1 = new Uint8Array([0x02, 0x20, 0x01, 0x01, 0x22]);
+const INT_1_ADDRESS = "YOE6C22GHCTKAN3HU4SE5PGIPN5UKXAJTXCQUPJ3KKF5HOAH646MKKCPDA";
+
+// A version 2 program padded out to the given size with err opcodes
+const sized = (size: number) => {
+  const program = new Uint8Array(size);
+  program[0] = 0x02;
+  return program;
+};
+
+describe("Program", () => {
+  // Polytest Suite: Program
+
+  describe("Program Tests", () => {
+    // Polytest Group: Program Tests
+
+    test("extra pages", () => {
+      expect(programExtraPages(sized(2000), sized(48))).toBe(0);
+      expect(programExtraPages(sized(2000), sized(49))).toBe(1);
+      expect(programExtraPages(sized(8000), sized(192))).toBe(3);
+
+      expect(() => programExtraPages(sized(8000), sized(193))).toThrow(
+        "exceeds the limit of 8192 bytes",
+      );
+    });
+
+    test("logic sig size", () => {
+      programCheckLogicSigSize(sized(990), 10n);
+
+      expect(() => programCheckLogicSigSize(sized(990), 11n)).toThrow(
+        "ProgramError: logic signature is 1001 bytes, which exceeds the limit of 1000 bytes",
+      );
+    });
+
+    test("address", () => {
+      expect(addressFromProgram(INT_1).address).toBe(INT_1_ADDRESS);
+      expect(programHash(INT_1)).toEqual(addressFromProgram(INT_1).pubKey);
+    });
+
+    test("branch targets", () => {
+      // b 1, which lands in the middle of pushint 1
+      expect(() =>
+        programVersion(new Uint8Array([0x04, 0x42, 0x00, 0x01, 0x81, 0x01])),
+      ).toThrow("not the start of an instruction");
+
+      // l1: pushint 1; bnz l1
+      expect(() =>
+        programVersion(new Uint8Array([0x03, 0x81, 0x01, 0x40, 0xff, 0xfb])),
+      ).toThrow("branches backwards");
+      expect(
+        programVersion(new Uint8Array([0x04, 0x81, 0x01, 0x40, 0xff, 0xfb])),
+      ).toBe(4n);
+    });
+
+    test("unavailable opcode", () => {
+      // pushint 1; box_create
+      expect(() =>
+        programVersion(new Uint8Array([0x05, 0x81, 0x01, 0xb9])),
+      ).toThrow("box_create at pc 3 requires version 8");
+
+      expect(programVersion(new Uint8Array([0x08, 0x81, 0x01, 0xb9]))).toBe(8n);
+    });
+
+    test("invalid bytecode", () => {
+      expect(() => programVersion(new Uint8Array([0x0b, 0x87]))).toThrow(
+        "unknown opcode 0x87 at pc 1",
+      );
+      expect(() =>
+        programVersion(new Uint8Array([0x03, 0x80, 0x05, 0x01])),
+      ).toThrow("truncated immediate arguments");
+      expect(() => programVersion(new Uint8Array())).toThrow(
+        "program is empty",
+      );
+    });
+
+    test("version", () => {
+      expect(programVersion(INT_1)).toBe(2n);
+      expect(programVersion(new Uint8Array([0x0b]))).toBe(11n);
+
+      expect(() => programVersion(new Uint8Array([0x0c]))).toThrow(
+        "version 12 is not supported",
+      );
+    });
+  });
+
+  describe("Disassembler Tests", () => {
+    // Polytest Group: Disassembler Tests
+
+    test("fields", () => {
+      // gtxna 1 ApplicationArgs 0; acct_params_get AcctBalance; block BlkProposer
+      const program = new Uint8Array([
+        0x0b, 0x37, 0x01, 0x1a, 0x00, 0x73, 0x00, 0xd1, 0x06,
+      ]);
+
+      expect(programDisassemble(program)).toBe(
+        "#pragma version 11\n" +
+          "gtxna 1 ApplicationArgs 0\n" +
+          "acct_params_get AcctBalance\n" +
+          "block BlkProposer\n",
+      );
+    });
+
+    test("labels", () => {
+      // l1: pushint 1; bnz l2; b l1; l2: retsub
+      const program = new Uint8Array([
+        0x04, 0x81, 0x01, 0x40, 0x00, 0x03, 0x42, 0xff, 0xf8, 0x89,
+      ]);
+
+      expect(programDisassemble(program)).toBe(
+        "#pragma version 4\n" +
+          "label2:\n" +
+          "pushint 1\n" +
+          "bnz label1\n" +
+          "b label2\n" +
+          "label1:\n" +
+          "retsub\n",
+      );
+    });
+
+    test("constants", () => {
+      // intcblock 1 1000; bytecblock "hi" 0xff00; intc_1; bytec_0; bytec 1
+      const program = new Uint8Array([
+        0x02, 0x20, 0x02, 0x01, 0xe8, 0x07, 0x26, 0x02, 0x02, 0x68, 0x69, 0x02,
+        0xff, 0x00, 0x23, 0x28, 0x27, 0x01,
+      ]);
+
+      expect(programDisassemble(program)).toBe(
+        "#pragma version 2\n" +
+          "intcblock 1 1000\n" +
+          "bytecblock 0x6869 0xff00\n" +
+          "intc_1 // 1000\n" +
+          'bytec_0 // "hi"\n' +
+          "bytec 1 // 0xff00\n",
+      );
+    });
+  });
+
+  describe("Assembler Tests", () => {
+    // Polytest Group: Assembler Tests
+
+    test("assembly errors", () => {
+      expect(() => programAssemble("#pragma version 3\nbox_create")).toThrow(
+        "line 2: box_create requires version 8, but the program is version 3",
+      );
+
+      expect(() => programAssemble("#pragma version 8\nb missing")).toThrow(
+        "line 2: reference to undefined label missing",
+      );
+    });
+
+    test("labels and macros", () => {
+      const source =
+        "#pragma version 8\n" +
+        "#define ARGS txn NumAppArgs\n" +
+        "ARGS\n" +
+        "bz create\n" +
+        "int 0; return\n" +
+        "create:\n" +
+        "int 1\n" +
+        "return\n";
+
+      expect(programAssemble(source)).toEqual(
+        new Uint8Array([
+          0x08, 0x31, 0x1b, 0x41, 0x00, 0x03, 0x81, 0x00, 0x43, 0x81, 0x01, 0x43,
+        ]),
+      );
+    });
+
+    test("constant optimization", () => {
+      const source =
+        "#pragma version 4\n" +
+        "int 7\n" +
+        "int 1\n" +
+        "int 1\n" +
+        "int 2\n" +
+        "int 2\n" +
+        "int 2\n" +
+        'byte "x"\n' +
+        'method "add(uint64,uint64)uint128"\n' +
+        'byte "x"\n';
+
+      expect(programAssemble(source)).toEqual(
+        new Uint8Array([
+          0x04, 0x20, 0x02, 0x02, 0x01, 0x26, 0x01, 0x01, 0x78, 0x81, 0x07, 0x23,
+          0x23, 0x22, 0x22, 0x22, 0x28, 0x80, 0x04, 0x8a, 0xa3, 0xb6, 0x1f, 0x28,
+        ]),
+      );
+    });
+  });
+});
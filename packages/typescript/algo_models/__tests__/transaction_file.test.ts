@@ -0,0 +1,64 @@
+import { expect, test, describe } from "bun:test";
+import * as path from "path";
+import {
+  inspectTransactionFile,
+  readTransactionFile,
+  writeTransactionFile,
+  writeUnsignedTransactionFile,
+} from "../src/index";
+
+const testDataDir = path.join(__dirname, "../../../../crates/algo_models/test_data");
+const paymentStxn = new Uint8Array(
+  await Bun.file(path.join(testDataDir, "payment.stxn")).arrayBuffer(),
+);
+const multisigStxn = new Uint8Array(
+  await Bun.file(path.join(testDataDir, "multisig.stxn")).arrayBuffer(),
+);
+
+describe("Transaction File", () => {
+  // Polytest Suite: Transaction File
+
+  describe("Transaction File Tests", () => {
+    // Polytest Group: Transaction File Tests
+
+    test("read signed file", () => {
+      const transactions = readTransactionFile(paymentStxn);
+      expect(transactions.length).toBe(1);
+      expect(transactions[0].signature?.length).toBe(64);
+      expect(transactions[0].transaction.payFields?.amount).toBe(10000000n);
+
+      expect(writeTransactionFile(transactions)).toEqual(paymentStxn);
+    });
+
+    test("unsigned file", () => {
+      const [signed] = readTransactionFile(paymentStxn);
+      const file = writeUnsignedTransactionFile([signed.transaction]);
+      expect(Array.from(file.slice(0, 5))).toEqual([0x81, 0xa3, 0x74, 0x78, 0x6e]);
+
+      const [unsigned] = readTransactionFile(file);
+      expect(unsigned.signature).toBeUndefined();
+      expect(unsigned.transaction).toEqual(signed.transaction);
+    });
+
+    test("inspect", async () => {
+      const expected = await Bun.file(path.join(testDataDir, "payment.stxn.inspect")).text();
+      expect(inspectTransactionFile("payment.stxn", paymentStxn)).toBe(expected);
+    });
+
+    test("multisig file", () => {
+      const [stxn] = readTransactionFile(multisigStxn);
+      expect(stxn.signature).toBeUndefined();
+      expect(stxn.multisigSignature?.threshold).toBe(2);
+      expect(stxn.multisigSignature?.subsignatures.map((s) => s.signature !== undefined)).toEqual([
+        true,
+        false,
+        true,
+      ]);
+      expect(stxn.authAddress?.address).toBe(
+        "KUIRTXLNEOOP7FL34O2SWPD6MI3R7UN6T6HTXNGQS5R5S3PKUTFXCJE6LE",
+      );
+
+      expect(writeTransactionFile([stxn])).toEqual(multisigStxn);
+    });
+  });
+});
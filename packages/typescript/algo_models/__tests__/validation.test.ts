@@ -0,0 +1,65 @@
+import { expect, test, describe } from "bun:test";
+import { testData } from "./common.ts";
+import {
+  currentConsensusParams,
+  Transaction,
+  TransactionHeader,
+  validateTransaction,
+} from "../src/index";
+
+const transaction: Transaction = testData.transaction;
+
+const withHeader = (header: Partial<TransactionHeader>): Transaction => ({
+  ...transaction,
+  header: { ...transaction.header, ...header },
+});
+
+describe("Validation", () => {
+  // Polytest Suite: Validation
+
+  describe("Validation Tests", () => {
+    // Polytest Group: Validation Tests
+
+    test("consensus params", () => {
+      const txn = withHeader({ note: new Uint8Array(2000) });
+      const params = currentConsensusParams();
+      expect(params.maxTxnNoteBytes).toBe(1024n);
+      expect(validateTransaction(txn, params)).toHaveLength(1);
+
+      expect(
+        validateTransaction(txn, { ...params, maxTxnNoteBytes: 2048n }),
+      ).toEqual([]);
+    });
+
+    test("asset id", () => {
+      const txn: Transaction = {
+        header: { ...transaction.header, transactionType: "AssetTransfer" },
+        assetTransferFields: {
+          assetId: 0n,
+          amount: 1n,
+          receiver: transaction.payFields!.receiver,
+        },
+      };
+
+      expect(validateTransaction(txn, currentConsensusParams())).toEqual([
+        "asset transfers require a non-zero asset ID",
+      ]);
+    });
+
+    test("violations", () => {
+      const firstValid = transaction.header.lastValid + 1n;
+      const txn = withHeader({ firstValid, note: new Uint8Array(1025) });
+
+      expect(validateTransaction(txn, currentConsensusParams())).toEqual([
+        `last valid round ${transaction.header.lastValid} is before first valid round ${firstValid}`,
+        "note of 1025 bytes exceeds the maximum of 1024",
+      ]);
+    });
+
+    test("valid transaction", () => {
+      expect(validateTransaction(transaction, currentConsensusParams())).toEqual(
+        [],
+      );
+    });
+  });
+});
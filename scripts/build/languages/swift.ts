@@ -92,5 +92,12 @@ export async function buildSwift(crate: string) {
     `packages/swift/${swiftPackage}/Tests/AlgoModelsTests/Resources/test_data.json`,
   );
 
+  for (const file of fs.readdirSync(`crates/${crate}/test_data`)) {
+    fs.copyFileSync(
+      `crates/${crate}/test_data/${file}`,
+      `packages/swift/${swiftPackage}/Tests/AlgoModelsTests/Resources/${file}`,
+    );
+  }
+
   console.log(`Updated ${swiftPackage} in packages/swift/${swiftPackage}/`);
 }